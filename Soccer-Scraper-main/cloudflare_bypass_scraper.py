@@ -52,6 +52,12 @@ class CloudflareBypassScraper:
             'spain/primera-division': 'La Liga',
             'germany/bundesliga/': 'Bundesliga',
             'international/uefa-champions-league/': 'UEFA Champions League',
+            'international/uefa-europa-league/': 'UEFA Europa League',
+            'england/fa-cup/': 'FA Cup',
+            'spain/copa-del-rey/': 'Copa del Rey',
+            'netherlands/eredivisie/': 'Eredivisie',
+            'portugal/primeira-liga/': 'Primeira Liga',
+            'usa/major-league-soccer/': 'MLS',
         }
     
     def setup_driver(self):
@@ -4,6 +4,26 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone};
+
+use crate::models::ChannelMapping;
+
+/// UTC offset (in seconds) of the times stored by the scraper.
+/// livesoccertv serves UTC kick-off times to clients without a timezone cookie.
+const SOURCE_UTC_OFFSET_SECS: i32 = 0;
+
+/// A team name as favorites are stored and matched: trimmed and lowercase,
+/// so "ÖSTERSUNDS FK" and "Östersunds FK" are one team. Done here rather
+/// than in SQLite, whose NOCASE only folds ASCII. Combining marks are
+/// dropped since some capitals gain one when lowercased ("İ" to "i̇").
+pub fn team_key(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !('\u{300}'..='\u{36f}').contains(c))
+        .collect()
+}
+
 /// Represents a football fixture (match)
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -16,6 +36,8 @@ pub struct FootballFixture {
     pub fixture_time: Option<String>,
     pub venue: Option<String>,
     pub broadcasters: Vec<Broadcaster>,
+    /// Timezone the `fixture_date`/`fixture_time` were recorded in
+    pub source_offset: FixedOffset,
 }
 
 #[allow(dead_code)]
@@ -25,9 +47,37 @@ impl FootballFixture {
         format!("{} vs {}", self.home_team, self.away_team)
     }
     
-    /// Returns display time or "TBD" if not set
-    pub fn display_time(&self) -> String {
-        self.fixture_time.clone().unwrap_or_else(|| "TBD".to_string())
+    /// Kick-off as a timezone-aware datetime, if the date and time parse
+    pub fn kickoff(&self) -> Option<chrono::DateTime<FixedOffset>> {
+        let date = NaiveDate::parse_from_str(&self.fixture_date, "%Y-%m-%d").ok()?;
+        let time = self.fixture_time.as_deref()
+            .and_then(|t| NaiveTime::parse_from_str(t.trim(), "%H:%M").ok())?;
+        self.source_offset.from_local_datetime(&date.and_time(time)).single()
+    }
+    
+    /// Returns display time with a timezone label (e.g. "20:00 UTC+1"),
+    /// converted to the user's local time when `local` is set.
+    /// Falls back to the raw string, or "TBD" if not set.
+    pub fn display_time(&self, local: bool) -> String {
+        match self.kickoff() {
+            Some(kickoff) if local => {
+                let kickoff = kickoff.with_timezone(&chrono::Local);
+                format!("{} {}", kickoff.format("%H:%M"), offset_label(*kickoff.offset()))
+            }
+            Some(kickoff) => {
+                format!("{} {}", kickoff.format("%H:%M"), offset_label(self.source_offset))
+            }
+            None => self.fixture_time.clone().unwrap_or_else(|| "TBD".to_string()),
+        }
+    }
+    
+    /// Returns the fixture date (YYYY-MM-DD), shifted to local time when `local`
+    /// is set so late kick-offs land on the right day.
+    pub fn display_date(&self, local: bool) -> String {
+        match self.kickoff() {
+            Some(kickoff) if local => kickoff.with_timezone(&chrono::Local).format("%Y-%m-%d").to_string(),
+            _ => self.fixture_date.clone(),
+        }
     }
     
     /// Get all channel names for searching
@@ -49,6 +99,112 @@ impl FootballFixture {
     }
 }
 
+/// Alternate names a competition may be stored under
+fn competition_aliases(competition: &str) -> Vec<&str> {
+    let aliases: &[&str] = match competition {
+        "La Liga" => &["LaLiga", "Primera Division"],
+        "Ligue 1" => &["Ligue 1 McDonald"],
+        "MLS" => &["Major League Soccer"],
+        "Primeira Liga" => &["Liga Portugal"],
+        "Eredivisie" => &["Dutch Eredivisie"],
+        "FA Cup" => &["Emirates FA Cup"],
+        _ => &[],
+    };
+    std::iter::once(competition).chain(aliases.iter().copied()).collect()
+}
+
+/// Format a UTC offset as a short label: "UTC", "UTC+1", "UTC-3:30"
+fn offset_label(offset: FixedOffset) -> String {
+    let secs = offset.local_minus_utc();
+    if secs == 0 {
+        return "UTC".to_string();
+    }
+    let sign = if secs < 0 { '-' } else { '+' };
+    let hours = secs.abs() / 3600;
+    let minutes = (secs.abs() % 3600) / 60;
+    if minutes == 0 {
+        format!("UTC{}{}", sign, hours)
+    } else {
+        format!("UTC{}{}:{:02}", sign, hours, minutes)
+    }
+}
+
+/// Built-in broadcaster → Live TV search rules as (channel name contains,
+/// broadcaster country contains or "" for any, search query). Queries use
+/// the `|CC|` country prefixes most providers put in channel names. Tried
+/// in order, so country-specific rules come before the catch-alls.
+const DEFAULT_CHANNEL_MAPPINGS: &[(&str, &str, &str)] = &[
+    ("dazn italia", "", "|IT| DAZN"),
+    ("dazn deutschland", "", "|DE| DAZN"),
+    ("dazn españa", "", "|ES| DAZN"),
+    ("dazn espana", "", "|ES| DAZN"),
+    ("dazn", "italy", "|IT| DAZN"),
+    ("dazn", "germany", "|DE| DAZN"),
+    ("dazn", "spain", "|ES| DAZN"),
+    ("dazn", "portugal", "|PT| DAZN"),
+    ("dazn", "france", "|FR| DAZN"),
+    ("dazn", "united kingdom", "|UK| DAZN"),
+    ("dazn", "belgium", "|BE| DAZN"),
+    ("dazn", "austria", "|AT| DAZN"),
+    ("dazn", "switzerland", "|CH| DAZN"),
+    ("dazn", "japan", "|JP| DAZN"),
+    ("dazn", "canada", "|CA| DAZN"),
+    ("dazn", "usa", "|US| DAZN"),
+    ("dazn", "", "DAZN"),
+    ("sky sports", "united kingdom", "|UK| Sky Sports"),
+    ("sky sports", "ireland", "|UK| Sky Sports"),
+    ("sky sport", "italy", "|IT| Sky Sport"),
+    ("sky sport", "germany", "|DE| Sky Sport"),
+    ("sky sport", "austria", "|DE| Sky Sport"),
+    ("sky sport", "switzerland", "|DE| Sky Sport"),
+    ("sky sport", "new zealand", "|NZ| Sky Sport"),
+    ("sky sports", "mexico", "|MX| Sky Sports"),
+    ("tnt sports", "united kingdom", "|UK| TNT Sports"),
+    ("tnt sports", "ireland", "|UK| TNT Sports"),
+    ("tnt sports", "argentina", "|AR| TNT Sports"),
+    ("tnt sports", "chile", "|CL| TNT Sports"),
+    ("tnt", "usa", "|US| TNT"),
+    ("bein", "france", "|FR| beIN Sports"),
+    ("bein", "usa", "|US| beIN Sports"),
+    ("bein", "turkey", "|TR| beIN Sports"),
+    ("bein", "", "beIN Sports"),
+    ("espn", "usa", "|US| ESPN"),
+    ("espn", "brazil", "|BR| ESPN"),
+    ("espn", "netherlands", "|NL| ESPN"),
+    ("espn", "", "ESPN"),
+    ("canal+", "france", "|FR| Canal+"),
+    ("canal+", "poland", "|PL| Canal+"),
+    ("canal+", "", "Canal+"),
+    ("movistar", "spain", "|ES| Movistar"),
+    ("movistar", "", "Movistar"),
+    ("premier sports", "united kingdom", "|UK| Premier Sports"),
+    ("viaplay", "", "Viaplay"),
+    ("supersport", "", "SuperSport"),
+    ("tring", "", "Tring Sport"),
+];
+
+/// Live TV search query for a broadcaster: the first matching user
+/// mapping, else the first built-in one, else the channel name itself.
+pub fn broadcaster_search_query(mappings: &[ChannelMapping], broadcaster: &Broadcaster) -> String {
+    let channel = broadcaster.channel.to_lowercase();
+    let country = broadcaster.country.to_lowercase();
+    let matches = |pattern: &str, rule_country: &str| {
+        let pattern = pattern.trim().to_lowercase();
+        let rule_country = rule_country.trim().to_lowercase();
+        !pattern.is_empty()
+            && channel.contains(&pattern)
+            && (rule_country.is_empty() || country.contains(&rule_country))
+    };
+
+    mappings.iter()
+        .find(|m| !m.query.trim().is_empty() && matches(&m.pattern, &m.country))
+        .map(|m| m.query.trim().to_string())
+        .or_else(|| DEFAULT_CHANNEL_MAPPINGS.iter()
+            .find(|(pattern, country, _)| matches(pattern, country))
+            .map(|(_, _, query)| query.to_string()))
+        .unwrap_or_else(|| broadcaster.channel.clone())
+}
+
 /// Represents a broadcaster (channel and country)
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -111,62 +267,8 @@ impl FootballClient {
     fn query_fixtures(&self, where_clause: &str, params: &[&dyn rusqlite::ToSql]) -> Result<Vec<FootballFixture>, String> {
         let conn = rusqlite::Connection::open(&self.db_path)
             .map_err(|e| format!("Failed to open database: {}", e))?;
-        
-        let query = format!(
-            "SELECT id, home_team, away_team, competition, fixture_date, fixture_time, venue 
-             FROM fixtures 
-             {} 
-             ORDER BY fixture_date ASC, fixture_time ASC",
-            where_clause
-        );
-        
-        let mut stmt = conn.prepare(&query)
-            .map_err(|e| format!("Failed to prepare query: {}", e))?;
-        
-        let fixture_iter = stmt.query_map(params, |row| {
-            Ok(FootballFixture {
-                id: row.get(0)?,
-                home_team: row.get(1)?,
-                away_team: row.get(2)?,
-                competition: row.get(3)?,
-                fixture_date: row.get(4)?,
-                fixture_time: row.get(5)?,
-                venue: row.get(6)?,
-                broadcasters: Vec::new(), // Will be populated later
-            })
-        }).map_err(|e| format!("Query failed: {}", e))?;
-        
-        let mut fixtures: Vec<FootballFixture> = Vec::new();
-        for fixture_result in fixture_iter {
-            if let Ok(fixture) = fixture_result {
-                fixtures.push(fixture);
-            }
-        }
-        
-        // Load broadcasters for each fixture
-        for fixture in &mut fixtures {
-            let broadcaster_query = 
-                "SELECT country, channel FROM broadcasters WHERE fixture_id = ? ORDER BY country, channel";
-            
-            if let Ok(mut stmt) = conn.prepare(broadcaster_query) {
-                let broadcaster_iter = stmt.query_map([fixture.id], |row| {
-                    Ok(Broadcaster {
-                        country: row.get(0)?,
-                        channel: row.get(1)?,
-                    })
-                });
-                
-                if let Ok(iter) = broadcaster_iter {
-                    for b in iter.flatten() {
-                        fixture.broadcasters.push(b);
-                    }
-                }
-            }
-        }
-        
-        Ok(fixtures)
+        query_fixtures_in(&conn, where_clause, params)
     }
-    
     /// Get today's fixtures
     pub fn get_today(&self) -> Result<Vec<FootballFixture>, String> {
         let today = chrono::Local::now().format("%Y-%m-%d").to_string();
@@ -197,13 +299,38 @@ impl FootballClient {
         self.query_fixtures("WHERE fixture_date >= ?", &[&today])
     }
     
-    /// Get fixtures for a specific competition
+    /// Get fixtures for a specific competition.
+    /// Matching is tolerant of prefixes and alternate names
+    /// (e.g. "Europa League" also matches "UEFA Europa League").
     pub fn get_by_competition(&self, competition: &str) -> Result<Vec<FootballFixture>, String> {
         let today = chrono::Local::now().format("%Y-%m-%d").to_string();
-        self.query_fixtures(
-            "WHERE fixture_date >= ? AND competition LIKE ?",
-            &[&today, &format!("%{}%", competition)]
-        )
+        let patterns: Vec<String> = competition_aliases(competition)
+            .iter()
+            .map(|name| format!("%{}%", name.split_whitespace().collect::<Vec<_>>().join("%")))
+            .collect();
+        
+        let like_clauses = vec!["competition LIKE ?"; patterns.len()];
+        let where_clause = format!("WHERE fixture_date >= ? AND ({})", like_clauses.join(" OR "));
+        
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&today];
+        for pattern in &patterns {
+            params.push(pattern);
+        }
+        
+        self.query_fixtures(&where_clause, &params)
+    }
+    
+    /// Get upcoming fixtures involving any of the given teams, given as
+    /// [`team_key`]s
+    pub fn get_by_teams(&self, teams: &[String]) -> Result<Vec<FootballFixture>, String> {
+        if teams.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let mut fixtures = self.query_fixtures("WHERE fixture_date >= ?", &[&today])?;
+        fixtures.retain(|f| teams.contains(&team_key(&f.home_team)) || teams.contains(&team_key(&f.away_team)));
+        Ok(fixtures)
     }
     
     /// Get database statistics
@@ -254,6 +381,96 @@ impl FootballClient {
     }
 }
 
+/// Runs a fixtures query on an open database and attaches broadcasters.
+/// Blank-team rows are skipped and duplicates merged.
+fn query_fixtures_in(conn: &rusqlite::Connection, where_clause: &str, params: &[&dyn rusqlite::ToSql]) -> Result<Vec<FootballFixture>, String> {
+    let query = format!(
+        "SELECT id, home_team, away_team, competition, fixture_date, fixture_time, venue 
+         FROM fixtures 
+         {} 
+         ORDER BY fixture_date ASC, fixture_time ASC",
+        where_clause
+    );
+    
+    let mut stmt = conn.prepare(&query)
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    
+    let fixture_iter = stmt.query_map(params, |row| {
+        Ok(FootballFixture {
+            id: row.get(0)?,
+            home_team: row.get(1)?,
+            away_team: row.get(2)?,
+            competition: row.get(3)?,
+            fixture_date: row.get(4)?,
+            fixture_time: row.get(5)?,
+            venue: row.get(6)?,
+            broadcasters: Vec::new(), // Will be populated later
+            source_offset: FixedOffset::east_opt(SOURCE_UTC_OFFSET_SECS)
+                .expect("valid source offset"),
+        })
+    }).map_err(|e| format!("Query failed: {}", e))?;
+    
+    // Rows with a missing team name are scraper noise
+    let mut fixtures: Vec<FootballFixture> = Vec::new();
+    for fixture_result in fixture_iter {
+        if let Ok(fixture) = fixture_result {
+            if !fixture.home_team.trim().is_empty() && !fixture.away_team.trim().is_empty() {
+                fixtures.push(fixture);
+            }
+        }
+    }
+    
+    // Load broadcasters for each fixture
+    for fixture in &mut fixtures {
+        let broadcaster_query = 
+            "SELECT country, channel FROM broadcasters WHERE fixture_id = ? ORDER BY country, channel";
+        
+        if let Ok(mut stmt) = conn.prepare(broadcaster_query) {
+            let broadcaster_iter = stmt.query_map([fixture.id], |row| {
+                Ok(Broadcaster {
+                    country: row.get(0)?,
+                    channel: row.get(1)?,
+                })
+            });
+            
+            if let Ok(iter) = broadcaster_iter {
+                for b in iter.flatten() {
+                    fixture.broadcasters.push(b);
+                }
+            }
+        }
+    }
+    
+    Ok(dedupe_fixtures(fixtures))
+}
+
+/// Drops duplicate fixtures (same teams and date, e.g. scraped from two
+/// sources), keeping the one with the most broadcasters in the position
+/// of the first.
+fn dedupe_fixtures(fixtures: Vec<FootballFixture>) -> Vec<FootballFixture> {
+    let key = |f: &FootballFixture| (
+        f.home_team.trim().to_lowercase(),
+        f.away_team.trim().to_lowercase(),
+        f.fixture_date.clone(),
+    );
+    let mut index: HashMap<(String, String, String), usize> = HashMap::new();
+    let mut unique: Vec<FootballFixture> = Vec::with_capacity(fixtures.len());
+    for fixture in fixtures {
+        match index.get(&key(&fixture)) {
+            Some(&i) => {
+                if fixture.broadcasters.len() > unique[i].broadcasters.len() {
+                    unique[i] = fixture;
+                }
+            }
+            None => {
+                index.insert(key(&fixture), unique.len());
+                unique.push(fixture);
+            }
+        }
+    }
+    unique
+}
+
 /// Database statistics
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -272,12 +489,20 @@ pub enum FootballCategory {
     Today,
     Tomorrow,
     ThisWeek,
+    MyTeams,
+    AllCompetitions,
     PremierLeague,
     LaLiga,
     SerieA,
     Bundesliga,
     Ligue1,
     ChampionsLeague,
+    EuropaLeague,
+    FaCup,
+    CopaDelRey,
+    Eredivisie,
+    PrimeiraLiga,
+    Mls,
 }
 
 impl FootballCategory {
@@ -286,12 +511,20 @@ impl FootballCategory {
             FootballCategory::Today,
             FootballCategory::Tomorrow,
             FootballCategory::ThisWeek,
+            FootballCategory::MyTeams,
+            FootballCategory::AllCompetitions,
             FootballCategory::PremierLeague,
             FootballCategory::LaLiga,
             FootballCategory::SerieA,
             FootballCategory::Bundesliga,
             FootballCategory::Ligue1,
             FootballCategory::ChampionsLeague,
+            FootballCategory::EuropaLeague,
+            FootballCategory::FaCup,
+            FootballCategory::CopaDelRey,
+            FootballCategory::Eredivisie,
+            FootballCategory::PrimeiraLiga,
+            FootballCategory::Mls,
         ]
     }
     
@@ -300,12 +533,20 @@ impl FootballCategory {
             FootballCategory::Today => "⚽ Today's Matches",
             FootballCategory::Tomorrow => "📅 Tomorrow",
             FootballCategory::ThisWeek => "📆 This Week",
+            FootballCategory::MyTeams => "⭐ My Teams",
+            FootballCategory::AllCompetitions => "🌍 All Competitions",
             FootballCategory::PremierLeague => "🏴󠁧󠁢󠁥󠁮󠁧󠁿 Premier League",
             FootballCategory::LaLiga => "🇪🇸 La Liga",
             FootballCategory::SerieA => "🇮🇹 Serie A",
             FootballCategory::Bundesliga => "🇩🇪 Bundesliga",
             FootballCategory::Ligue1 => "🇫🇷 Ligue 1",
             FootballCategory::ChampionsLeague => "🏆 Champions League",
+            FootballCategory::EuropaLeague => "🥈 Europa League",
+            FootballCategory::FaCup => "🏴󠁧󠁢󠁥󠁮󠁧󠁿 FA Cup",
+            FootballCategory::CopaDelRey => "🇪🇸 Copa del Rey",
+            FootballCategory::Eredivisie => "🇳🇱 Eredivisie",
+            FootballCategory::PrimeiraLiga => "🇵🇹 Primeira Liga",
+            FootballCategory::Mls => "🇺🇸 MLS",
         }
     }
     
@@ -318,9 +559,20 @@ impl FootballCategory {
             FootballCategory::Bundesliga => Some("Bundesliga"),
             FootballCategory::Ligue1 => Some("Ligue 1"),
             FootballCategory::ChampionsLeague => Some("Champions League"),
+            FootballCategory::EuropaLeague => Some("Europa League"),
+            FootballCategory::FaCup => Some("FA Cup"),
+            FootballCategory::CopaDelRey => Some("Copa del Rey"),
+            FootballCategory::Eredivisie => Some("Eredivisie"),
+            FootballCategory::PrimeiraLiga => Some("Primeira Liga"),
+            FootballCategory::Mls => Some("MLS"),
             _ => None,
         }
     }
+    
+    /// Whether this category lists a competition (rather than a date range)
+    pub fn is_competition(&self) -> bool {
+        *self == FootballCategory::AllCompetitions || self.competition_filter().is_some()
+    }
 }
 
 /// Message for async loading
@@ -332,6 +584,9 @@ pub enum FootballMessage {
     StatsLoaded(FootballStats),
 }
 
+/// How long loaded fixtures count as fresh when nothing else is set.
+const FIXTURES_FRESH_FOR: std::time::Duration = std::time::Duration::from_secs(300);
+
 /// Cache for football fixtures with async loading
 pub struct FootballCache {
     cache: HashMap<FootballCategory, Vec<FootballFixture>>,
@@ -342,6 +597,7 @@ pub struct FootballCache {
     pub last_error: Option<String>,
     pub stats: Option<FootballStats>,
     db_path: Option<PathBuf>,
+    favorite_teams: Vec<String>,
 }
 
 #[allow(dead_code)]
@@ -361,6 +617,7 @@ impl FootballCache {
             last_error: None,
             stats: None,
             db_path,
+            favorite_teams: Vec::new(),
         }
     }
     
@@ -400,11 +657,28 @@ impl FootballCache {
         self.db_path.as_ref().map(|p| p.to_string_lossy().to_string())
     }
     
+    /// Set the teams used by `FootballCategory::MyTeams`.
+    /// Invalidates the cached "My Teams" fixtures when the list changes.
+    pub fn set_favorite_teams(&mut self, teams: Vec<String>) {
+        let mut teams = teams;
+        teams.sort();
+        if teams != self.favorite_teams {
+            self.favorite_teams = teams;
+            self.cache.remove(&FootballCategory::MyTeams);
+            self.last_fetch.remove(&FootballCategory::MyTeams);
+        }
+    }
+    
     /// Request fixtures for a category
     pub fn request_category(&mut self, category: FootballCategory) {
-        // Check cache validity (5 minutes)
+        self.request_category_max_age(category, FIXTURES_FRESH_FOR);
+    }
+    
+    /// Request fixtures for a category unless they were loaded less than
+    /// `max_age` ago. The old fixtures stay visible while reloading.
+    pub fn request_category_max_age(&mut self, category: FootballCategory, max_age: std::time::Duration) {
         if let Some(last_fetch) = self.last_fetch.get(&category) {
-            if last_fetch.elapsed() < std::time::Duration::from_secs(300) {
+            if last_fetch.elapsed() < max_age {
                 return;
             }
         }
@@ -423,12 +697,14 @@ impl FootballCache {
         
         self.pending_requests.insert(category);
         let sender = self.sender.clone();
+        let favorite_teams = self.favorite_teams.clone();
         
         std::thread::spawn(move || {
             let client = FootballClient::new(db_path);
             
             let result = match category {
                 FootballCategory::Today => client.get_today(),
+                FootballCategory::MyTeams => client.get_by_teams(&favorite_teams),
                 FootballCategory::Tomorrow => client.get_tomorrow(),
                 FootballCategory::ThisWeek => client.get_this_week(),
                 _ => {
@@ -494,6 +770,11 @@ impl FootballCache {
         self.cache.get(&category)
     }
     
+    /// Time since a category was last loaded
+    pub fn loaded_ago(&self, category: FootballCategory) -> Option<std::time::Duration> {
+        self.last_fetch.get(&category).map(|t| t.elapsed())
+    }
+    
     /// Check if a category is loading
     pub fn is_loading(&self, category: FootballCategory) -> bool {
         self.pending_requests.contains(&category)
@@ -508,3 +789,60 @@ impl FootballCache {
         self.db_path = Self::find_database();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_fixtures_dedupes_and_skips_blank_teams() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE fixtures (id INTEGER PRIMARY KEY, home_team TEXT, away_team TEXT,
+                competition TEXT, fixture_date TEXT, fixture_time TEXT, venue TEXT);
+             CREATE TABLE broadcasters (fixture_id INTEGER, country TEXT, channel TEXT);
+             INSERT INTO fixtures VALUES
+                (1, 'Arsenal', 'Chelsea', 'Premier League', '2025-01-04', '12:30', NULL),
+                (2, 'arsenal ', 'Chelsea', 'Premier League', '2025-01-04', '12:30', NULL),
+                (3, '', 'Chelsea', 'Premier League', '2025-01-04', '15:00', NULL),
+                (4, 'Everton', NULL, 'Premier League', '2025-01-04', '15:00', NULL),
+                (5, 'Arsenal', 'Chelsea', 'FA Cup', '2025-01-11', '17:45', NULL);
+             INSERT INTO broadcasters VALUES
+                (1, 'UK', 'Sky Sports'),
+                (2, 'UK', 'Sky Sports'), (2, 'USA', 'Peacock'),
+                (3, 'UK', 'BBC One');",
+        ).unwrap();
+
+        let fixtures = query_fixtures_in(&conn, "", &[]).unwrap();
+        let ids: Vec<i64> = fixtures.iter().map(|f| f.id).collect();
+        assert_eq!(ids, vec![2, 5]);
+        assert_eq!(fixtures[0].broadcasters.len(), 2);
+    }
+
+    #[test]
+    fn test_team_key() {
+        assert_eq!(team_key("Östersunds FK"), team_key("ÖSTERSUNDS FK"));
+        assert_eq!(team_key("İstanbul Başakşehir"), team_key("İSTANBUL BAŞAKŞEHİR"));
+        assert_eq!(team_key(" Arsenal "), "arsenal");
+        assert_ne!(team_key("Arsenal"), team_key("Arsenal Tula"));
+    }
+
+    #[test]
+    fn test_broadcaster_search_query() {
+        let broadcaster = |country: &str, channel: &str| Broadcaster {
+            country: country.to_string(),
+            channel: channel.to_string(),
+        };
+        let custom = vec![ChannelMapping {
+            pattern: "Sky Sports".to_string(),
+            country: String::new(),
+            query: "UK: SKY SPORTS".to_string(),
+        }];
+
+        assert_eq!(broadcaster_search_query(&[], &broadcaster("United Kingdom", "TNT Sports 1")), "|UK| TNT Sports");
+        assert_eq!(broadcaster_search_query(&[], &broadcaster("Italy", "DAZN")), "|IT| DAZN");
+        assert_eq!(broadcaster_search_query(&[], &broadcaster("United Kingdom", "Sky Sports Main Event")), "|UK| Sky Sports");
+        assert_eq!(broadcaster_search_query(&custom, &broadcaster("United Kingdom", "Sky Sports Main Event")), "UK: SKY SPORTS");
+        assert_eq!(broadcaster_search_query(&custom, &broadcaster("Nigeria", "NTA Sports 24")), "NTA Sports 24");
+    }
+}
@@ -1,9 +1,12 @@
-﻿pub mod xtream;
+﻿pub mod http;
+pub mod xtream;
 pub mod omdb;
 pub mod football;
 pub mod scraper_integration;
+pub mod stream_probe;
+pub mod updates;
 
-pub use xtream::XtreamClient;
+pub use xtream::{normalize_server_url, XtreamClient};
 pub use omdb::{DiscoverCache, DiscoverItem, DiscoverCategory, DiscoverContentType};
 pub use football::{FootballCache, FootballFixture, FootballCategory};
-pub use scraper_integration::{ScraperManager, ScrapingStatus};
+pub use scraper_integration::{ScraperManager, ScraperSetup, ScrapingStatus};
@@ -87,6 +87,30 @@ pub struct DiscoverItem {
     pub runtime: Option<String>,
     pub director: Option<String>,
     pub actors: Option<String>,
+    /// Trailer the player is given, see [`trailer_search_url`].
+    pub trailer_url: Option<String>,
+}
+
+/// Prefix MPV's yt-dlp hook resolves to the top YouTube search result.
+const TRAILER_SEARCH_PREFIX: &str = "ytdl://ytsearch1:";
+
+/// Player URL for a title's trailer. OMDb doesn't expose trailers, so MPV
+/// searches YouTube through yt-dlp and plays the first result.
+pub fn trailer_search_url(title: &str, year: Option<&str>) -> String {
+    let query = match year {
+        Some(year) => format!("{} {} trailer", title, year),
+        None => format!("{} trailer", title),
+    };
+    format!("{}{}", TRAILER_SEARCH_PREFIX, query)
+}
+
+/// Page the browser opens for a trailer when the player can't run the
+/// search itself: the YouTube results for the same query.
+pub fn trailer_page_url(trailer_url: &str) -> String {
+    match trailer_url.strip_prefix(TRAILER_SEARCH_PREFIX) {
+        Some(query) => format!("https://www.youtube.com/results?search_query={}", urlencoding::encode(query)),
+        None => trailer_url.to_string(),
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -173,11 +197,7 @@ impl OmdbClient {
     }
     
     pub fn with_api_key(api_key: String) -> Self {
-        let client = reqwest::blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(15))
-            .danger_accept_invalid_certs(true)
-            .build()
-            .expect("Failed to create HTTP client");
+        let client = super::http::client();
             
         Self { client, api_key }
     }
@@ -195,7 +215,7 @@ impl OmdbClient {
             url.push_str(&format!("&y={}", y));
         }
         
-        eprintln!("[OMDb] Searching: {}", url);
+        log::debug!("[OMDb] Searching: {}", url);
         
         let response = self.client
             .get(&url)
@@ -222,7 +242,7 @@ impl OmdbClient {
         }
         
         let items = result.search.unwrap_or_default();
-        eprintln!("[OMDb] Found {} results for '{}'", items.len(), query);
+        log::debug!("[OMDb] Found {} results for '{}'", items.len(), query);
         
         // Convert search items to DiscoverItems
         let discover_items: Vec<DiscoverItem> = items.into_iter().map(|item| {
@@ -233,11 +253,13 @@ impl OmdbClient {
             };
             
             let poster_url = item.poster.filter(|p| p != "N/A" && !p.is_empty());
+            let year = Some(item.year).filter(|y| y != "N/A");
+            let trailer_url = Some(trailer_search_url(&item.title, year.as_deref()));
             
             DiscoverItem {
                 id: item.imdb_id.clone(),
                 title: item.title,
-                year: Some(item.year).filter(|y| y != "N/A"),
+                year,
                 overview: String::new(), // Not available in search results
                 rating: None, // Not available in search results
                 votes: None,
@@ -248,6 +270,7 @@ impl OmdbClient {
                 runtime: None,
                 director: None,
                 actors: None,
+                trailer_url,
             }
         }).collect();
         
@@ -295,11 +318,14 @@ impl OmdbClient {
             .unwrap_or_default();
             
         let poster_url = data.poster.filter(|p| p != "N/A" && !p.is_empty());
+        let title = data.title.unwrap_or_else(|| "Unknown".to_string());
+        let year = data.year.filter(|y| y != "N/A");
+        let trailer_url = Some(trailer_search_url(&title, year.as_deref()));
         
         Ok(DiscoverItem {
             id: imdb_id.to_string(),
-            title: data.title.unwrap_or_else(|| "Unknown".to_string()),
-            year: data.year.filter(|y| y != "N/A"),
+            title,
+            year,
             overview: data.plot.unwrap_or_default(),
             rating,
             votes: data.imdb_votes.filter(|v| v != "N/A"),
@@ -310,6 +336,7 @@ impl OmdbClient {
             runtime: data.runtime.filter(|r| r != "N/A"),
             director: data.director.filter(|d| d != "N/A"),
             actors: data.actors.filter(|a| a != "N/A"),
+            trailer_url,
         })
     }
     
@@ -397,16 +424,16 @@ impl DiscoverCache {
         let sender = self.sender.clone();
 
         std::thread::spawn(move || {
-            eprintln!("[DiscoverCache] Loading {:?}", category);
+            log::debug!("[DiscoverCache] Loading {:?}", category);
             let client = OmdbClient::new();
             
             match client.get_category(category) {
                 Ok(items) => {
-                    eprintln!("[DiscoverCache] Loaded {} items for {:?}", items.len(), category);
+                    log::debug!("[DiscoverCache] Loaded {} items for {:?}", items.len(), category);
                     let _ = sender.send(DiscoverMessage::CategoryLoaded(category, items));
                 }
                 Err(e) => {
-                    eprintln!("[DiscoverCache] Error loading {:?}: {}", category, e);
+                    log::warn!("[DiscoverCache] Error loading {:?}: {}", category, e);
                     let _ = sender.send(DiscoverMessage::CategoryError(category, e));
                 }
             }
@@ -6,6 +6,35 @@
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 
+/// Written into the venv once `pip install -r requirements.txt` succeeded.
+const REQUIREMENTS_MARKER: &str = "requirements.installed";
+
+/// What the scraper needs and what of it is in place.
+#[derive(Debug, Clone, Default)]
+pub struct ScraperSetup {
+    /// The Soccer-Scraper-main folder with `fixtures.py` and `requirements.txt`
+    pub scraper_found: bool,
+    /// Version string of the system Python, if one was found on PATH
+    pub python: Option<String>,
+    /// `.venv` exists in the scraper folder
+    pub venv: bool,
+    /// Requirements were installed into the venv from here
+    pub requirements: bool,
+}
+
+impl ScraperSetup {
+    /// Whether the scraper can run (it falls back to the system Python
+    /// without a venv).
+    pub fn is_ready(&self) -> bool {
+        self.scraper_found && (self.venv || self.python.is_some())
+    }
+
+    /// Whether "Install" has something to do.
+    pub fn can_install(&self) -> bool {
+        self.scraper_found && self.python.is_some() && !(self.venv && self.requirements)
+    }
+}
+
 /// Status of a scraping operation
 #[derive(Debug, Clone)]
 pub enum ScrapingStatus {
@@ -20,6 +49,7 @@ pub enum ScrapingStatus {
 }
 
 /// Manager for Python scraper integration
+#[derive(Clone)]
 pub struct ScraperManager {
     scraper_dir: PathBuf,
     status: ScrapingStatus,
@@ -52,6 +82,62 @@ impl ScraperManager {
         self.scraper_dir.join("output").join("fixtures.db")
     }
 
+    /// Checks for the scraper, Python, the venv and its requirements.
+    /// Runs `python --version`, so don't call it every frame.
+    pub fn check_setup(&self) -> ScraperSetup {
+        let venv = self.venv_python().exists();
+        ScraperSetup {
+            scraper_found: self.is_available(),
+            python: system_python().map(|(_, version)| version),
+            venv,
+            requirements: venv && self.scraper_dir.join(".venv").join(REQUIREMENTS_MARKER).exists(),
+        }
+    }
+
+    /// The Python inside the scraper's venv.
+    pub fn venv_python(&self) -> PathBuf {
+        let venv = self.scraper_dir.join(".venv");
+        if cfg!(windows) {
+            venv.join("Scripts").join("python.exe")
+        } else {
+            venv.join("bin").join("python")
+        }
+    }
+
+    /// The venv's Python when set up, the system one otherwise.
+    fn python_command(&self) -> std::ffi::OsString {
+        let venv_python = self.venv_python();
+        if venv_python.exists() {
+            return venv_python.into_os_string();
+        }
+        let fallback = if cfg!(windows) { "python" } else { "python3" };
+        system_python().map_or(fallback, |(cmd, _)| cmd).into()
+    }
+
+    /// Creates the venv if needed and installs `requirements.txt` into it.
+    /// Blocks for as long as pip takes; call from a background thread.
+    pub fn install_blocking(&self) -> Result<(), String> {
+        if !self.is_available() {
+            return Err("Soccer Scraper not found. Ensure Soccer-Scraper-main directory exists.".to_string());
+        }
+
+        if !self.venv_python().exists() {
+            let (python, _) = system_python()
+                .ok_or("Python 3 not found. Install it from python.org and make sure it is on PATH.")?;
+            log::info!("[Scraper] Creating venv with {}", python);
+            run_setup_step(Command::new(python).current_dir(&self.scraper_dir).args(["-m", "venv", ".venv"]))?;
+        }
+
+        log::info!("[Scraper] Installing requirements");
+        run_setup_step(
+            Command::new(self.venv_python())
+                .current_dir(&self.scraper_dir)
+                .args(["-m", "pip", "install", "-r", "requirements.txt"]),
+        )?;
+        std::fs::write(self.scraper_dir.join(".venv").join(REQUIREMENTS_MARKER), "")
+            .map_err(|e| e.to_string())
+    }
+
     /// Trigger a scraping operation (non-blocking)
     ///
     /// Returns immediately with Scraping status.
@@ -67,9 +153,10 @@ impl ScraperManager {
         ScrapingStatus::Scraping
     }
 
-    /// Execute scraping in background and return immediately
+    /// Runs `fixtures.py scrape` and waits for it to finish
     ///
-    /// Call this from a background thread to actually run the scraper.
+    /// Call this from a background thread. Succeeds only if the scraper
+    /// exited cleanly and actually wrote the fixtures database.
     pub fn scrape_blocking(&mut self) -> ScrapingStatus {
         if !self.is_available() {
             let err = "Soccer Scraper not found. Ensure Soccer-Scraper-main directory exists.".to_string();
@@ -77,11 +164,12 @@ impl ScraperManager {
             return self.status.clone();
         }
 
-        // Detect Python executable
-        let python_cmd = if cfg!(windows) { "python" } else { "python3" };
+        let database = self.get_database_path();
+        let modified = || std::fs::metadata(&database).and_then(|m| m.modified()).ok();
+        let before = modified();
 
         // Try to run: python fixtures.py scrape
-        let result = Command::new(python_cmd)
+        let result = Command::new(self.python_command())
             .current_dir(&self.scraper_dir)
             .arg("fixtures.py")
             .arg("scrape")
@@ -89,31 +177,24 @@ impl ScraperManager {
             .stderr(Stdio::piped())
             .output();
 
-        match result {
-            Ok(output) => {
-                if output.status.success() {
-                    let message = format!(
-                        "Scraping completed successfully. Database: {:?}",
-                        self.get_database_path()
-                    );
-                    self.status = ScrapingStatus::Success(message.clone());
-                    ScrapingStatus::Success(message)
+        self.status = match result {
+            Ok(output) if output.status.success() => {
+                if modified().is_some() && modified() != before {
+                    ScrapingStatus::Success("Fixtures updated successfully!".to_string())
                 } else {
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-                    let error_msg = format!("Scraping failed: {}", stderr);
-                    self.status = ScrapingStatus::Error(error_msg.clone());
-                    ScrapingStatus::Error(error_msg)
+                    ScrapingStatus::Error("The scraper finished but didn't update the fixtures database".to_string())
                 }
             }
-            Err(e) => {
-                let error_msg = format!(
-                    "Failed to run scraper. Ensure Python is installed and in PATH. Error: {}",
-                    e
-                );
-                self.status = ScrapingStatus::Error(error_msg.clone());
-                ScrapingStatus::Error(error_msg)
+            Ok(output) => {
+                log::warn!("[Scraper] fixtures.py failed: {}", String::from_utf8_lossy(&output.stderr));
+                ScrapingStatus::Error(format!("Scraper failed: {}", error_summary(&output.stderr)))
             }
-        }
+            Err(e) => ScrapingStatus::Error(format!(
+                "Failed to run scraper. Ensure Python is installed and in PATH. Error: {}",
+                e
+            )),
+        };
+        self.status.clone()
     }
 
     /// Query today's fixtures
@@ -137,9 +218,7 @@ impl ScraperManager {
             return Err("Soccer Scraper not found".to_string());
         }
 
-        let python_cmd = if cfg!(windows) { "python" } else { "py" };
-
-        let mut cmd = Command::new(python_cmd);
+        let mut cmd = Command::new(self.python_command());
         cmd.current_dir(&self.scraper_dir)
             .arg("fixtures.py")
             .args(args)
@@ -159,6 +238,43 @@ impl ScraperManager {
     }
 }
 
+/// The system Python 3 command and its version, trying the usual names.
+fn system_python() -> Option<(&'static str, String)> {
+    let candidates: &[&'static str] = if cfg!(windows) { &["python", "py"] } else { &["python3", "python"] };
+    candidates.iter().find_map(|&cmd| {
+        let output = Command::new(cmd).arg("--version").output().ok()?;
+        // Python 2 printed its version to stderr
+        let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (output.status.success() && version.starts_with("Python 3")).then_some((cmd, version))
+    })
+}
+
+/// Runs one setup command, turning a failure into its error summary.
+fn run_setup_step(cmd: &mut Command) -> Result<(), String> {
+    let output = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| e.to_string())?;
+    if output.status.success() {
+        return Ok(());
+    }
+    log::warn!("[Scraper] Setup step failed: {}", String::from_utf8_lossy(&output.stderr));
+    Err(error_summary(&output.stderr))
+}
+
+/// The last non-empty line of a Python error output: the exception
+/// message of a traceback, or pip's final complaint.
+fn error_summary(stderr: &[u8]) -> String {
+    String::from_utf8_lossy(stderr)
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or("Unknown error")
+        .to_string()
+}
+
 impl Default for ScraperManager {
     fn default() -> Self {
         Self::new()
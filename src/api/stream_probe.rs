@@ -0,0 +1,171 @@
+//! Stream availability probing for the "Check channels" button.
+//!
+//! Each stream URL is requested once and the connection dropped as soon as
+//! the response headers arrive, so no video is downloaded. Probes run one
+//! at a time with a pause in between, and wait while something is playing:
+//! most providers allow a single connection per account, and a burst of
+//! stream requests can get the account flagged.
+//!
+//! The optional preflight before playback goes a little further and reads
+//! the first few KB, to catch panels that answer 200 with an error page.
+
+use std::io::Read;
+use std::time::Duration;
+
+/// Pause between two probes.
+const PROBE_INTERVAL: Duration = Duration::from_millis(1500);
+
+/// How often a probe waiting for playback to end checks again.
+const PLAYBACK_WAIT: Duration = Duration::from_secs(2);
+
+/// How long a single stream gets to answer.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// How long the preflight waits before playback, kept short since the
+/// user is waiting for the player.
+const PREFLIGHT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Bytes read by the preflight; enough to tell a page from video.
+const PREFLIGHT_BYTES: u64 = 4096;
+
+/// Shown when the provider refuses the stream or answers with a page.
+const EXPIRED_MESSAGE: &str = "Stream unavailable — your subscription may have expired";
+
+/// Check whether a stream URL answers with a successful status.
+/// Redirects are followed; timeouts and connection errors count as offline.
+/// `None` means the provider refused to say: it rejected the login or is
+/// rate limiting, which tells nothing about the channel itself.
+pub fn probe_stream(client: &reqwest::blocking::Client, url: &str) -> Option<bool> {
+    match client.get(url.trim()).send() {
+        // Dropping the response closes the connection without reading the body
+        Ok(response) => probe_status(response.status().as_u16()),
+        Err(_) => Some(false),
+    }
+}
+
+/// Reads a probe's HTTP status as online, offline or unknown.
+fn probe_status(status: u16) -> Option<bool> {
+    match status {
+        200..=299 => Some(true),
+        401 | 403 | 429 | 458 => None,
+        _ => Some(false),
+    }
+}
+
+/// Reads the start of a stream before the player opens it. The error
+/// explains why it won't play: no answer, an error status, or a page where
+/// video should be (usually an expired login).
+pub fn preflight(url: &str) -> Result<(), String> {
+    let client = super::http::provider_client_builder()
+        .timeout(PREFLIGHT_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Couldn't check the stream: {}", e))?;
+    let response = client
+        .get(url.trim())
+        .send()
+        .map_err(|_| "Stream unavailable — the server didn't answer".to_string())?;
+
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let mut head = Vec::new();
+    // A stalled body after good headers is left for the player to judge
+    let _ = response.take(PREFLIGHT_BYTES).read_to_end(&mut head);
+
+    let result = check_response(status, &content_type, &head);
+    if let Err(e) = &result {
+        log::warn!("[Probe] Preflight failed ({}, {}): {}", status, content_type, e);
+    }
+    result
+}
+
+/// Judges a stream response by its status, content type and first bytes.
+/// Video and HLS playlists pass; HTML and JSON are error pages.
+fn check_response(status: u16, content_type: &str, head: &[u8]) -> Result<(), String> {
+    match status {
+        200..=299 => {}
+        401 | 403 | 407 | 458 | 512 | 513 => return Err(EXPIRED_MESSAGE.to_string()),
+        404 => return Err("Stream unavailable — the channel wasn't found on the server".to_string()),
+        _ => return Err(format!("Stream unavailable — the server answered HTTP {}", status)),
+    }
+
+    let content_type = content_type.to_ascii_lowercase();
+    let start = head.iter().position(|b| !b.is_ascii_whitespace()).map_or(&[][..], |i| &head[i..]);
+    let page = content_type.starts_with("text/html")
+        || content_type.starts_with("application/json")
+        || start.starts_with(b"<")
+        || start.starts_with(b"{");
+    if page && !start.starts_with(b"#EXTM3U") {
+        return Err(EXPIRED_MESSAGE.to_string());
+    }
+    if start.is_empty() {
+        return Err("Stream unavailable — the server sent no data".to_string());
+    }
+    Ok(())
+}
+
+/// Probe every `(id, url)` pair in turn, calling `on_result(id, online)`
+/// as each one finishes. No probe starts while `playing()` is true. Blocks
+/// until all probes are done.
+pub fn probe_streams<P, F>(targets: Vec<(String, String)>, playing: P, on_result: F)
+where
+    P: Fn() -> bool,
+    F: Fn(String, Option<bool>),
+{
+    let client = match super::http::provider_client_builder().timeout(PROBE_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            log::error!("[Probe] Failed to build HTTP client: {}", e);
+            return;
+        }
+    };
+
+    for (i, (id, url)) in targets.into_iter().enumerate() {
+        if i > 0 {
+            std::thread::sleep(PROBE_INTERVAL);
+        }
+        while playing() {
+            std::thread::sleep(PLAYBACK_WAIT);
+        }
+        let online = probe_stream(&client, &url);
+        match online {
+            Some(false) => log::debug!("[Probe] Offline: {}", id),
+            None => log::debug!("[Probe] Provider refused the probe: {}", id),
+            Some(true) => {}
+        }
+        on_result(id, online);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_response() {
+        assert!(check_response(200, "video/mp2t", &[0x47, 0x40, 0x11]).is_ok());
+        assert!(check_response(200, "application/vnd.apple.mpegurl", b"#EXTM3U\n#EXT-X-VERSION:3").is_ok());
+        // Some panels serve playlists as text/html
+        assert!(check_response(200, "text/html", b"#EXTM3U\n").is_ok());
+
+        assert_eq!(check_response(200, "text/html", b"\n<html>Account expired</html>"), Err(EXPIRED_MESSAGE.to_string()));
+        assert_eq!(check_response(200, "", br#"{"user_info":{"auth":0}}"#), Err(EXPIRED_MESSAGE.to_string()));
+        assert_eq!(check_response(403, "video/mp2t", &[0x47]), Err(EXPIRED_MESSAGE.to_string()));
+        assert!(check_response(404, "", b"").is_err());
+        assert!(check_response(200, "video/mp2t", b"").is_err());
+    }
+
+    #[test]
+    fn test_probe_status() {
+        assert_eq!(probe_status(200), Some(true));
+        assert_eq!(probe_status(404), Some(false));
+        assert_eq!(probe_status(502), Some(false));
+        assert_eq!(probe_status(403), None);
+        assert_eq!(probe_status(429), None);
+        assert_eq!(probe_status(458), None);
+    }
+}
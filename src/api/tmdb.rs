@@ -200,14 +200,8 @@ impl TmdbClient {
     pub fn new(api_key: String) -> Self {
         Self {
             api_key,
-            client: reqwest::blocking::Client::builder()
-                .timeout(std::time::Duration::from_secs(10))
-                // WARNING: Disabling certificate verification to work around
-                // network proxies/firewalls that intercept HTTPS traffic.
-                // This is NOT recommended for production use.
-                .danger_accept_invalid_certs(true)
-                .build()
-                .unwrap_or_default(),
+            // Goes through the configured proxy, if any
+            client: super::http::client(),
         }
     }
     
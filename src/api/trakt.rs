@@ -187,11 +187,7 @@ impl TraktClient {
     }
     
     pub fn with_client_id(client_id: String) -> Self {
-        let client = reqwest::blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(15))
-            .danger_accept_invalid_certs(true) // For networks with proxy issues
-            .build()
-            .expect("Failed to create HTTP client");
+        let client = super::http::client();
             
         Self { client, client_id }
     }
@@ -199,7 +195,7 @@ impl TraktClient {
     fn get(&self, endpoint: &str) -> Result<String, String> {
         let url = format!("{}{}", TRAKT_API_URL, endpoint);
         
-        eprintln!("[Trakt] Fetching: {}", url);
+        log::debug!("[Trakt] Fetching: {}", url);
         
         let response = self.client
             .get(&url)
@@ -208,16 +204,16 @@ impl TraktClient {
             .header("trakt-api-key", &self.client_id)
             .send()
             .map_err(|e| {
-                eprintln!("[Trakt] Request error: {:?}", e);
+                log::warn!("[Trakt] Request error: {:?}", e);
                 format!("Request failed: {}", e)
             })?;
             
         let status = response.status();
-        eprintln!("[Trakt] Response status: {}", status);
+        log::debug!("[Trakt] Response status: {}", status);
         
         if !status.is_success() {
             let error_text = response.text().unwrap_or_default();
-            eprintln!("[Trakt] Error response: {}", error_text);
+            log::warn!("[Trakt] Error response: {}", error_text);
             return Err(format!("API error {}: {}", status, error_text));
         }
         
@@ -432,16 +428,16 @@ impl DiscoverCache {
         let sender = self.sender.clone();
 
         std::thread::spawn(move || {
-            eprintln!("[DiscoverCache] Loading {:?}", category);
+            log::debug!("[DiscoverCache] Loading {:?}", category);
             let client = TraktClient::new();
             
             match client.get_category(category, 20) {
                 Ok(items) => {
-                    eprintln!("[DiscoverCache] Loaded {} items for {:?}", items.len(), category);
+                    log::debug!("[DiscoverCache] Loaded {} items for {:?}", items.len(), category);
                     let _ = sender.send(DiscoverMessage::CategoryLoaded(category, items));
                 }
                 Err(e) => {
-                    eprintln!("[DiscoverCache] Error loading {:?}: {}", category, e);
+                    log::warn!("[DiscoverCache] Error loading {:?}: {}", category, e);
                     let _ = sender.send(DiscoverMessage::CategoryError(category, e));
                 }
             }
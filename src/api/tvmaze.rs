@@ -157,12 +157,7 @@ impl TvMazeClient {
     /// Create a new TVMaze client.
     pub fn new() -> Self {
         Self {
-            client: reqwest::blocking::Client::builder()
-                .timeout(std::time::Duration::from_secs(15))
-                // Accept invalid certs to work around network proxies
-                .danger_accept_invalid_certs(true)
-                .build()
-                .unwrap_or_default(),
+            client: super::http::client(),
         }
     }
 
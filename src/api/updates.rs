@@ -0,0 +1,71 @@
+//! Update check against the project's GitHub releases.
+//!
+//! Only tells the user a newer build exists; nothing is downloaded.
+
+use serde::Deserialize;
+
+/// Latest published release of the project.
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/ChildishKauai/IPTV-Player/releases/latest";
+
+/// A published release.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Release page on GitHub
+    pub html_url: String,
+    /// Release notes (Markdown)
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// The latest release if it is newer than this build.
+pub fn check_for_update() -> Result<Option<Release>, String> {
+    let release: Release = super::http::client()
+        .get(LATEST_RELEASE_URL)
+        // GitHub rejects API requests without a User-Agent
+        .header("User-Agent", concat!("iptv-player/", env!("CARGO_PKG_VERSION")))
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .and_then(|r| r.error_for_status())
+        .and_then(|r| r.json())
+        .map_err(|e| e.to_string())?;
+    Ok(is_newer(&release.tag_name, env!("CARGO_PKG_VERSION")).then_some(release))
+}
+
+/// Whether version tag `latest` (e.g. "v1.2.0") is newer than `current`.
+pub fn is_newer(latest: &str, current: &str) -> bool {
+    match (parse_version(latest), parse_version(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+/// "v1.2.3" or "1.2" as comparable numbers; pre-release suffixes are ignored.
+fn parse_version(tag: &str) -> Option<Vec<u64>> {
+    let version = tag.trim().trim_start_matches(['v', 'V']);
+    let version = version.split(['-', '+']).next()?;
+    let mut parts: Vec<u64> = version.split('.').map(|p| p.parse().ok()).collect::<Option<_>>()?;
+    // 1.2 == 1.2.0
+    while parts.last() == Some(&0) {
+        parts.pop();
+    }
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("v0.2.0", "0.1.0"));
+        assert!(is_newer("0.1.10", "0.1.9"));
+        assert!(is_newer("v1.0", "0.9.9"));
+        assert!(!is_newer("v0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1", "0.1.0"));
+        assert!(!is_newer("v0.1.0-beta", "0.1.0"));
+        assert!(!is_newer("nightly", "0.1.0"));
+    }
+}
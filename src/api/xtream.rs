@@ -1,6 +1,55 @@
 ﻿use crate::models::*;
 use serde_json::Value;
-use std::time::Duration;
+
+/// Trailing path segments users paste along with the server address
+/// (portal pages and API endpoints), removed during normalization.
+const STRIPPED_PATH_SEGMENTS: &[&str] = &["c", "player_api.php", "get.php", "xmltv.php", "panel_api.php"];
+
+/// Clean up a server URL as typed on the login screen: trims whitespace,
+/// adds `http://` when no scheme is given, drops query strings, portal
+/// paths like `/c/` and trailing slashes. Returns a message suitable for
+/// the login screen when the input can't be a server address.
+pub fn normalize_server_url(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Enter the server URL, e.g. http://example.com:8080".to_string());
+    }
+    if input.contains(char::is_whitespace) {
+        return Err("The server URL can't contain spaces".to_string());
+    }
+
+    let with_scheme = if input.contains("://") {
+        input.to_string()
+    } else {
+        format!("http://{}", input)
+    };
+
+    let url = reqwest::Url::parse(&with_scheme)
+        .map_err(|e| format!("\"{}\" is not a valid server URL ({})", input, e))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(format!("Unsupported scheme \"{}://\". Use http:// or https://", url.scheme()));
+    }
+    let host = url.host_str()
+        .filter(|h| !h.is_empty())
+        .ok_or_else(|| format!("\"{}\" has no host name", input))?;
+
+    let mut segments: Vec<&str> = url.path_segments()
+        .map(|s| s.filter(|seg| !seg.is_empty()).collect())
+        .unwrap_or_default();
+    while segments.last().is_some_and(|seg| STRIPPED_PATH_SEGMENTS.contains(&seg.to_ascii_lowercase().as_str())) {
+        segments.pop();
+    }
+
+    let mut normalized = format!("{}://{}", url.scheme(), host);
+    if let Some(port) = url.port() {
+        normalized.push_str(&format!(":{}", port));
+    }
+    for segment in segments {
+        normalized.push('/');
+        normalized.push_str(segment);
+    }
+    Ok(normalized)
+}
 
 pub struct XtreamClient {
     base_url: String,
@@ -16,10 +65,8 @@ impl XtreamClient {
         let username = username.trim().to_string();
         let password = password.trim().to_string();
         
-        // Create client with timeout and redirect settings
-        let client = reqwest::blocking::Client::builder()
-            .timeout(Duration::from_secs(120))  // Increased for large responses (13MB+)
-            .connect_timeout(Duration::from_secs(15))
+        // Create client with the configured timeout and redirect settings
+        let client = super::http::provider_client_builder()
             .redirect(reqwest::redirect::Policy::limited(5))
             .build()
             .unwrap_or_else(|_| reqwest::blocking::Client::new());
@@ -39,6 +86,18 @@ impl XtreamClient {
         )
     }
 
+    /// API URL for a listing action, scoped to a category when one is given
+    fn category_url(&self, action: &str, category_id: Option<&str>) -> String {
+        match category_id {
+            Some(id) => format!("{}&category_id={}", self.api_url(action), id),
+            None => self.api_url(action),
+        }
+    }
+
+    /// Checks the sign-in. `Ok(false)` only when the provider turns the
+    /// credentials down (401/403, or `user_info.auth` of 0); a server
+    /// error or an answer that isn't the expected JSON is an `Err`, as the
+    /// API may just be broken while the playlist still works.
     pub fn authenticate(&self) -> Result<bool, Box<dyn std::error::Error>> {
         let url = format!(
             "{}/player_api.php?username={}&password={}",
@@ -47,23 +106,17 @@ impl XtreamClient {
         
         let response = self.client.get(&url).send()?;
         
-        if !response.status().is_success() {
+        let status = response.status();
+        if matches!(status, reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN) {
             return Ok(false);
         }
-        
-        // Try to parse the response as JSON to verify it's valid
-        let json: Result<Value, _> = response.json();
-        match json {
-            Ok(val) => {
-                // Check if the response contains user_info (valid authentication)
-                if val.get("user_info").is_some() {
-                    Ok(true)
-                } else {
-                    Ok(false)
-                }
-            }
-            Err(_) => Ok(false),
+        if !status.is_success() {
+            return Err(format!("API returned status: {}", status).into());
         }
+        
+        let json: Value = response.json()
+            .map_err(|e| format!("Unexpected answer from the API: {}", e))?;
+        auth_accepted(&json).ok_or_else(|| "The API answer has no user info".into())
     }
 
     pub fn get_live_categories(&self) -> Result<Vec<Category>, Box<dyn std::error::Error>> {
@@ -85,11 +138,11 @@ impl XtreamClient {
 
     pub fn get_live_streams(&self) -> Result<Vec<Channel>, Box<dyn std::error::Error>> {
         let url = self.api_url("get_live_streams");
-        eprintln!("[DEBUG] Fetching channels from: {}", url);
+        log::debug!("[Xtream] Fetching channels from: {}", url);
         
         let response = self.client.get(&url).send()?;
-        eprintln!("[DEBUG] Response status: {}", response.status());
-        eprintln!("[DEBUG] Response headers: {:?}", response.headers());
+        log::debug!("[Xtream] Response status: {}", response.status());
+        log::debug!("[Xtream] Response headers: {:?}", response.headers());
         
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -97,17 +150,17 @@ impl XtreamClient {
         
         // Read raw bytes first to handle encoding issues
         let bytes = response.bytes().map_err(|e| {
-            eprintln!("[DEBUG] Error reading response bytes: {}", e);
+            log::warn!("[Xtream] Error reading response bytes: {}", e);
             format!("Error reading response bytes: {}", e)
         })?;
         
-        eprintln!("[DEBUG] Response bytes length: {}", bytes.len());
+        log::debug!("[Xtream] Response bytes length: {}", bytes.len());
         
         // Try to convert to UTF-8, handling invalid sequences
         let text = String::from_utf8_lossy(&bytes).into_owned();
         
-        eprintln!("[DEBUG] Response text length: {} chars", text.len());
-        eprintln!("[DEBUG] Response first 500 chars: {}", &text[..text.len().min(500)]);
+        log::debug!("[Xtream] Response text length: {} chars", text.len());
+        log::debug!("[Xtream] Response first 500 chars: {}", &text[..text.len().min(500)]);
         
         // First, let's check if it's a valid JSON array
         let json_value: Value = serde_json::from_str(&text)
@@ -126,9 +179,7 @@ impl XtreamClient {
                 if let Some(first_item) = array.first() {
                     let error_msg = format!("Failed to parse channels: {}. First item: {}", e, 
                         serde_json::to_string_pretty(first_item).unwrap_or_default());
-                    eprintln!("\n=== DESERIALIZATION ERROR ===");
-                    eprintln!("{}", error_msg);
-                    eprintln!("=========================\n");
+                    log::error!("[Xtream] {}", error_msg);
                     error_msg
                 } else {
                     format!("Failed to parse channels: {}. Empty array", e)
@@ -163,13 +214,14 @@ impl XtreamClient {
         Ok(categories)
     }
 
-    #[allow(dead_code)]
-    pub fn get_vod_streams(&self) -> Result<Vec<Value>, Box<dyn std::error::Error>> {
-        let url = self.api_url("get_vod_streams");
-        eprintln!("[DEBUG] Fetching VOD streams from: {}", url);
+    /// Get VOD streams, optionally only those of one category. Panels that
+    /// don't support `category_id` ignore it and return everything.
+    pub fn get_vod_streams(&self, category_id: Option<&str>) -> Result<Vec<Movie>, Box<dyn std::error::Error>> {
+        let url = self.category_url("get_vod_streams", category_id);
+        log::debug!("[Xtream] Fetching VOD streams from: {}", url);
         
         let response = self.client.get(&url).send()?;
-        eprintln!("[DEBUG] VOD Response status: {}", response.status());
+        log::debug!("[Xtream] VOD Response status: {}", response.status());
         
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -177,12 +229,12 @@ impl XtreamClient {
         
         // Read raw bytes to handle encoding issues
         let bytes = response.bytes()?;
-        eprintln!("[DEBUG] VOD Response bytes length: {}", bytes.len());
+        log::debug!("[Xtream] VOD Response bytes length: {}", bytes.len());
         
         // Check if response is too small (likely empty or error)
         if bytes.len() < 10 {
             let text = String::from_utf8_lossy(&bytes);
-            eprintln!("[DEBUG] VOD Response too small: {}", text);
+            log::warn!("[Xtream] VOD Response too small: {}", text);
             // Return empty array if response is just "[]"
             if text.trim() == "[]" {
                 return Ok(Vec::new());
@@ -190,13 +242,22 @@ impl XtreamClient {
         }
         
         let text = String::from_utf8_lossy(&bytes).into_owned();
-        eprintln!("[DEBUG] VOD First 200 chars: {}", &text[..text.len().min(200)]);
+        log::debug!("[Xtream] VOD First 200 chars: {}", &text[..text.len().min(200)]);
         
         let streams: Vec<Value> = serde_json::from_str(&text)
             .map_err(|e| format!("Failed to parse VOD streams: {}. Response: {}", e, &text[..text.len().min(200)]))?;
         
-        eprintln!("[DEBUG] VOD Parsed {} movies", streams.len());
-        Ok(streams)
+        // Skip the odd malformed entry rather than failing the whole list
+        let total = streams.len();
+        let movies: Vec<Movie> = streams.into_iter()
+            .filter_map(|v| serde_json::from_value(v).ok())
+            .collect();
+        if movies.len() < total {
+            log::warn!("[Xtream] VOD skipped {} malformed entries", total - movies.len());
+        }
+        
+        log::debug!("[Xtream] VOD Parsed {} movies", movies.len());
+        Ok(movies)
     }
 
     pub fn get_series_categories(&self) -> Result<Vec<Category>, Box<dyn std::error::Error>> {
@@ -216,12 +277,13 @@ impl XtreamClient {
         Ok(categories)
     }
 
-    pub fn get_series(&self) -> Result<Vec<Series>, Box<dyn std::error::Error>> {
-        let url = self.api_url("get_series");
-        eprintln!("[DEBUG] Fetching series from: {}", url);
+    /// Get series, optionally only those of one category (see `get_vod_streams`)
+    pub fn get_series(&self, category_id: Option<&str>) -> Result<Vec<Series>, Box<dyn std::error::Error>> {
+        let url = self.category_url("get_series", category_id);
+        log::debug!("[Xtream] Fetching series from: {}", url);
         
         let response = self.client.get(&url).send()?;
-        eprintln!("[DEBUG] Series Response status: {}", response.status());
+        log::debug!("[Xtream] Series Response status: {}", response.status());
         
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -229,14 +291,14 @@ impl XtreamClient {
         
         // Read raw bytes to handle encoding issues
         let bytes = response.bytes()?;
-        eprintln!("[DEBUG] Series Response bytes length: {}", bytes.len());
+        log::debug!("[Xtream] Series Response bytes length: {}", bytes.len());
         
         let text = String::from_utf8_lossy(&bytes).into_owned();
         
         let mut series: Vec<Series> = serde_json::from_str(&text)
             .map_err(|e| {
-                eprintln!("[DEBUG] Series parse error: {}", e);
-                eprintln!("[DEBUG] First 500 chars: {}", &text[..text.len().min(500)]);
+                log::warn!("[Xtream] Series parse error: {}", e);
+                log::warn!("[Xtream] First 500 chars: {}", &text[..text.len().min(500)]);
                 format!("Failed to parse series: {}. Response: {}", e, &text[..text.len().min(200)])
             })?;
         
@@ -273,10 +335,10 @@ impl XtreamClient {
         )
     }
 
-    pub fn get_live_stream_url(&self, stream_id: &str) -> String {
+    pub fn get_live_stream_url(&self, stream_id: &str, format: LiveStreamFormat) -> String {
         format!(
-            "{}/live/{}/{}/{}.ts",
-            self.base_url, self.username, self.password, stream_id
+            "{}/live/{}/{}/{}.{}",
+            self.base_url, self.username, self.password, stream_id, format.extension()
         )
     }
 
@@ -287,6 +349,32 @@ impl XtreamClient {
         )
     }
     
+    /// Get the catch-up (timeshift) URL for a past programme on a channel
+    /// with `tv_archive` support. Panels expect the start in their local
+    /// time, formatted `YYYY-MM-DD:HH-MM`.
+    pub fn get_catchup_url(
+        &self,
+        stream_id: &str,
+        start_unix: i64,
+        duration_minutes: i64,
+        format: CatchupUrlFormat,
+    ) -> String {
+        let start = chrono::DateTime::from_timestamp(start_unix, 0)
+            .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d:%H-%M").to_string())
+            .unwrap_or_default();
+        let duration = duration_minutes.max(1);
+        match format {
+            CatchupUrlFormat::Path => format!(
+                "{}/timeshift/{}/{}/{}/{}/{}.ts",
+                self.base_url, self.username, self.password, duration, start, stream_id
+            ),
+            CatchupUrlFormat::Query => format!(
+                "{}/streaming/timeshift.php?username={}&password={}&stream={}&start={}&duration={}",
+                self.base_url, self.username, self.password, stream_id, start, duration
+            ),
+        }
+    }
+    
     /// Get short EPG for a specific stream (current and next few programs)
     pub fn get_short_epg(&self, stream_id: &str) -> Result<Vec<EpgProgram>, Box<dyn std::error::Error>> {
         let url = format!(
@@ -315,8 +403,8 @@ impl XtreamClient {
         }
     }
     
-    /// Get EPG for all streams for a specific date range
-    #[allow(dead_code)]
+    /// Get the full EPG of a stream, including past programmes (used for
+    /// catch-up browsing)
     pub fn get_simple_data_table(&self, stream_id: &str) -> Result<Vec<EpgProgram>, Box<dyn std::error::Error>> {
         let url = format!(
             "{}/player_api.php?username={}&password={}&action=get_simple_data_table&stream_id={}",
@@ -342,6 +430,14 @@ impl XtreamClient {
         }
     }
     
+    /// Get the full `m3u_plus` playlist URL (fallback when the API is down)
+    pub fn get_m3u_url(&self) -> String {
+        format!(
+            "{}/get.php?username={}&password={}&type=m3u_plus&output=ts",
+            self.base_url, self.username, self.password
+        )
+    }
+    
     /// Get XMLTV EPG URL (for full EPG data)
     #[allow(dead_code)]
     pub fn get_xmltv_url(&self) -> String {
@@ -350,4 +446,189 @@ impl XtreamClient {
             self.base_url, self.username, self.password
         )
     }
+    
+    // Connection diagnostics, one check per stage. Each returns a short
+    // description on success and a specific error message on failure.
+    
+    /// Host and port from the server URL (port defaults to the scheme's).
+    pub fn host_and_port(&self) -> Result<(String, u16), String> {
+        let url = reqwest::Url::parse(&self.base_url)
+            .map_err(|e| format!("Invalid server URL \"{}\": {}", self.base_url, e))?;
+        let host = match url.host_str() {
+            Some(host) if matches!(url.scheme(), "http" | "https") => host.to_string(),
+            _ => return Err("Server URL must start with http:// or https://".to_string()),
+        };
+        let port = url.port_or_known_default().unwrap_or(80);
+        Ok((host, port))
+    }
+    
+    /// Resolve the server's host name.
+    pub fn check_dns(&self) -> Result<String, String> {
+        use std::net::ToSocketAddrs;
+        
+        let (host, port) = self.host_and_port()?;
+        let addrs: Vec<_> = (host.as_str(), port).to_socket_addrs()
+            .map_err(|e| format!("Could not resolve {}: {}. Check the address and your DNS.", host, e))?
+            .collect();
+        match addrs.first() {
+            Some(addr) => Ok(format!("{} → {}", host, addr.ip())),
+            None => Err(format!("{} did not resolve to any address", host)),
+        }
+    }
+    
+    /// Open a TCP connection to the server port. Goes direct, even when a
+    /// proxy is configured.
+    pub fn check_tcp(&self) -> Result<String, String> {
+        use std::net::{TcpStream, ToSocketAddrs};
+        
+        let (host, port) = self.host_and_port()?;
+        let addrs = (host.as_str(), port).to_socket_addrs()
+            .map_err(|e| format!("Could not resolve {}: {}", host, e))?;
+        
+        let mut last_error = None;
+        for addr in addrs {
+            match TcpStream::connect_timeout(&addr, std::time::Duration::from_secs(5)) {
+                Ok(_) => return Ok(format!("Port {} is open", port)),
+                Err(e) => last_error = Some(format!("{}: {}", addr, e)),
+            }
+        }
+        Err(format!(
+            "Could not connect to port {} ({}). The server may be down, the port wrong, or a firewall blocking it.",
+            port,
+            last_error.unwrap_or_else(|| "no addresses".to_string()),
+        ))
+    }
+    
+    /// Check that `player_api.php` answers over HTTP, without credentials.
+    pub fn check_api_reachable(&self) -> Result<String, String> {
+        let url = format!("{}/player_api.php", self.base_url);
+        let response = self.client.get(&url).send().map_err(|e| {
+            if e.is_timeout() {
+                "player_api.php timed out. The server is overloaded or the network is slow.".to_string()
+            } else {
+                format!("HTTP request failed: {}", e)
+            }
+        })?;
+        
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            Err("player_api.php not found (404). This URL may not be an Xtream Codes server.".to_string())
+        } else if status.is_server_error() {
+            Err(format!("Server error ({}). The provider's panel has a problem.", status))
+        } else {
+            Ok(format!("HTTP {}", status.as_u16()))
+        }
+    }
+    
+    /// Check the credentials and account status.
+    pub fn check_auth(&self) -> Result<String, String> {
+        let url = format!(
+            "{}/player_api.php?username={}&password={}",
+            self.base_url, self.username, self.password
+        );
+        let response = self.client.get(&url).send()
+            .map_err(|e| format!("HTTP request failed: {}", e))?;
+        
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(format!("Credentials rejected (HTTP {})", status.as_u16()));
+        }
+        if !status.is_success() {
+            return Err(format!("Unexpected response (HTTP {})", status.as_u16()));
+        }
+        
+        let json: Value = response.json()
+            .map_err(|_| "Response is not valid JSON. This may not be an Xtream Codes server.".to_string())?;
+        let user_info = json.get("user_info")
+            .ok_or_else(|| "Response has no account info. This may not be an Xtream Codes server.".to_string())?;
+        
+        let auth = user_info.get("auth")
+            .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+            .unwrap_or(0);
+        if auth != 1 {
+            return Err("Wrong username or password".to_string());
+        }
+        
+        let account_status = user_info.get("status").and_then(|v| v.as_str()).unwrap_or("Active");
+        if !account_status.eq_ignore_ascii_case("active") {
+            return Err(format!("Account is {}", account_status));
+        }
+        
+        let expires = user_info.get("exp_date")
+            .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+            .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0));
+        match expires {
+            Some(date) if date < chrono::Utc::now() => {
+                Err(format!("Account expired on {}", date.format("%Y-%m-%d")))
+            }
+            Some(date) => Ok(format!("Account active until {}", date.format("%Y-%m-%d"))),
+            None => Ok("Account active".to_string()),
+        }
+    }
+}
+
+/// Whether a `player_api.php` answer accepts the sign-in; `None` when it
+/// has no `user_info`. Panels send `auth` as a number or a string.
+fn auth_accepted(answer: &Value) -> Option<bool> {
+    let user_info = answer.get("user_info")?;
+    let auth = match user_info.get("auth") {
+        Some(Value::Number(n)) => n.as_i64() != Some(0),
+        Some(Value::String(s)) => s.trim() != "0",
+        Some(Value::Bool(b)) => *b,
+        // Older panels leave it out when the sign-in is fine
+        _ => true,
+    };
+    Some(auth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_accepted() {
+        assert_eq!(auth_accepted(&serde_json::json!({"user_info": {"auth": 1}})), Some(true));
+        assert_eq!(auth_accepted(&serde_json::json!({"user_info": {"auth": 0}})), Some(false));
+        assert_eq!(auth_accepted(&serde_json::json!({"user_info": {"auth": "0"}})), Some(false));
+        assert_eq!(auth_accepted(&serde_json::json!({"error": "maintenance"})), None);
+    }
+
+    #[test]
+    fn test_normalize_server_url() {
+        let ok = |input: &str| normalize_server_url(input).unwrap();
+        assert_eq!(ok("http://host.tv:8080/"), "http://host.tv:8080");
+        assert_eq!(ok("  host.tv:8080  "), "http://host.tv:8080");
+        assert_eq!(ok("http://host.tv:8080/c/"), "http://host.tv:8080");
+        assert_eq!(ok("HTTPS://Host.TV"), "https://host.tv");
+        assert_eq!(ok("http://host.tv:80"), "http://host.tv");
+        assert_eq!(ok("host.tv/player_api.php?username=a&password=b"), "http://host.tv");
+        assert_eq!(ok("http://10.0.0.5:25461///"), "http://10.0.0.5:25461");
+        assert_eq!(ok("http://host.tv/iptv/"), "http://host.tv/iptv");
+    }
+
+    #[test]
+    fn test_normalize_server_url_rejects_invalid_input() {
+        assert!(normalize_server_url("").is_err());
+        assert!(normalize_server_url("   ").is_err());
+        assert!(normalize_server_url("host tv:8080").is_err());
+        assert!(normalize_server_url("ftp://host.tv").is_err());
+        assert!(normalize_server_url("http://host.tv:99999").is_err());
+        assert!(normalize_server_url("http://").is_err());
+    }
+
+    #[test]
+    fn test_catchup_url_formats() {
+        let client = XtreamClient::new("http://host.tv".to_string(), "u".to_string(), "p".to_string());
+        let start = 1_700_000_000;
+        let stamp = chrono::DateTime::from_timestamp(start, 0).unwrap()
+            .with_timezone(&chrono::Local).format("%Y-%m-%d:%H-%M").to_string();
+        assert_eq!(
+            client.get_catchup_url("42", start, 30, CatchupUrlFormat::Path),
+            format!("http://host.tv/timeshift/u/p/30/{}/42.ts", stamp)
+        );
+        assert_eq!(
+            client.get_catchup_url("42", start, 0, CatchupUrlFormat::Query),
+            format!("http://host.tv/streaming/timeshift.php?username=u&password=p&stream=42&start={}&duration=1", stamp)
+        );
+    }
 }
@@ -0,0 +1,463 @@
+//! Built-in video player: libmpv drawing into an egui texture.
+//!
+//! libmpv is loaded at runtime (with the `embedded-player` feature), so
+//! the app still starts without it and playback falls back to the
+//! external player. Frames come from mpv's software render API, which
+//! needs no access to eframe's GL/wgpu context; hardware decoding copies
+//! frames back to system memory.
+
+use eframe::egui;
+
+use crate::models::PlayerSettings;
+
+/// External-window options of `build_mpv_args` that don't apply inside
+/// the app window.
+const WINDOW_OPTIONS: &[&str] = &["fullscreen", "border", "cursor-autohide", "keep-open"];
+
+/// A stream playing in the built-in player.
+pub struct EmbeddedPlayer {
+    mpv: imp::Mpv,
+    title: String,
+    texture: Option<egui::TextureHandle>,
+    /// Set once playback ended: `Err` with mpv's reason if it failed
+    ended: Option<Result<(), String>>,
+}
+
+impl EmbeddedPlayer {
+    /// Loads libmpv and starts playing `url`, `start` seconds in when
+    /// given. Honors the audio, subtitle, volume, buffering and provider
+    /// header settings the external MPV gets.
+    pub fn open(url: &str, title: &str, settings: &PlayerSettings, start: Option<f64>) -> Result<Self, String> {
+        let mut options: Vec<(String, String)> = settings
+            .build_mpv_args(None)
+            .iter()
+            .chain(&settings.build_start_args(start.unwrap_or(0.0)))
+            .chain(&crate::api::http::provider_headers().mpv_args())
+            .filter_map(|arg| option_from_arg(arg))
+            .filter(|(name, _)| !WINDOW_OPTIONS.contains(&name.as_str()))
+            .map(|(name, value)| match (name.as_str(), value.as_str()) {
+                // Decoded frames have to come back to system memory for the
+                // software renderer
+                ("hwdec", "auto") => (name, "auto-copy".to_string()),
+                _ => (name, value),
+            })
+            .collect();
+        options.push(("force-media-title".to_string(), title.to_string()));
+
+        let mpv = imp::Mpv::new(&options)?;
+        mpv.command(&["loadfile", url])?;
+        log::info!("[Player] Playing in the built-in player: {}", url);
+        Ok(Self {
+            mpv,
+            title: title.to_string(),
+            texture: None,
+            ended: None,
+        })
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Draws the latest frame into the player's texture, rendered at up
+    /// to `max_size` pixels. `None` until the first frame arrives.
+    pub fn frame(&mut self, ctx: &egui::Context, max_size: egui::Vec2) -> Option<&egui::TextureHandle> {
+        if let Some(size) = self.video_size().map(|video| fit(video, max_size)) {
+            let stale = self.texture.as_ref().is_none_or(|t| t.size() != size);
+            if let Some(image) = self.mpv.render(size, stale) {
+                match &mut self.texture {
+                    Some(texture) => texture.set(image, egui::TextureOptions::LINEAR),
+                    None => {
+                        self.texture = Some(ctx.load_texture("embedded_player", image, egui::TextureOptions::LINEAR));
+                    }
+                }
+            }
+        }
+        self.texture.as_ref()
+    }
+
+    /// Width and height of the video, once known.
+    fn video_size(&self) -> Option<[usize; 2]> {
+        let width = self.mpv.get_i64("dwidth")?;
+        let height = self.mpv.get_i64("dheight")?;
+        (width > 0 && height > 0).then_some([width as usize, height as usize])
+    }
+
+    /// Playback position in seconds.
+    pub fn position(&self) -> Option<f64> {
+        self.mpv.get_f64("time-pos")
+    }
+
+    /// Length of the stream in seconds; `None` for live streams.
+    pub fn duration(&self) -> Option<f64> {
+        self.mpv.get_f64("duration").filter(|d| *d > 0.0)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.mpv.get_flag("pause").unwrap_or(false)
+    }
+
+    pub fn toggle_pause(&self) {
+        self.run(&["cycle", "pause"]);
+    }
+
+    /// Jumps `seconds` forward (or back when negative).
+    pub fn seek_by(&self, seconds: f64) {
+        self.run(&["seek", &seconds.to_string(), "relative"]);
+    }
+
+    pub fn seek_to(&self, seconds: f64) {
+        self.run(&["seek", &seconds.to_string(), "absolute"]);
+    }
+
+    pub fn volume(&self) -> f64 {
+        self.mpv.get_f64("volume").unwrap_or(100.0)
+    }
+
+    pub fn set_volume(&self, volume: f64) {
+        self.run(&["set", "volume", &volume.round().to_string()]);
+    }
+
+    /// `Some` once the stream finished or failed.
+    pub fn ended(&mut self) -> Option<&Result<(), String>> {
+        if self.ended.is_none() {
+            self.ended = self.mpv.poll_end();
+        }
+        self.ended.as_ref()
+    }
+
+    fn run(&self, args: &[&str]) {
+        if let Err(e) = self.mpv.command(args) {
+            log::warn!("[Player] mpv {:?} failed: {}", args, e);
+        }
+    }
+}
+
+/// `--name=value` as an mpv option; `--flag` is "yes", `--no-flag` "no".
+fn option_from_arg(arg: &str) -> Option<(String, String)> {
+    let arg = arg.strip_prefix("--")?;
+    Some(match arg.split_once('=') {
+        Some((name, value)) => (name.to_string(), value.to_string()),
+        None => match arg.strip_prefix("no-") {
+            Some(name) => (name.to_string(), "no".to_string()),
+            None => (arg.to_string(), "yes".to_string()),
+        },
+    })
+}
+
+/// `video` scaled down to fit `max` (never up), keeping its aspect ratio.
+fn fit(video: [usize; 2], max: egui::Vec2) -> [usize; 2] {
+    let scale = (max.x / video[0] as f32).min(max.y / video[1] as f32).min(1.0);
+    [
+        ((video[0] as f32 * scale) as usize).max(1),
+        ((video[1] as f32 * scale) as usize).max(1),
+    ]
+}
+
+#[cfg(feature = "embedded-player")]
+mod imp {
+    //! libmpv's client and software render API, resolved at runtime.
+
+    use std::ffi::{c_char, c_double, c_int, c_void, CStr, CString};
+    use std::ptr;
+
+    use eframe::egui;
+    use libloading::Library;
+
+    #[cfg(target_os = "windows")]
+    const LIBRARY_NAMES: &[&str] = &["libmpv-2.dll", "mpv-2.dll", "mpv-1.dll"];
+    #[cfg(target_os = "macos")]
+    const LIBRARY_NAMES: &[&str] = &["libmpv.2.dylib", "libmpv.dylib"];
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    const LIBRARY_NAMES: &[&str] = &["libmpv.so.2", "libmpv.so.1", "libmpv.so"];
+
+    // client.h / render.h constants
+    const FORMAT_FLAG: c_int = 3;
+    const FORMAT_INT64: c_int = 4;
+    const FORMAT_DOUBLE: c_int = 5;
+    const EVENT_NONE: c_int = 0;
+    const EVENT_SHUTDOWN: c_int = 1;
+    const EVENT_END_FILE: c_int = 7;
+    const END_FILE_REASON_ERROR: c_int = 4;
+    const RENDER_PARAM_INVALID: c_int = 0;
+    const RENDER_PARAM_API_TYPE: c_int = 1;
+    const RENDER_PARAM_SW_SIZE: c_int = 17;
+    const RENDER_PARAM_SW_FORMAT: c_int = 18;
+    const RENDER_PARAM_SW_STRIDE: c_int = 19;
+    const RENDER_PARAM_SW_POINTER: c_int = 20;
+    const RENDER_UPDATE_FRAME: u64 = 1;
+
+    #[repr(C)]
+    struct RenderParam {
+        kind: c_int,
+        data: *mut c_void,
+    }
+
+    #[repr(C)]
+    struct Event {
+        event_id: c_int,
+        error: c_int,
+        reply_userdata: u64,
+        data: *mut c_void,
+    }
+
+    #[repr(C)]
+    struct EventEndFile {
+        reason: c_int,
+        error: c_int,
+    }
+
+    /// Function pointers into the loaded library.
+    struct Api {
+        create: unsafe extern "C" fn() -> *mut c_void,
+        initialize: unsafe extern "C" fn(*mut c_void) -> c_int,
+        terminate_destroy: unsafe extern "C" fn(*mut c_void),
+        set_option_string: unsafe extern "C" fn(*mut c_void, *const c_char, *const c_char) -> c_int,
+        command: unsafe extern "C" fn(*mut c_void, *mut *const c_char) -> c_int,
+        get_property: unsafe extern "C" fn(*mut c_void, *const c_char, c_int, *mut c_void) -> c_int,
+        wait_event: unsafe extern "C" fn(*mut c_void, c_double) -> *mut Event,
+        error_string: unsafe extern "C" fn(c_int) -> *const c_char,
+        render_context_create: unsafe extern "C" fn(*mut *mut c_void, *mut c_void, *mut RenderParam) -> c_int,
+        render_context_render: unsafe extern "C" fn(*mut c_void, *mut RenderParam) -> c_int,
+        render_context_update: unsafe extern "C" fn(*mut c_void) -> u64,
+        render_context_free: unsafe extern "C" fn(*mut c_void),
+    }
+
+    impl Api {
+        fn load(lib: &Library) -> Result<Self, libloading::Error> {
+            // SAFETY: the signatures match libmpv's client.h and render.h, and
+            // the pointers are only used while `lib` is loaded (see `Mpv`).
+            unsafe {
+                Ok(Self {
+                    create: *lib.get(b"mpv_create\0")?,
+                    initialize: *lib.get(b"mpv_initialize\0")?,
+                    terminate_destroy: *lib.get(b"mpv_terminate_destroy\0")?,
+                    set_option_string: *lib.get(b"mpv_set_option_string\0")?,
+                    command: *lib.get(b"mpv_command\0")?,
+                    get_property: *lib.get(b"mpv_get_property\0")?,
+                    wait_event: *lib.get(b"mpv_wait_event\0")?,
+                    error_string: *lib.get(b"mpv_error_string\0")?,
+                    render_context_create: *lib.get(b"mpv_render_context_create\0")?,
+                    render_context_render: *lib.get(b"mpv_render_context_render\0")?,
+                    render_context_update: *lib.get(b"mpv_render_context_update\0")?,
+                    render_context_free: *lib.get(b"mpv_render_context_free\0")?,
+                })
+            }
+        }
+    }
+
+    /// An mpv instance with a software render context.
+    pub struct Mpv {
+        api: Api,
+        handle: *mut c_void,
+        render: *mut c_void,
+        // Dropped after `Drop::drop` released the handles above
+        _lib: Library,
+    }
+
+    impl Mpv {
+        pub fn new(options: &[(String, String)]) -> Result<Self, String> {
+            // SAFETY: loading libmpv runs no initialization code with
+            // preconditions of its own.
+            let lib = LIBRARY_NAMES
+                .iter()
+                .find_map(|name| unsafe { Library::new(name) }.ok())
+                .ok_or("libmpv not found")?;
+            let api = Api::load(&lib).map_err(|e| format!("libmpv is too old: {}", e))?;
+
+            // SAFETY: mpv_create has no preconditions; a null result is checked.
+            let handle = unsafe { (api.create)() };
+            if handle.is_null() {
+                return Err("mpv_create failed".to_string());
+            }
+            let mut mpv = Self { api, handle, render: ptr::null_mut(), _lib: lib };
+
+            mpv.set_option("vo", "libmpv")?;
+            mpv.set_option("idle", "yes")?;
+            for (name, value) in options {
+                // Unknown options in an older libmpv shouldn't stop playback
+                if let Err(e) = mpv.set_option(name, value) {
+                    log::warn!("[Player] Ignoring mpv option {}={}: {}", name, value, e);
+                }
+            }
+            // SAFETY: `handle` is a fresh, uninitialized mpv handle.
+            mpv.check(unsafe { (mpv.api.initialize)(mpv.handle) })?;
+
+            let mut api_type = *b"sw\0";
+            let mut params = [
+                RenderParam { kind: RENDER_PARAM_API_TYPE, data: api_type.as_mut_ptr().cast() },
+                RenderParam { kind: RENDER_PARAM_INVALID, data: ptr::null_mut() },
+            ];
+            let mut render = ptr::null_mut();
+            // SAFETY: `params` is terminated by an INVALID entry and outlives
+            // the call.
+            mpv.check(unsafe { (mpv.api.render_context_create)(&mut render, mpv.handle, params.as_mut_ptr()) })?;
+            mpv.render = render;
+            Ok(mpv)
+        }
+
+        fn set_option(&self, name: &str, value: &str) -> Result<(), String> {
+            let name = cstring(name)?;
+            let value = cstring(value)?;
+            // SAFETY: both strings are NUL-terminated and live through the call.
+            self.check(unsafe { (self.api.set_option_string)(self.handle, name.as_ptr(), value.as_ptr()) })
+        }
+
+        pub fn command(&self, args: &[&str]) -> Result<(), String> {
+            let args = args.iter().map(|a| cstring(a)).collect::<Result<Vec<_>, _>>()?;
+            let mut argv: Vec<*const c_char> = args.iter().map(|a| a.as_ptr()).collect();
+            argv.push(ptr::null());
+            // SAFETY: `argv` is a NULL-terminated array of C strings owned by `args`.
+            self.check(unsafe { (self.api.command)(self.handle, argv.as_mut_ptr()) })
+        }
+
+        fn get<T: Default>(&self, name: &str, format: c_int) -> Option<T> {
+            let name = cstring(name).ok()?;
+            let mut value = T::default();
+            // SAFETY: `value` has the layout mpv writes for `format`.
+            let status = unsafe {
+                (self.api.get_property)(self.handle, name.as_ptr(), format, (&mut value as *mut T).cast())
+            };
+            (status >= 0).then_some(value)
+        }
+
+        pub fn get_f64(&self, name: &str) -> Option<f64> {
+            self.get::<c_double>(name, FORMAT_DOUBLE)
+        }
+
+        pub fn get_i64(&self, name: &str) -> Option<i64> {
+            self.get::<i64>(name, FORMAT_INT64)
+        }
+
+        pub fn get_flag(&self, name: &str) -> Option<bool> {
+            self.get::<c_int>(name, FORMAT_FLAG).map(|flag| flag != 0)
+        }
+
+        /// Renders a frame of `size` pixels if mpv has a new one (or
+        /// `force`, e.g. after the size changed).
+        pub fn render(&self, size: [usize; 2], force: bool) -> Option<egui::ColorImage> {
+            // SAFETY: `render` is the live context created in `new`.
+            let update = unsafe { (self.api.render_context_update)(self.render) };
+            if update & RENDER_UPDATE_FRAME == 0 && !force {
+                return None;
+            }
+
+            let mut sw_size = [size[0] as c_int, size[1] as c_int];
+            let mut format = *b"rgb0\0";
+            let mut stride = size[0] * 4;
+            let mut pixels = vec![0u8; stride * size[1]];
+            let mut params = [
+                RenderParam { kind: RENDER_PARAM_SW_SIZE, data: sw_size.as_mut_ptr().cast() },
+                RenderParam { kind: RENDER_PARAM_SW_FORMAT, data: format.as_mut_ptr().cast() },
+                RenderParam { kind: RENDER_PARAM_SW_STRIDE, data: (&mut stride as *mut usize).cast() },
+                RenderParam { kind: RENDER_PARAM_SW_POINTER, data: pixels.as_mut_ptr().cast() },
+                RenderParam { kind: RENDER_PARAM_INVALID, data: ptr::null_mut() },
+            ];
+            // SAFETY: `pixels` holds `stride * height` bytes for the requested
+            // size and format; the parameter list is INVALID-terminated.
+            let status = unsafe { (self.api.render_context_render)(self.render, params.as_mut_ptr()) };
+            if status < 0 {
+                return None;
+            }
+
+            // rgb0 leaves the fourth byte undefined
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel[3] = 255;
+            }
+            Some(egui::ColorImage::from_rgba_unmultiplied(size, &pixels))
+        }
+
+        /// Drains mpv's events; `Some` once the file ended.
+        pub fn poll_end(&self) -> Option<Result<(), String>> {
+            loop {
+                // SAFETY: mpv returns a valid event that stays valid until the
+                // next wait_event call, and only reads it before that.
+                let event = unsafe { &*(self.api.wait_event)(self.handle, 0.0) };
+                match event.event_id {
+                    EVENT_NONE => return None,
+                    EVENT_SHUTDOWN => return Some(Ok(())),
+                    EVENT_END_FILE => {
+                        // SAFETY: END_FILE events carry an mpv_event_end_file.
+                        let end = unsafe { &*(event.data as *const EventEndFile) };
+                        return Some(if end.reason == END_FILE_REASON_ERROR {
+                            Err(self.error_string(end.error))
+                        } else {
+                            Ok(())
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        fn check(&self, status: c_int) -> Result<(), String> {
+            if status < 0 {
+                Err(self.error_string(status))
+            } else {
+                Ok(())
+            }
+        }
+
+        fn error_string(&self, status: c_int) -> String {
+            // SAFETY: mpv_error_string returns a static C string for any code.
+            unsafe { CStr::from_ptr((self.api.error_string)(status)) }.to_string_lossy().into_owned()
+        }
+    }
+
+    impl Drop for Mpv {
+        fn drop(&mut self) {
+            // SAFETY: the render context has to go before the core; both were
+            // created by this instance and aren't used afterwards.
+            unsafe {
+                if !self.render.is_null() {
+                    (self.api.render_context_free)(self.render);
+                }
+                (self.api.terminate_destroy)(self.handle);
+            }
+        }
+    }
+
+    fn cstring(s: &str) -> Result<CString, String> {
+        CString::new(s).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(not(feature = "embedded-player"))]
+mod imp {
+    //! Stand-in when built without the `embedded-player` feature: never
+    //! constructed, so playback always falls back to the external player.
+
+    use eframe::egui;
+
+    pub enum Mpv {}
+
+    impl Mpv {
+        pub fn new(_options: &[(String, String)]) -> Result<Self, String> {
+            Err("built without the embedded-player feature".to_string())
+        }
+
+        pub fn command(&self, _args: &[&str]) -> Result<(), String> {
+            match *self {}
+        }
+
+        pub fn get_f64(&self, _name: &str) -> Option<f64> {
+            match *self {}
+        }
+
+        pub fn get_i64(&self, _name: &str) -> Option<i64> {
+            match *self {}
+        }
+
+        pub fn get_flag(&self, _name: &str) -> Option<bool> {
+            match *self {}
+        }
+
+        pub fn render(&self, _size: [usize; 2], _force: bool) -> Option<egui::ColorImage> {
+            match *self {}
+        }
+
+        pub fn poll_end(&self) -> Option<Result<(), String>> {
+            match *self {}
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Application log: stderr plus a size-rotated file in the data folder.
+//!
+//! Messages keep the `[Tag] message` form used throughout the app; the
+//! logger adds a timestamp and level. The file is what users can attach
+//! to a bug report, so known secrets (the account password) are masked
+//! before anything is written.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::models::LogLevel;
+
+/// The log file is rotated once it grows past this size.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Rotated files kept next to the current one (`.1` is the newest).
+const KEEP_ROTATED: usize = 3;
+
+struct AppLogger {
+    file: Mutex<Option<File>>,
+    secrets: Mutex<Vec<String>>,
+}
+
+static LOGGER: AppLogger = AppLogger {
+    file: Mutex::new(None),
+    secrets: Mutex::new(Vec::new()),
+};
+
+impl log::Log for AppLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        // Dependencies (eframe, wgpu, reqwest...) only get through with warnings
+        metadata.level() <= log::max_level()
+            && (metadata.target().starts_with(env!("CARGO_CRATE_NAME")) || metadata.level() <= log::Level::Warn)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let mut line = format!(
+            "{} {:<5} {}",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+            record.level(),
+            record.args(),
+        );
+        for secret in self.secrets.lock().unwrap_or_else(|e| e.into_inner()).iter() {
+            line = line.replace(secret.as_str(), "***");
+        }
+        eprintln!("{}", line);
+
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(f) = file.as_mut() {
+            if writeln!(f, "{}", line).is_err() {
+                return;
+            }
+            if f.metadata().is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+                *file = rotate(&log_path()).ok();
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(f) = self.file.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            let _ = f.flush();
+        }
+    }
+}
+
+/// Starts logging at `level`. Call once, after the config path is known.
+pub fn init(level: LogLevel) {
+    let path = log_path();
+    match open(&path) {
+        Ok(file) => *LOGGER.file.lock().unwrap_or_else(|e| e.into_inner()) = Some(file),
+        Err(e) => eprintln!("[Log] Couldn't open {}: {}", path.display(), e),
+    }
+    if log::set_logger(&LOGGER).is_ok() {
+        set_level(level);
+    }
+}
+
+/// Changes the level of messages that get logged.
+pub fn set_level(level: LogLevel) {
+    log::set_max_level(level.filter());
+}
+
+/// Masks `secret` in every message logged from now on.
+pub fn add_secret(secret: &str) {
+    if secret.is_empty() {
+        return;
+    }
+    let mut secrets = LOGGER.secrets.lock().unwrap_or_else(|e| e.into_inner());
+    if !secrets.iter().any(|s| s == secret) {
+        secrets.push(secret.to_string());
+    }
+}
+
+/// The current log file.
+pub fn log_path() -> PathBuf {
+    crate::models::config::data_dir().join("logs").join("iptv-player.log")
+}
+
+fn open(path: &Path) -> std::io::Result<File> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Shifts `log.1` to `log.2` and so on, dropping the oldest, and starts a
+/// fresh file.
+fn rotate(path: &Path) -> std::io::Result<File> {
+    let rotated = |n: usize| path.with_extension(format!("log.{}", n));
+    for n in (1..KEEP_ROTATED).rev() {
+        let _ = fs::rename(rotated(n), rotated(n + 1));
+    }
+    fs::rename(path, rotated(1))?;
+    open(path)
+}
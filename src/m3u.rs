@@ -1,6 +1,6 @@
 ﻿use std::fs::File;
 use std::io::{BufRead, BufReader};
-use crate::models::Channel;
+use crate::models::{Category, Channel};
 
 /// Result from parsing M3U playlist
 pub struct M3UParseResult {
@@ -52,6 +52,7 @@ impl M3UParser {
                         direct_source: Some(line.to_string()),
                         tv_archive_duration: None,
                         is_adult: None,
+                        quality: None,
                     });
                     channel_num += 1;
                     current_name.clear();
@@ -65,7 +66,9 @@ impl M3UParser {
     }
     
     pub fn parse_url(url: &str) -> Result<Vec<Channel>, String> {
-        let response = reqwest::blocking::get(url)
+        let response = crate::api::http::provider_client()
+            .get(url)
+            .send()
             .map_err(|e| e.to_string())?
             .text()
             .map_err(|e| e.to_string())?;
@@ -107,6 +110,7 @@ impl M3UParser {
                         direct_source: Some(line.to_string()),
                         tv_archive_duration: None,
                         is_adult: None,
+                        quality: None,
                     });
                     channel_num += 1;
                     current_name.clear();
@@ -223,6 +227,7 @@ impl M3UParser {
                         direct_source: Some(line.to_string()),
                         tv_archive_duration: None,
                         is_adult: None,
+                        quality: None,
                     });
                     channel_num += 1;
                     current_name.clear();
@@ -238,9 +243,79 @@ impl M3UParser {
         })
     }
 
+    /// Turn an Xtream `get.php?type=m3u_plus` playlist into the live
+    /// categories and channels the API would have returned. VOD and series
+    /// entries are skipped, and stream IDs are taken from the stream URLs
+    /// (`.../user/pass/12345.ts`) so playback goes through the normal
+    /// Xtream stream URLs.
+    pub fn parse_xtream_live(content: &str) -> Result<(Vec<Category>, Vec<Channel>), String> {
+        let mut channels = Self::parse_content(content)?;
+        channels.retain(|ch| {
+            let url = ch.direct_source.as_deref().unwrap_or("");
+            !url.contains("/movie/") && !url.contains("/series/")
+        });
+        if channels.is_empty() {
+            return Err("Playlist contains no live channels".to_string());
+        }
+
+        for channel in &mut channels {
+            let stream_id = channel.direct_source.as_deref()
+                .and_then(|url| url.rsplit('/').next())
+                .map(|file| file.split('.').next().unwrap_or(file))
+                .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()));
+            if let Some(id) = stream_id {
+                channel.stream_id = id.to_string();
+            }
+        }
+
+        Ok((Self::group_categories(&channels), channels))
+    }
+
+    /// Turn a plain playlist (signing in with an M3U instead of Xtream)
+    /// into live categories from its groups and channels that play their
+    /// own stream URL.
+    pub fn parse_playlist(content: &str) -> Result<(Vec<Category>, Vec<Channel>), String> {
+        let channels = Self::parse_content(content)?;
+        if channels.is_empty() {
+            return Err("Playlist contains no channels".to_string());
+        }
+        Ok((Self::group_categories(&channels), channels))
+    }
+
+    /// One category per `group-title`, in playlist order.
+    fn group_categories(channels: &[Channel]) -> Vec<Category> {
+        let mut seen = std::collections::HashSet::new();
+        channels.iter()
+            .filter(|ch| seen.insert(ch.category_id.as_str()))
+            .map(|ch| Category {
+                category_id: ch.category_id.clone(),
+                category_name: ch.category_id.clone(),
+                parent_id: 0,
+            })
+            .collect()
+    }
+
+    /// Read a playlist from an `http(s)://` URL or a local file.
+    pub fn read_source(source: &str) -> Result<String, String> {
+        let source = source.trim();
+        if source.starts_with("http://") || source.starts_with("https://") {
+            crate::api::http::provider_client()
+                .get(source)
+                .send()
+                .and_then(|r| r.error_for_status())
+                .and_then(|r| r.text())
+                .map_err(|e| format!("Couldn't download the playlist: {}", e))
+        } else {
+            let path = source.strip_prefix("file://").unwrap_or(source);
+            std::fs::read_to_string(path).map_err(|e| format!("Couldn't read {}: {}", path, e))
+        }
+    }
+
     /// Parse M3U from URL and extract EPG URL
     pub fn parse_url_with_epg(url: &str) -> Result<M3UParseResult, String> {
-        let response = reqwest::blocking::get(url)
+        let response = crate::api::http::provider_client()
+            .get(url)
+            .send()
             .map_err(|e| e.to_string())?
             .text()
             .map_err(|e| e.to_string())?;
@@ -278,6 +353,43 @@ http://example.com/stream.m3u8
         assert_eq!(channels[0].epg_channel_id, Some("test.tv".to_string()));
     }
     
+    #[test]
+    fn test_parse_xtream_live() {
+        let content = r#"#EXTM3U
+#EXTINF:-1 tvg-id="bbc1.uk" group-title="UK",BBC One
+http://host.tv:8080/user/pass/1234.ts
+#EXTINF:-1 group-title="Movies",Some Film
+http://host.tv:8080/movie/user/pass/99.mkv
+#EXTINF:-1 group-title="News",CNN
+http://host.tv:8080/user/pass/55.ts
+"#;
+        let (categories, channels) = M3UParser::parse_xtream_live(content).unwrap();
+        assert_eq!(channels.len(), 2);
+        assert_eq!(channels[0].stream_id, "1234");
+        assert_eq!(channels[1].stream_id, "55");
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories[0].category_name, "UK");
+    }
+    
+    #[test]
+    fn test_parse_playlist() {
+        let content = r#"#EXTM3U
+#EXTINF:-1 tvg-id="news.tv" tvg-logo="http://example.com/n.png" group-title="News",News 24
+http://cdn.example.com/news/index.m3u8
+#EXTINF:-1 group-title="Sports",Sport 1
+http://cdn.example.com/sport.ts
+#EXTINF:-1 group-title="News",World News
+http://cdn.example.com/world.m3u8
+"#;
+        let (categories, channels) = M3UParser::parse_playlist(content).unwrap();
+        assert_eq!(channels.len(), 3);
+        assert_eq!(channels[0].direct_source.as_deref(), Some("http://cdn.example.com/news/index.m3u8"));
+        assert_eq!(channels[0].epg_channel_id.as_deref(), Some("news.tv"));
+        let names: Vec<&str> = categories.iter().map(|c| c.category_name.as_str()).collect();
+        assert_eq!(names, vec!["News", "Sports"]);
+        assert!(M3UParser::parse_playlist("#EXTM3U\n").is_err());
+    }
+    
     #[test]
     fn test_extract_categories() {
         let content = r#"#EXTM3U
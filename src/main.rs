@@ -9,7 +9,12 @@
 //!
 //! - `api` - Xtream Codes API client
 //! - `models` - Data models and configuration
+//! - `logging` - Log to stderr and a rotating file
 //! - `m3u` - M3U playlist parsing
+//! - `recording` - Live stream recording via ffmpeg
+//! - `reminders` - Reminders for upcoming EPG programmes
+//! - `sync` - Favorites and watch history sync through a shared folder or WebDAV
+//! - `track_probe` - Audio/subtitle track detection via ffprobe
 //! - `ui` - User interface components
 //!
 //! # Steam Deck Support
@@ -23,17 +28,31 @@
 //!
 //! ```bash
 //! cargo run --release
+//! cargo run --release -- --config /path/to/config.json
 //! ```
+//!
+//! The config file is chosen by `--config <path>`, then the
+//! `IPTV_PLAYER_CONFIG` environment variable, then the default
+//! `~/.iptv_player_config.json`. With a custom config, watch history and
+//! cached data are kept in a `<name>-data` folder next to it.
 
 use eframe::egui;
 
 mod api;
+mod embedded_player;
+mod logging;
 mod media_tools;
 mod models;
+mod mpv_ipc;
 mod m3u;
+mod recording;
+mod reminders;
+mod sync;
+mod track_probe;
 mod xmltv;
 mod ui;
 
+use models::{Config, LogLevel, StartupWindowMode};
 use ui::IPTVPlayerApp;
 
 /// Detects if running on Steam Deck based on environment or display characteristics
@@ -65,11 +84,99 @@ fn is_steam_deck_environment() -> bool {
     false
 }
 
+/// Command-line usage, printed for `--help` and bad arguments.
+const USAGE: &str = "Usage: iptv-player [--config <path>]
+
+Options:
+  --config <path>  Use this config file instead of ~/.iptv_player_config.json
+                   (overrides the IPTV_PLAYER_CONFIG environment variable)
+  -h, --help       Show this help";
+
+/// Command-line options.
+#[derive(Debug, Default, PartialEq)]
+struct CliArgs {
+    config_path: Option<std::path::PathBuf>,
+    help: bool,
+    /// Arguments we don't know, e.g. ones a launcher adds (Steam launch
+    /// options, macOS `-psn_…`, a desktop file's `%U`); logged and ignored
+    ignored: Vec<String>,
+}
+
+/// Parses the arguments after the program name.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<CliArgs, String> {
+    let mut parsed = CliArgs::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => parsed.help = true,
+            "--config" => {
+                let path = args.next().ok_or("--config needs a path")?;
+                parsed.config_path = Some(path.into());
+            }
+            _ => match arg.strip_prefix("--config=") {
+                Some("") => return Err("--config needs a path".to_string()),
+                Some(path) => parsed.config_path = Some(path.into()),
+                None => parsed.ignored.push(arg),
+            },
+        }
+    }
+    Ok(parsed)
+}
+
+/// Desktop window: the last size and position if known, opened in the
+/// user's preferred mode.
+fn desktop_viewport(config: &Config) -> egui::ViewportBuilder {
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size([1400.0, 800.0])
+        .with_min_inner_size([1000.0, 600.0])
+        .with_resizable(true);
+
+    if let Some(window) = config.last_window {
+        viewport = viewport
+            .with_inner_size([window.width.max(1000.0), window.height.max(600.0)])
+            .with_position([window.x, window.y]);
+    }
+
+    match config.startup_window {
+        StartupWindowMode::Windowed => viewport,
+        StartupWindowMode::Maximized => viewport.with_maximized(true),
+        StartupWindowMode::Fullscreen => viewport.with_fullscreen(true),
+    }
+}
+
 /// Application entry point.
 ///
 /// Initializes the eframe window and starts the IPTV Player application.
 /// Automatically configures for Steam Deck Game Mode when detected.
 fn main() -> Result<(), eframe::Error> {
+    let cli = match parse_args(std::env::args().skip(1)) {
+        Ok(args) if args.help => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+    if let Some(path) = &cli.config_path {
+        models::config::set_config_path_override(path.clone());
+    }
+
+    let config = Config::load();
+    logging::init(config.as_ref().map_or(LogLevel::default(), |c| c.log_level));
+    if let Some(path) = &cli.config_path {
+        log::info!("[Config] Using {}", path.display());
+    }
+    for arg in &cli.ignored {
+        log::warn!("[Startup] Ignoring unknown argument: {}", arg);
+    }
+    let config = config.unwrap_or_else(|e| {
+        log::error!("[Config] Couldn't read the config file, using defaults: {}", e);
+        Config::default()
+    });
+
     let is_steam_deck = is_steam_deck_environment();
 
     // Configure viewport based on environment
@@ -82,11 +189,7 @@ fn main() -> Result<(), eframe::Error> {
             .with_decorations(false)
             .with_resizable(false)
     } else {
-        // Desktop mode: windowed with standard sizing
-        egui::ViewportBuilder::default()
-            .with_inner_size([1400.0, 800.0])
-            .with_min_inner_size([1000.0, 600.0])
-            .with_resizable(true)
+        desktop_viewport(&config)
     };
 
     let options = eframe::NativeOptions {
@@ -112,3 +215,26 @@ fn main() -> Result<(), eframe::Error> {
         }),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Result<CliArgs, String> {
+        parse_args(list.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_config_arg() {
+        assert_eq!(args(&[]).unwrap(), CliArgs::default());
+        assert_eq!(args(&["--config", "a.json"]).unwrap().config_path, Some("a.json".into()));
+        assert_eq!(args(&["--config=b.json"]).unwrap().config_path, Some("b.json".into()));
+        assert!(args(&["--config"]).is_err());
+        assert!(args(&["--config="]).is_err());
+
+        // Launchers add arguments of their own; those don't stop the app
+        let parsed = args(&["-psn_0_12345", "--config", "a.json", "%U"]).unwrap();
+        assert_eq!(parsed.config_path, Some("a.json".into()));
+        assert_eq!(parsed.ignored, vec!["-psn_0_12345".to_string(), "%U".to_string()]);
+    }
+}
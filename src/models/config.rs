@@ -1,7 +1,55 @@
 ﻿use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use std::fs;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Environment variable naming an alternative config file.
+pub const CONFIG_ENV_VAR: &str = "IPTV_PLAYER_CONFIG";
+
+/// Config file given with `--config`, set once at startup.
+static CONFIG_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Use `path` as the config file for this run. Takes precedence over
+/// [`CONFIG_ENV_VAR`]; only the first call has an effect.
+pub fn set_config_path_override(path: PathBuf) {
+    let _ = CONFIG_PATH_OVERRIDE.set(path);
+}
+
+/// Config file from `--config` or, failing that, the environment.
+fn config_path_override() -> Option<PathBuf> {
+    CONFIG_PATH_OVERRIDE.get().cloned().or_else(|| {
+        std::env::var_os(CONFIG_ENV_VAR)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+    })
+}
+
+/// Where the watch history, reminders and similar files live. With a
+/// custom config file that's a `<name>-data` folder beside it, so each
+/// config (account, portable install) keeps its own; otherwise the
+/// platform config folder.
+pub fn data_dir() -> PathBuf {
+    match config_path_override() {
+        Some(config) => {
+            let stem = config.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+            config.parent()
+                .filter(|p| !p.as_os_str().is_empty())
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join(format!("{}-data", stem))
+        }
+        None => dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("iptv-player"),
+    }
+}
+
+/// Where cached library data is kept: inside [`data_dir`] with a custom
+/// config file, otherwise the platform cache folder.
+pub fn cache_dir() -> PathBuf {
+    match config_path_override() {
+        Some(_) => data_dir().join("cache"),
+        None => dirs::cache_dir().unwrap_or_else(|| PathBuf::from(".")).join("iptv-player").join("cache"),
+    }
+}
 
 /// Available media player backends.
 /// MPV is the default as it works more reliably across platforms including Steam Deck.
@@ -20,6 +68,9 @@ pub enum PlayerType {
     PotPlayer,
     /// Custom player with user-defined path
     Custom,
+    /// Built-in player drawn inside the app window through libmpv; the
+    /// external MPV is used where it can't be (multi-view, playlists)
+    Embedded,
 }
 
 impl PlayerType {
@@ -32,6 +83,7 @@ impl PlayerType {
             PlayerType::MpcHc => "MPC-HC",
             PlayerType::PotPlayer => "PotPlayer",
             PlayerType::Custom => "Custom Player",
+            PlayerType::Embedded => "Built-in (libmpv)",
         }
     }
     
@@ -50,7 +102,7 @@ impl PlayerType {
                 "C:\\Program Files\\VideoLAN\\VLC\\vlc.exe",
                 "C:\\Program Files (x86)\\VideoLAN\\VLC\\vlc.exe",
             ],
-            PlayerType::MPV => vec![
+            PlayerType::MPV | PlayerType::Embedded => vec![
                 "mpv",
                 "mpv.exe",
                 "bundled/mpv.exe",
@@ -98,10 +150,201 @@ impl PlayerType {
             PlayerType::MpcHc,
             PlayerType::PotPlayer,
             PlayerType::Custom,
+            PlayerType::Embedded,
         ]
     }
 }
 
+/// User rule turning a football broadcaster into a Live TV search, e.g.
+/// "sky sports" in the United Kingdom → "|UK| Sky Sports".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ChannelMapping {
+    /// Text the broadcaster's channel name contains (case-insensitive)
+    pub pattern: String,
+    /// Broadcaster country it applies to; empty for any country
+    #[serde(default)]
+    pub country: String,
+    /// Search query used in Live TV
+    pub query: String,
+}
+
+/// Container requested for live channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LiveStreamFormat {
+    /// MPEG-TS (`.ts`)
+    #[default]
+    Ts,
+    /// HLS playlist (`.m3u8`)
+    Hls,
+}
+
+impl LiveStreamFormat {
+    /// File extension used in the stream URL.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            LiveStreamFormat::Ts => "ts",
+            LiveStreamFormat::Hls => "m3u8",
+        }
+    }
+
+    /// Get display name for the format.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LiveStreamFormat::Ts => "MPEG-TS (.ts)",
+            LiveStreamFormat::Hls => "HLS (.m3u8)",
+        }
+    }
+}
+
+/// How the login screen signs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LoginMode {
+    /// Xtream Codes server, username and password
+    #[default]
+    Xtream,
+    /// A plain M3U/M3U8 playlist URL or file, live channels only
+    Playlist,
+}
+
+impl LoginMode {
+    pub const ALL: [LoginMode; 2] = [LoginMode::Xtream, LoginMode::Playlist];
+
+    /// Get display name for the mode.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LoginMode::Xtream => "Xtream Codes",
+            LoginMode::Playlist => "M3U Playlist",
+        }
+    }
+}
+
+/// URL scheme used for catch-up (timeshift) streams. Panels differ in
+/// which one they answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CatchupUrlFormat {
+    /// `/timeshift/user/pass/minutes/start/id.ts`
+    #[default]
+    Path,
+    /// `/streaming/timeshift.php?username=...&stream=...`
+    Query,
+}
+
+impl CatchupUrlFormat {
+    pub const ALL: [CatchupUrlFormat; 2] = [CatchupUrlFormat::Path, CatchupUrlFormat::Query];
+
+    /// Get display name for the format.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CatchupUrlFormat::Path => "/timeshift/…",
+            CatchupUrlFormat::Query => "/streaming/timeshift.php",
+        }
+    }
+}
+
+/// How much goes into the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    /// Also API requests and responses, for troubleshooting a provider
+    Debug,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 4] = [LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug];
+
+    /// Get display name for the level.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "Errors",
+            LogLevel::Warn => "Warnings",
+            LogLevel::Info => "Info",
+            LogLevel::Debug => "Debug",
+        }
+    }
+
+    pub fn filter(&self) -> log::LevelFilter {
+        match self {
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+        }
+    }
+}
+
+/// The view shown after connecting, and what "back" returns to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DefaultContent {
+    #[default]
+    LiveTV,
+    Series,
+    Movies,
+    RecentlyAdded,
+    ContinueWatching,
+    Favorites,
+    OnNow,
+    Discover,
+    FootballFixtures,
+}
+
+impl DefaultContent {
+    pub const ALL: [DefaultContent; 9] = [
+        DefaultContent::LiveTV,
+        DefaultContent::Series,
+        DefaultContent::Movies,
+        DefaultContent::RecentlyAdded,
+        DefaultContent::ContinueWatching,
+        DefaultContent::Favorites,
+        DefaultContent::OnNow,
+        DefaultContent::Discover,
+        DefaultContent::FootballFixtures,
+    ];
+}
+
+/// Connection quality preset that drives the buffering settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ConnectionPreset {
+    /// Player defaults
+    #[default]
+    Auto,
+    /// Small buffer, low latency - for fast, stable connections
+    Fast,
+    /// Large buffer - for slow or unstable connections
+    Slow,
+    /// Low latency and buffer size set by hand
+    Custom,
+}
+
+impl ConnectionPreset {
+    /// Get display name for the preset.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ConnectionPreset::Auto => "Auto",
+            ConnectionPreset::Fast => "Fast (low latency)",
+            ConnectionPreset::Slow => "Slow (large buffer)",
+            ConnectionPreset::Custom => "Custom",
+        }
+    }
+    
+    /// Get all presets that can be picked (Custom is set by the advanced controls).
+    pub fn all() -> &'static [ConnectionPreset] {
+        &[ConnectionPreset::Auto, ConnectionPreset::Fast, ConnectionPreset::Slow]
+    }
+    
+    /// The `(low_latency_mode, buffer_size_kb)` pair the preset stands for.
+    pub fn buffering(&self) -> Option<(bool, u32)> {
+        match self {
+            ConnectionPreset::Auto => Some((false, 0)),
+            ConnectionPreset::Fast => Some((true, 0)),
+            ConnectionPreset::Slow => Some((false, 8192)),
+            ConnectionPreset::Custom => None,
+        }
+    }
+}
+
 /// Player settings for audio and subtitle track selection.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerSettings {
@@ -147,6 +390,13 @@ pub struct PlayerSettings {
     /// Buffer size in KB (0 = auto)
     #[serde(default)]
     pub buffer_size_kb: u32,
+    /// Connection quality preset; older configs keep their hand-set values
+    #[serde(default = "legacy_connection_preset")]
+    pub connection_preset: ConnectionPreset,
+    /// Probe movies and episodes with ffprobe and offer their real audio and
+    /// subtitle tracks before playback
+    #[serde(default = "default_true")]
+    pub choose_tracks_before_playback: bool,
 }
 
 impl Default for PlayerSettings {
@@ -166,6 +416,8 @@ impl Default for PlayerSettings {
             hardware_acceleration: true,
             low_latency_mode: false,
             buffer_size_kb: 0,
+            connection_preset: ConnectionPreset::Auto,
+            choose_tracks_before_playback: true,
         }
     }
 }
@@ -173,8 +425,18 @@ impl Default for PlayerSettings {
 fn default_subtitle_track() -> i32 { -1 }
 fn default_volume() -> i32 { 100 }
 fn default_true() -> bool { true }
+fn legacy_connection_preset() -> ConnectionPreset { ConnectionPreset::Custom }
 
 impl PlayerSettings {
+    /// Switch to a connection preset and set the buffering it stands for.
+    pub fn apply_connection_preset(&mut self, preset: ConnectionPreset) {
+        self.connection_preset = preset;
+        if let Some((low_latency, buffer_kb)) = preset.buffering() {
+            self.low_latency_mode = low_latency;
+            self.buffer_size_kb = buffer_kb;
+        }
+    }
+    
     /// Build ffplay arguments based on settings.
     pub fn build_ffplay_args(&self) -> Vec<String> {
         let mut args = Vec::new();
@@ -225,6 +487,14 @@ impl PlayerSettings {
             args.push(format!("{}k", self.buffer_size_kb));
         }
         
+        // Slow connections: ride out stalls instead of giving up
+        if self.connection_preset == ConnectionPreset::Slow {
+            args.push("-reconnect".to_string());
+            args.push("1".to_string());
+            args.push("-reconnect_streamed".to_string());
+            args.push("1".to_string());
+        }
+        
         // Netflix-style seamless fullscreen playback
         args.push("-fs".to_string());            // Start in fullscreen
         args.push("-autoexit".to_string());      // Exit when done
@@ -295,6 +565,12 @@ impl PlayerSettings {
             args.push("--network-caching=1000".to_string());
         }
         
+        // Slow connections: larger live cache and reconnect on drops
+        if self.connection_preset == ConnectionPreset::Slow {
+            args.push(format!("--live-caching={}", self.buffer_size_kb.max(1000)));
+            args.push("--http-reconnect".to_string());
+        }
+        
         // Additional VLC options for seamless playback
         args.push("--no-video-title-show".to_string());  // No title overlay
         args.push("--mouse-hide-timeout=1500".to_string()); // Quick mouse hide
@@ -319,8 +595,9 @@ impl PlayerSettings {
         args
     }
     
-    /// Build MPV arguments based on settings.
-    pub fn build_mpv_args(&self) -> Vec<String> {
+    /// Build MPV arguments based on settings. `ipc_socket` is where MPV
+    /// listens for the mini player's commands.
+    pub fn build_mpv_args(&self, ipc_socket: Option<&str>) -> Vec<String> {
         let mut args = Vec::new();
         
         // Audio track selection
@@ -374,6 +651,12 @@ impl PlayerSettings {
             args.push(format!("--cache-secs={}", self.buffer_size_kb / 1024));
         }
         
+        // Slow connections: always cache and read further ahead
+        if self.connection_preset == ConnectionPreset::Slow {
+            args.push("--cache=yes".to_string());
+            args.push("--demuxer-readahead-secs=20".to_string());
+        }
+        
         // Netflix-style seamless playback
         args.push("--fullscreen".to_string());           // Start in fullscreen
         args.push("--no-border".to_string());            // Borderless window
@@ -382,6 +665,11 @@ impl PlayerSettings {
         args.push("--cursor-autohide=1000".to_string()); // Quick cursor hide
         args.push("--keep-open=no".to_string());         // Close when done
         
+        // Remote control from the mini player
+        if let Some(socket) = ipc_socket {
+            args.push(format!("--input-ipc-server={}", socket));
+        }
+        
         args
     }
     
@@ -463,47 +751,8 @@ impl PlayerSettings {
             PlayerType::Custom => self.custom_player_path.clone(),
             _ => {
                 // Try each possible executable path from defaults
-                for path in self.player_type.default_executables() {
-                    // Check if it's a full path and exists
-                    if path.contains('\\') || path.contains('/') {
-                        if std::path::Path::new(path).exists() {
-                            return path.to_string();
-                        }
-                    } else {
-                        // It's just a command name, try to find it using `where` on Windows
-                        #[cfg(windows)]
-                        {
-                            use std::os::windows::process::CommandExt;
-                            if let Ok(output) = std::process::Command::new("where")
-                                .arg(path)
-                                .creation_flags(0x08000000) // CREATE_NO_WINDOW for this check
-                                .output()
-                            {
-                                if output.status.success() {
-                                    // Return the first found path from where output
-                                    if let Ok(stdout) = String::from_utf8(output.stdout) {
-                                        if let Some(first_path) = stdout.lines().next() {
-                                            let trimmed = first_path.trim();
-                                            if !trimmed.is_empty() {
-                                                return trimmed.to_string();
-                                            }
-                                        }
-                                    }
-                                    return path.to_string();
-                                }
-                            }
-                        }
-                        
-                        #[cfg(not(windows))]
-                        if let Ok(output) = std::process::Command::new("which")
-                            .arg(path)
-                            .output()
-                        {
-                            if output.status.success() {
-                                return path.to_string();
-                            }
-                        }
-                    }
+                if let Some(path) = find_executable(&self.player_type.default_executables()) {
+                    return path;
                 }
                 
                 // Try additional dynamic paths based on player type
@@ -529,7 +778,7 @@ impl PlayerSettings {
             // Get user profile directory
             if let Ok(userprofile) = std::env::var("USERPROFILE") {
                 let exe_name = match self.player_type {
-                    PlayerType::MPV => "mpv.exe",
+                    PlayerType::MPV | PlayerType::Embedded => "mpv.exe",
                     PlayerType::VLC => "vlc.exe",
                     PlayerType::MpcHc => "mpc-hc64.exe",
                     PlayerType::PotPlayer => "PotPlayerMini64.exe",
@@ -540,7 +789,7 @@ impl PlayerSettings {
                 // Scoop installation
                 paths.push(format!("{}\\scoop\\apps\\{}\\current\\{}", userprofile, 
                     match self.player_type {
-                        PlayerType::MPV => "mpv",
+                        PlayerType::MPV | PlayerType::Embedded => "mpv",
                         PlayerType::VLC => "vlc",
                         PlayerType::MpcHc => "mpc-hc",
                         PlayerType::PotPlayer => "potplayer",
@@ -554,7 +803,7 @@ impl PlayerSettings {
             // Local app data
             if let Ok(localappdata) = std::env::var("LOCALAPPDATA") {
                 match self.player_type {
-                    PlayerType::MPV => {
+                    PlayerType::MPV | PlayerType::Embedded => {
                         paths.push(format!("{}\\Programs\\mpv\\mpv.exe", localappdata));
                         paths.push(format!("{}\\mpv\\mpv.exe", localappdata));
                     }
@@ -569,15 +818,129 @@ impl PlayerSettings {
         paths
     }
     
-    /// Launch the configured player with the given URL and title.
-    pub fn launch_player(&self, url: &str, title: &str, is_live: bool) -> std::io::Result<std::process::Child> {
+    /// Build window placement arguments for a multi-view tile.
+    /// `muted` silences the tile so only one stream plays audio.
+    /// Players without command-line positioning get no extra arguments.
+    pub fn build_tile_args(&self, geometry: &WindowGeometry, muted: bool) -> Vec<String> {
+        let mut args = Vec::new();
+        let WindowGeometry { x, y, width, height } = *geometry;
+        
+        match self.player_type {
+            PlayerType::MPV | PlayerType::Embedded => {
+                args.push(format!("--geometry={}x{}+{}+{}", width, height, x, y));
+                args.push("--autofit-larger=100%x100%".to_string());
+                args.push("--force-window=immediate".to_string());
+                if muted {
+                    args.push("--mute=yes".to_string());
+                }
+            }
+            PlayerType::VLC => {
+                // VLC only honours positioning for a standalone video window
+                args.push("--no-embedded-video".to_string());
+                args.push(format!("--video-x={}", x));
+                args.push(format!("--video-y={}", y));
+                args.push(format!("--width={}", width));
+                args.push(format!("--height={}", height));
+                if muted {
+                    args.push("--no-audio".to_string());
+                }
+            }
+            PlayerType::FFplay => {
+                args.extend([
+                    "-left".to_string(), x.to_string(),
+                    "-top".to_string(), y.to_string(),
+                    "-x".to_string(), width.to_string(),
+                    "-y".to_string(), height.to_string(),
+                ]);
+                if muted {
+                    args.push("-an".to_string());
+                }
+            }
+            PlayerType::MpcHc | PlayerType::PotPlayer | PlayerType::Custom => {}
+        }
+        
+        args
+    }
+    
+    /// Launch the configured player with the given URL and title. MPV
+    /// takes remote commands on `ipc_socket` when given.
+    pub fn launch_player(&self, url: &str, title: &str, is_live: bool, ipc_socket: Option<&str>) -> std::io::Result<std::process::Child> {
+        self.spawn_player(&[url], title, is_live, None, ipc_socket)
+    }
+    
+    /// Launch a VOD stream starting `seconds` in, to resume where the user
+    /// left off.
+    pub fn launch_player_from(&self, url: &str, title: &str, seconds: f64, ipc_socket: Option<&str>) -> std::io::Result<std::process::Child> {
+        self.spawn_player(&[url], title, false, Some(self.build_start_args(seconds)), ipc_socket)
+    }
+    
+    /// Build arguments that start playback `seconds` into the stream.
+    pub fn build_start_args(&self, seconds: f64) -> Vec<String> {
+        let seconds = seconds.max(0.0) as u64;
+        if seconds == 0 {
+            return Vec::new();
+        }
+        
+        match self.player_type {
+            PlayerType::MPV | PlayerType::Embedded => vec![format!("--start={}", seconds)],
+            PlayerType::VLC => vec![format!("--start-time={}", seconds)],
+            PlayerType::FFplay => vec!["-ss".to_string(), seconds.to_string()],
+            PlayerType::MpcHc => vec!["/start".to_string(), (seconds * 1000).to_string()],
+            PlayerType::PotPlayer => vec![format!("/seek={}", seconds)],
+            PlayerType::Custom => Vec::new(),
+        }
+    }
+    
+    /// Whether the configured player plays several URLs passed on the
+    /// command line back to back, as a playlist.
+    pub fn supports_playlists(&self) -> bool {
+        matches!(self.player_type, PlayerType::MPV | PlayerType::Embedded | PlayerType::VLC)
+    }
+    
+    /// Launch the configured player with several URLs queued as a playlist.
+    /// Check [`Self::supports_playlists`] first; other players only get the
+    /// first URL.
+    pub fn launch_playlist(&self, urls: &[String], title: &str, ipc_socket: Option<&str>) -> std::io::Result<std::process::Child> {
+        let urls: Vec<&str> = urls.iter().map(String::as_str).collect();
+        let urls = if self.supports_playlists() { &urls[..] } else { &urls[..urls.len().min(1)] };
+        self.spawn_player(urls, title, false, None, ipc_socket)
+    }
+    
+    /// Launch a live stream into a multi-view tile at the given position.
+    pub fn launch_player_in_tile(
+        &self,
+        url: &str,
+        title: &str,
+        geometry: &WindowGeometry,
+        muted: bool,
+    ) -> std::io::Result<std::process::Child> {
+        self.spawn_player(&[url], title, true, Some(self.build_tile_args(geometry, muted)), None)
+    }
+    
+    /// Spawn the player process for one or more URLs, with optional extra
+    /// arguments (tile placement, start position) and MPV IPC socket.
+    fn spawn_player(
+        &self,
+        urls: &[&str],
+        title: &str,
+        is_live: bool,
+        extra_args: Option<Vec<String>>,
+        ipc_socket: Option<&str>,
+    ) -> std::io::Result<std::process::Child> {
         let executable = self.get_player_executable();
+        let extra_args = extra_args.unwrap_or_default();
+        let headers = crate::api::http::provider_headers();
         
-        // Trim URL to remove any leading/trailing whitespace
-        let url = url.trim();
+        // Trim URLs to remove any leading/trailing whitespace
+        let urls: Vec<&str> = urls.iter().map(|url| url.trim()).collect();
+        let url = urls.first().copied().unwrap_or_default();
         
         // Log the command for debugging
-        eprintln!("[Player] Launching: {} with URL: {}", executable, url);
+        if urls.len() > 1 {
+            log::info!("[Player] Launching: {} with {} URLs, first: {}", executable, urls.len(), url);
+        } else {
+            log::info!("[Player] Launching: {} with URL: {}", executable, url);
+        }
         
         // Create command - GUI players need to show their window, don't use CREATE_NO_WINDOW
         // Only use DETACHED_PROCESS to separate from our console
@@ -608,22 +971,25 @@ impl PlayerSettings {
                 for arg in self.build_ffplay_args() {
                     cmd.arg(arg);
                 }
+                cmd.args(&extra_args);
                 if is_live && self.low_latency_mode {
                     cmd.arg("-probesize").arg("32");
                     cmd.arg("-analyzeduration").arg("0");
                 }
-                cmd.arg(url);
+                cmd.args(headers.ffmpeg_args());
+                cmd.args(&urls);
             }
             PlayerType::VLC => {
                 // VLC on Linux needs URL first, then options work better
                 #[cfg(not(windows))]
                 {
-                    cmd.arg(url);
+                    cmd.args(&urls);
                     cmd.arg(format!("--meta-title={}", title));
                     for arg in self.build_vlc_args() {
                         cmd.arg(arg);
                     }
-                    cmd.arg("--http-user-agent=IPTV-Player/1.0");
+                    cmd.args(&extra_args);
+                    cmd.args(headers.vlc_args());
                 }
                 
                 #[cfg(windows)]
@@ -632,32 +998,36 @@ impl PlayerSettings {
                     for arg in self.build_vlc_args() {
                         cmd.arg(arg);
                     }
-                    cmd.arg("--http-user-agent=IPTV-Player/1.0");
-                    cmd.arg(url);
+                    cmd.args(&extra_args);
+                    cmd.args(headers.vlc_args());
+                    cmd.args(&urls);
                 }
             }
-            PlayerType::MPV => {
+            PlayerType::MPV | PlayerType::Embedded => {
                 cmd.arg(format!("--title={}", title));
-                for arg in self.build_mpv_args() {
+                for arg in self.build_mpv_args(ipc_socket) {
                     cmd.arg(arg);
                 }
-                // Add user-agent for IPTV compatibility
-                cmd.arg("--user-agent=IPTV-Player/1.0");
+                cmd.args(&extra_args);
+                // Provider User-Agent/Referer for IPTV compatibility
+                cmd.args(headers.mpv_args());
                 // Use -- to separate options from URL
                 cmd.arg("--");
-                cmd.arg(url);
+                cmd.args(&urls);
             }
             PlayerType::MpcHc => {
                 for arg in self.build_mpchc_args() {
                     cmd.arg(arg);
                 }
-                cmd.arg(url);
+                cmd.args(&extra_args);
+                cmd.args(&urls);
             }
             PlayerType::PotPlayer => {
                 for arg in self.build_potplayer_args() {
                     cmd.arg(arg);
                 }
-                cmd.arg(url);
+                cmd.args(&extra_args);
+                cmd.args(&urls);
             }
             PlayerType::Custom => {
                 for arg in self.build_custom_args(url, title) {
@@ -668,15 +1038,257 @@ impl PlayerSettings {
         
         cmd.spawn()
     }
+    
+    /// Whether the configured player can resolve `ytdl://` URLs (e.g. a
+    /// YouTube search) itself. Only MPV does, through yt-dlp.
+    pub fn supports_web_urls(&self) -> bool {
+        matches!(self.player_type, PlayerType::MPV | PlayerType::Embedded)
+    }
+    
+    /// Play a trailer URL in the configured player when it can handle web
+    /// URLs, falling back to the YouTube page in the system browser.
+    pub fn launch_trailer(&self, url: &str, title: &str) -> std::io::Result<std::process::Child> {
+        if self.supports_web_urls() {
+            match self.launch_player(url, title, false, None) {
+                Ok(child) => return Ok(child),
+                Err(e) => log::warn!("[Player] Trailer playback failed ({}), opening browser", e),
+            }
+        }
+        open_in_browser(&crate::api::omdb::trailer_page_url(url))
+    }
+}
+
+/// Screen position and size of a player window, in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowGeometry {
+    /// Tile `index` (row-major) of a `cols`×`rows` grid covering the screen.
+    pub fn grid_tile(screen_width: u32, screen_height: u32, cols: u32, rows: u32, index: u32) -> Self {
+        let cols = cols.max(1);
+        let rows = rows.max(1);
+        let width = screen_width / cols;
+        let height = screen_height / rows;
+        Self {
+            x: ((index % cols) * width) as i32,
+            y: ((index / cols % rows) * height) as i32,
+            width,
+            height,
+        }
+    }
+}
+
+/// Find the first usable executable among `candidates`.
+///
+/// Entries containing a path separator must exist on disk; bare command
+/// names are looked up on the PATH (`where` on Windows, `which` elsewhere).
+pub fn find_executable(candidates: &[&str]) -> Option<String> {
+    for path in candidates {
+        // Check if it's a full path and exists
+        if path.contains('\\') || path.contains('/') {
+            if std::path::Path::new(path).exists() {
+                return Some(path.to_string());
+            }
+        } else {
+            // It's just a command name, try to find it using `where` on Windows
+            #[cfg(windows)]
+            {
+                use std::os::windows::process::CommandExt;
+                if let Ok(output) = std::process::Command::new("where")
+                    .arg(path)
+                    .creation_flags(0x08000000) // CREATE_NO_WINDOW for this check
+                    .output()
+                {
+                    if output.status.success() {
+                        // Return the first found path from where output
+                        if let Ok(stdout) = String::from_utf8(output.stdout) {
+                            if let Some(first_path) = stdout.lines().next() {
+                                let trimmed = first_path.trim();
+                                if !trimmed.is_empty() {
+                                    return Some(trimmed.to_string());
+                                }
+                            }
+                        }
+                        return Some(path.to_string());
+                    }
+                }
+            }
+            
+            #[cfg(not(windows))]
+            if let Ok(output) = std::process::Command::new("which")
+                .arg(path)
+                .output()
+            {
+                if output.status.success() {
+                    return Some(path.to_string());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Open a URL with the platform's default handler.
+pub fn open_in_browser(url: &str) -> std::io::Result<std::process::Child> {
+    log::info!("[Player] Opening in browser: {}", url);
+    
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut c = std::process::Command::new("cmd");
+        c.args(["/C", "start", ""]);
+        c
+    };
+    
+    #[cfg(target_os = "macos")]
+    let mut cmd = std::process::Command::new("open");
+    
+    #[cfg(all(not(windows), not(target_os = "macos")))]
+    let mut cmd = std::process::Command::new("xdg-open");
+    
+    cmd.arg(url).spawn()
+}
+
+/// How the movie and series lists are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ViewMode {
+    /// Poster cards
+    #[default]
+    Grid,
+    /// Dense rows with a small thumbnail
+    List,
+}
+
+/// How the main window opens on desktop. Steam Deck Game Mode always
+/// starts fullscreen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StartupWindowMode {
+    #[default]
+    Windowed,
+    Maximized,
+    Fullscreen,
+}
+
+impl StartupWindowMode {
+    pub const ALL: [StartupWindowMode; 3] = [
+        StartupWindowMode::Windowed,
+        StartupWindowMode::Maximized,
+        StartupWindowMode::Fullscreen,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StartupWindowMode::Windowed => "Windowed",
+            StartupWindowMode::Maximized => "Maximized",
+            StartupWindowMode::Fullscreen => "Fullscreen",
+        }
+    }
+}
+
+/// Order of the categories in the sidebar, below the pinned ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CategorySort {
+    /// As the provider lists them
+    #[default]
+    Provider,
+    Alphabetical,
+    /// Most items first
+    ItemCount,
+}
+
+impl CategorySort {
+    pub const ALL: [CategorySort; 3] = [
+        CategorySort::Provider,
+        CategorySort::Alphabetical,
+        CategorySort::ItemCount,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CategorySort::Provider => "Provider order",
+            CategorySort::Alphabetical => "A–Z",
+            CategorySort::ItemCount => "Most items",
+        }
+    }
+}
+
+/// Position and size of the main window when it was last closed, in
+/// logical points.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SavedWindow {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// A saved provider account: its sign-in and its own My List.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    #[serde(default)]
+    pub login_mode: LoginMode,
+    #[serde(default)]
+    pub server_url: String,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub playlist_url: String,
+    #[serde(default)]
+    pub favorites: Vec<String>,
+    #[serde(default)]
+    pub favorite_movies: Vec<String>,
+    #[serde(default)]
+    pub favorite_series: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// Sign-in and favorites below are those of the active profile; they
+    /// stay at the top level so older versions can still read the config.
     pub server_url: String,
     pub username: String,
     pub password: String,
-    pub favorites: HashSet<String>,
+    /// Whether to sign in with Xtream credentials or an M3U playlist
+    #[serde(default)]
+    pub login_mode: LoginMode,
+    /// M3U playlist URL or file path for `LoginMode::Playlist`
+    #[serde(default)]
+    pub playlist_url: String,
+    /// Saved accounts to switch between
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    /// Name of the profile signed in with; `None` until the first sign-in
+    /// creates one
+    #[serde(default)]
+    pub active_profile: Option<String>,
+    /// Favorite channel stream IDs, in the order My List shows them.
+    /// Older configs saved an unordered set, which loads the same way.
+    pub favorites: Vec<String>,
+    /// Movie stream IDs in My List, in the order it shows them
+    #[serde(default)]
+    pub favorite_movies: Vec<String>,
+    /// Series IDs in My List, in the order it shows them
+    #[serde(default)]
+    pub favorite_series: Vec<String>,
+    /// Channels the user hid (dead or unwanted): stream ID -> name, the
+    /// name only for listing them in settings
+    #[serde(default)]
+    pub hidden_channels: BTreeMap<String, String>,
+    /// Connect with the saved credentials on startup
     pub auto_login: bool,
+    /// Container requested for live channels
+    #[serde(default)]
+    pub live_stream_format: LiveStreamFormat,
+    /// URL scheme for catch-up streams
+    #[serde(default)]
+    pub catchup_url_format: CatchupUrlFormat,
     /// Player settings for audio/subtitle configuration
     #[serde(default)]
     pub player_settings: PlayerSettings,
@@ -686,31 +1298,320 @@ pub struct Config {
     /// Whether to enable external EPG
     #[serde(default)]
     pub epg_enabled: bool,
+    /// Show football kick-off times in local time instead of the source timezone
+    #[serde(default = "default_true")]
+    pub football_local_time: bool,
+    /// Favorite football teams as `football::team_key`s, used by the
+    /// "My Teams" fixtures filter
+    #[serde(default)]
+    pub favorite_teams: HashSet<String>,
+    /// Reload the open fixtures category this often, in minutes (0 = off)
+    #[serde(default)]
+    pub football_refresh_minutes: u32,
+    /// Re-run the fixtures scraper this often while the sports view is
+    /// open, in hours (0 = off)
+    #[serde(default)]
+    pub football_scrape_hours: u32,
+    /// Broadcaster → Live TV search rules, tried before the built-in ones
+    #[serde(default)]
+    pub channel_mappings: Vec<ChannelMapping>,
+    /// Analog stick dead zone (0.0-1.0) for controller scrolling
+    #[serde(default = "default_stick_dead_zone")]
+    pub stick_dead_zone: f32,
+    /// Multiplier for analog stick scroll speed
+    #[serde(default = "default_stick_scroll_sensitivity")]
+    pub stick_scroll_sensitivity: f32,
+    /// Directory live recordings are written to (None = default Videos folder)
+    #[serde(default)]
+    pub recordings_dir: Option<String>,
+    /// How long the channel/series/movie library is cached, in hours
+    #[serde(default = "default_cache_ttl_hours")]
+    pub content_cache_ttl_hours: u64,
+    /// Keep the library on disk between runs; off fetches it on every start
+    #[serde(default = "default_true")]
+    pub content_cache_enabled: bool,
+    /// Fetch movies and series one category at a time, when it is picked,
+    /// instead of the whole catalog up front (for very large providers)
+    #[serde(default)]
+    pub load_vod_by_category: bool,
+    /// Movies and series added within this many days get a "NEW" badge
+    #[serde(default = "default_new_content_days")]
+    pub new_content_days: u32,
+    /// Percent watched after which a title counts as finished
+    #[serde(default = "default_watched_percent")]
+    pub watched_percent: u32,
+    /// Percent watched a title needs to show in Continue Watching
+    #[serde(default = "default_continue_min_percent")]
+    pub continue_min_percent: u32,
+    /// Look for a newer release on startup (opt-in, network)
+    #[serde(default)]
+    pub check_for_updates: bool,
+    /// Release tag whose update banner was dismissed
+    #[serde(default)]
+    pub dismissed_update: Option<String>,
+    /// App version whose "What's new" notes were last shown
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+    /// What gets written to the log file
+    #[serde(default)]
+    pub log_level: LogLevel,
+    /// Cards per row in the channel, movie and series grids (0 = fit to width)
+    #[serde(default)]
+    pub grid_columns: u32,
+    /// View to open after connecting
+    #[serde(default)]
+    pub default_content: DefaultContent,
+    /// Cover posters from adult categories until hovered
+    #[serde(default = "default_true")]
+    pub blur_adult_content: bool,
+    /// Zoom movie/series posters and show their details on hover
+    #[serde(default = "default_true")]
+    pub card_hover_preview: bool,
+    /// Use the touch layout even on screens not detected as touch
+    /// (convertible laptops, unrecognized handhelds)
+    #[serde(default)]
+    pub touch_mode: bool,
+    /// Minimum height of buttons and other controls in touch mode, in
+    /// points (0 = built-in sizes)
+    #[serde(default)]
+    pub touch_target_size: f32,
+    /// Locale for number and date formatting ("de-DE"); `None` follows
+    /// the system
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// UI language code ("de"); `None` follows the system if translated
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Show channels and movies listed more than once (same stream ID or
+    /// name) only the first time
+    #[serde(default)]
+    pub hide_duplicates: bool,
+    /// Proxy for all outbound requests (http://, https:// or socks5:// URL)
+    #[serde(default)]
+    pub proxy_url: Option<String>,
+    /// Skip TLS certificate verification (insecure, opt-in for broken
+    /// networks that intercept HTTPS)
+    #[serde(default)]
+    pub allow_insecure_tls: bool,
+    /// Timeout for every network request, in seconds (large playlists need
+    /// a generous value on slow links)
+    #[serde(default = "default_network_timeout_secs")]
+    pub network_timeout_secs: u64,
+    /// User-Agent sent to the provider and passed to the player (some
+    /// panels only serve clients they recognize)
+    #[serde(default = "default_user_agent")]
+    pub user_agent: String,
+    /// Referer sent to the provider and passed to the player, for panels
+    /// that require one
+    #[serde(default)]
+    pub referer: Option<String>,
+    /// Posters and logos downloaded at the same time (lower it if the
+    /// provider throttles, raise it on fast connections)
+    #[serde(default = "default_image_downloads")]
+    pub image_downloads: usize,
+    /// Disk space for downloaded posters and logos, in MB; 0 keeps them
+    /// in memory only
+    #[serde(default = "default_image_cache_mb")]
+    pub image_cache_mb: u64,
+    /// Read the start of a stream before opening the player, to report a
+    /// dead stream or expired login instead of a black player window
+    #[serde(default)]
+    pub stream_preflight: bool,
+    /// Shared folder or WebDAV URL that favorites and watch history are
+    /// synced through; `None` disables sync
+    #[serde(default)]
+    pub sync_target: Option<String>,
+    /// Switch to the channel by itself when a programme reminder fires
+    #[serde(default)]
+    pub reminder_auto_tune: bool,
+    /// Layout of the Movies section
+    #[serde(default)]
+    pub movies_view: ViewMode,
+    /// Layout of the Series section
+    #[serde(default)]
+    pub series_view: ViewMode,
+    /// Order of the sidebar categories
+    #[serde(default)]
+    pub category_sort: CategorySort,
+    /// Category IDs pinned to the top of the sidebar, in pin order, by
+    /// section ("live", "series", "movies")
+    #[serde(default)]
+    pub pinned_categories: HashMap<String, Vec<String>>,
+    /// How the window opens on desktop
+    #[serde(default)]
+    pub startup_window: StartupWindowMode,
+    /// Window position and size to restore on launch
+    #[serde(default)]
+    pub last_window: Option<SavedWindow>,
 }
 
+fn default_stick_dead_zone() -> f32 { 0.15 }
+fn default_stick_scroll_sensitivity() -> f32 { 1.0 }
+fn default_cache_ttl_hours() -> u64 { 24 }
+fn default_new_content_days() -> u32 { 7 }
+fn default_watched_percent() -> u32 { 90 }
+fn default_continue_min_percent() -> u32 { 2 }
+fn default_network_timeout_secs() -> u64 { DEFAULT_NETWORK_TIMEOUT_SECS }
+fn default_user_agent() -> String { DEFAULT_USER_AGENT.to_string() }
+fn default_image_downloads() -> usize { 4 }
+
+fn default_image_cache_mb() -> u64 { 500 }
+
+/// Default network request timeout, in seconds. Full channel and VOD
+/// catalogs run to tens of MB, which takes this long on slow links.
+pub const DEFAULT_NETWORK_TIMEOUT_SECS: u64 = 120;
+
+/// User-Agent used with the provider unless configured otherwise. A common
+/// player string, since panels tend to whitelist those.
+pub const DEFAULT_USER_AGENT: &str = "VLC/3.0.20 LibVLC/3.0.20";
+
 impl Config {
+    /// Reads the config file, or its backup if the file is corrupt.
     pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
         let config_path = Self::config_path()?;
-        if config_path.exists() {
-            let content = fs::read_to_string(&config_path)?;
-            let config: Config = serde_json::from_str(&content)?;
-            Ok(config)
-        } else {
-            Ok(Self::default())
-        }
+        Ok(super::storage::load_json(&config_path)?.unwrap_or_default())
     }
 
+    /// Writes the config atomically, keeping the previous one as a backup.
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let config_path = Self::config_path()?;
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
+        super::storage::save_json(&Self::config_path()?, self)
+    }
+
+    /// Writes the config over its backup as well, for when something was
+    /// removed that shouldn't linger in the previous version (a password).
+    pub fn save_replacing_backup(&self) -> Result<(), Box<dyn std::error::Error>> {
+        super::storage::save_json_replacing_backup(&Self::config_path()?, self)
+    }
+
+    /// Writes the sign-in and favorites into the active profile, creating
+    /// one named `name` (or after the account) when none is active. A
+    /// different `name` renames the active profile.
+    pub fn store_active_profile(&mut self, name: &str) {
+        let name = match name.trim() {
+            "" => self.active_profile.clone().unwrap_or_else(|| self.default_profile_name()),
+            name => name.to_string(),
+        };
+        let index = self.active_profile.as_ref()
+            .and_then(|active| self.profiles.iter().position(|p| &p.name == active));
+        // Another profile already has the name: keep the old one
+        let taken = self.profiles.iter().enumerate().any(|(i, p)| p.name == name && Some(i) != index);
+        let name = match (taken, index) {
+            (true, Some(i)) => self.profiles[i].name.clone(),
+            (true, None) => self.unique_profile_name(&name),
+            (false, _) => name,
+        };
+
+        let profile = Profile {
+            name: name.clone(),
+            login_mode: self.login_mode,
+            server_url: self.server_url.clone(),
+            username: self.username.clone(),
+            password: self.password.clone(),
+            playlist_url: self.playlist_url.clone(),
+            favorites: self.favorites.clone(),
+            favorite_movies: self.favorite_movies.clone(),
+            favorite_series: self.favorite_series.clone(),
+        };
+        match index {
+            Some(i) => self.profiles[i] = profile,
+            None => self.profiles.push(profile),
+        }
+        self.active_profile = Some(name);
+    }
+
+    /// Makes the profile `name` the active one, replacing the sign-in and
+    /// favorites at the top level. Returns false if there is no such profile.
+    pub fn activate_profile(&mut self, name: &str) -> bool {
+        let Some(profile) = self.profiles.iter().find(|p| p.name == name).cloned() else {
+            return false;
+        };
+        self.login_mode = profile.login_mode;
+        self.server_url = profile.server_url;
+        self.username = profile.username;
+        self.password = profile.password;
+        self.playlist_url = profile.playlist_url;
+        self.favorites = profile.favorites;
+        self.favorite_movies = profile.favorite_movies;
+        self.favorite_series = profile.favorite_series;
+        self.active_profile = Some(profile.name);
+        true
+    }
+
+    /// Starts a new account: empty sign-in and favorites, no active profile.
+    pub fn deactivate_profile(&mut self) {
+        self.active_profile = None;
+        self.server_url.clear();
+        self.username.clear();
+        self.password.clear();
+        self.playlist_url.clear();
+        self.favorites.clear();
+        self.favorite_movies.clear();
+        self.favorite_series.clear();
+    }
+
+    /// Removes the active profile along with the sign-in at the top level.
+    pub fn remove_active_profile(&mut self) {
+        if let Some(active) = self.active_profile.take() {
+            self.profiles.retain(|p| p.name != active);
+        }
+        self.deactivate_profile();
+    }
+
+    /// "user@host", or the playlist's file name.
+    fn default_profile_name(&self) -> String {
+        let name = match self.login_mode {
+            LoginMode::Xtream => {
+                let host = self.server_url.split("://").last().unwrap_or_default();
+                format!("{}@{}", self.username, host.trim_end_matches('/'))
+            }
+            LoginMode::Playlist => self.playlist_url.trim_end_matches('/')
+                .rsplit(['/', '\\'])
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        };
+        if name.is_empty() { "Account".to_string() } else { name }
+    }
+
+    /// `name`, or `name (2)`, `name (3)`... when it's taken.
+    fn unique_profile_name(&self, name: &str) -> String {
+        (2..)
+            .map(|n| format!("{} ({})", name, n))
+            .find(|candidate| !self.profiles.iter().any(|p| &p.name == candidate))
+            .unwrap_or_default()
+    }
+
+    /// Library cache lifetime in seconds; 0 when the library isn't cached.
+    pub fn content_cache_ttl_secs(&self) -> u64 {
+        if !self.content_cache_enabled {
+            return 0;
+        }
+        self.content_cache_ttl_hours.max(1) * 3600
+    }
+
+    /// Directory live recordings are saved to: the user's choice, or
+    /// the default recordings folder.
+    pub fn recordings_path(&self) -> PathBuf {
+        match self.recordings_dir.as_deref().map(str::trim) {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => Self::default_recordings_path(),
         }
-        let content = serde_json::to_string_pretty(self)?;
-        fs::write(&config_path, content)?;
-        Ok(())
     }
 
+    /// "IPTV Recordings" in the Videos (or home) folder.
+    pub fn default_recordings_path() -> PathBuf {
+        dirs::video_dir()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("IPTV Recordings")
+    }
+
+    /// The config file: `--config`, then `IPTV_PLAYER_CONFIG`, then
+    /// `~/.iptv_player_config.json`.
     fn config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+        if let Some(path) = config_path_override() {
+            return Ok(path);
+        }
         let home = dirs::home_dir().ok_or("Could not find home directory")?;
         Ok(home.join(".iptv_player_config.json"))
     }
@@ -722,11 +1623,150 @@ impl Default for Config {
             server_url: String::new(),
             username: String::new(),
             password: String::new(),
-            favorites: HashSet::new(),
-            auto_login: false,
+            login_mode: LoginMode::Xtream,
+            playlist_url: String::new(),
+            profiles: Vec::new(),
+            active_profile: None,
+            favorites: Vec::new(),
+            favorite_movies: Vec::new(),
+            favorite_series: Vec::new(),
+            hidden_channels: BTreeMap::new(),
+            auto_login: true,
+            live_stream_format: LiveStreamFormat::Ts,
+            catchup_url_format: CatchupUrlFormat::Path,
             player_settings: PlayerSettings::default(),
             epg_url: None,
             epg_enabled: false,
+            football_local_time: true,
+            favorite_teams: HashSet::new(),
+            football_refresh_minutes: 0,
+            football_scrape_hours: 0,
+            channel_mappings: Vec::new(),
+            stick_dead_zone: default_stick_dead_zone(),
+            stick_scroll_sensitivity: default_stick_scroll_sensitivity(),
+            recordings_dir: None,
+            content_cache_ttl_hours: default_cache_ttl_hours(),
+            content_cache_enabled: true,
+            load_vod_by_category: false,
+            new_content_days: default_new_content_days(),
+            watched_percent: default_watched_percent(),
+            continue_min_percent: default_continue_min_percent(),
+            check_for_updates: false,
+            dismissed_update: None,
+            last_seen_version: None,
+            log_level: LogLevel::Info,
+            grid_columns: 0,
+            default_content: DefaultContent::LiveTV,
+            blur_adult_content: true,
+            card_hover_preview: true,
+            touch_mode: false,
+            touch_target_size: 0.0,
+            hide_duplicates: false,
+            locale: None,
+            language: None,
+            proxy_url: None,
+            allow_insecure_tls: false,
+            network_timeout_secs: default_network_timeout_secs(),
+            user_agent: default_user_agent(),
+            referer: None,
+            image_downloads: default_image_downloads(),
+            image_cache_mb: default_image_cache_mb(),
+            stream_preflight: false,
+            sync_target: None,
+            reminder_auto_tune: false,
+            movies_view: ViewMode::Grid,
+            series_view: ViewMode::Grid,
+            category_sort: CategorySort::Provider,
+            pinned_categories: HashMap::new(),
+            startup_window: StartupWindowMode::Windowed,
+            last_window: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signed_in(username: &str, favorite: &str) -> Config {
+        Config {
+            server_url: "http://provider.tv:8080/".to_string(),
+            username: username.to_string(),
+            password: "secret".to_string(),
+            favorites: vec![favorite.to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_profile_created_on_first_sign_in() {
+        let mut config = signed_in("alice", "1");
+        config.store_active_profile("");
+        assert_eq!(config.active_profile.as_deref(), Some("alice@provider.tv:8080"));
+        assert_eq!(config.profiles.len(), 1);
+        assert_eq!(config.profiles[0].favorites, vec!["1".to_string()]);
+
+        // Storing again updates it instead of adding another
+        config.favorites.push("2".to_string());
+        config.store_active_profile("");
+        assert_eq!(config.profiles.len(), 1);
+        assert_eq!(config.profiles[0].favorites.len(), 2);
+    }
+
+    #[test]
+    fn test_profile_names_stay_unique() {
+        let mut config = signed_in("alice", "1");
+        config.store_active_profile("Home");
+        config.deactivate_profile();
+        config.username = "bob".to_string();
+        config.store_active_profile("Work");
+
+        // Renaming onto a taken name keeps the old name...
+        config.store_active_profile("Home");
+        assert_eq!(config.active_profile.as_deref(), Some("Work"));
+        let names: Vec<&str> = config.profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Home", "Work"]);
+        assert_eq!(config.profiles[0].username, "alice");
+
+        // ...and a new account with a taken name gets a suffix
+        config.deactivate_profile();
+        config.username = "carol".to_string();
+        config.store_active_profile("Home");
+        assert_eq!(config.active_profile.as_deref(), Some("Home (2)"));
+        assert_eq!(config.profiles[0].username, "alice");
+
+        // A free name renames the active profile
+        config.store_active_profile("Carol");
+        let names: Vec<&str> = config.profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Home", "Work", "Carol"]);
+    }
+
+    #[test]
+    fn test_switching_profiles_keeps_favorites() {
+        let mut config = signed_in("alice", "1");
+        config.store_active_profile("Alice");
+        config.deactivate_profile();
+        config.username = "bob".to_string();
+        config.favorites = vec!["2".to_string()];
+        config.store_active_profile("Bob");
+
+        // Switch as the app does: store the active profile, then activate
+        config.store_active_profile("");
+        assert!(config.activate_profile("Alice"));
+        assert_eq!(config.username, "alice");
+        assert_eq!(config.favorites, vec!["1".to_string()]);
+        config.favorites.push("3".to_string());
+
+        config.store_active_profile("");
+        assert!(config.activate_profile("Bob"));
+        assert_eq!(config.username, "bob");
+        assert_eq!(config.favorites, vec!["2".to_string()]);
+
+        config.store_active_profile("");
+        assert!(config.activate_profile("Alice"));
+        assert_eq!(config.favorites, vec!["1".to_string(), "3".to_string()]);
+
+        assert!(!config.activate_profile("Nobody"));
+        assert_eq!(config.active_profile.as_deref(), Some("Alice"));
+    }
+}
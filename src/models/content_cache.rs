@@ -24,28 +24,65 @@ impl<T> CachedData<T> {
     }
     
     pub fn is_expired(&self) -> bool {
+        self.age_secs() > self.cache_duration_secs
+    }
+    
+    /// Whether the data is younger than both `ttl_secs` and the TTL it was
+    /// saved with (so lowering the TTL takes effect immediately).
+    pub fn is_fresh(&self, ttl_secs: u64) -> bool {
+        !self.is_expired() && self.age_secs() <= ttl_secs
+    }
+    
+    /// Seconds since the data was cached.
+    pub fn age_secs(&self) -> u64 {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs();
-        now - self.cached_at > self.cache_duration_secs
+        now.saturating_sub(self.cached_at)
     }
 }
 
+/// Format a cache age as "just now", "5m ago", "3h ago" or "2d ago".
+pub fn format_age(age_secs: u64) -> String {
+    match age_secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", age_secs / 60),
+        3600..=86399 => format!("{}h ago", age_secs / 3600),
+        _ => format!("{}d ago", age_secs / 86400),
+    }
+}
+
+/// Total size of the files under `dir`, in bytes.
+fn dir_size(dir: &std::path::Path) -> u64 {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries.filter_map(Result::ok)
+        .map(|entry| match entry.file_type() {
+            Ok(kind) if kind.is_dir() => dir_size(&entry.path()),
+            _ => entry.metadata().map(|m| m.len()).unwrap_or(0),
+        })
+        .sum()
+}
+
 pub struct ContentCache;
 
 #[allow(dead_code)]
 impl ContentCache {
     fn get_cache_dir() -> PathBuf {
-        let mut path = dirs::cache_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("iptv-player");
-        path.push("cache");
-        path
+        super::config::cache_dir()
     }
     
+    /// The file of `key`. Keys hold the server URL, so anything but
+    /// letters, digits, `-` and `.` becomes `_`, keeping every file
+    /// directly in the cache folder.
     fn get_cache_path(key: &str) -> PathBuf {
+        let name: String = key.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+            .collect();
         let mut path = Self::get_cache_dir();
-        path.push(format!("{}.json", key));
+        path.push(format!("{}.json", name));
         path
     }
     
@@ -62,28 +99,48 @@ impl ContentCache {
         Ok(())
     }
     
-    pub fn load<T: for<'de> Deserialize<'de>>(key: &str) -> Option<T> {
+    /// Load cached data that is still fresh (see [`CachedData::is_fresh`]).
+    pub fn load<T: for<'de> Deserialize<'de>>(key: &str, ttl_secs: u64) -> Option<T> {
+        Self::load_stale::<T>(key)
+            .filter(|cached| cached.is_fresh(ttl_secs))
+            .map(|cached| cached.data)
+    }
+    
+    /// Load cached data regardless of age, with its timestamp. Used to show
+    /// stale content while fresh data is fetched.
+    pub fn load_stale<T: for<'de> Deserialize<'de>>(key: &str) -> Option<CachedData<T>> {
         let path = Self::get_cache_path(key);
         if !path.exists() {
             return None;
         }
         
         let content = fs::read_to_string(path).ok()?;
-        let cached: CachedData<T> = serde_json::from_str(&content).ok()?;
-        
-        if cached.is_expired() {
-            return None;
-        }
-        
-        Some(cached.data)
+        serde_json::from_str(&content).ok()
     }
     
-    pub fn clear_all() -> Result<(), Box<dyn std::error::Error>> {
+    /// Deletes every cached library file, including those older versions
+    /// left in subfolders named after the server URL; the image cache in
+    /// `images/` is left alone. Returns the bytes freed.
+    pub fn clear_all() -> Result<u64, Box<dyn std::error::Error>> {
         let cache_dir = Self::get_cache_dir();
-        if cache_dir.exists() {
-            fs::remove_dir_all(cache_dir)?;
+        if !cache_dir.exists() {
+            return Ok(0);
         }
-        Ok(())
+        let mut freed = 0;
+        for entry in fs::read_dir(cache_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                if entry.file_name() != "images" {
+                    freed += dir_size(&path);
+                    fs::remove_dir_all(&path)?;
+                }
+            } else {
+                freed += entry.metadata()?.len();
+                fs::remove_file(&path)?;
+            }
+        }
+        Ok(freed)
     }
     
     pub fn remove(key: &str) -> Result<(), Box<dyn std::error::Error>> {
@@ -2,8 +2,9 @@
 pub mod types;
 pub mod watch_history;
 pub mod content_cache;
+pub mod storage;
 
-pub use config::{Config, PlayerSettings, PlayerType};
+pub use config::{CatchupUrlFormat, CategorySort, ChannelMapping, Config, ConnectionPreset, DefaultContent, LiveStreamFormat, LogLevel, LoginMode, PlayerSettings, PlayerType, SavedWindow, StartupWindowMode, ViewMode, WindowGeometry};
 pub use types::*;
-pub use watch_history::WatchHistory;
+pub use watch_history::{WatchHistory, WatchStats};
 pub use content_cache::ContentCache;
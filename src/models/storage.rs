@@ -0,0 +1,112 @@
+//! Crash-safe JSON files.
+//!
+//! A file is written to a temporary sibling and renamed over the target,
+//! so a crash or full disk mid-write leaves the old file intact. The
+//! previous good version is kept as `<file>.bak` and used when the main
+//! file can't be parsed.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// `<file>.bak`, the last good version of `path`.
+pub fn backup_path(path: &Path) -> PathBuf {
+    sibling(path, "bak")
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Serializes `value` as pretty JSON and atomically replaces `path` with it.
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> Result<(), Box<dyn std::error::Error>> {
+    let content = serde_json::to_string_pretty(value)?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+
+    let tmp = sibling(path, "tmp");
+    {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+    }
+
+    // Only a file that still parses is worth keeping as the backup
+    let current_is_good = fs::read(path)
+        .is_ok_and(|bytes| serde_json::from_slice::<serde_json::Value>(&bytes).is_ok());
+    if current_is_good {
+        fs::copy(path, backup_path(path))?;
+    }
+
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Like [`save_json`], but the backup is replaced with the new content
+/// too, so nothing of the previous version is left on disk (e.g. after
+/// removing a password).
+pub fn save_json_replacing_backup<T: Serialize>(path: &Path, value: &T) -> Result<(), Box<dyn std::error::Error>> {
+    save_json(path, value)?;
+    fs::copy(path, backup_path(path))?;
+    Ok(())
+}
+
+/// Reads JSON from `path`, falling back to its backup when the file is
+/// corrupt. `Ok(None)` when neither exists.
+pub fn load_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, Box<dyn std::error::Error>> {
+    let backup = backup_path(path);
+    let err = match fs::read(path) {
+        Ok(bytes) => match serde_json::from_slice(&bytes) {
+            Ok(value) => return Ok(Some(value)),
+            Err(e) => Box::<dyn std::error::Error>::from(e),
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && !backup.exists() => return Ok(None),
+        Err(e) => e.into(),
+    };
+
+    log::warn!("[Storage] {} is unreadable ({}); trying the backup", path.display(), err);
+    match fs::read(&backup).ok().and_then(|bytes| serde_json::from_slice(&bytes).ok()) {
+        Some(value) => {
+            log::warn!("[Storage] Restored {} from {}", path.display(), backup.display());
+            Ok(Some(value))
+        }
+        None => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corrupt_file_falls_back_to_backup() {
+        let dir = std::env::temp_dir().join(format!("iptv-storage-test-{}", std::process::id()));
+        let path = dir.join("config.json");
+
+        save_json(&path, &vec!["first"]).unwrap();
+        save_json(&path, &vec!["second"]).unwrap();
+        assert_eq!(load_json::<Vec<String>>(&path).unwrap(), Some(vec!["second".to_string()]));
+
+        // Simulate a write cut short by a crash
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(&path, &content[..content.len() / 2]).unwrap();
+        assert_eq!(load_json::<Vec<String>>(&path).unwrap(), Some(vec!["first".to_string()]));
+
+        // A corrupt file must not replace the good backup on the next save
+        save_json(&path, &vec!["third"]).unwrap();
+        let backup: Vec<String> = serde_json::from_slice(&fs::read(backup_path(&path)).unwrap()).unwrap();
+        assert_eq!(backup, vec!["first".to_string()]);
+
+        save_json_replacing_backup(&path, &vec!["fourth"]).unwrap();
+        let backup: Vec<String> = serde_json::from_slice(&fs::read(backup_path(&path)).unwrap()).unwrap();
+        assert_eq!(backup, vec!["fourth".to_string()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -91,6 +91,66 @@ where
     }
 }
 
+// Lenient deserializer for IDs: number or numeric string, anything else is 0
+fn deserialize_lenient_i64<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde_json::Value;
+    
+    let value = Value::deserialize(deserializer)?;
+    Ok(match value {
+        Value::Number(n) => n.as_i64().or_else(|| n.as_f64().map(|f| f as i64)).unwrap_or(0),
+        Value::String(s) => s.trim().parse().unwrap_or(0),
+        _ => 0,
+    })
+}
+
+// Lenient deserializer for optional numbers: unparseable values ("N/A", "") become None
+fn deserialize_lenient_f32<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde_json::Value;
+    
+    let value = Value::deserialize(deserializer)?;
+    Ok(match value {
+        Value::Number(n) => n.as_f64().map(|f| f as f32),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    })
+}
+
+// Lenient deserializer for optional text: numbers are stringified, empty becomes None
+fn deserialize_optional_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde_json::Value;
+    
+    let value = Value::deserialize(deserializer)?;
+    Ok(match value {
+        Value::String(s) if !s.trim().is_empty() => Some(s),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+// Lenient deserializer for optional timestamps given as numbers or numeric strings
+fn deserialize_lenient_timestamp<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde_json::Value;
+    
+    let value = Value::deserialize(deserializer)?;
+    Ok(match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Channel {
     #[serde(deserialize_with = "deserialize_string_or_int", default)]
@@ -123,6 +183,66 @@ pub struct Channel {
     pub tv_archive_duration: Option<i32>,
     #[serde(deserialize_with = "deserialize_int_or_string", default)]
     pub is_adult: Option<i32>,
+    /// Provider-supplied quality label, when the panel sends one
+    #[serde(default, alias = "video_quality")]
+    pub quality: Option<String>,
+}
+
+impl Channel {
+    /// Stream quality, from the provider's quality field if present,
+    /// otherwise guessed from tags in the channel name ("FHD", "4K", ...).
+    pub fn quality(&self) -> Option<Quality> {
+        self.quality.as_deref()
+            .and_then(Quality::from_name)
+            .or_else(|| Quality::from_name(&self.name))
+    }
+
+    /// Whether the provider keeps a catch-up archive of this channel.
+    pub fn has_catchup(&self) -> bool {
+        self.tv_archive == Some(1) && self.archive_days() > 0
+    }
+
+    /// How many days back the catch-up archive reaches.
+    pub fn archive_days(&self) -> i64 {
+        self.tv_archive_duration.unwrap_or(0).max(0) as i64
+    }
+}
+
+/// Stream resolution class shown as a badge on channel cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Quality {
+    Sd,
+    Hd,
+    Fhd,
+    Uhd,
+}
+
+impl Quality {
+    pub const ALL: [Quality; 4] = [Quality::Sd, Quality::Hd, Quality::Fhd, Quality::Uhd];
+
+    /// Short badge label
+    pub fn label(&self) -> &'static str {
+        match self {
+            Quality::Sd => "SD",
+            Quality::Hd => "HD",
+            Quality::Fhd => "FHD",
+            Quality::Uhd => "4K",
+        }
+    }
+
+    /// Find a quality tag in `name`. Tags must be whole words, so
+    /// "HDTV Shop" or "SDF News" don't count. The highest tag wins.
+    pub fn from_name(name: &str) -> Option<Quality> {
+        name.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter_map(|token| match token.to_ascii_uppercase().as_str() {
+                "UHD" | "4K" | "2160P" => Some(Quality::Uhd),
+                "FHD" | "1080P" | "1080I" => Some(Quality::Fhd),
+                "HD" | "720P" => Some(Quality::Hd),
+                "SD" | "480P" | "576P" | "576I" => Some(Quality::Sd),
+                _ => None,
+            })
+            .max()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -134,6 +254,17 @@ pub struct Category {
     pub parent_id: i32,
 }
 
+impl Category {
+    /// Whether the name marks an adult category ("XXX", "Adults 18+", ...).
+    pub fn is_adult(&self) -> bool {
+        let name = self.category_name.to_lowercase();
+        if name.contains("adult swim") {
+            return false;
+        }
+        ["adult", "xxx", "18+", "+18", "porn"].iter().any(|p| name.contains(p))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Series {
     #[serde(default)]
@@ -174,6 +305,83 @@ pub struct Series {
     pub category_ids: Option<Vec<i32>>,
 }
 
+/// A movie from `get_vod_streams`. Panels mix strings and numbers for the
+/// same fields (and fill some with "N/A"), so everything parses leniently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Movie {
+    #[serde(deserialize_with = "deserialize_lenient_i64", default)]
+    pub stream_id: i64,
+    #[serde(deserialize_with = "deserialize_string_or_int", default)]
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_optional_text", default)]
+    pub container_extension: Option<String>,
+    #[serde(deserialize_with = "deserialize_optional_text", default)]
+    pub stream_icon: Option<String>,
+    #[serde(deserialize_with = "deserialize_string_or_int", default)]
+    pub category_id: String,
+    /// Rating out of 10
+    #[serde(deserialize_with = "deserialize_lenient_f32", default)]
+    pub rating: Option<f32>,
+    #[serde(deserialize_with = "deserialize_lenient_f32", default)]
+    pub rating_5based: Option<f32>,
+    /// When the provider added the movie (unix seconds)
+    #[serde(deserialize_with = "deserialize_lenient_timestamp", default)]
+    pub added: Option<i64>,
+    #[serde(deserialize_with = "deserialize_optional_text", default)]
+    pub year: Option<String>,
+    #[serde(deserialize_with = "deserialize_optional_text", alias = "releasedate", default)]
+    pub release_date: Option<String>,
+    #[serde(deserialize_with = "deserialize_optional_text", default)]
+    pub genre: Option<String>,
+}
+
+impl Movie {
+    /// File extension of the stream, `mp4` if the provider left it out.
+    pub fn container(&self) -> &str {
+        self.container_extension.as_deref().unwrap_or("mp4")
+    }
+    
+    /// Rating out of 10, from either rating field. Zero means unrated.
+    pub fn rating_out_of_10(&self) -> Option<f32> {
+        self.rating
+            .or_else(|| self.rating_5based.map(|r| r * 2.0))
+            .filter(|r| *r > 0.0)
+    }
+    
+    /// Release year, from the year field or the release date.
+    pub fn release_year(&self) -> Option<String> {
+        self.year.as_deref()
+            .and_then(year_of)
+            .or_else(|| self.release_date.as_deref().and_then(year_of))
+    }
+}
+
+/// The leading four-digit year of a date like "2023-05-01", if it has one.
+fn year_of(date: &str) -> Option<String> {
+    let year: String = date.trim().chars().take(4).collect();
+    (year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()) && year != "0000").then_some(year)
+}
+
+impl Series {
+    /// Rating out of 10, from either rating field. Zero means unrated.
+    pub fn rating_out_of_10(&self) -> Option<f32> {
+        self.rating.as_deref()
+            .and_then(|r| r.trim().parse::<f32>().ok())
+            .or_else(|| self.rating_5based.map(|r| r * 2.0))
+            .filter(|r| *r > 0.0)
+    }
+
+    /// First-air year, from the release date.
+    pub fn release_year(&self) -> Option<String> {
+        self.release_date.as_deref().and_then(year_of)
+    }
+
+    /// When the series was added or last got new episodes (unix seconds).
+    pub fn added_at(&self) -> Option<i64> {
+        self.last_modified.as_deref().and_then(|t| t.trim().parse().ok())
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Episode {
@@ -307,3 +515,89 @@ impl EpgProgram {
         format!("{:02}:{:02}", hours, minutes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_is_adult() {
+        let category = |name: &str| Category {
+            category_id: "1".to_string(),
+            category_name: name.to_string(),
+            parent_id: 0,
+        };
+        assert!(category("VOD | XXX").is_adult());
+        assert!(category("Adults 18+").is_adult());
+        assert!(category("FOR ADULTS").is_adult());
+        assert!(!category("UK | Adult Swim").is_adult());
+        assert!(!category("Kids").is_adult());
+    }
+
+    #[test]
+    fn test_quality_from_name() {
+        assert_eq!(Quality::from_name("UK: Sky Sports FHD"), Some(Quality::Fhd));
+        assert_eq!(Quality::from_name("BBC One HD"), Some(Quality::Hd));
+        assert_eq!(Quality::from_name("|DE| ZDF 4K"), Some(Quality::Uhd));
+        assert_eq!(Quality::from_name("Eurosport 1 SD/HD"), Some(Quality::Hd));
+        assert_eq!(Quality::from_name("HDTV Shopping"), None);
+        assert_eq!(Quality::from_name("CNN"), None);
+    }
+
+    #[test]
+    fn test_movie_from_sample_response() {
+        // First entry of tests/fixtures/vod_response.json
+        let json = r#"{"num":1,"name":"AZ - Yudhra (-2024)","stream_type":"movie","stream_id":1308888,"stream_icon":"https:\/\/image.tmdb.org\/t\/p\/w600_and_h900_bestv2\/cwmVHD41mGDQyPyCAsR8x6aMGc2.jpg","rating":"4","rating_5based":2,"tmdb":797223,"trailer":"","added":"1743294540","is_adult":0,"category_id":"2087","category_ids":[2087],"container_extension":"mkv","custom_sid":null,"direct_source":""}"#;
+        let movie: Movie = serde_json::from_str(json).unwrap();
+        assert_eq!(movie.stream_id, 1308888);
+        assert_eq!(movie.category_id, "2087");
+        assert_eq!(movie.container(), "mkv");
+        assert_eq!(movie.rating, Some(4.0));
+        assert_eq!(movie.added, Some(1743294540));
+    }
+
+    #[test]
+    fn test_movie_with_inconsistent_types() {
+        let json = r#"[
+            {"stream_id": "42", "name": 1917, "category_id": 7, "rating": 7.5, "added": 1700000000,
+             "stream_icon": "", "container_extension": null, "year": 2019},
+            {"stream_id": 43.0, "name": "No Rating", "rating": "N/A", "rating_5based": "3.5",
+             "releasedate": "2021-05-01", "added": ""},
+            {"name": "Bare"}
+        ]"#;
+        let movies: Vec<Movie> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(movies[0].stream_id, 42);
+        assert_eq!(movies[0].name, "1917");
+        assert_eq!(movies[0].category_id, "7");
+        assert_eq!(movies[0].stream_icon, None);
+        assert_eq!(movies[0].container(), "mp4");
+        assert_eq!(movies[0].release_year().as_deref(), Some("2019"));
+
+        assert_eq!(movies[1].stream_id, 43);
+        assert_eq!(movies[1].rating, None);
+        assert_eq!(movies[1].rating_out_of_10(), Some(7.0));
+        assert_eq!(movies[1].release_year().as_deref(), Some("2021"));
+        assert_eq!(movies[1].added, None);
+
+        assert_eq!(movies[2].stream_id, 0);
+        assert_eq!(movies[2].rating_out_of_10(), None);
+    }
+
+    #[test]
+    fn test_series_metadata() {
+        let json = r#"[
+            {"name": "Rated", "rating": "8.2", "release_date": "2023-01-15"},
+            {"name": "Five based", "rating": "N/A", "rating_5based": 4, "release_date": "N/A"},
+            {"name": "Unrated", "rating": "0", "release_date": "0000-00-00"}
+        ]"#;
+        let series: Vec<Series> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(series[0].rating_out_of_10(), Some(8.2));
+        assert_eq!(series[0].release_year().as_deref(), Some("2023"));
+        assert_eq!(series[1].rating_out_of_10(), Some(8.0));
+        assert_eq!(series[1].release_year(), None);
+        assert_eq!(series[2].rating_out_of_10(), None);
+        assert_eq!(series[2].release_year(), None);
+    }
+}
@@ -1,13 +1,24 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Progress (percent) past which a title counts as watched.
+static FINISHED_PERCENT: AtomicU32 = AtomicU32::new(90);
+/// Progress (percent) a title needs before it shows in Continue Watching.
+static CONTINUE_MIN_PERCENT: AtomicU32 = AtomicU32::new(2);
+
+/// Applies the watched thresholds from the config.
+pub fn configure(config: &super::Config) {
+    FINISHED_PERCENT.store(config.watched_percent.clamp(50, 100), Ordering::Relaxed);
+    CONTINUE_MIN_PERCENT.store(config.continue_min_percent.min(50), Ordering::Relaxed);
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WatchProgress {
     pub content_id: String,
     pub content_name: String,
-    pub content_type: String, // "movie", "series", "channel"
+    pub content_type: String, // "movie", "series", "channel", "catchup"
     pub position_seconds: f64,
     pub duration_seconds: f64,
     pub last_watched: i64, // Unix timestamp
@@ -26,59 +37,178 @@ impl WatchProgress {
         }
     }
     
+    /// Past the configured "watched" threshold (90% by default).
     pub fn is_nearly_finished(&self) -> bool {
-        self.progress_percentage() > 90.0
+        self.progress_percentage() > FINISHED_PERCENT.load(Ordering::Relaxed) as f32
+    }
+    
+    /// Too little watched to be worth resuming, likely an accidental click.
+    /// Unknown durations never count as barely started.
+    pub fn is_barely_started(&self) -> bool {
+        self.duration_seconds > 0.0
+            && self.progress_percentage() < CONTINUE_MIN_PERCENT.load(Ordering::Relaxed) as f32
+    }
+    
+    /// How far through the title playback got (0.0-1.0), for titles that
+    /// were started but not finished.
+    pub fn resume_fraction(&self) -> Option<f32> {
+        (!self.is_nearly_finished()).then(|| self.progress_percentage() / 100.0)
+    }
+    
+    /// Position to resume from, if playback stopped part-way through.
+    pub fn resume_position(&self) -> Option<f64> {
+        (self.position_seconds > 0.0 && !self.is_nearly_finished()).then_some(self.position_seconds)
+    }
+    
+    /// Time spent watching: the whole title once finished, otherwise as
+    /// far as playback got.
+    pub fn watched_seconds(&self) -> f64 {
+        if self.is_nearly_finished() {
+            self.duration_seconds
+        } else {
+            self.position_seconds
+        }
+    }
+    
+    /// `content_id` of a catch-up entry: the channel plus the programme start.
+    pub fn catchup_id(stream_id: &str, start_unix: i64) -> String {
+        format!("catchup_{}_{}", stream_id, start_unix)
+    }
+    
+    /// Channel stream ID and programme start encoded in a catch-up `content_id`.
+    pub fn parse_catchup_id(content_id: &str) -> Option<(&str, i64)> {
+        let (stream_id, start) = content_id.strip_prefix("catchup_")?.rsplit_once('_')?;
+        Some((stream_id, start.parse().ok()?))
     }
 }
 
+/// Days covered by the activity summary of [`WatchStats`].
+pub const ACTIVITY_DAYS: usize = 30;
+
+/// Totals over the watch history, for the statistics view.
+///
+/// Each title counts once with its latest watch, so activity is by the
+/// day a title was last played rather than every session.
+#[derive(Debug, Clone, Default)]
+pub struct WatchStats {
+    pub total_seconds: f64,
+    pub titles: usize,
+    /// Titles per content type ("movie", "series"...), most first
+    pub by_type: Vec<(String, usize)>,
+    /// Series with the most episodes watched: name, episodes, seconds
+    pub top_series: Vec<(String, usize, f64)>,
+    /// Titles played on each of the last [`ACTIVITY_DAYS`] days, oldest first
+    pub daily: [usize; ACTIVITY_DAYS],
+    /// Watch time of the titles in `daily`
+    pub recent_seconds: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WatchHistory {
     history: HashMap<String, WatchProgress>,
+    /// Most recent episode entry per series name, so cards can look up a
+    /// series without scanning. Rebuilt on load.
+    #[serde(skip)]
+    latest_by_series: HashMap<String, String>,
+    /// Bumped on every change, so sync can tell when to run
+    #[serde(skip)]
+    revision: u64,
 }
 
 #[allow(dead_code)]
 impl WatchHistory {
     pub fn load() -> Self {
-        let path = Self::get_history_path();
-        if path.exists() {
-            if let Ok(content) = fs::read_to_string(&path) {
-                if let Ok(history) = serde_json::from_str(&content) {
-                    return history;
-                }
+        match super::storage::load_json::<Self>(&Self::get_history_path()) {
+            Ok(Some(mut history)) => {
+                history.reindex();
+                history
+            }
+            Ok(None) => Self::default(),
+            Err(e) => {
+                log::error!("[History] Couldn't read watch history, starting empty: {}", e);
+                Self::default()
             }
         }
-        Self::default()
     }
     
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let path = Self::get_history_path();
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
+        super::storage::save_json(&Self::get_history_path(), self)
+    }
+    
+    /// Saves after a change, logging a failure.
+    fn persist(&mut self) {
+        self.revision += 1;
+        if let Err(e) = self.save() {
+            log::error!("[History] Couldn't save watch history: {}", e);
         }
-        let content = serde_json::to_string_pretty(self)?;
-        fs::write(path, content)?;
-        Ok(())
     }
     
     fn get_history_path() -> PathBuf {
-        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("iptv-player");
-        path.push("watch_history.json");
-        path
+        super::config::data_dir().join("watch_history.json")
     }
     
     pub fn update_progress(&mut self, progress: WatchProgress) {
+        if progress.content_type == "series" {
+            self.latest_by_series.insert(progress.content_name.clone(), progress.content_id.clone());
+        }
         self.history.insert(progress.content_id.clone(), progress);
-        let _ = self.save();
+        self.persist();
+    }
+    
+    /// Every entry, keyed by content ID.
+    pub fn entries(&self) -> &HashMap<String, WatchProgress> {
+        &self.history
+    }
+    
+    /// Changes since load; compare two values to detect a change.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+    
+    /// Replaces all entries, e.g. with the result of a sync.
+    pub fn replace(&mut self, history: HashMap<String, WatchProgress>) {
+        self.history = history;
+        self.reindex();
+        self.persist();
     }
     
     pub fn get_progress(&self, content_id: &str) -> Option<&WatchProgress> {
         self.history.get(content_id)
     }
     
+    /// Most recently watched episode of the series called `series_name`.
+    pub fn series_progress(&self, series_name: &str) -> Option<&WatchProgress> {
+        self.latest_by_series.get(series_name).and_then(|id| self.history.get(id))
+    }
+    
+    /// The latest episode of each series whose latest episode was watched
+    /// to the end, most recently watched first.
+    pub fn finished_series(&self) -> Vec<&WatchProgress> {
+        let mut latest: Vec<_> = self.latest_by_series.values()
+            .filter_map(|id| self.history.get(id))
+            .filter(|p| p.is_nearly_finished())
+            .collect();
+        latest.sort_by_key(|p| std::cmp::Reverse(p.last_watched));
+        latest
+    }
+    
+    fn reindex(&mut self) {
+        self.latest_by_series.clear();
+        let mut latest: HashMap<&str, &WatchProgress> = HashMap::new();
+        for progress in self.history.values().filter(|p| p.content_type == "series") {
+            let entry = latest.entry(progress.content_name.as_str()).or_insert(progress);
+            if progress.last_watched > entry.last_watched {
+                *entry = progress;
+            }
+        }
+        self.latest_by_series = latest.into_iter()
+            .map(|(name, p)| (name.to_string(), p.content_id.clone()))
+            .collect();
+    }
+    
     pub fn get_continue_watching(&self, limit: usize) -> Vec<WatchProgress> {
         let mut items: Vec<_> = self.history.values()
-            .filter(|p| !p.is_nearly_finished())
+            .filter(|p| !p.is_nearly_finished() && !p.is_barely_started())
             .cloned()
             .collect();
         
@@ -87,13 +217,90 @@ impl WatchHistory {
         items
     }
     
+    /// Aggregates the history in one pass; `now` is the current Unix time.
+    pub fn stats(&self, now: i64, top_series: usize) -> WatchStats {
+        let mut stats = WatchStats::default();
+        let mut by_type: HashMap<&str, usize> = HashMap::new();
+        let mut series: HashMap<&str, (usize, f64)> = HashMap::new();
+        
+        for progress in self.history.values() {
+            let seconds = progress.watched_seconds();
+            stats.total_seconds += seconds;
+            stats.titles += 1;
+            *by_type.entry(progress.content_type.as_str()).or_default() += 1;
+            if progress.content_type == "series" {
+                let entry = series.entry(progress.content_name.as_str()).or_default();
+                entry.0 += 1;
+                entry.1 += seconds;
+            }
+            let days_ago = (now - progress.last_watched).max(0) / 86400;
+            if let Some(slot) = usize::try_from(days_ago).ok().filter(|d| *d < ACTIVITY_DAYS) {
+                stats.daily[ACTIVITY_DAYS - 1 - slot] += 1;
+                stats.recent_seconds += seconds;
+            }
+        }
+        
+        stats.by_type = by_type.into_iter().map(|(kind, n)| (kind.to_string(), n)).collect();
+        stats.by_type.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        stats.top_series = series.into_iter().map(|(name, (n, secs))| (name.to_string(), n, secs)).collect();
+        stats.top_series.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.2.total_cmp(&a.2)).then_with(|| a.0.cmp(&b.0)));
+        stats.top_series.truncate(top_series);
+        stats
+    }
+    
     pub fn remove(&mut self, content_id: &str) {
-        self.history.remove(content_id);
-        let _ = self.save();
+        if let Some(removed) = self.history.remove(content_id) {
+            if removed.content_type == "series" {
+                self.reindex();
+            }
+        }
+        self.persist();
     }
     
     pub fn clear(&mut self) {
         self.history.clear();
-        let _ = self.save();
+        self.latest_by_series.clear();
+        self.persist();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, name: &str, kind: &str, position: f64, duration: f64, last_watched: i64) -> WatchProgress {
+        WatchProgress {
+            content_id: id.to_string(),
+            content_name: name.to_string(),
+            content_type: kind.to_string(),
+            position_seconds: position,
+            duration_seconds: duration,
+            last_watched,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let now = 100 * 86400;
+        let mut history = WatchHistory::default();
+        for progress in [
+            entry("1", "Heat", "movie", 6000.0, 6000.0, now - 60),
+            entry("Lost_1_1", "Lost", "series", 600.0, 2400.0, now - 86400),
+            entry("Lost_1_2", "Lost", "series", 2400.0, 2400.0, now - 40 * 86400),
+            entry("Fargo_1_1", "Fargo", "series", 3000.0, 3000.0, now - 2 * 86400),
+        ] {
+            history.history.insert(progress.content_id.clone(), progress);
+        }
+
+        let stats = history.stats(now, 1);
+        assert_eq!(stats.titles, 4);
+        assert_eq!(stats.total_seconds, 12000.0);
+        assert_eq!(stats.by_type, vec![("series".to_string(), 3), ("movie".to_string(), 1)]);
+        assert_eq!(stats.top_series, vec![("Lost".to_string(), 2, 3000.0)]);
+        assert_eq!(stats.daily[ACTIVITY_DAYS - 1], 1);
+        assert_eq!(stats.daily[ACTIVITY_DAYS - 2], 1);
+        assert_eq!(stats.daily.iter().sum::<usize>(), 3);
+        assert_eq!(stats.recent_seconds, 9600.0);
     }
 }
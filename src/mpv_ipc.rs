@@ -0,0 +1,166 @@
+//! Remote control of an external MPV over its JSON IPC socket.
+//!
+//! MPV started with `--input-ipc-server=<path>` listens on a Unix socket
+//! (a named pipe on Windows) for JSON commands, one per line. The mini
+//! player uses it to pause and change the volume of the stream playing in
+//! the MPV window without switching to it, and the playback position is
+//! read from it for the watch history.
+
+use std::io::{BufRead, BufReader, Write};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+/// How long to wait for MPV to answer a command.
+const REPLY_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Request ID sent with every command, to tell its reply from events.
+const REQUEST_ID: i64 = 1;
+
+/// Counter keeping socket paths unique within this process.
+static NEXT_SOCKET: AtomicU32 = AtomicU32::new(0);
+
+#[cfg(unix)]
+type Stream = std::os::unix::net::UnixStream;
+#[cfg(windows)]
+type Stream = std::fs::File;
+
+/// A fresh socket path for one player launch.
+pub fn new_socket_path() -> String {
+    let name = format!(
+        "iptv-player-mpv-{}-{}",
+        std::process::id(),
+        NEXT_SOCKET.fetch_add(1, Ordering::Relaxed)
+    );
+    #[cfg(windows)]
+    {
+        format!(r"\\.\pipe\{}", name)
+    }
+    #[cfg(not(windows))]
+    {
+        std::env::temp_dir().join(format!("{}.sock", name)).to_string_lossy().into_owned()
+    }
+}
+
+/// The IPC socket of one running MPV.
+#[derive(Debug, Clone)]
+pub struct MpvIpc {
+    path: String,
+}
+
+impl MpvIpc {
+    pub fn new(path: String) -> Self {
+        Self { path }
+    }
+
+    /// Sends one command (e.g. `["get_property", "pause"]`) and returns
+    /// the `data` of MPV's reply.
+    pub fn command(&self, args: Value) -> Result<Value, String> {
+        let stream = connect(&self.path).map_err(|e| format!("Couldn't reach MPV: {}", e))?;
+        let request = json!({ "command": args, "request_id": REQUEST_ID });
+        (&stream)
+            .write_all(format!("{}\n", request).as_bytes())
+            .map_err(|e| format!("Couldn't send to MPV: {}", e))?;
+
+        let mut reader = BufReader::new(&stream);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line).map_err(|e| format!("No reply from MPV: {}", e))?;
+            if read == 0 {
+                return Err("MPV closed the connection".to_string());
+            }
+            // Events arrive on the same socket; skip them until the reply
+            let Ok(reply) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            if reply.get("request_id").and_then(Value::as_i64) != Some(REQUEST_ID) {
+                continue;
+            }
+            return match reply.get("error").and_then(Value::as_str) {
+                Some("success") => Ok(reply.get("data").cloned().unwrap_or(Value::Null)),
+                Some(error) => Err(format!("MPV: {}", error)),
+                None => Err("Malformed reply from MPV".to_string()),
+            };
+        }
+    }
+
+    pub fn is_paused(&self) -> Result<bool, String> {
+        self.command(json!(["get_property", "pause"]))?
+            .as_bool()
+            .ok_or_else(|| "MPV returned no pause state".to_string())
+    }
+
+    pub fn set_paused(&self, paused: bool) -> Result<(), String> {
+        self.command(json!(["set_property", "pause", paused])).map(|_| ())
+    }
+
+    /// Volume in percent (100 = unchanged).
+    pub fn volume(&self) -> Result<f64, String> {
+        self.command(json!(["get_property", "volume"]))?
+            .as_f64()
+            .ok_or_else(|| "MPV returned no volume".to_string())
+    }
+
+    pub fn set_volume(&self, volume: f64) -> Result<(), String> {
+        self.command(json!(["set_property", "volume", volume])).map(|_| ())
+    }
+
+    /// Playback position in seconds.
+    pub fn position(&self) -> Result<f64, String> {
+        self.command(json!(["get_property", "time-pos"]))?
+            .as_f64()
+            .ok_or_else(|| "MPV returned no position".to_string())
+    }
+
+    /// Length of the stream in seconds, `None` while MPV doesn't know it
+    /// (e.g. live streams).
+    pub fn duration(&self) -> Result<Option<f64>, String> {
+        self.command(json!(["get_property", "duration"])).map(|data| data.as_f64())
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+#[cfg(unix)]
+fn connect(path: &str) -> std::io::Result<Stream> {
+    let stream = Stream::connect(path)?;
+    stream.set_read_timeout(Some(REPLY_TIMEOUT))?;
+    stream.set_write_timeout(Some(REPLY_TIMEOUT))?;
+    Ok(stream)
+}
+
+#[cfg(windows)]
+fn connect(path: &str) -> std::io::Result<Stream> {
+    // Named pipes have no timeouts; MPV answers every request
+    std::fs::OpenOptions::new().read(true).write(true).open(path)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixListener;
+
+    #[test]
+    fn test_command_skips_events() {
+        let path = new_socket_path();
+        let listener = UnixListener::bind(&path).unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            BufReader::new(&stream).read_line(&mut request).unwrap();
+            let request: Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(request["command"], json!(["get_property", "volume"]));
+            (&stream).write_all(b"{\"event\":\"playback-restart\"}\n").unwrap();
+            (&stream).write_all(b"{\"data\":42.0,\"request_id\":1,\"error\":\"success\"}\n").unwrap();
+        });
+
+        let ipc = MpvIpc::new(path.clone());
+        assert_eq!(ipc.volume(), Ok(42.0));
+        server.join().unwrap();
+        let _ = std::fs::remove_file(&path);
+    }
+}
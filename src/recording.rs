@@ -0,0 +1,143 @@
+//! Live stream recording (DVR) via ffmpeg.
+//!
+//! Each recording is an `ffmpeg -i <url> -c copy <file>.ts` child process.
+//! The stream is copied without re-encoding, so recording is cheap enough to
+//! run alongside playback.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::models::config::find_executable;
+
+/// Possible ffmpeg locations, tried in order (first match wins).
+const FFMPEG_EXECUTABLES: &[&str] = &[
+    "ffmpeg",
+    "ffmpeg.exe",
+    "bundled/ffmpeg.exe",
+    "bundled\\ffmpeg.exe",
+    "media-tools/ffmpeg",
+    "C:\\ffmpeg\\bin\\ffmpeg.exe",
+    "C:\\Program Files\\ffmpeg\\bin\\ffmpeg.exe",
+];
+
+/// How long ffmpeg gets to finalize the file after being asked to quit.
+const STOP_GRACE_PERIOD: Duration = Duration::from_secs(3);
+
+/// Resolve the ffmpeg executable, falling back to the bare command name.
+pub fn ffmpeg_executable() -> String {
+    find_executable(FFMPEG_EXECUTABLES).unwrap_or_else(|| "ffmpeg".to_string())
+}
+
+/// An in-progress recording of a live channel.
+pub struct Recording {
+    /// Stream ID of the recorded channel
+    pub stream_id: String,
+    /// Channel name, for display
+    pub channel_name: String,
+    /// File the stream is written to
+    pub output_path: PathBuf,
+    started_at: Instant,
+    child: Child,
+}
+
+impl Recording {
+    /// Start recording `url` into a new timestamped `.ts` file in `dir`.
+    pub fn start(url: &str, stream_id: &str, channel_name: &str, dir: &Path) -> Result<Self, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Cannot create recordings folder {}: {}", dir.display(), e))?;
+
+        let file_name = format!(
+            "{}_{}.ts",
+            sanitize_file_name(channel_name),
+            chrono::Local::now().format("%Y-%m-%d_%H-%M-%S"),
+        );
+        let output_path = dir.join(file_name);
+
+        let executable = ffmpeg_executable();
+        log::info!("[Recording] Starting: {} -> {}", executable, output_path.display());
+
+        let mut cmd = Command::new(&executable);
+        cmd.args(["-hide_banner", "-loglevel", "error", "-nostats"])
+            .args(crate::api::http::provider_headers().ffmpeg_args())
+            .arg("-i").arg(url.trim())
+            .args(["-c", "copy", "-f", "mpegts"])
+            .arg(&output_path)
+            // stdin stays open so we can send `q` for a clean stop
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::inherit());
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        }
+
+        let child = cmd.spawn()
+            .map_err(|e| format!("Could not start ffmpeg ({}): {}", executable, e))?;
+
+        Ok(Self {
+            stream_id: stream_id.to_string(),
+            channel_name: channel_name.to_string(),
+            output_path,
+            started_at: Instant::now(),
+            child,
+        })
+    }
+
+    /// Time since the recording started.
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Elapsed time as `H:MM:SS`.
+    pub fn elapsed_formatted(&self) -> String {
+        let secs = self.elapsed().as_secs();
+        format!("{}:{:02}:{:02}", secs / 3600, (secs / 60) % 60, secs % 60)
+    }
+
+    /// Returns the exit status if ffmpeg has stopped on its own
+    /// (stream ended, network error, disk full...).
+    pub fn exit_status(&mut self) -> Option<std::process::ExitStatus> {
+        self.child.try_wait().ok().flatten()
+    }
+
+    /// Stop the recording. ffmpeg is asked to quit so it can finalize the
+    /// file, and killed if it hasn't exited after a grace period. Waiting
+    /// happens on the returned background thread so the UI doesn't block.
+    pub fn stop(mut self) -> std::thread::JoinHandle<()> {
+        log::info!("[Recording] Stopping: {}", self.output_path.display());
+
+        if let Some(mut stdin) = self.child.stdin.take() {
+            let _ = stdin.write_all(b"q");
+        }
+
+        std::thread::spawn(move || {
+            let deadline = Instant::now() + STOP_GRACE_PERIOD;
+            while Instant::now() < deadline {
+                if let Ok(Some(_)) = self.child.try_wait() {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        })
+    }
+}
+
+/// Replace characters that aren't safe in file names.
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_') { c } else { '_' })
+        .collect();
+    if cleaned.is_empty() {
+        "recording".to_string()
+    } else {
+        cleaned
+    }
+}
@@ -0,0 +1,122 @@
+//! Reminders for upcoming EPG programmes.
+//!
+//! A reminder remembers the channel, programme title and start time. The UI
+//! polls [`Reminders::take_due`] every frame and notifies the user shortly
+//! before the programme starts. Reminders are saved next to the watch
+//! history so they survive restarts.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Channel, EpgProgram};
+
+/// How long before the start time a reminder fires, in seconds.
+pub const REMINDER_LEAD_SECS: i64 = 5 * 60;
+
+/// Reminders for programmes that started longer ago than this are dropped
+/// without notifying (e.g. the app was closed at the time).
+const MISSED_GRACE_SECS: i64 = 10 * 60;
+
+/// A reminder for one programme on one channel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub stream_id: String,
+    pub channel_name: String,
+    pub title: String,
+    /// Programme start, Unix timestamp
+    pub start: i64,
+}
+
+impl Reminder {
+    /// Reminder for `program` airing on `channel`.
+    pub fn new(channel: &Channel, program: &EpgProgram) -> Self {
+        Self {
+            stream_id: channel.stream_id.clone(),
+            channel_name: channel.name.clone(),
+            title: program.title.clone(),
+            start: program.start_timestamp_unix(),
+        }
+    }
+}
+
+/// Pending reminders, persisted to `reminders.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Reminders {
+    pending: Vec<Reminder>,
+}
+
+impl Reminders {
+    pub fn load() -> Self {
+        let path = Self::reminders_path();
+        fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Self::reminders_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn reminders_path() -> PathBuf {
+        crate::models::config::data_dir().join("reminders.json")
+    }
+
+    /// Whether a reminder is set for the programme starting at `start`
+    /// on `stream_id`.
+    pub fn contains(&self, stream_id: &str, start: i64) -> bool {
+        self.pending.iter().any(|r| r.stream_id == stream_id && r.start == start)
+    }
+
+    /// Adds the reminder, or removes it if it was already set.
+    /// Returns true if it is now set.
+    pub fn toggle(&mut self, reminder: Reminder) -> bool {
+        let before = self.pending.len();
+        self.pending.retain(|r| !(r.stream_id == reminder.stream_id && r.start == reminder.start));
+        let added = self.pending.len() == before;
+        if added {
+            self.pending.push(reminder);
+        }
+        self.persist();
+        added
+    }
+
+    /// Removes and returns every reminder due at `now` (Unix seconds).
+    /// Reminders for programmes that started too long ago are dropped
+    /// without being returned.
+    pub fn take_due(&mut self, now: i64) -> Vec<Reminder> {
+        if !self.pending.iter().any(|r| r.start - REMINDER_LEAD_SECS <= now) {
+            return Vec::new();
+        }
+
+        let (due, pending): (Vec<Reminder>, Vec<Reminder>) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|r| r.start - REMINDER_LEAD_SECS <= now);
+        self.pending = pending;
+        self.persist();
+
+        due.into_iter()
+            .filter(|r| now - r.start <= MISSED_GRACE_SECS)
+            .collect()
+    }
+
+    /// Seconds until the next reminder fires, if any are pending.
+    pub fn secs_until_next(&self, now: i64) -> Option<i64> {
+        self.pending.iter()
+            .map(|r| (r.start - REMINDER_LEAD_SECS - now).max(0))
+            .min()
+    }
+
+    fn persist(&self) {
+        if let Err(e) = self.save() {
+            log::error!("[Reminders] Failed to save reminders: {}", e);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Audio and subtitle track detection for VOD streams via ffprobe.
+//!
+//! Before a movie or episode starts, `ffprobe` reads the container's stream
+//! list so the user can pick a real audio language or subtitle instead of a
+//! blind "Track N". Indexes are counted per track type, which is what the
+//! player arguments in [`PlayerSettings`](crate::models::PlayerSettings) expect.
+
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+use crate::models::config::find_executable;
+
+/// Possible ffprobe locations, tried in order (first match wins).
+const FFPROBE_EXECUTABLES: &[&str] = &[
+    "ffprobe",
+    "ffprobe.exe",
+    "bundled/ffprobe.exe",
+    "bundled\\ffprobe.exe",
+    "media-tools/ffprobe",
+    "C:\\ffmpeg\\bin\\ffprobe.exe",
+    "C:\\Program Files\\ffmpeg\\bin\\ffprobe.exe",
+];
+
+/// Network timeout handed to ffprobe, in microseconds.
+const PROBE_TIMEOUT_US: &str = "10000000";
+
+/// Resolve the ffprobe executable, if it is installed.
+pub fn ffprobe_executable() -> Option<String> {
+    find_executable(FFPROBE_EXECUTABLES)
+}
+
+/// One audio or subtitle track of a stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamTrack {
+    /// Index among tracks of the same type, starting at 0
+    pub index: i32,
+    pub language: Option<String>,
+    pub title: Option<String>,
+    pub codec: String,
+    /// Audio channel count
+    pub channels: Option<u32>,
+}
+
+impl StreamTrack {
+    /// Human readable label, e.g. "English · Director's commentary (aac 2ch)".
+    pub fn label(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(language) = &self.language {
+            parts.push(language_name(language).to_string());
+        }
+        if let Some(title) = &self.title {
+            parts.push(title.clone());
+        }
+        if parts.is_empty() {
+            parts.push(format!("Track {}", self.index + 1));
+        }
+
+        let details = match self.channels {
+            Some(channels) => format!("{} {}ch", self.codec, channels),
+            None => self.codec.clone(),
+        };
+        format!("{} ({})", parts.join(" · "), details)
+    }
+}
+
+/// Audio and subtitle tracks found in a stream.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamTracks {
+    pub audio: Vec<StreamTrack>,
+    pub subtitles: Vec<StreamTrack>,
+}
+
+impl StreamTracks {
+    /// Whether there is anything to choose between.
+    pub fn has_choices(&self) -> bool {
+        self.audio.len() > 1 || !self.subtitles.is_empty()
+    }
+}
+
+/// Run ffprobe on `url` and list its tracks. Blocks until ffprobe exits,
+/// so call it from a background thread.
+pub fn probe(url: &str) -> Result<StreamTracks, String> {
+    let executable = ffprobe_executable().ok_or("ffprobe not found")?;
+
+    let mut cmd = Command::new(&executable);
+    cmd.args(["-v", "error", "-print_format", "json", "-show_streams"])
+        .args(crate::api::http::provider_headers().ffmpeg_args())
+        .args(["-timeout", PROBE_TIMEOUT_US])
+        .arg(url.trim())
+        .stdin(Stdio::null());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    let output = cmd.output()
+        .map_err(|e| format!("Could not run ffprobe ({}): {}", executable, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "ffprobe failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    parse_ffprobe_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the JSON printed by `ffprobe -print_format json -show_streams`.
+pub fn parse_ffprobe_output(json: &str) -> Result<StreamTracks, String> {
+    let value: Value = serde_json::from_str(json)
+        .map_err(|e| format!("Invalid ffprobe output: {}", e))?;
+    let streams = value.get("streams").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+
+    let mut tracks = StreamTracks::default();
+    for stream in &streams {
+        let list = match stream.get("codec_type").and_then(|v| v.as_str()) {
+            Some("audio") => &mut tracks.audio,
+            Some("subtitle") => &mut tracks.subtitles,
+            _ => continue,
+        };
+
+        let tag = |key: &str| {
+            stream.get("tags")
+                .and_then(|tags| tags.get(key))
+                .and_then(|v| v.as_str())
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty() && s != "und")
+        };
+        list.push(StreamTrack {
+            index: list.len() as i32,
+            language: tag("language"),
+            title: tag("title"),
+            codec: stream.get("codec_name").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
+            channels: stream.get("channels").and_then(|v| v.as_u64()).map(|c| c as u32),
+        });
+    }
+
+    Ok(tracks)
+}
+
+/// English name for common ISO 639-2 codes, or the code itself.
+fn language_name(code: &str) -> &str {
+    match code {
+        "eng" | "en" => "English",
+        "spa" | "es" => "Spanish",
+        "fra" | "fre" | "fr" => "French",
+        "deu" | "ger" | "de" => "German",
+        "ita" | "it" => "Italian",
+        "por" | "pt" => "Portuguese",
+        "rus" | "ru" => "Russian",
+        "jpn" | "ja" => "Japanese",
+        "kor" | "ko" => "Korean",
+        "zho" | "chi" | "zh" => "Chinese",
+        "ara" | "ar" => "Arabic",
+        "hin" | "hi" => "Hindi",
+        "tur" | "tr" => "Turkish",
+        "pol" | "pl" => "Polish",
+        "nld" | "dut" | "nl" => "Dutch",
+        _ => code,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ffprobe_output() {
+        let json = r#"{"streams": [
+            {"codec_type": "video", "codec_name": "h264"},
+            {"codec_type": "audio", "codec_name": "aac", "channels": 6, "tags": {"language": "eng"}},
+            {"codec_type": "audio", "codec_name": "ac3", "channels": 2, "tags": {"language": "fre", "title": "Commentary"}},
+            {"codec_type": "subtitle", "codec_name": "subrip", "tags": {"language": "und"}}
+        ]}"#;
+
+        let tracks = parse_ffprobe_output(json).unwrap();
+        assert_eq!(tracks.audio.len(), 2);
+        assert_eq!(tracks.audio[1].index, 1);
+        assert_eq!(tracks.audio[0].label(), "English (aac 6ch)");
+        assert_eq!(tracks.audio[1].label(), "French · Commentary (ac3 2ch)");
+        assert_eq!(tracks.subtitles[0].label(), "Track 1 (subrip)");
+        assert!(tracks.has_choices());
+    }
+}
@@ -7,18 +7,324 @@
 //! - Background task communication
 
 use eframe::egui;
-use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::mpsc::{channel, Receiver};
 use std::thread;
 
 use crate::api::XtreamClient;
 use crate::api::DiscoverCategory;
 use crate::api::{FootballCache, FootballCategory};
-use crate::api::ScraperManager;
+use crate::api::{ScraperManager, ScrapingStatus};
 use crate::models::*;
 use super::theme::{Theme, dimensions};
-use super::messages::{AppMessage, ContentType};
+use super::messages::{AppMessage, UiSender, ConnectStage, ContentType, DiagnosticStage, DiagnosticStatus, LibrarySource};
 use super::image_cache::ImageCache;
+use super::toast::Toasts;
 use super::components::*;
+use super::sort::{self, SortOrder};
+
+/// Delay after the last search edit before the content is re-filtered.
+const SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Repaint interval when nothing else asks for one. Also keeps guide
+/// progress bars moving between programme boundaries.
+const IDLE_POLL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How often to check whether the server is back while offline.
+const OFFLINE_RETRY: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// How long after a change to favorites or history the sync runs, so a
+/// burst of changes syncs once.
+const SYNC_DEBOUNCE: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How long to wait before retrying a sync that failed (e.g. offline).
+const SYNC_RETRY: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Maximum number of channels in the multi-view grid (2x2).
+const MULTIVIEW_MAX: usize = 4;
+
+/// Content scroll speed (points per second) at full analog stick deflection.
+const STICK_SCROLL_SPEED: f32 = 1600.0;
+
+/// An episode must have played at least this long before "Play next" is
+/// offered, so a player that fails to start doesn't trigger the prompt.
+const NEXT_EPISODE_MIN_PLAYTIME: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Longest the app waits on exit for recordings to be finalized.
+const RECORDINGS_EXIT_WAIT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Movies and series each shown in the Recently Added view.
+const RECENTLY_ADDED_LIMIT: usize = 48;
+
+/// Results first shown per section of "Search All"; "Show more" adds as many.
+const SEARCH_ALL_LIMIT: usize = 24;
+
+/// Series listed under "most watched" in the statistics.
+const WATCH_STATS_TOP_SERIES: usize = 5;
+
+/// Previously visited sections remembered for "back".
+const NAV_HISTORY_LIMIT: usize = 20;
+
+/// How often an external MPV is asked for its position, pause and volume.
+const POSITION_POLL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// How often the position read from MPV is written to the watch history
+/// while playing; it is also written when the player exits.
+const POSITION_SAVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Stop asking MPV for the position after it hasn't answered this long.
+const POSITION_GIVE_UP: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Series checked for a Next Up episode, most recently watched first.
+const NEXT_UP_SERIES: usize = 20;
+
+/// An entry of the Next Up row: the episode at `index` in `episodes`.
+struct NextUp {
+    episodes: std::sync::Arc<Vec<episode_dialog::EpisodeAction>>,
+    index: usize,
+    cover: Option<String>,
+}
+
+/// Starts a playback once its stream has passed the preflight.
+type PlaybackStart = Box<dyn FnOnce(&mut IPTVPlayerApp)>;
+
+/// Episode playing in the external player, tracked to offer the next one.
+struct PlayingEpisode {
+    started_at: std::time::Instant,
+    /// Episodes after this one, in order (next season included)
+    upcoming: Vec<episode_dialog::EpisodeAction>,
+}
+
+/// External player process of the stream playing now, controlled from the
+/// mini player.
+struct ExternalPlayback {
+    child: std::process::Child,
+    title: String,
+    /// MPV's IPC socket; other players have no remote control
+    ipc: Option<crate::mpv_ipc::MpvIpc>,
+    /// Last pause/volume read over IPC, `None` until MPV answers
+    remote: Option<mini_player::RemoteState>,
+    /// The volume slider is being dragged, so MPV's reports leave the
+    /// volume alone until it's let go
+    adjusting_volume: bool,
+    /// Watch history entry the position from MPV goes to
+    history_key: Option<String>,
+    /// Live channel playing, which can be recorded from the mini player
+    channel: Option<Channel>,
+    /// Last position and duration MPV reported, in seconds
+    position: Option<(f64, Option<f64>)>,
+    position_saved_at: Option<std::time::Instant>,
+}
+
+/// Stream playing in the built-in player.
+struct EmbeddedPlayback {
+    player: crate::embedded_player::EmbeddedPlayer,
+    /// Watch history entry the position is saved to when playback stops
+    history_key: Option<String>,
+    /// Live channel playing, which can be recorded from the controls
+    channel: Option<Channel>,
+}
+
+/// A movie or episode waiting for its tracks to be picked.
+enum PendingVod {
+    Movie {
+        stream_id: i64,
+        name: String,
+        thumbnail: Option<String>,
+    },
+    Episode {
+        action: episode_dialog::EpisodeAction,
+        upcoming: Vec<episode_dialog::EpisodeAction>,
+    },
+}
+
+/// Pre-playback "Select tracks" step for a VOD stream.
+struct TrackSelection {
+    playback: PendingVod,
+    /// Stream URL, launched once the tracks are picked
+    url: String,
+    title: String,
+    /// ffprobe result, until it arrives
+    rx: Option<Receiver<Result<crate::track_probe::StreamTracks, String>>>,
+    tracks: Option<crate::track_probe::StreamTracks>,
+    choice: track_picker::TrackChoice,
+}
+
+/// Favorites and history sync (see [`crate::sync`]).
+#[derive(Default)]
+struct SyncState {
+    /// Account the state belongs to (see `sync::account_key`); each
+    /// syncs to its own file
+    account: Option<String>,
+    /// The state after the last successful sync
+    base: crate::sync::Snapshot,
+    /// The local state sent to the sync running now
+    running: Option<crate::sync::Snapshot>,
+    /// When the next sync is due
+    due: Option<std::time::Instant>,
+    /// History revision and favorites last seen, to spot changes
+    seen_revision: u64,
+    seen_favorites: Vec<String>,
+    /// The last sync failed; the user was told once
+    failing: bool,
+}
+
+/// Results of searching the whole library ("Search All").
+#[derive(Default)]
+struct GlobalSearch {
+    /// The query the results are for; empty when not searching
+    query: String,
+    channels: Vec<Channel>,
+    movies: Vec<Movie>,
+    series: Vec<Series>,
+    /// Results shown per section: channels, movies, series
+    shown: [usize; 3],
+}
+
+/// Which categories of the movie or series catalog are loaded, when the
+/// catalog is fetched one category at a time.
+#[derive(Default)]
+struct CategoryPaging {
+    /// Categories whose content is in the library
+    loaded: std::collections::HashSet<String>,
+    /// Categories being fetched
+    loading: std::collections::HashSet<String>,
+    /// The whole catalog is in the library
+    complete: bool,
+    /// The user asked for the whole catalog ("Load all")
+    load_all: bool,
+}
+
+/// Fetch state of a library section.
+#[derive(Debug, Clone, PartialEq, Default)]
+enum LoadState {
+    #[default]
+    NotStarted,
+    Loading,
+    Loaded,
+    /// The last fetch failed; whatever was loaded before stays visible
+    Error(String),
+}
+
+/// Key of a section's pinned categories in the config.
+fn category_section(content: ContentType) -> &'static str {
+    match content {
+        ContentType::Series => "series",
+        ContentType::Movies => "movies",
+        _ => "live",
+    }
+}
+
+/// Random index below `len`, seeded from the system clock.
+fn random_index(len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    // splitmix64 finalizer, so consecutive clicks land far apart
+    let mut x = nanos.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+    Some((x % len as u64) as usize)
+}
+
+/// Reports the pause and volume of an external MPV, and with
+/// `track_position` its playback position, every [`POSITION_POLL`] until
+/// it stops answering. Runs off the UI thread, as a stuck MPV can take a
+/// while to answer, or not answer at all.
+fn follow_mpv(ipc: crate::mpv_ipc::MpvIpc, tx: UiSender, track_position: bool) {
+    let mut answered_at = std::time::Instant::now();
+    while answered_at.elapsed() < POSITION_GIVE_UP {
+        thread::sleep(POSITION_POLL);
+        let state = ipc.is_paused()
+            .and_then(|paused| Ok(mini_player::RemoteState { paused, volume: ipc.volume()? }));
+        // Unavailable until MPV has opened the stream
+        let position = if track_position { ipc.position().ok() } else { None };
+        if state.is_err() && position.is_none() {
+            continue;
+        }
+        answered_at = std::time::Instant::now();
+        
+        if let Ok(state) = state {
+            if tx.send(AppMessage::PlayerRemoteState { socket: ipc.path().to_string(), state }).is_err() {
+                return;
+            }
+        }
+        if let Some(position) = position {
+            let duration = ipc.duration().ok().flatten();
+            let progress = AppMessage::PlaybackProgress {
+                socket: ipc.path().to_string(),
+                position,
+                duration,
+            };
+            if tx.send(progress).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Drops items whose ID or name (ignoring case) already appeared earlier
+/// in `items`, keeping the first occurrence.
+fn dedupe<T>(items: &mut Vec<T>, key: impl Fn(&T) -> (String, &str)) {
+    let mut ids = std::collections::HashSet::new();
+    let mut names = std::collections::HashSet::new();
+    items.retain(|item| {
+        let (id, name) = key(item);
+        let new_id = ids.insert(id);
+        let new_name = names.insert(name.trim().to_lowercase());
+        new_id && new_name
+    });
+}
+
+/// Items with their match scores, best first; ties keep library order.
+fn ranked<'a, T: Clone + 'a>(found: impl Iterator<Item = (i64, &'a T)>) -> Vec<T> {
+    let mut found: Vec<(i64, &T)> = found.collect();
+    found.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    found.into_iter().map(|(_, item)| item.clone()).collect()
+}
+
+/// Loads a section's category list for the per-category catalog mode,
+/// from the content cache when it's fresh.
+fn load_categories(
+    client: &XtreamClient,
+    cache_key: &str,
+    cache_ttl: u64,
+    force: bool,
+    fetch: impl FnOnce(&XtreamClient) -> Result<Vec<Category>, Box<dyn std::error::Error>>,
+) -> Result<(Vec<Category>, u64, LibrarySource), String> {
+    if !force {
+        if let Some(cached) = load_cached::<Vec<Category>>(cache_key, cache_ttl).filter(|c| c.is_fresh(cache_ttl)) {
+            return Ok((cached.data, cached.cached_at, LibrarySource::Cache));
+        }
+    }
+    let categories = fetch(client).map_err(|e| e.to_string())?;
+    save_to_cache(cache_key, &categories, cache_ttl);
+    Ok((categories, chrono::Utc::now().timestamp() as u64, LibrarySource::Server))
+}
+
+/// Reads `cache_key` from the content cache however old it is; nothing
+/// when caching is turned off (`cache_ttl` of 0).
+fn load_cached<T: serde::de::DeserializeOwned>(cache_key: &str, cache_ttl: u64) -> Option<crate::models::content_cache::CachedData<T>> {
+    if cache_ttl == 0 {
+        return None;
+    }
+    crate::models::ContentCache::load_stale(cache_key)
+}
+
+/// Writes `data` to the content cache; a failure is logged, the data is
+/// still used. A `cache_ttl` of 0 (caching turned off) writes nothing.
+fn save_to_cache<T: serde::Serialize>(cache_key: &str, data: &T, cache_ttl: u64) {
+    if cache_ttl == 0 {
+        return;
+    }
+    if let Err(e) = crate::models::ContentCache::save(cache_key, data, cache_ttl) {
+        log::warn!("[Cache] Couldn't save {}: {}", cache_key, e);
+    }
+}
 
 /// Main application struct for the IPTV Player.
 ///
@@ -41,12 +347,47 @@ pub struct IPTVPlayerApp {
     username: String,
     /// Password for authentication
     password: String,
+    /// M3U playlist URL or file when signing in without Xtream
+    playlist_url: String,
+    /// Name the account is saved under, as typed on the login screen
+    account_name: String,
     /// Whether the user is connected
     connected: bool,
     /// Whether a connection attempt is in progress
     connecting: bool,
+    /// Current stage of the connection attempt, shown on the login screen
+    connect_stage: Option<ConnectStage>,
+    /// "Test connection" checklist on the login screen (empty until run)
+    connection_test: Vec<(DiagnosticStage, DiagnosticStatus)>,
     /// Current error message to display
     error_message: Option<String>,
+    /// Whether the running connect is a manual library refresh (series and
+    /// movies then bypass the cache too)
+    refreshing_library: bool,
+    /// Sections refreshed by hand, announced with a toast once fetched
+    refresh_requested: std::collections::HashSet<ContentType>,
+    /// Bumped on sign-out and account switches; library fetches started
+    /// before are ignored when they finish
+    library_generation: u64,
+    /// Match scores of the titles found by the last search, by title
+    search_scores: std::collections::HashMap<String, i64>,
+    /// Set when the Xtream API failed and live channels came from the
+    /// M3U playlist instead (holds the API error)
+    playlist_fallback: Option<String>,
+    /// Set while the server is unreachable and the library comes from the
+    /// cache (holds the connection error)
+    offline: Option<String>,
+    /// When the server was last checked while offline
+    offline_checked_at: Option<std::time::Instant>,
+    /// The server came back after an offline spell; offers a refresh
+    back_online: bool,
+    /// Newer release found by the update check
+    available_update: Option<crate::api::updates::Release>,
+    /// When each library section's data was fetched (unix seconds) and
+    /// whether it came from the cache
+    library_status: std::collections::HashMap<ContentType, (u64, LibrarySource)>,
+    /// Fetch state of each library section
+    library_state: std::collections::HashMap<ContentType, LoadState>,
     
     // ─────────────────────────────────────────────────────────────────────
     // Content Data
@@ -70,9 +411,9 @@ pub struct IPTVPlayerApp {
     filtered_series: Vec<Series>,
     
     /// All movies (as raw JSON values)
-    all_movies: Vec<serde_json::Value>,
+    all_movies: Vec<Movie>,
     /// Filtered movies
-    filtered_movies: Vec<serde_json::Value>,
+    filtered_movies: Vec<Movie>,
     
     // ─────────────────────────────────────────────────────────────────────
     // UI State
@@ -80,18 +421,64 @@ pub struct IPTVPlayerApp {
     
     /// Current content type being displayed
     current_content: ContentType,
-    /// Currently selected category filter
-    selected_category: Option<String>,
+    /// Sections visited before the current one, most recent last
+    nav_history: Vec<ContentType>,
+    /// Categories to show (any of them); empty shows every category
+    selected_categories: std::collections::HashSet<String>,
+    /// Loaded items per category ID, by section; dropped when content loads
+    category_counts: std::collections::HashMap<&'static str, std::collections::HashMap<String, usize>>,
+    /// Loaded movie categories in per-category catalog mode
+    movie_paging: CategoryPaging,
+    /// Loaded series categories in per-category catalog mode
+    series_paging: CategoryPaging,
+    /// Stream quality filter for live channels
+    quality_filter: Option<Quality>,
+    /// Restrict the current view to favorites (reset on section change)
+    favorites_only: bool,
+    /// "Clear all favorites" was clicked and waits for confirmation
+    confirm_clear_favorites: bool,
+    /// Sort order of the channel, series and movie grids
+    sort_order: SortOrder,
     /// Search query for filtering content
     search_query: String,
+    /// When the search query was last edited (pending debounced filter)
+    search_changed_at: Option<std::time::Instant>,
+    /// Search the whole library instead of the current view
+    search_all: bool,
+    /// Results of the whole-library search
+    global_search: GlobalSearch,
+    /// Whether the on-screen keyboard is shown (touch / Game Mode search)
+    show_on_screen_keyboard: bool,
     /// Search query for filtering categories
     category_search: String,
     /// Episode dialog state (holds cached data for fast rendering)
     episode_dialog_state: Option<episode_dialog::EpisodeDialogState>,
+    /// Episode currently playing, if it was started from the episode dialog
+    playing_episode: Option<PlayingEpisode>,
+    /// External player running for the current stream
+    external_playback: Option<ExternalPlayback>,
+    /// Stream shown in the built-in player instead of the browser
+    embedded_playback: Option<EmbeddedPlayback>,
+    /// Episodes to offer in the "Play next" prompt (first one is next)
+    next_episode_prompt: Option<Vec<episode_dialog::EpisodeAction>>,
+    /// Movie or episode whose tracks are being probed or picked
+    track_selection: Option<TrackSelection>,
+    /// Channel whose catch-up archive is being browsed
+    catchup: Option<catchup_dialog::CatchupState>,
+    /// Open the focused card's context menu this frame (Shift+F10, controller Y)
+    context_menu_requested: bool,
+    /// Reminders set on upcoming programmes
+    reminders: crate::reminders::Reminders,
+    /// Reminders that went off and are still shown
+    due_reminders: Vec<crate::reminders::Reminder>,
     /// Whether the player settings dialog is open
     show_player_settings: bool,
     /// Temporary player settings for the dialog (to allow cancel)
     temp_player_settings: Option<crate::models::PlayerSettings>,
+    /// Whether the app settings dialog is open
+    show_app_settings: bool,
+    /// Settings being edited in the app settings dialog (to allow cancel)
+    temp_config: Option<app_settings::AppSettings>,
     /// Whether the EPG settings dialog is open
     show_epg_settings: bool,
     /// Temporary EPG settings for the dialog (to allow cancel)
@@ -107,6 +494,16 @@ pub struct IPTVPlayerApp {
     screen_height: f32,
     /// Whether running in Steam Deck Game Mode environment
     is_steam_deck_mode: bool,
+    /// Whether the app window is fullscreen
+    fullscreen: bool,
+    /// Whether the keyboard shortcuts overlay is open
+    show_shortcuts_help: bool,
+    /// Stream ID of the channel card under the pointer or keyboard focus
+    focused_channel: Option<String>,
+    /// Stream ID of the favorite being dragged to a new place in My List
+    dragged_favorite: Option<String>,
+    /// Controller input (D-pad/buttons) for Steam Deck Game Mode
+    gamepad: super::gamepad::GamepadInput,
     
     // ─────────────────────────────────────────────────────────────────────
     // Pagination
@@ -124,7 +521,7 @@ pub struct IPTVPlayerApp {
     /// Receiver for messages from background threads
     rx: Option<Receiver<AppMessage>>,
     /// Sender for messages to background threads
-    tx: Option<Sender<AppMessage>>,
+    tx: Option<UiSender>,
     
     // ─────────────────────────────────────────────────────────────────────
     // Media
@@ -133,10 +530,44 @@ pub struct IPTVPlayerApp {
     /// Current stream URL being played
     #[allow(dead_code)]
     current_stream_url: Option<String>,
+    /// Playback waiting for its stream preflight: the URL and what starts it
+    pending_preflight: Option<(String, PlaybackStart)>,
+    /// URL that just passed the preflight, let through once
+    preflight_passed: Option<String>,
+    sync: SyncState,
+    /// Whether channel cards pick channels for multi-view instead of playing
+    multiview_selecting: bool,
+    /// Channels picked for the multi-view grid (in tile order)
+    multiview_channels: Vec<Channel>,
+    /// Player processes of the running multi-view grid
+    multiview_players: Vec<std::process::Child>,
+    /// Last multi-view launch error
+    multiview_error: Option<String>,
+    /// Live recordings currently running (one ffmpeg process each)
+    recordings: Vec<crate::recording::Recording>,
+    /// Whether the active recordings panel is open
+    show_recordings: bool,
+    /// Watch statistics being shown, computed when opened
+    watch_stats: Option<WatchStats>,
+    /// Release notes of this version, shown once after an update
+    whats_new: Option<&'static [&'static str]>,
+    /// Transient notifications (favorite added, player failed, ...)
+    toasts: Toasts,
+    /// Current window placement while windowed, saved on exit
+    window_placement: Option<SavedWindow>,
+    /// Stream probe results for this session (stream ID -> online)
+    channel_online: std::collections::HashMap<String, bool>,
+    /// Progress of the running "Check channels" probe (done, total)
+    channel_probe_progress: Option<(usize, usize)>,
+    /// Whether a stream is playing, read by the probe thread so it doesn't
+    /// compete with playback for the provider's connection
+    playback_active: std::sync::Arc<std::sync::atomic::AtomicBool>,
     /// Image cache for channel/series/movie artwork
     image_cache: ImageCache,
     /// EPG cache for program guide data
     epg_cache: super::epg_cache::EpgCache,
+    /// Episode lists of watched series, for Next Up
+    episode_index: super::episode_index::EpisodeIndex,
     /// Discover cache for TV show discovery (TVMaze - free, no API key needed)
     discover_cache: crate::api::DiscoverCache,
     /// Currently selected discover category
@@ -151,17 +582,32 @@ pub struct IPTVPlayerApp {
     scraper_manager: ScraperManager,
     /// Whether the scraper settings dialog is open
     show_scraper_settings: bool,
+    /// Scraper setup checklist, `None` until checked
+    scraper_setup: Option<crate::api::ScraperSetup>,
+    /// Whether the scraper's venv/requirements are being installed
+    scraper_installing: bool,
     /// Whether scraper is currently running
     scraping_in_progress: bool,
+    /// When the scraper last started, or when the sports view was first
+    /// opened (for the scheduled re-run)
+    last_scrape_at: Option<std::time::Instant>,
     /// Last scraper result message
     scraper_message: Option<String>,
 }
 
 impl IPTVPlayerApp {
     /// Creates a new application instance.
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         let config = Config::load().unwrap_or_default();
         let (tx, rx) = channel();
+        
+        // Proxy settings must be in place before any client is built
+        crate::api::http::configure(&config);
+        crate::ui::image_cache::configure(&config);
+        crate::ui::i18n::configure(&config);
+        crate::models::watch_history::configure(&config);
+        super::locale::configure(&config);
+        dimensions::configure(&config);
 
         // Detect Steam Deck environment for scaling
         let is_steam_deck_mode = Self::detect_steam_deck_environment();
@@ -170,9 +616,24 @@ impl IPTVPlayerApp {
             username: config.username.clone(),
             password: config.password.clone(),
             server_url: config.server_url.clone(),
+            playlist_url: config.playlist_url.clone(),
+            account_name: config.active_profile.clone().unwrap_or_default(),
             connected: false,
             connecting: false,
+            connect_stage: None,
+            connection_test: Vec::new(),
+            playlist_fallback: None,
+            offline: None,
+            offline_checked_at: None,
+            back_online: false,
+            available_update: None,
             error_message: None,
+            refreshing_library: false,
+            refresh_requested: std::collections::HashSet::new(),
+            library_generation: 0,
+            search_scores: std::collections::HashMap::new(),
+            library_status: std::collections::HashMap::new(),
+            library_state: std::collections::HashMap::new(),
             live_categories: Vec::new(),
             series_categories: Vec::new(),
             movie_categories: Vec::new(),
@@ -182,13 +643,36 @@ impl IPTVPlayerApp {
             filtered_series: Vec::new(),
             all_movies: Vec::new(),
             filtered_movies: Vec::new(),
-            current_content: ContentType::LiveTV,
-            selected_category: None,
+            current_content: config.default_content.into(),
+            nav_history: Vec::new(),
+            selected_categories: std::collections::HashSet::new(),
+            category_counts: std::collections::HashMap::new(),
+            movie_paging: CategoryPaging::default(),
+            series_paging: CategoryPaging::default(),
+            quality_filter: None,
+            favorites_only: false,
+            confirm_clear_favorites: false,
+            sort_order: SortOrder::default(),
             search_query: String::new(),
+            search_changed_at: None,
+            search_all: false,
+            global_search: GlobalSearch::default(),
+            show_on_screen_keyboard: false,
             category_search: String::new(),
             episode_dialog_state: None,
+            playing_episode: None,
+            external_playback: None,
+            embedded_playback: None,
+            next_episode_prompt: None,
+            track_selection: None,
+            catchup: None,
+            context_menu_requested: false,
+            reminders: crate::reminders::Reminders::load(),
+            due_reminders: Vec::new(),
             show_player_settings: false,
             temp_player_settings: None,
+            show_app_settings: false,
+            temp_config: None,
             show_epg_settings: false,
             temp_epg_enabled: false,
             temp_epg_url: String::new(),
@@ -196,15 +680,43 @@ impl IPTVPlayerApp {
             sidebar_visible: true,  // Visible by default on desktop
             screen_width: 1280.0,   // Default, will be updated each frame
             screen_height: 800.0,   // Default Steam Deck height, will be updated each frame
+            fullscreen: is_steam_deck_mode || config.startup_window == StartupWindowMode::Fullscreen,
             is_steam_deck_mode,
+            show_shortcuts_help: false,
+            focused_channel: None,
+            dragged_favorite: None,
+            gamepad: super::gamepad::GamepadInput::new(),
             page_size: dimensions::DEFAULT_PAGE_SIZE,
             current_page: 0,
             rx: Some(rx),
-            tx: Some(tx),
+            tx: Some(UiSender::new(tx, cc.egui_ctx.clone())),
             current_stream_url: None,
+            pending_preflight: None,
+            sync: SyncState {
+                account: crate::sync::account_key(&config),
+                base: crate::sync::load_base(crate::sync::account_key(&config).as_deref()),
+                // Pick up changes from other installs on startup
+                due: Some(std::time::Instant::now()),
+                ..Default::default()
+            },
+            preflight_passed: None,
             config,
+            multiview_selecting: false,
+            multiview_channels: Vec::new(),
+            multiview_players: Vec::new(),
+            multiview_error: None,
+            recordings: Vec::new(),
+            show_recordings: false,
+            watch_stats: None,
+            whats_new: None,
+            toasts: Toasts::default(),
+            window_placement: None,
+            channel_online: std::collections::HashMap::new(),
+            channel_probe_progress: None,
+            playback_active: Default::default(),
             image_cache: ImageCache::new(),
-            epg_cache: super::epg_cache::EpgCache::new(),
+            epg_cache: super::epg_cache::EpgCache::new(cc.egui_ctx.clone()),
+            episode_index: super::episode_index::EpisodeIndex::new(cc.egui_ctx.clone()),
             discover_cache: crate::api::DiscoverCache::new(),
             discover_category: crate::api::DiscoverCategory::NewMovies2026,
             football_cache: FootballCache::new(),
@@ -212,13 +724,35 @@ impl IPTVPlayerApp {
             watch_history: crate::models::WatchHistory::load(),
             scraper_manager: ScraperManager::new(),
             show_scraper_settings: false,
+            scraper_setup: None,
+            scraper_installing: false,
             scraping_in_progress: false,
+            last_scrape_at: None,
             scraper_message: None,
         };
         
+        if app.config.check_for_updates {
+            app.check_for_updates(false);
+        }
+        app.football_cache.set_favorite_teams(app.config.favorite_teams.iter().cloned().collect());
+        
+        // Release notes once per version; new installs (never logged in)
+        // have nothing to compare with and skip them
+        let version = env!("CARGO_PKG_VERSION");
+        if app.config.last_seen_version.as_deref() != Some(version) {
+            app.whats_new = whats_new::notes_for(version).filter(|_| !app.config.server_url.is_empty());
+            if app.whats_new.is_none() {
+                app.dismiss_whats_new();
+            }
+        }
+        
         // Auto-login if credentials are saved
-        if app.config.auto_login && !app.username.is_empty() && !app.password.is_empty() && !app.server_url.is_empty() {
-            app.connect();
+        let has_login = match app.config.login_mode {
+            LoginMode::Xtream => !app.username.is_empty() && !app.password.is_empty() && !app.server_url.is_empty(),
+            LoginMode::Playlist => !app.playlist_url.trim().is_empty(),
+        };
+        if app.config.auto_login && has_login {
+            app.connect(false);
         }
         
         app
@@ -258,10 +792,82 @@ impl IPTVPlayerApp {
     // Connection Methods
     // ═══════════════════════════════════════════════════════════════════════
     
+    /// Runs the staged connection self-test in the background. Each stage
+    /// reports back as it finishes; later stages are skipped after a failure.
+    fn test_connection(&mut self) {
+        self.connection_test = DiagnosticStage::ALL.iter()
+            .map(|stage| (*stage, DiagnosticStatus::Pending))
+            .collect();
+        
+        crate::logging::add_secret(&self.password);
+        match crate::api::normalize_server_url(&self.server_url) {
+            Ok(url) => self.server_url = url,
+            Err(err) => {
+                self.connection_test = DiagnosticStage::ALL.iter()
+                    .map(|stage| (*stage, DiagnosticStatus::Skipped))
+                    .collect();
+                self.connection_test[0].1 = DiagnosticStatus::Failed(err);
+                return;
+            }
+        }
+        
+        let client = XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        );
+        let tx = self.tx.as_ref().unwrap().clone();
+        
+        thread::spawn(move || {
+            let mut failed = false;
+            for stage in DiagnosticStage::ALL {
+                if failed {
+                    let _ = tx.send(AppMessage::DiagnosticUpdate(stage, DiagnosticStatus::Skipped));
+                    continue;
+                }
+                let _ = tx.send(AppMessage::DiagnosticUpdate(stage, DiagnosticStatus::Running));
+                let result = match stage {
+                    DiagnosticStage::Dns => client.check_dns(),
+                    DiagnosticStage::Tcp => client.check_tcp(),
+                    DiagnosticStage::Http => client.check_api_reachable(),
+                    DiagnosticStage::Auth => client.check_auth(),
+                };
+                let status = match result {
+                    Ok(detail) => DiagnosticStatus::Passed(detail),
+                    Err(err) => {
+                        failed = true;
+                        DiagnosticStatus::Failed(err)
+                    }
+                };
+                let _ = tx.send(AppMessage::DiagnosticUpdate(stage, status));
+            }
+        });
+    }
+    
     /// Initiates a connection to the IPTV server.
-    fn connect(&mut self) {
+    /// `force` skips the content cache and fetches fresh data.
+    fn connect(&mut self, force: bool) {
+        if self.config.login_mode == LoginMode::Playlist {
+            self.connect_playlist();
+            return;
+        }
+        
+        // Catch malformed server URLs before starting the connect thread
+        match crate::api::normalize_server_url(&self.server_url) {
+            Ok(url) => self.server_url = url,
+            Err(err) => {
+                self.error_message = Some(err);
+                return;
+            }
+        }
+        
         self.connecting = true;
+        self.connect_stage = None;
         self.error_message = None;
+        crate::logging::add_secret(&self.password);
+        // Give artwork that failed on a flaky connection another chance
+        self.image_cache.clear_failures();
+        self.playlist_fallback = None;
         
         // Set EPG cache credentials
         self.epg_cache.set_credentials(
@@ -269,6 +875,7 @@ impl IPTVPlayerApp {
             self.username.clone(),
             self.password.clone(),
         );
+        self.episode_index.clear();
 
         // Set external EPG URL if enabled
         if self.config.epg_enabled {
@@ -280,11 +887,11 @@ impl IPTVPlayerApp {
         let server_url = self.server_url.clone();
         let username = self.username.clone();
         let password = self.password.clone();
-        let tx = self.tx.as_ref().unwrap().clone();
+        let cache_ttl = self.config.content_cache_ttl_secs();
+        let tx = self.library_tx();
+        self.library_state.insert(ContentType::LiveTV, LoadState::Loading);
         
         thread::spawn(move || {
-            use crate::models::ContentCache;
-            
             // Validate inputs
             if server_url.is_empty() || username.is_empty() || password.is_empty() {
                 let _ = tx.send(AppMessage::Error("Please fill in all fields".to_string()));
@@ -294,47 +901,170 @@ impl IPTVPlayerApp {
             let cache_key = format!("channels_{}_{}", &username, &server_url);
             let cat_cache_key = format!("categories_{}_{}", &username, &server_url);
             
-            // Try to load from cache first (24 hour cache)
-            if let (Some(categories), Some(channels)) = (
-                ContentCache::load::<Vec<Category>>(&cat_cache_key),
-                ContentCache::load::<Vec<Channel>>(&cache_key)
-            ) {
-                let _ = tx.send(AppMessage::Connected(categories, channels));
-                return;
+            // Serve a fresh cache directly; show an expired one while fetching
+            if !force {
+                if let (Some(categories), Some(channels)) = (
+                    load_cached::<Vec<Category>>(&cat_cache_key, cache_ttl),
+                    load_cached::<Vec<Channel>>(&cache_key, cache_ttl)
+                ) {
+                    let fresh = channels.is_fresh(cache_ttl);
+                    let source = if fresh { LibrarySource::Cache } else { LibrarySource::StaleCache };
+                    let _ = tx.send(AppMessage::LibraryUpdated(ContentType::LiveTV, channels.cached_at, source));
+                    let _ = tx.send(AppMessage::Connected(categories.data, channels.data));
+                    if fresh {
+                        return;
+                    }
+                }
             }
             
             let client = XtreamClient::new(server_url, username, password);
             
-            match client.authenticate() {
-                Ok(true) => {
-                    match (client.get_live_categories(), client.get_live_streams()) {
-                        (Ok(categories), Ok(channels)) => {
-                            // Cache for 24 hours (86400 seconds)
-                            let _ = ContentCache::save(&cat_cache_key, &categories, 86400);
-                            let _ = ContentCache::save(&cache_key, &channels, 86400);
+            // Set when the provider turned the sign-in down, rather than
+            // the API failing; the playlist wouldn't take it either
+            let mut rejected = false;
+            let api_result = (|| {
+                let _ = tx.send(AppMessage::ConnectProgress(ConnectStage::Authenticating));
+                match client.authenticate() {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        rejected = true;
+                        return Err("Authentication failed: Invalid credentials or server response".to_string());
+                    }
+                    Err(e) => return Err(format!("Connection error: {}. Check your server URL and network connection.", e)),
+                }
+                
+                let _ = tx.send(AppMessage::ConnectProgress(ConnectStage::LoadingCategories));
+                let categories = client.get_live_categories()
+                    .map_err(|e| format!("Failed to fetch categories: {}", e))?;
+                
+                let _ = tx.send(AppMessage::ConnectProgress(ConnectStage::LoadingChannels));
+                let channels = client.get_live_streams()
+                    .map_err(|e| format!("Failed to fetch channels: {}", e))?;
+                Ok((categories, channels))
+            })();
+            
+            match api_result {
+                Ok((categories, channels)) => {
+                    save_to_cache(&cat_cache_key, &categories, cache_ttl);
+                    save_to_cache(&cache_key, &channels, cache_ttl);
+                    let _ = tx.send(AppMessage::LibraryUpdated(ContentType::LiveTV, chrono::Utc::now().timestamp() as u64, LibrarySource::Server));
+                    let _ = tx.send(AppMessage::Connected(categories, channels));
+                }
+                Err(api_err) if rejected => {
+                    let _ = tx.send(AppMessage::LoginRejected(api_err));
+                }
+                Err(api_err) => {
+                    // Some panels have a broken player_api.php but a working playlist
+                    log::warn!("[Connect] {}; trying the M3U playlist", api_err);
+                    let _ = tx.send(AppMessage::ConnectProgress(ConnectStage::LoadingPlaylist));
+                    let playlist = crate::api::http::provider_client()
+                        .get(client.get_m3u_url())
+                        .send()
+                        .and_then(|r| r.error_for_status())
+                        .and_then(|r| r.text())
+                        .map_err(|e| e.to_string())
+                        .and_then(|text| crate::m3u::M3UParser::parse_xtream_live(&text));
+                    
+                    match playlist {
+                        // Not cached: the next connect should try the API again
+                        Ok((categories, channels)) => {
+                            let _ = tx.send(AppMessage::PlaylistFallback(api_err));
+                            let _ = tx.send(AppMessage::LibraryUpdated(ContentType::LiveTV, chrono::Utc::now().timestamp() as u64, LibrarySource::Server));
                             let _ = tx.send(AppMessage::Connected(categories, channels));
                         }
-                        (Err(e), _) => {
-                            let _ = tx.send(AppMessage::Error(format!("Failed to fetch categories: {}", e)));
-                        }
-                        (_, Err(e)) => {
-                            let _ = tx.send(AppMessage::Error(format!("Failed to fetch channels: {}", e)));
+                        Err(m3u_err) => {
+                            log::warn!("[Connect] M3U fallback failed: {}", m3u_err);
+                            // Offline: keep browsing whatever is cached, however old
+                            match (
+                                load_cached::<Vec<Category>>(&cat_cache_key, cache_ttl),
+                                load_cached::<Vec<Channel>>(&cache_key, cache_ttl)
+                            ) {
+                                (Some(categories), Some(channels)) => {
+                                    let _ = tx.send(AppMessage::WentOffline(api_err));
+                                    // Without `force` the cached channels were already sent
+                                    if force {
+                                        let _ = tx.send(AppMessage::LibraryUpdated(ContentType::LiveTV, channels.cached_at, LibrarySource::StaleCache));
+                                        let _ = tx.send(AppMessage::Connected(categories.data, channels.data));
+                                    }
+                                }
+                                _ => {
+                                    let _ = tx.send(AppMessage::Error(api_err));
+                                }
+                            }
                         }
                     }
                 }
-                Ok(false) => {
-                    let _ = tx.send(AppMessage::Error("Authentication failed: Invalid credentials or server response".to_string()));
+            }
+        });
+    }
+    
+    /// Signs in with an M3U playlist instead of Xtream: live channels
+    /// only, grouped by `group-title`, each playing its own URL. The
+    /// playlist is read and parsed off the UI thread; big ones take a while.
+    fn connect_playlist(&mut self) {
+        self.connecting = true;
+        self.connect_stage = None;
+        self.error_message = None;
+        self.image_cache.clear_failures();
+        self.playlist_fallback = None;
+        
+        // No Xtream API for the short EPG; an XMLTV guide still works
+        self.epg_cache.set_credentials(String::new(), String::new(), String::new());
+        if self.config.epg_enabled {
+            self.epg_cache.set_xmltv_url(self.config.epg_url.clone());
+        } else {
+            self.epg_cache.set_xmltv_url(None);
+        }
+        self.episode_index.clear();
+        
+        let source = self.playlist_url.trim().to_string();
+        let tx = self.library_tx();
+        self.library_state.insert(ContentType::LiveTV, LoadState::Loading);
+        
+        thread::spawn(move || {
+            if source.is_empty() {
+                let _ = tx.send(AppMessage::Error("Please enter a playlist URL or file".to_string()));
+                return;
+            }
+            
+            let _ = tx.send(AppMessage::ConnectProgress(ConnectStage::ReadingPlaylist));
+            let playlist = crate::m3u::M3UParser::read_source(&source)
+                .and_then(|text| crate::m3u::M3UParser::parse_playlist(&text));
+            match playlist {
+                Ok((categories, channels)) => {
+                    log::info!("[Connect] Playlist has {} channels in {} groups", channels.len(), categories.len());
+                    let _ = tx.send(AppMessage::LibraryUpdated(ContentType::LiveTV, chrono::Utc::now().timestamp() as u64, LibrarySource::Server));
+                    let _ = tx.send(AppMessage::Connected(categories, channels));
                 }
                 Err(e) => {
-                    let _ = tx.send(AppMessage::Error(format!("Connection error: {}. Check your server URL and network connection.", e)));
+                    let _ = tx.send(AppMessage::Error(e));
                 }
             }
         });
     }
     
+    /// Whether only live channels are available: signed in with a
+    /// playlist, or the Xtream API is down and the M3U fallback is in use.
+    fn live_only(&self) -> bool {
+        self.config.login_mode == LoginMode::Playlist || self.playlist_fallback.is_some()
+    }
+    
+    /// Stream URL of a live channel; playlist channels carry their own.
+    fn live_stream_url(&self, channel: &Channel) -> String {
+        if self.config.login_mode == LoginMode::Playlist {
+            return channel.direct_source.clone().unwrap_or_default();
+        }
+        XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        ).get_live_stream_url(&channel.stream_id, self.config.live_stream_format)
+    }
+    
     /// Disconnects from the server and clears all data.
     fn disconnect(&mut self) {
         self.connected = false;
+        self.library_generation += 1;
         self.live_categories.clear();
         self.series_categories.clear();
         self.movie_categories.clear();
@@ -344,8 +1074,16 @@ impl IPTVPlayerApp {
         self.filtered_series.clear();
         self.all_movies.clear();
         self.filtered_movies.clear();
+        self.movie_paging = CategoryPaging::default();
+        self.series_paging = CategoryPaging::default();
+        self.nav_history.clear();
+        self.category_counts.clear();
         // Clear EPG cache on disconnect
         self.epg_cache.clear();
+        self.episode_index.clear();
+        self.channel_online.clear();
+        self.offline = None;
+        self.back_online = false;
     }
     
     // ═══════════════════════════════════════════════════════════════════════
@@ -353,82 +1091,132 @@ impl IPTVPlayerApp {
     // ═══════════════════════════════════════════════════════════════════════
     
     /// Loads series data in the background.
-    fn load_series(&mut self) {
+    /// `force` skips the content cache and fetches fresh data.
+    fn load_series(&mut self, force: bool) {
         let server_url = self.server_url.clone();
         let username = self.username.clone();
         let password = self.password.clone();
-        let tx = self.tx.as_ref().unwrap().clone();
+        let cache_ttl = self.config.content_cache_ttl_secs();
+        let tx = self.library_tx();
+        self.library_state.insert(ContentType::Series, LoadState::Loading);
+        let by_category = self.config.load_vod_by_category && !self.series_paging.load_all;
+        self.series_paging.complete = !by_category;
         
         thread::spawn(move || {
-            use crate::models::ContentCache;
-            
             let cache_key = format!("series_{}_{}", &username, &server_url);
             let cat_cache_key = format!("series_cat_{}_{}", &username, &server_url);
             
-            // Try cache first (24 hour cache)
-            if let (Some(categories), Some(series)) = (
-                ContentCache::load::<Vec<Category>>(&cat_cache_key),
-                ContentCache::load::<Vec<Series>>(&cache_key)
-            ) {
-                let _ = tx.send(AppMessage::SeriesLoaded(categories, series));
+            // Per-category mode: only the category list now, content on demand
+            if by_category {
+                let client = XtreamClient::new(server_url, username, password);
+                match load_categories(&client, &cat_cache_key, cache_ttl, force, |c| c.get_series_categories()) {
+                    Ok((categories, fetched_at, source)) => {
+                        let _ = tx.send(AppMessage::LibraryUpdated(ContentType::Series, fetched_at, source));
+                        let _ = tx.send(AppMessage::SeriesLoaded(categories, Vec::new()));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(AppMessage::LibraryLoadFailed(ContentType::Series, format!("Failed to fetch series categories: {}", e)));
+                    }
+                }
                 return;
             }
             
+            // Serve a fresh cache directly; show an expired one while fetching
+            if !force {
+                if let (Some(categories), Some(series)) = (
+                    load_cached::<Vec<Category>>(&cat_cache_key, cache_ttl),
+                    load_cached::<Vec<Series>>(&cache_key, cache_ttl)
+                ) {
+                    let fresh = series.is_fresh(cache_ttl);
+                    let source = if fresh { LibrarySource::Cache } else { LibrarySource::StaleCache };
+                    let _ = tx.send(AppMessage::LibraryUpdated(ContentType::Series, series.cached_at, source));
+                    let _ = tx.send(AppMessage::SeriesLoaded(categories.data, series.data));
+                    if fresh {
+                        return;
+                    }
+                }
+            }
+            
             let client = XtreamClient::new(server_url, username, password);
             
-            match (client.get_series_categories(), client.get_series()) {
+            match (client.get_series_categories(), client.get_series(None)) {
                 (Ok(categories), Ok(series)) => {
-                    // Cache for 24 hours
-                    let _ = ContentCache::save(&cat_cache_key, &categories, 86400);
-                    let _ = ContentCache::save(&cache_key, &series, 86400);
+                    save_to_cache(&cat_cache_key, &categories, cache_ttl);
+                    save_to_cache(&cache_key, &series, cache_ttl);
+                    let _ = tx.send(AppMessage::LibraryUpdated(ContentType::Series, chrono::Utc::now().timestamp() as u64, LibrarySource::Server));
                     let _ = tx.send(AppMessage::SeriesLoaded(categories, series));
                 }
                 (Err(e), _) => {
-                    let _ = tx.send(AppMessage::Error(format!("Failed to fetch series categories: {}", e)));
+                    let _ = tx.send(AppMessage::LibraryLoadFailed(ContentType::Series, format!("Failed to fetch series categories: {}", e)));
                 }
                 (_, Err(e)) => {
-                    let _ = tx.send(AppMessage::Error(format!("Failed to fetch series: {}", e)));
+                    let _ = tx.send(AppMessage::LibraryLoadFailed(ContentType::Series, format!("Failed to fetch series: {}", e)));
                 }
             }
         });
     }
     
     /// Loads movies data in the background.
-    fn load_movies(&mut self) {
+    /// `force` skips the content cache and fetches fresh data.
+    fn load_movies(&mut self, force: bool) {
         let server_url = self.server_url.clone();
         let username = self.username.clone();
         let password = self.password.clone();
-        let tx = self.tx.as_ref().unwrap().clone();
+        let cache_ttl = self.config.content_cache_ttl_secs();
+        let tx = self.library_tx();
+        self.library_state.insert(ContentType::Movies, LoadState::Loading);
+        let by_category = self.config.load_vod_by_category && !self.movie_paging.load_all;
+        self.movie_paging.complete = !by_category;
         
         thread::spawn(move || {
-            use crate::models::ContentCache;
-            
             let cache_key = format!("movies_{}_{}", &username, &server_url);
             let cat_cache_key = format!("movies_cat_{}_{}", &username, &server_url);
             
-            // Try cache first (24 hour cache)
-            if let (Some(categories), Some(movies)) = (
-                ContentCache::load::<Vec<Category>>(&cat_cache_key),
-                ContentCache::load::<Vec<serde_json::Value>>(&cache_key)
-            ) {
-                let _ = tx.send(AppMessage::MoviesLoaded(categories, movies));
+            // Per-category mode: only the category list now, content on demand
+            if by_category {
+                let client = XtreamClient::new(server_url, username, password);
+                match load_categories(&client, &cat_cache_key, cache_ttl, force, |c| c.get_vod_categories()) {
+                    Ok((categories, fetched_at, source)) => {
+                        let _ = tx.send(AppMessage::LibraryUpdated(ContentType::Movies, fetched_at, source));
+                        let _ = tx.send(AppMessage::MoviesLoaded(categories, Vec::new()));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(AppMessage::LibraryLoadFailed(ContentType::Movies, format!("Failed to fetch movie categories: {}", e)));
+                    }
+                }
                 return;
             }
             
+            // Serve a fresh cache directly; show an expired one while fetching
+            if !force {
+                if let (Some(categories), Some(movies)) = (
+                    load_cached::<Vec<Category>>(&cat_cache_key, cache_ttl),
+                    load_cached::<Vec<Movie>>(&cache_key, cache_ttl)
+                ) {
+                    let fresh = movies.is_fresh(cache_ttl);
+                    let source = if fresh { LibrarySource::Cache } else { LibrarySource::StaleCache };
+                    let _ = tx.send(AppMessage::LibraryUpdated(ContentType::Movies, movies.cached_at, source));
+                    let _ = tx.send(AppMessage::MoviesLoaded(categories.data, movies.data));
+                    if fresh {
+                        return;
+                    }
+                }
+            }
+            
             let client = XtreamClient::new(server_url, username, password);
             
-            match (client.get_vod_categories(), client.get_vod_streams()) {
+            match (client.get_vod_categories(), client.get_vod_streams(None)) {
                 (Ok(categories), Ok(movies)) => {
-                    // Cache for 24 hours
-                    let _ = ContentCache::save(&cat_cache_key, &categories, 86400);
-                    let _ = ContentCache::save(&cache_key, &movies, 86400);
+                    save_to_cache(&cat_cache_key, &categories, cache_ttl);
+                    save_to_cache(&cache_key, &movies, cache_ttl);
+                    let _ = tx.send(AppMessage::LibraryUpdated(ContentType::Movies, chrono::Utc::now().timestamp() as u64, LibrarySource::Server));
                     let _ = tx.send(AppMessage::MoviesLoaded(categories, movies));
                 }
                 (Err(e), _) => {
-                    let _ = tx.send(AppMessage::Error(format!("Failed to fetch movie categories: {}", e)));
+                    let _ = tx.send(AppMessage::LibraryLoadFailed(ContentType::Movies, format!("Failed to fetch movie categories: {}", e)));
                 }
                 (_, Err(e)) => {
-                    let _ = tx.send(AppMessage::Error(format!("Failed to fetch movies: {}", e)));
+                    let _ = tx.send(AppMessage::LibraryLoadFailed(ContentType::Movies, format!("Failed to fetch movies: {}", e)));
                 }
             }
         });
@@ -438,33 +1226,148 @@ impl IPTVPlayerApp {
     // Content Filtering
     // ═══════════════════════════════════════════════════════════════════════
     
+    /// Fetches the selected movie or series categories that aren't in the
+    /// library yet (per-category catalog mode only).
+    fn request_selected_categories(&mut self) {
+        let content = self.current_content;
+        let live_only = self.live_only();
+        let paging = match content {
+            ContentType::Movies => &mut self.movie_paging,
+            ContentType::Series => &mut self.series_paging,
+            _ => return,
+        };
+        if paging.complete || live_only {
+            return;
+        }
+        let missing: Vec<String> = self.selected_categories.iter()
+            .filter(|id| !paging.loaded.contains(*id) && !paging.loading.contains(*id))
+            .cloned()
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+        paging.loading.extend(missing.iter().cloned());
+        
+        let client = XtreamClient::new(self.server_url.clone(), self.username.clone(), self.password.clone());
+        let tx = self.library_tx();
+        thread::spawn(move || {
+            for category_id in missing {
+                let result = match content {
+                    ContentType::Movies => client.get_vod_streams(Some(&category_id))
+                        .map(|movies| AppMessage::MovieCategoryLoaded(category_id.clone(), movies)),
+                    _ => client.get_series(Some(&category_id))
+                        .map(|series| AppMessage::SeriesCategoryLoaded(category_id.clone(), series)),
+                };
+                let message = result.unwrap_or_else(|e| {
+                    AppMessage::CategoryLoadFailed(content, category_id.clone(), e.to_string())
+                });
+                let _ = tx.send(message);
+            }
+        });
+    }
+    
+    /// Switches the current movie or series section to the full catalog,
+    /// so search covers everything.
+    fn load_whole_catalog(&mut self) {
+        match self.current_content {
+            ContentType::Movies => {
+                self.movie_paging.load_all = true;
+                self.load_movies(false);
+            }
+            ContentType::Series => {
+                self.series_paging.load_all = true;
+                self.load_series(false);
+            }
+            _ => {}
+        }
+    }
+    
+    /// Drops the loaded movies and series and fetches them again, e.g.
+    /// after switching between full and per-category catalog loading.
+    fn reload_vod_library(&mut self) {
+        self.all_movies.clear();
+        self.filtered_movies.clear();
+        self.all_series.clear();
+        self.filtered_series.clear();
+        self.movie_paging = CategoryPaging::default();
+        self.series_paging = CategoryPaging::default();
+        if self.connected && !self.live_only() {
+            self.load_series(false);
+            self.load_movies(false);
+        }
+    }
+    
+    /// Items per category ID in the current section, counted over the
+    /// loaded content.
+    fn count_categories(&self) -> std::collections::HashMap<String, usize> {
+        let ids: Vec<&str> = match self.current_content {
+            ContentType::Series => self.all_series.iter().map(|s| s.category_id.as_str()).collect(),
+            ContentType::Movies => self.all_movies.iter().map(|m| m.category_id.as_str()).collect(),
+            _ => self.all_channels.iter().map(|ch| ch.category_id.as_str()).collect(),
+        };
+        let mut counts = std::collections::HashMap::new();
+        for id in ids {
+            *counts.entry(id.to_string()).or_insert(0) += 1;
+        }
+        counts
+    }
+    
     /// Filters content based on current search query and category.
     fn filter_content(&mut self) {
-        let query = self.search_query.to_lowercase();
+        self.request_selected_categories();
         self.current_page = 0; // Reset to first page when filtering
         
+        self.global_search = GlobalSearch::default();
+        self.search_scores.clear();
+        let search = super::fuzzy::Query::new(&self.search_query);
+        if let Some(search) = search.as_ref().filter(|_| self.search_all) {
+            self.search_library(search);
+            return;
+        }
+        // Scores by title, kept for ranking so no title is scored twice
+        let scores = std::cell::RefCell::new(std::collections::HashMap::new());
+        let matches = |name: &str| {
+            let Some(search) = &search else {
+                return true;
+            };
+            if scores.borrow().contains_key(name) {
+                return true;
+            }
+            let Some(score) = search.score(name) else {
+                return false;
+            };
+            scores.borrow_mut().insert(name.to_string(), score);
+            true
+        };
+        
         match self.current_content {
             ContentType::LiveTV => {
                 self.filtered_channels = self.all_channels.iter()
                     .filter(|ch| {
-                        let matches_search = query.is_empty() || ch.name.to_lowercase().contains(&query);
-                        let matches_category = self.selected_category.is_none() 
-                            || self.selected_category.as_ref() == Some(&ch.category_id);
-                        matches_search && matches_category
+                        let matches_category = self.selected_categories.is_empty()
+                            || self.selected_categories.contains(&ch.category_id);
+                        let matches_quality = self.quality_filter.is_none()
+                            || ch.quality() == self.quality_filter;
+                        let matches_favorites = !self.favorites_only
+                            || self.config.favorites.contains(&ch.stream_id);
+                        let is_hidden = self.config.hidden_channels.contains_key(&ch.stream_id);
+                        matches_category && matches_quality && matches_favorites && !is_hidden && matches(&ch.name)
                     })
-                    .take(1000)
                     .cloned()
                     .collect();
+                if self.config.hide_duplicates {
+                    dedupe(&mut self.filtered_channels, |ch| (ch.stream_id.clone(), ch.name.as_str()));
+                }
             }
             ContentType::Series => {
                 self.filtered_series = self.all_series.iter()
                     .filter(|s| {
-                        let matches_search = query.is_empty() || s.name.to_lowercase().contains(&query);
-                        let matches_category = self.selected_category.is_none() 
-                            || self.selected_category.as_ref() == Some(&s.category_id);
-                        matches_search && matches_category
+                        let matches_category = self.selected_categories.is_empty()
+                            || self.selected_categories.contains(&s.category_id);
+                        let matches_favorites = !self.favorites_only
+                            || self.config.favorite_series.contains(&s.series_id.to_string());
+                        matches_category && matches_favorites && matches(&s.name)
                     })
-                    .take(1000)
                     .cloned()
                     .collect();
             }
@@ -472,26 +1375,48 @@ impl IPTVPlayerApp {
                 self.filtered_channels = self.all_channels.iter()
                     .filter(|ch| {
                         let is_favorite = self.config.favorites.contains(&ch.stream_id);
-                        let matches_search = query.is_empty() || ch.name.to_lowercase().contains(&query);
-                        is_favorite && matches_search
+                        let matches_quality = self.quality_filter.is_none()
+                            || ch.quality() == self.quality_filter;
+                        let is_hidden = self.config.hidden_channels.contains_key(&ch.stream_id);
+                        is_favorite && matches_quality && !is_hidden && matches(&ch.name)
                     })
-                    .take(1000)
                     .cloned()
                     .collect();
+                if self.config.hide_duplicates {
+                    dedupe(&mut self.filtered_channels, |ch| (ch.stream_id.clone(), ch.name.as_str()));
+                }
+                
+                // Movies and series in My List show below the channels,
+                // once each even when listed in several categories
+                self.filtered_movies = self.all_movies.iter()
+                    .filter(|m| self.config.favorite_movies.contains(&m.stream_id.to_string()))
+                    .filter(|m| matches(&m.name))
+                    .cloned()
+                    .collect();
+                let mut seen = std::collections::HashSet::new();
+                self.filtered_movies.retain(|m| seen.insert(m.stream_id));
+                self.filtered_series = self.all_series.iter()
+                    .filter(|s| self.config.favorite_series.contains(&s.series_id.to_string()))
+                    .filter(|s| matches(&s.name))
+                    .cloned()
+                    .collect();
+                let mut seen = std::collections::HashSet::new();
+                self.filtered_series.retain(|s| seen.insert(s.series_id));
             }
             ContentType::Movies => {
                 self.filtered_movies = self.all_movies.iter()
                     .filter(|m| {
-                        let name = m.get("name").and_then(|v| v.as_str()).unwrap_or("");
-                        let matches_search = query.is_empty() || name.to_lowercase().contains(&query);
-                        let category_id = m.get("category_id").and_then(|v| v.as_str()).unwrap_or("");
-                        let matches_category = self.selected_category.is_none() 
-                            || self.selected_category.as_ref() == Some(&category_id.to_string());
-                        matches_search && matches_category
+                        let matches_category = self.selected_categories.is_empty()
+                            || self.selected_categories.contains(&m.category_id);
+                        let matches_favorites = !self.favorites_only
+                            || self.config.favorite_movies.contains(&m.stream_id.to_string());
+                        matches_category && matches_favorites && matches(&m.name)
                     })
-                    .take(1000)
                     .cloned()
                     .collect();
+                if self.config.hide_duplicates {
+                    dedupe(&mut self.filtered_movies, |m| (m.stream_id.to_string(), m.name.as_str()));
+                }
             }
             ContentType::Discover => {
                 // Discover doesn't use traditional filtering
@@ -502,7 +1427,88 @@ impl IPTVPlayerApp {
             ContentType::ContinueWatching => {
                 // Continue watching doesn't use traditional filtering
             }
+            ContentType::OnNow => {
+                // On Now always lists every favorite channel
+            }
+            ContentType::RecentlyAdded => {
+                // Recently Added lists the newest items of the whole library
+            }
+        }
+        self.search_scores = scores.into_inner();
+        self.sort_filtered();
+    }
+    
+    /// Searches channels, movies and series at once, each on its own
+    /// thread, listing every item once with the best matches first.
+    fn search_library(&mut self, search: &super::fuzzy::Query) {
+        let hidden = &self.config.hidden_channels;
+        let (channels, movies, series) = thread::scope(|scope| {
+            let channels = scope.spawn(|| {
+                let mut seen = std::collections::HashSet::new();
+                let found = self.all_channels.iter()
+                    .filter(|ch| !hidden.contains_key(&ch.stream_id))
+                    .filter_map(|ch| Some((search.score(&ch.name)?, ch)))
+                    .filter(|(_, ch)| seen.insert(ch.stream_id.as_str()));
+                ranked(found)
+            });
+            let movies = scope.spawn(|| {
+                let mut seen = std::collections::HashSet::new();
+                let found = self.all_movies.iter()
+                    .filter_map(|m| Some((search.score(&m.name)?, m)))
+                    .filter(|(_, m)| seen.insert(m.stream_id));
+                ranked(found)
+            });
+            let series = scope.spawn(|| {
+                let mut seen = std::collections::HashSet::new();
+                let found = self.all_series.iter()
+                    .filter_map(|s| Some((search.score(&s.name)?, s)))
+                    .filter(|(_, s)| seen.insert(s.series_id));
+                ranked(found)
+            });
+            (
+                channels.join().unwrap_or_default(),
+                movies.join().unwrap_or_default(),
+                series.join().unwrap_or_default(),
+            )
+        });
+        self.global_search = GlobalSearch {
+            query: self.search_query.trim().to_string(),
+            channels,
+            movies,
+            series,
+            shown: [SEARCH_ALL_LIMIT; 3],
+        };
+    }
+    
+    /// Applies the chosen sort order to the current view's filtered list.
+    fn sort_filtered(&mut self) {
+        match self.current_content {
+            ContentType::LiveTV => sort::sort_channels(&mut self.filtered_channels, self.sort_order),
+            ContentType::Favorites => {
+                // My List keeps the order the user dragged it into
+                let order = &self.config.favorites;
+                self.filtered_channels.sort_by_key(|ch| order.iter().position(|id| *id == ch.stream_id));
+                let order = &self.config.favorite_movies;
+                self.filtered_movies.sort_by_key(|m| order.iter().position(|id| *id == m.stream_id.to_string()));
+                let order = &self.config.favorite_series;
+                self.filtered_series.sort_by_key(|s| order.iter().position(|id| *id == s.series_id.to_string()));
+            }
+            ContentType::Series => sort::sort_series(&mut self.filtered_series, self.sort_order),
+            ContentType::Movies => sort::sort_movies(&mut self.filtered_movies, self.sort_order),
+            _ => {}
+        }
+
+        // With a search the best matches go first, the sort order breaking
+        // ties; a channel or title searched from Football or Discover lands
+        // at the top of the first page
+        if self.search_scores.is_empty() {
+            return;
         }
+        let scores = &self.search_scores;
+        let rank = |name: &str| std::cmp::Reverse(scores.get(name).copied());
+        self.filtered_channels.sort_by_key(|ch| rank(&ch.name));
+        self.filtered_movies.sort_by_key(|m| rank(&m.name));
+        self.filtered_series.sort_by_key(|s| rank(&s.name));
     }
     
     // ═══════════════════════════════════════════════════════════════════════
@@ -511,72 +1517,1301 @@ impl IPTVPlayerApp {
     
     /// Plays a live channel using the configured media player.
     fn play_channel(&mut self, channel: &Channel) {
-        let client = XtreamClient::new(
-            self.server_url.clone(),
-            self.username.clone(),
-            self.password.clone(),
-        );
+        if self.blocked_offline() {
+            return;
+        }
         
-        let url = client.get_live_stream_url(&channel.stream_id);
-        self.current_stream_url = Some(url.clone());
+        let url = self.live_stream_url(channel);
+        let retry = channel.clone();
+        if !self.stream_ready(&url, move |app| app.play_channel(&retry)) {
+            return;
+        }
+        self.current_stream_url = Some(url.clone());
+        
+        let settings = self.config.player_settings.clone();
+        if self.play_embedded(&settings, &url, &channel.name, None, None) {
+            if let Some(playback) = &mut self.embedded_playback {
+                playback.channel = Some(channel.clone());
+            }
+            return;
+        }
+        
+        // Launch the configured player (live stream = true)
+        if self.launch_external(settings, &channel.name, None, |s, ipc| s.launch_player(&url, &channel.name, true, ipc)) {
+            if let Some(playback) = &mut self.external_playback {
+                playback.channel = Some(channel.clone());
+            }
+        }
+    }
+    
+    /// Whether the live channel of a playback is being recorded, `None`
+    /// when it isn't a live channel.
+    fn recording_state(&self, channel: Option<&Channel>) -> Option<bool> {
+        channel.map(|channel| self.recordings.iter().any(|r| r.stream_id == channel.stream_id))
+    }
+    
+    /// Remembers where the window is while it's a normal window, so a
+    /// maximized or fullscreen session doesn't overwrite the saved size.
+    fn track_window_placement(&mut self, ctx: &egui::Context) {
+        if self.is_steam_deck_mode {
+            return;
+        }
+        let placement = ctx.input(|i| {
+            let viewport = i.viewport();
+            if viewport.fullscreen == Some(true) || viewport.maximized == Some(true) || viewport.minimized == Some(true) {
+                return None;
+            }
+            let outer = viewport.outer_rect?;
+            let inner = viewport.inner_rect?;
+            Some(SavedWindow { x: outer.min.x, y: outer.min.y, width: inner.width(), height: inner.height() })
+        });
+        if placement.is_some() {
+            self.window_placement = placement;
+        }
+    }
+    
+    /// Puts a stream URL on the clipboard for use in another app. The URL
+    /// carries the account credentials, since that's how streams authenticate.
+    fn copy_stream_url(&mut self, ctx: &egui::Context, url: String, name: &str) {
+        ctx.copy_text(url);
+        self.toasts.success(format!("Copied stream URL for {}", name));
+    }
+    
+    /// Plays in the built-in player when it's the selected one. `false` if
+    /// it isn't, or libmpv couldn't be loaded, and the caller should launch
+    /// the external player instead.
+    fn play_embedded(&mut self, settings: &PlayerSettings, url: &str, title: &str, start: Option<f64>, history_key: Option<String>) -> bool {
+        if settings.player_type != PlayerType::Embedded {
+            return false;
+        }
+        self.close_embedded_player();
+        match crate::embedded_player::EmbeddedPlayer::open(url, title, settings, start) {
+            Ok(player) => {
+                self.embedded_playback = Some(EmbeddedPlayback { player, history_key, channel: None });
+                true
+            }
+            Err(e) => {
+                log::warn!("[Player] Built-in player unavailable: {}", e);
+                self.toasts.warn(format!("Built-in player unavailable ({}); using MPV", e));
+                false
+            }
+        }
+    }
+    
+    /// Stops the built-in player, saving how far it got to watch history.
+    fn close_embedded_player(&mut self) {
+        let Some(playback) = self.embedded_playback.take() else {
+            return;
+        };
+        let entry = playback.history_key
+            .and_then(|key| self.watch_history.get_progress(&key).cloned());
+        if let (Some(mut entry), Some(position)) = (entry, playback.player.position()) {
+            entry.position_seconds = position;
+            if let Some(duration) = playback.player.duration() {
+                entry.duration_seconds = duration;
+            }
+            entry.last_watched = chrono::Utc::now().timestamp();
+            self.watch_history.update_progress(entry);
+        }
+    }
+    
+    /// Draws the built-in player and handles its controls and end of playback.
+    fn render_embedded_player(&mut self, ctx: &egui::Context, theme: &Theme) {
+        let recording = self.recording_state(self.embedded_playback.as_ref().and_then(|p| p.channel.as_ref()));
+        let Some(playback) = self.embedded_playback.as_mut() else {
+            return;
+        };
+        let ended = playback.player.ended().cloned();
+        let action = EmbeddedPlayerView::show(ctx, &mut playback.player, theme, recording);
+        if action == Some(EmbeddedPlayerAction::ToggleRecording) {
+            if let Some(channel) = playback.channel.clone() {
+                self.toggle_recording(&channel);
+            }
+        }
+        
+        if let Some(Err(e)) = &ended {
+            self.toasts.error(format!("Playback failed: {}", e));
+        }
+        if ended.is_some() || action == Some(EmbeddedPlayerAction::Close) {
+            self.close_embedded_player();
+        } else {
+            // Keep frames coming while the video plays
+            ctx.request_repaint_after(std::time::Duration::from_millis(10));
+        }
+    }
+    
+    /// Tells the user the configured player couldn't be started.
+    fn report_player_error(&mut self, err: std::io::Error) {
+        let player = self.config.player_settings.player_type.display_name();
+        log::error!("[Player] Failed to launch {}: {}", player, err);
+        self.toasts.error(format!("Couldn't start {}: {}", player, err));
+    }
+    
+    /// Adds a channel to the multi-view selection, or removes it if already picked.
+    fn toggle_multiview_channel(&mut self, channel: &Channel) {
+        if let Some(idx) = self.multiview_channels.iter().position(|c| c.stream_id == channel.stream_id) {
+            self.multiview_channels.remove(idx);
+        } else if self.multiview_channels.len() < MULTIVIEW_MAX {
+            self.multiview_channels.push(channel.clone());
+        }
+    }
+    
+    /// Launches the selected channels as a grid of player windows, replacing
+    /// any running multi-view. Only the first tile plays audio.
+    fn launch_multiview(&mut self, ctx: &egui::Context) {
+        if self.blocked_offline() {
+            return;
+        }
+        self.close_multiview();
+        
+        // Monitor size in physical pixels (players position in pixels)
+        let (screen_w, screen_h) = ctx.input(|i| {
+            i.viewport().monitor_size
+                .map(|size| size * i.pixels_per_point)
+                .map(|size| (size.x as u32, size.y as u32))
+                .unwrap_or((1920, 1080))
+        });
+        
+        let count = self.multiview_channels.len() as u32;
+        let (cols, rows) = if count <= 1 { (1, 1) } else if count == 2 { (2, 1) } else { (2, 2) };
+        
+        let mut errors = Vec::new();
+        for (idx, channel) in self.multiview_channels.iter().enumerate() {
+            let url = self.live_stream_url(channel);
+            let geometry = WindowGeometry::grid_tile(screen_w, screen_h, cols, rows, idx as u32);
+            match self.config.player_settings.launch_player_in_tile(&url, &channel.name, &geometry, idx > 0) {
+                Ok(child) => self.multiview_players.push(child),
+                Err(e) => errors.push(format!("{}: {}", channel.name, e)),
+            }
+        }
+        
+        self.multiview_error = if errors.is_empty() {
+            None
+        } else {
+            log::error!("[MultiView] Failed to launch: {}", errors.join(", "));
+            Some(format!("Failed to launch {}", errors.join(", ")))
+        };
+        self.multiview_selecting = false;
+    }
+    
+    /// Closes every player window of the multi-view grid.
+    fn close_multiview(&mut self) {
+        for mut child in self.multiview_players.drain(..) {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+    
+    /// Renders the subtle library status row under a section title:
+    /// where the data came from and how old it is, a refreshing indicator
+    /// while a background fetch runs, and the "Refresh library" button.
+    fn render_library_status(&mut self, ui: &mut egui::Ui, theme: &Theme) {
+        if !matches!(self.current_content, ContentType::LiveTV | ContentType::Series | ContentType::Movies) {
+            return;
+        }
+        
+        if let Some(reason) = &self.playlist_fallback {
+            ui.label(egui::RichText::new("⚠ Playlist mode: the Xtream API isn't responding, so only live channels from the M3U playlist are available. Series, movies and catch-up need the API.")
+                .size(12.0)
+                .color(theme.warning_color))
+                .on_hover_text(reason);
+        }
+        
+        let mut retry_connection = false;
+        if let Some(reason) = &self.offline {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("⚠ Offline — cached data. You can browse and manage favorites; playback needs the server.")
+                    .size(12.0)
+                    .color(theme.warning_color))
+                    .on_hover_text(reason);
+                retry_connection = ui.small_button("Retry now").clicked();
+            });
+        } else if self.back_online {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("✓ Back online")
+                    .size(12.0)
+                    .color(theme.success_color));
+                if ui.small_button("⟳ Refresh library").clicked() {
+                    retry_connection = true;
+                }
+                if ui.small_button("✕").on_hover_text("Dismiss").clicked() {
+                    self.back_online = false;
+                }
+            });
+        }
+        if retry_connection {
+            self.refresh_library();
+        }
+        
+        let status = self.library_status.get(&self.current_content).copied();
+        let loading = self.library_loading(self.current_content);
+        let mut refresh = false;
+        
+        ui.horizontal(|ui| {
+            let age_text = status.map(|(fetched_at, source)| {
+                let age = (chrono::Utc::now().timestamp() as u64).saturating_sub(fetched_at);
+                let age = crate::models::content_cache::format_age(age);
+                match source {
+                    LibrarySource::Server => format!("Updated {}", age),
+                    LibrarySource::Cache | LibrarySource::StaleCache => format!("Loaded from cache ({})", age),
+                }
+            });
+            
+            if loading {
+                ui.add(egui::Spinner::new().size(12.0).color(theme.text_muted));
+                let text = match age_text {
+                    Some(age) => format!("Refreshing… · {}", age),
+                    None => "Fetching…".to_string(),
+                };
+                ui.label(egui::RichText::new(text).size(12.0).color(theme.text_muted));
+            } else if let Some(age) = age_text {
+                ui.label(egui::RichText::new(age).size(12.0).color(theme.text_muted));
+            }
+            
+            if let Some(LoadState::Error(err)) = self.library_state.get(&self.current_content) {
+                if status.is_some() {
+                    ui.label(egui::RichText::new("⚠ Couldn't refresh")
+                        .size(12.0)
+                        .color(theme.warning_color))
+                        .on_hover_text(err.as_str());
+                }
+            }
+            
+            let button = egui::Button::new(
+                egui::RichText::new("⟳ Refresh").size(12.0).color(theme.text_secondary),
+            )
+            .fill(egui::Color32::TRANSPARENT);
+            if ui.add_enabled(!loading && !self.connecting, button)
+                .on_hover_text(format!("Fetch {} from the server, skipping the cache", self.current_content.title()))
+                .clicked()
+            {
+                refresh = true;
+            }
+        });
+        
+        if refresh {
+            self.refresh_section(self.current_content);
+        }
+        
+        self.render_category_paging(ui, theme);
+    }
+    
+    /// Whether a background fetch for `content_type` is in progress.
+    fn library_loading(&self, content_type: ContentType) -> bool {
+        self.library_state.get(&content_type) == Some(&LoadState::Loading)
+    }
+    
+    /// Error panel shown in place of an empty grid when its fetch failed.
+    /// Returns true when Retry was clicked.
+    fn render_load_error(ui: &mut egui::Ui, theme: &Theme, what: &str, err: &str) -> bool {
+        let mut retry = false;
+        ui.vertical_centered(|ui| {
+            ui.add_space(40.0);
+            ui.label(egui::RichText::new(format!("⚠ Couldn't load {}", what))
+                .size(18.0)
+                .color(theme.error_color));
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new(err).size(13.0).color(theme.text_secondary));
+            ui.add_space(12.0);
+            retry = ui.button("🔄 Retry").clicked();
+        });
+        retry
+    }
+    
+    /// Per-category catalog mode: says what is loaded and offers "Load all".
+    fn render_category_paging(&mut self, ui: &mut egui::Ui, theme: &Theme) {
+        let paging = match self.current_content {
+            ContentType::Movies => &self.movie_paging,
+            ContentType::Series => &self.series_paging,
+            _ => return,
+        };
+        if paging.complete || self.library_loading(self.current_content) {
+            return;
+        }
+        
+        let text = if !paging.loading.is_empty() {
+            "Loading category…"
+        } else if self.selected_categories.is_empty() {
+            "Pick a category to browse, or load the whole catalog"
+        } else if !self.search_query.is_empty() {
+            "Search only covers the categories loaded so far"
+        } else {
+            "Only the selected categories are loaded"
+        };
+        let loading = !paging.loading.is_empty();
+        let mut load_all = false;
+        ui.horizontal(|ui| {
+            if loading {
+                ui.add(egui::Spinner::new().size(12.0).color(theme.text_muted));
+            }
+            ui.label(egui::RichText::new(text).size(12.0).color(theme.text_muted));
+            if ui.small_button("Load all").on_hover_text("Fetch the whole catalog so search covers everything").clicked() {
+                load_all = true;
+            }
+        });
+        if load_all {
+            self.load_whole_catalog();
+        }
+    }
+    
+    /// Renders the multi-view toolbar above the channel grid.
+    fn render_multiview_bar(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
+        // Forget players the user already closed
+        self.multiview_players.retain_mut(|c| matches!(c.try_wait(), Ok(None)));
+        
+        let mut launch = false;
+        let mut close_all = false;
+        let mut remove: Option<Channel> = None;
+        
+        ui.horizontal_wrapped(|ui| {
+            let toggle = egui::Button::new(
+                egui::RichText::new("▦ Multi-view")
+                    .size(13.0)
+                    .color(if self.multiview_selecting { egui::Color32::WHITE } else { theme.text_secondary }),
+            )
+            .fill(if self.multiview_selecting { theme.accent_blue } else { theme.inactive_bg() })
+            .rounding(egui::Rounding::same(4.0));
+            
+            if ui.add(toggle)
+                .on_hover_text(format!("Pick up to {} channels to watch side by side", MULTIVIEW_MAX))
+                .clicked()
+            {
+                self.multiview_selecting = !self.multiview_selecting;
+            }
+            
+            if self.multiview_selecting {
+                ui.label(egui::RichText::new(format!(
+                    "Click channels to add them ({}/{})",
+                    self.multiview_channels.len(),
+                    MULTIVIEW_MAX,
+                ))
+                .size(12.0)
+                .color(theme.text_muted));
+            }
+            
+            for channel in &self.multiview_channels {
+                if ui.small_button(format!("{} ✕", channel.name)).clicked() {
+                    remove = Some(channel.clone());
+                }
+            }
+            
+            if !self.multiview_channels.is_empty() {
+                let launch_btn = egui::Button::new(
+                    egui::RichText::new("▶ Launch grid").size(13.0).color(egui::Color32::WHITE),
+                )
+                .fill(theme.accent_blue)
+                .rounding(egui::Rounding::same(4.0));
+                if ui.add(launch_btn).clicked() {
+                    launch = true;
+                }
+            }
+            
+            if !self.multiview_players.is_empty()
+                && ui.button(format!("✕ Close all ({})", self.multiview_players.len())).clicked()
+            {
+                close_all = true;
+            }
+        });
+        
+        if let Some(err) = &self.multiview_error {
+            ui.label(egui::RichText::new(err).size(12.0).color(theme.error_color));
+        }
+        
+        if let Some(channel) = remove {
+            self.toggle_multiview_channel(&channel);
+        }
+        if launch {
+            self.launch_multiview(ctx);
+        }
+        if close_all {
+            self.close_multiview();
+        }
+    }
+    
+    /// "Check channels" button: probes the streams on the current page that
+    /// haven't been checked yet this session.
+    fn render_channel_check(&mut self, ui: &mut egui::Ui, theme: &Theme) {
+        let mut start = false;
+        let mut hide_offline = false;
+        
+        ui.horizontal(|ui| {
+            if let Some((done, total)) = self.channel_probe_progress {
+                ui.spinner();
+                ui.label(egui::RichText::new(format!("Checking channels… {}/{}", done, total))
+                    .size(12.0)
+                    .color(theme.text_muted));
+            } else {
+                let check_btn = egui::Button::new(
+                    egui::RichText::new("📡 Check channels").size(13.0).color(theme.text_secondary),
+                )
+                .fill(theme.inactive_bg())
+                .rounding(egui::Rounding::same(4.0));
+                
+                if ui.add(check_btn)
+                    .on_hover_text("Test which channels on this page are reachable")
+                    .clicked()
+                {
+                    start = true;
+                }
+                
+                let offline = self.offline_channels().len();
+                if offline > 0 {
+                    ui.label(egui::RichText::new(format!("{} offline", offline))
+                        .size(12.0)
+                        .color(theme.text_muted));
+                    if ui.small_button("🚫 Hide offline")
+                        .on_hover_text("Hide the channels found offline; unhide them in App Settings")
+                        .clicked()
+                    {
+                        hide_offline = true;
+                    }
+                }
+            }
+        });
+        
+        if start {
+            self.check_channels();
+        }
+        if hide_offline {
+            let offline = self.offline_channels();
+            self.hide_channels(&offline);
+        }
+    }
+    
+    /// Listed channels the probe found offline and that aren't hidden yet.
+    fn offline_channels(&self) -> Vec<Channel> {
+        self.all_channels.iter()
+            .filter(|ch| self.channel_online.get(&ch.stream_id) == Some(&false))
+            .filter(|ch| !self.config.hidden_channels.contains_key(&ch.stream_id))
+            .cloned()
+            .collect()
+    }
+    
+    /// Hides `channels` from the channel lists until unhidden in settings.
+    fn hide_channels(&mut self, channels: &[Channel]) {
+        if channels.is_empty() {
+            return;
+        }
+        for channel in channels {
+            self.config.hidden_channels.insert(channel.stream_id.clone(), channel.name.clone());
+        }
+        self.save_config();
+        self.filter_content();
+        match channels {
+            [channel] => self.toasts.info(format!("Hid {}", channel.name)),
+            _ => self.toasts.info(format!("Hid {} channels", channels.len())),
+        }
+    }
+    
+    /// Probes the unchecked channels on the current page in the background.
+    fn check_channels(&mut self) {
+        let targets: Vec<(String, String)> = self.filtered_channels.iter()
+            .skip(self.current_page * self.page_size)
+            .take(self.page_size)
+            .filter(|ch| !self.channel_online.contains_key(&ch.stream_id))
+            .map(|ch| (ch.stream_id.clone(), self.live_stream_url(ch)))
+            .collect();
+        
+        if targets.is_empty() {
+            return;
+        }
+        
+        self.channel_probe_progress = Some((0, targets.len()));
+        let tx = self.tx.as_ref().unwrap().clone();
+        let playback_active = self.playback_active.clone();
+        
+        thread::spawn(move || {
+            let playing = || playback_active.load(std::sync::atomic::Ordering::Relaxed);
+            crate::api::stream_probe::probe_streams(targets, playing, |stream_id, online| {
+                let _ = tx.send(AppMessage::ChannelProbed(stream_id, online));
+            });
+            let _ = tx.send(AppMessage::ChannelProbeFinished);
+        });
+    }
+    
+    /// Starts recording a live channel, or stops it if already recording.
+    fn toggle_recording(&mut self, channel: &Channel) {
+        if let Some(idx) = self.recordings.iter().position(|r| r.stream_id == channel.stream_id) {
+            self.recordings.remove(idx).stop();
+            self.toasts.info(format!("Stopped recording {}", channel.name));
+            return;
+        }
+        if self.blocked_offline() {
+            return;
+        }
+        
+        let url = self.live_stream_url(channel);
+        let dir = self.config.recordings_path();
+        
+        match crate::recording::Recording::start(&url, &channel.stream_id, &channel.name, &dir) {
+            Ok(recording) => {
+                self.toasts.success(format!("Recording {}", channel.name));
+                self.recordings.push(recording);
+            }
+            Err(err) => {
+                log::error!("[Recording] {}", err);
+                self.toasts.error(err);
+            }
+        }
+    }
+    
+    /// Drops recordings whose ffmpeg process has exited on its own.
+    fn poll_recordings(&mut self) {
+        let mut ended = Vec::new();
+        self.recordings.retain_mut(|r| match r.exit_status() {
+            Some(status) => {
+                ended.push(format!("Recording of {} stopped ({})", r.channel_name, status));
+                false
+            }
+            None => true,
+        });
+        
+        for msg in ended {
+            log::warn!("[Recording] {}", msg);
+            self.toasts.warn(msg);
+        }
+    }
+    
+    /// Plays a movie using the configured media player.
+    fn play_movie(&mut self, stream_id: i64, name: &str, container_extension: &str, thumbnail: Option<String>) {
+        if self.blocked_offline() {
+            return;
+        }
+        let client = XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        );
+        let url = client.get_stream_url(&stream_id.to_string(), container_extension);
+        
+        if self.wants_track_selection() {
+            let playback = PendingVod::Movie { stream_id, name: name.to_string(), thumbnail };
+            self.begin_track_selection(playback, url, name.to_string());
+            return;
+        }
+        self.launch_movie(stream_id, name, &url, thumbnail, None);
+    }
+    
+    /// Adds the movie to watch history and launches the player, resuming
+    /// where it was left off.
+    fn launch_movie(&mut self, stream_id: i64, name: &str, url: &str, thumbnail: Option<String>, tracks: Option<track_picker::TrackChoice>) {
+        let retry = (name.to_string(), url.to_string(), thumbnail.clone());
+        if !self.stream_ready(url, move |app| app.launch_movie(stream_id, &retry.0, &retry.1, retry.2, tracks)) {
+            return;
+        }
+        let content_id = stream_id.to_string();
+        let previous = self.watch_history.get_progress(&content_id).cloned();
+        let resume_at = previous.as_ref().and_then(|p| p.resume_position());
+        
+        // Add to watch history
+        let progress = crate::models::watch_history::WatchProgress {
+            content_id,
+            content_name: name.to_string(),
+            content_type: "movie".to_string(),
+            position_seconds: resume_at.unwrap_or(0.0),
+            duration_seconds: previous.map_or(0.0, |p| p.duration_seconds),
+            last_watched: chrono::Utc::now().timestamp(),
+            thumbnail,
+            season: None,
+            episode: None,
+        };
+        self.watch_history.update_progress(progress);
+        
+        // Launch the configured player (not live stream)
+        let settings = self.player_settings_for(tracks);
+        if self.play_embedded(&settings, url, name, resume_at, Some(stream_id.to_string())) {
+            return;
+        }
+        self.launch_external(settings, name, Some(stream_id.to_string()), |s, ipc| match resume_at {
+            Some(seconds) => s.launch_player_from(url, name, seconds, ipc),
+            None => s.launch_player(url, name, false, ipc),
+        });
+    }
+    
+    /// Plays an episode and adds it to watch history.
+    fn play_episode(&mut self, action: &episode_dialog::EpisodeAction, tracks: Option<track_picker::TrackChoice>) {
+        let episode_dialog::EpisodeAction::PlayEpisode { series_name, season, episode, title, .. } = action else {
+            return;
+        };
+        if self.blocked_offline() {
+            return;
+        }
+        let url = self.episode_url(action).unwrap_or_default();
+        let window_title = format!("S{}E{}: {}", season, episode, title);
+        
+        let resume_at = self.record_episode_watch(series_name, *season, *episode);
+        
+        // Launch the configured player (not live stream)
+        let settings = self.player_settings_for(tracks);
+        let history_key = format!("{}_{}_{}", series_name, season, episode);
+        if self.play_embedded(&settings, &url, &window_title, resume_at, Some(history_key.clone())) {
+            self.playing_episode = None;
+            return;
+        }
+        let launched = self.launch_external(settings, &window_title, Some(history_key), |s, ipc| match resume_at {
+            Some(seconds) => s.launch_player_from(&url, &window_title, seconds, ipc),
+            None => s.launch_player(&url, &window_title, false, ipc),
+        });
+        if launched {
+            self.playing_episode = Some(PlayingEpisode {
+                started_at: std::time::Instant::now(),
+                upcoming: Vec::new(),
+            });
+        }
+    }
+    
+    /// Adds an episode to the watch history. Returns the position to resume
+    /// from if it was left part-way through.
+    fn record_episode_watch(&mut self, series_name: &str, season: i32, episode: i32) -> Option<f64> {
+        let content_id = format!("{}_{}_{}",  series_name, season, episode);
+        let previous = self.watch_history.get_progress(&content_id).cloned();
+        let resume_at = previous.as_ref().and_then(|p| p.resume_position());
+        let progress = crate::models::watch_history::WatchProgress {
+            content_id,
+            content_name: series_name.to_string(),
+            content_type: "series".to_string(),
+            position_seconds: resume_at.unwrap_or(0.0),
+            duration_seconds: previous.map_or(0.0, |p| p.duration_seconds),
+            last_watched: chrono::Utc::now().timestamp(),
+            thumbnail: None, // We don't have episode thumbnails readily available
+            season: Some(season),
+            episode: Some(episode),
+        };
+        self.watch_history.update_progress(progress);
+        resume_at
+    }
+    
+    /// Plays `queue` (an episode and the rest of its season) as one playlist.
+    /// Players without playlist support get the first episode, with the rest
+    /// offered through the usual "Play next" prompt.
+    fn play_season(&mut self, mut queue: Vec<episode_dialog::EpisodeAction>, upcoming: Vec<episode_dialog::EpisodeAction>) {
+        if queue.is_empty() {
+            return;
+        }
+        if queue.len() == 1 || !self.config.player_settings.supports_playlists() {
+            let first = queue.remove(0);
+            queue.extend(upcoming);
+            self.start_episode(first, queue);
+            return;
+        }
+        
+        let client = XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        );
+        let mut urls = Vec::with_capacity(queue.len());
+        let mut first_episode = None;
+        let mut last_episode = 0;
+        for action in &queue {
+            if let episode_dialog::EpisodeAction::PlayEpisode { episode_id, series_name, season, episode, container, .. } = action {
+                urls.push(client.get_episode_url(episode_id, container));
+                first_episode.get_or_insert((series_name.clone(), *season, *episode));
+                last_episode = *episode;
+            }
+        }
+        let Some((series_name, season, episode)) = first_episode else {
+            return;
+        };
+        
+        // Only the first episode is known to start; the player takes it from there
+        self.record_episode_watch(&series_name, season, episode);
+        self.next_episode_prompt = None;
+        
+        let window_title = format!("{} S{} E{}-E{}", series_name, season, episode, last_episode);
+        let settings = self.config.player_settings.clone();
+        // The position MPV reports is per file, so a playlist isn't tracked
+        if self.launch_external(settings, &window_title, None, |s, ipc| s.launch_playlist(&urls, &window_title, ipc)) {
+            self.playing_episode = Some(PlayingEpisode {
+                started_at: std::time::Instant::now(),
+                upcoming,
+            });
+        }
+    }
+    
+    /// Plays an episode from the dialog or the "Play next" prompt and
+    /// remembers the episodes that follow it.
+    fn start_episode(&mut self, action: episode_dialog::EpisodeAction, upcoming: Vec<episode_dialog::EpisodeAction>) {
+        let episode_dialog::EpisodeAction::PlayEpisode { series_name, season, episode, title, .. } = &action else {
+            return;
+        };
+        if self.wants_track_selection() {
+            let url = self.episode_url(&action).unwrap_or_default();
+            let title = format!("{} S{}E{}: {}", series_name, season, episode, title);
+            self.begin_track_selection(PendingVod::Episode { action, upcoming }, url, title);
+            return;
+        }
+        self.launch_episode(action, upcoming, None);
+    }
+    
+    /// Plays an episode with the given tracks and remembers what follows it.
+    fn launch_episode(&mut self, action: episode_dialog::EpisodeAction, upcoming: Vec<episode_dialog::EpisodeAction>, tracks: Option<track_picker::TrackChoice>) {
+        self.next_episode_prompt = None;
+        let url = self.episode_url(&action).unwrap_or_default();
+        let retry = (action.clone(), upcoming.clone());
+        if !self.stream_ready(&url, move |app| app.launch_episode(retry.0, retry.1, tracks)) {
+            return;
+        }
+        self.play_episode(&action, tracks);
+        if let Some(playing) = &mut self.playing_episode {
+            playing.upcoming = upcoming;
+        }
+    }
+    
+    /// Stream URL of an episode action.
+    fn episode_url(&self, action: &episode_dialog::EpisodeAction) -> Option<String> {
+        let episode_dialog::EpisodeAction::PlayEpisode { episode_id, container, .. } = action else {
+            return None;
+        };
+        let client = XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        );
+        Some(client.get_episode_url(episode_id, container))
+    }
+    
+    // ═══════════════════════════════════════════════════════════════════
+    // Track selection
+    // ═══════════════════════════════════════════════════════════════════
+    
+    /// Whether VOD playback should first offer the stream's real tracks.
+    fn wants_track_selection(&self) -> bool {
+        self.config.player_settings.choose_tracks_before_playback
+            && crate::track_probe::ffprobe_executable().is_some()
+    }
+    
+    /// Probes `url` in the background; playback continues once the tracks
+    /// are picked, or straight away if there is nothing to pick.
+    fn begin_track_selection(&mut self, playback: PendingVod, url: String, title: String) {
+        let (tx, rx) = channel();
+        let probe_url = url.clone();
+        thread::spawn(move || {
+            let _ = tx.send(crate::track_probe::probe(&probe_url));
+        });
+        
+        let settings = &self.config.player_settings;
+        let choice = track_picker::TrackChoice {
+            audio: settings.audio_track.max(0),
+            subtitle: settings.subtitles_enabled.then_some(settings.subtitle_track.max(0)),
+        };
+        self.track_selection = Some(TrackSelection { playback, url, title, rx: Some(rx), tracks: None, choice });
+    }
+    
+    /// Picks up the probe result. Streams with a single audio track and no
+    /// subtitles start right away, as do streams that couldn't be probed.
+    fn poll_track_selection(&mut self) {
+        let Some(selection) = &mut self.track_selection else {
+            return;
+        };
+        let Some(rx) = &selection.rx else {
+            return;
+        };
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(std::sync::mpsc::TryRecvError::Empty) => return,
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => Err("probe thread stopped".to_string()),
+        };
+        selection.rx = None;
+        
+        match result {
+            Ok(tracks) if tracks.has_choices() => {
+                // Fall back to the first track when the saved one doesn't exist here
+                if !tracks.audio.iter().any(|t| t.index == selection.choice.audio) {
+                    selection.choice.audio = 0;
+                }
+                if let Some(index) = selection.choice.subtitle {
+                    if !tracks.subtitles.iter().any(|t| t.index == index) {
+                        selection.choice.subtitle = tracks.subtitles.first().map(|t| t.index);
+                    }
+                }
+                selection.tracks = Some(tracks);
+            }
+            result => {
+                if let Err(e) = result {
+                    log::warn!("[Tracks] Could not probe stream, using player defaults: {}", e);
+                }
+                if let Some(selection) = self.track_selection.take() {
+                    self.finish_track_selection(selection, None);
+                }
+            }
+        }
+    }
+    
+    /// Starts the pending movie or episode.
+    fn finish_track_selection(&mut self, selection: TrackSelection, tracks: Option<track_picker::TrackChoice>) {
+        match selection.playback {
+            PendingVod::Movie { stream_id, name, thumbnail } => {
+                self.launch_movie(stream_id, &name, &selection.url, thumbnail, tracks);
+            }
+            PendingVod::Episode { action, upcoming } => {
+                self.launch_episode(action, upcoming, tracks);
+            }
+        }
+    }
+    
+    /// Player settings with the picked tracks applied, if any.
+    fn player_settings_for(&self, tracks: Option<track_picker::TrackChoice>) -> PlayerSettings {
+        let mut settings = self.config.player_settings.clone();
+        if let Some(tracks) = tracks {
+            // An explicit track beats the language preferences
+            settings.audio_track = tracks.audio;
+            settings.preferred_audio_language.clear();
+            settings.preferred_subtitle_language.clear();
+            settings.subtitles_enabled = tracks.subtitle.is_some();
+            settings.subtitle_track = tracks.subtitle.unwrap_or(-1);
+        }
+        settings
+    }
+    
+    /// Renders the "Select tracks" sheet while a selection is pending.
+    fn render_track_selection(&mut self, ctx: &egui::Context) {
+        let picked = self.track_selection.as_mut().and_then(|selection| {
+            TrackPicker::show(ctx, &selection.title, selection.tracks.as_ref(), &mut selection.choice)
+        });
+        let Some(action) = picked else {
+            return;
+        };
+        if let Some(selection) = self.track_selection.take() {
+            match action {
+                track_picker::TrackPickerAction::Play => {
+                    let choice = selection.choice;
+                    self.finish_track_selection(selection, Some(choice));
+                }
+                track_picker::TrackPickerAction::Skip => self.finish_track_selection(selection, None),
+                track_picker::TrackPickerAction::Cancel => {}
+            }
+        }
+    }
+    
+    /// Opens the catch-up browser for a channel and loads its full guide.
+    fn open_catchup(&mut self, channel: Channel) {
+        self.epg_cache.request_archive(&channel.stream_id);
+        self.catchup = Some(catchup_dialog::CatchupState::new(channel));
+    }
+    
+    /// Renders the catch-up browser while it is open.
+    fn render_catchup(&mut self, ctx: &egui::Context) {
+        let Some(state) = &mut self.catchup else {
+            return;
+        };
+        let programs = self.epg_cache.get_archive(&state.channel.stream_id);
+        match CatchupDialog::show(ctx, state, programs.as_deref()) {
+            Some(CatchupAction::Play(program)) => {
+                let channel = state.channel.clone();
+                self.play_catchup(&channel, &program);
+            }
+            Some(CatchupAction::Close) => self.catchup = None,
+            None => {}
+        }
+    }
+    
+    /// Replays a past programme through the provider's timeshift URL.
+    fn play_catchup(&mut self, channel: &Channel, program: &EpgProgram) {
+        if self.blocked_offline() {
+            return;
+        }
+        let client = XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        );
+        let start = program.start_timestamp_unix();
+        let duration = program.end_timestamp_unix() - start;
+        let url = client.get_catchup_url(
+            &channel.stream_id,
+            start,
+            (duration + 59) / 60,
+            self.config.catchup_url_format,
+        );
+        let title = format!("{} - {}", channel.name, program.title);
+        
+        // Keyed by channel and programme start so a replay can be resumed
+        // from Continue Watching
+        let content_id = crate::models::watch_history::WatchProgress::catchup_id(&channel.stream_id, start);
+        let resume_at = self.watch_history.get_progress(&content_id).and_then(|p| p.resume_position());
+        self.watch_history.update_progress(crate::models::watch_history::WatchProgress {
+            content_id: content_id.clone(),
+            content_name: title.clone(),
+            content_type: "catchup".to_string(),
+            position_seconds: resume_at.unwrap_or(0.0),
+            duration_seconds: duration.max(0) as f64,
+            last_watched: chrono::Utc::now().timestamp(),
+            thumbnail: Some(channel.stream_icon.clone()).filter(|icon| !icon.is_empty()),
+            season: None,
+            episode: None,
+        });
+        
+        self.launch_catchup(url, &title, resume_at, content_id);
+    }
+    
+    /// Resumes a catch-up replay from Continue Watching.
+    fn resume_catchup(&mut self, content_id: &str) {
+        if self.blocked_offline() {
+            return;
+        }
+        let Some(progress) = self.watch_history.get_progress(content_id).cloned() else {
+            return;
+        };
+        let Some((stream_id, start)) = crate::models::watch_history::WatchProgress::parse_catchup_id(content_id) else {
+            return;
+        };
+        let client = XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        );
+        let minutes = (progress.duration_seconds as i64 + 59) / 60;
+        let url = client.get_catchup_url(stream_id, start, minutes, self.config.catchup_url_format);
+        
+        let mut entry = progress.clone();
+        entry.last_watched = chrono::Utc::now().timestamp();
+        self.watch_history.update_progress(entry);
+        
+        self.launch_catchup(url, &progress.content_name, progress.resume_position(), content_id.to_string());
+    }
+    
+    /// Starts a timeshift stream, `resume_at` seconds in when given.
+    /// `content_id` is its watch history entry.
+    fn launch_catchup(&mut self, url: String, title: &str, resume_at: Option<f64>, content_id: String) {
+        let retry = (url.clone(), title.to_string(), content_id.clone());
+        if !self.stream_ready(&url, move |app| app.launch_catchup(retry.0, &retry.1, resume_at, retry.2)) {
+            return;
+        }
+        self.current_stream_url = Some(url.clone());
+        let settings = self.config.player_settings.clone();
+        if self.play_embedded(&settings, &url, title, resume_at, Some(content_id.clone())) {
+            return;
+        }
+        self.launch_external(settings, title, Some(content_id), |s, ipc| match resume_at {
+            Some(seconds) => s.launch_player_from(&url, title, seconds, ipc),
+            None => s.launch_player(&url, title, false, ipc),
+        });
+    }
+    
+    /// Whether `url` can go to the player now. With the preflight setting
+    /// on, the stream is checked in the background first and `false`
+    /// returned; `start` runs once it has answered with video, otherwise
+    /// the reason is shown.
+    fn stream_ready(&mut self, url: &str, start: impl FnOnce(&mut IPTVPlayerApp) + 'static) -> bool {
+        if !self.config.stream_preflight || self.preflight_passed.take().is_some_and(|passed| passed == url) {
+            return true;
+        }
+        let Some(tx) = self.tx.clone() else {
+            return true;
+        };
+        self.pending_preflight = Some((url.to_string(), Box::new(start)));
+        self.toasts.info("Checking stream…");
+        let url = url.to_string();
+        thread::spawn(move || {
+            let result = crate::api::stream_probe::preflight(&url);
+            let _ = tx.send(AppMessage::StreamChecked(url, result));
+        });
+        false
+    }
+    
+    /// Starts the external player with `launch` and keeps the process for
+    /// the mini player. MPV gets an IPC socket for remote control, and
+    /// its position is saved to the `history_key` watch history entry.
+    /// `false` (with the error reported) if the player couldn't be started.
+    fn launch_external(
+        &mut self,
+        settings: PlayerSettings,
+        title: &str,
+        history_key: Option<String>,
+        launch: impl FnOnce(&PlayerSettings, Option<&str>) -> std::io::Result<std::process::Child>,
+    ) -> bool {
+        let ipc_socket = matches!(settings.player_type, PlayerType::MPV | PlayerType::Embedded)
+            .then(crate::mpv_ipc::new_socket_path);
+        // Whatever played before is no longer followed
+        self.playing_episode = None;
+        self.save_external_position();
+        match launch(&settings, ipc_socket.as_deref()) {
+            Ok(child) => {
+                let ipc = ipc_socket.map(crate::mpv_ipc::MpvIpc::new);
+                if let Some(ipc) = &ipc {
+                    let ipc = ipc.clone();
+                    let tx = self.tx.as_ref().unwrap().clone();
+                    let track_position = history_key.is_some();
+                    thread::spawn(move || follow_mpv(ipc, tx, track_position));
+                }
+                self.external_playback = Some(ExternalPlayback {
+                    child,
+                    title: title.to_string(),
+                    ipc,
+                    remote: None,
+                    adjusting_volume: false,
+                    history_key,
+                    channel: None,
+                    position: None,
+                    position_saved_at: None,
+                });
+                true
+            }
+            Err(e) => {
+                self.report_player_error(e);
+                false
+            }
+        }
+    }
+    
+    /// Writes the position last read from the external MPV to its watch
+    /// history entry.
+    fn save_external_position(&mut self) {
+        let Some(playback) = &mut self.external_playback else {
+            return;
+        };
+        let Some((position, duration)) = playback.position else {
+            return;
+        };
+        playback.position_saved_at = Some(std::time::Instant::now());
+        let entry = playback.history_key.as_ref()
+            .and_then(|key| self.watch_history.get_progress(key).cloned());
+        if let Some(mut entry) = entry {
+            entry.position_seconds = position;
+            if let Some(duration) = duration {
+                entry.duration_seconds = duration;
+            }
+            entry.last_watched = chrono::Utc::now().timestamp();
+            self.watch_history.update_progress(entry);
+        }
+    }
+    
+    /// Remote control bar for the running external player.
+    fn render_mini_player(&mut self, ctx: &egui::Context, theme: &Theme) {
+        let recording = self.recording_state(self.external_playback.as_ref().and_then(|p| p.channel.as_ref()));
+        let Some(playback) = &mut self.external_playback else {
+            return;
+        };
+        // Pause and volume come from `follow_mpv`; until MPV has answered
+        // only Stop is offered. Commands go out on a thread of their own,
+        // so a stuck player can't hold up the UI
+        let action = egui::TopBottomPanel::bottom("mini_player")
+            .frame(egui::Frame::none()
+                .fill(theme.panel_bg)
+                .stroke(egui::Stroke::new(1.0, theme.border_color))
+                .inner_margin(egui::Margin::symmetric(16.0, 8.0)))
+            .show(ctx, |ui| MiniPlayer::show(ui, theme, &playback.title, playback.remote, recording))
+            .inner;
+        
+        match action {
+            Some(MiniPlayerAction::TogglePause) => {
+                if let (Some(ipc), Some(remote)) = (&playback.ipc, &mut playback.remote) {
+                    remote.paused = !remote.paused;
+                    let (ipc, paused) = (ipc.clone(), remote.paused);
+                    thread::spawn(move || {
+                        if let Err(e) = ipc.set_paused(paused) {
+                            log::warn!("[Player] Pause failed: {}", e);
+                        }
+                    });
+                }
+            }
+            Some(MiniPlayerAction::AdjustVolume(volume)) => {
+                if let Some(remote) = &mut playback.remote {
+                    remote.volume = volume;
+                    playback.adjusting_volume = true;
+                }
+            }
+            Some(MiniPlayerAction::SetVolume(volume)) => {
+                playback.adjusting_volume = false;
+                if let (Some(ipc), Some(remote)) = (&playback.ipc, &mut playback.remote) {
+                    remote.volume = volume;
+                    let ipc = ipc.clone();
+                    thread::spawn(move || {
+                        if let Err(e) = ipc.set_volume(volume) {
+                            log::warn!("[Player] Volume change failed: {}", e);
+                        }
+                    });
+                }
+            }
+            Some(MiniPlayerAction::ToggleRecording) => {
+                if let Some(channel) = playback.channel.clone() {
+                    self.toggle_recording(&channel);
+                }
+            }
+            Some(MiniPlayerAction::Stop) => {
+                self.save_external_position();
+                if let Some(mut playback) = self.external_playback.take() {
+                    let _ = playback.child.kill();
+                    let _ = playback.child.wait();
+                }
+                // Stopped on purpose, so no "Play next"
+                self.playing_episode = None;
+            }
+            None => {}
+        }
+    }
+    
+    /// Drops the mini player once the external player exits, and offers
+    /// the next episode if one was playing.
+    fn poll_external_playback(&mut self) {
+        let exited = match &mut self.external_playback {
+            Some(playback) => !matches!(playback.child.try_wait(), Ok(None)),
+            None => true,
+        };
+        if !exited {
+            return;
+        }
+        self.save_external_position();
+        self.external_playback = None;
         
-        // Launch the configured player (live stream = true)
-        let _ = self.config.player_settings.launch_player(&url, &channel.name, true);
+        if let Some(playing) = self.playing_episode.take() {
+            if playing.started_at.elapsed() >= NEXT_EPISODE_MIN_PLAYTIME && !playing.upcoming.is_empty() {
+                self.next_episode_prompt = Some(playing.upcoming);
+            }
+        }
     }
     
-    /// Plays a movie using the configured media player.
-    fn play_movie(&mut self, stream_id: i64, name: &str, container_extension: &str, thumbnail: Option<String>) {
-        let client = XtreamClient::new(
-            self.server_url.clone(),
-            self.username.clone(),
-            self.password.clone(),
-        );
-        let url = client.get_stream_url(&stream_id.to_string(), container_extension);
+    /// Fires reminders whose programme is about to start: shows them and,
+    /// if enabled, tunes to the channel.
+    fn poll_reminders(&mut self, ctx: &egui::Context) {
+        let now = chrono::Utc::now().timestamp();
+        for reminder in self.reminders.take_due(now) {
+            log::info!("[Reminders] {} on {}", reminder.title, reminder.channel_name);
+            if self.config.reminder_auto_tune {
+                self.tune_to(&reminder.stream_id);
+            }
+            self.due_reminders.push(reminder);
+        }
         
-        // Add to watch history
-        let progress = crate::models::watch_history::WatchProgress {
-            content_id: stream_id.to_string(),
-            content_name: name.to_string(),
-            content_type: "movie".to_string(),
-            position_seconds: 0.0,
-            duration_seconds: 0.0, // We don't know duration yet
-            last_watched: chrono::Utc::now().timestamp(),
-            thumbnail,
-            season: None,
-            episode: None,
+        if let Some(secs) = self.reminders.secs_until_next(now) {
+            ctx.request_repaint_after(std::time::Duration::from_secs(secs.max(1) as u64));
+        }
+    }
+    
+    /// Syncs favorites and history with the sync target: on startup, a
+    /// moment after they change, and again later while the target is
+    /// unreachable.
+    fn poll_sync(&mut self, ctx: &egui::Context) {
+        self.follow_sync_account();
+        let Some(target) = self.config.sync_target.clone() else {
+            return;
         };
-        self.watch_history.update_progress(progress);
+        let favorites = crate::sync::favorite_keys(&self.config);
+        if self.sync.seen_revision != self.watch_history.revision() || self.sync.seen_favorites != favorites {
+            self.sync.seen_revision = self.watch_history.revision();
+            self.sync.seen_favorites = favorites;
+            self.sync.due.get_or_insert_with(|| std::time::Instant::now() + SYNC_DEBOUNCE);
+        }
+        let Some(due) = self.sync.due.filter(|_| self.sync.running.is_none()) else {
+            return;
+        };
+        let now = std::time::Instant::now();
+        if due > now {
+            ctx.request_repaint_after(due - now);
+            return;
+        }
         
-        // Launch the configured player (not live stream)
-        let _ = self.config.player_settings.launch_player(&url, name, false);
+        self.sync.due = None;
+        let local = crate::sync::Snapshot::capture(
+            &self.sync.base,
+            &self.sync.seen_favorites,
+            self.watch_history.entries(),
+            chrono::Utc::now().timestamp(),
+        );
+        self.sync.running = Some(local.clone());
+        let account = self.sync.account.clone();
+        let tx = self.tx.as_ref().unwrap().clone();
+        thread::spawn(move || {
+            let _ = tx.send(AppMessage::SyncFinished(crate::sync::sync(&target, account.as_deref(), &local)));
+        });
     }
     
-    /// Plays an episode and adds it to watch history.
-    fn play_episode(&mut self, episode_id: &str, series_name: &str, season: i32, episode: i32, title: &str, container: &str) {
-        let client = XtreamClient::new(
-            self.server_url.clone(),
-            self.username.clone(),
-            self.password.clone(),
+    /// Starts over with the sync state of the active profile after an
+    /// account switch. A sync still running for the old one is ignored.
+    fn follow_sync_account(&mut self) {
+        let account = crate::sync::account_key(&self.config);
+        if self.sync.account == account {
+            return;
+        }
+        self.sync = SyncState {
+            base: crate::sync::load_base(account.as_deref()),
+            account,
+            due: Some(std::time::Instant::now()),
+            ..Default::default()
+        };
+    }
+    
+    /// Takes over the result of a sync that started from `started_from`,
+    /// keeping changes made while it ran (those sync next time).
+    fn apply_sync(&mut self, started_from: &crate::sync::Snapshot, merged: crate::sync::Snapshot) {
+        let local = crate::sync::Snapshot::capture(
+            started_from,
+            &crate::sync::favorite_keys(&self.config),
+            self.watch_history.entries(),
+            chrono::Utc::now().timestamp(),
         );
-        let url = client.get_episode_url(episode_id, container);
-        let window_title = format!("S{}E{}: {}", season, episode, title);
+        let result = crate::sync::merge(&local, &merged);
+        if !result.same_as(&merged) {
+            self.sync.due = Some(std::time::Instant::now());
+        }
         
-        // Add to watch history
-        let progress = crate::models::watch_history::WatchProgress {
-            content_id: format!("{}_{}_{}",  series_name, season, episode),
-            content_name: series_name.to_string(),
-            content_type: "series".to_string(),
-            position_seconds: 0.0,
-            duration_seconds: 0.0,
-            last_watched: chrono::Utc::now().timestamp(),
-            thumbnail: None, // We don't have episode thumbnails readily available
-            season: Some(season),
-            episode: Some(episode),
-        };
-        self.watch_history.update_progress(progress);
+        let favorites = result.favorite_ids();
+        if favorites != crate::sync::favorite_keys(&self.config) {
+            crate::sync::set_favorite_keys(&mut self.config, &favorites);
+            self.save_config();
+            if self.favorites_only || self.current_content == ContentType::Favorites {
+                self.filter_content();
+            }
+        }
+        if !result.same_as(&local) {
+            self.watch_history.replace(result.history_entries());
+        }
+        if self.sync.failing {
+            self.toasts.info("Sync is working again");
+        }
         
-        // Launch the configured player (not live stream)
-        let _ = self.config.player_settings.launch_player(&url, &window_title, false);
+        self.sync.failing = false;
+        self.sync.seen_revision = self.watch_history.revision();
+        self.sync.seen_favorites = favorites;
+        crate::sync::save_base(self.sync.account.as_deref(), &result);
+        self.sync.base = result;
+    }
+    
+    /// Plays the channel with `stream_id`, if it's in the loaded list.
+    fn tune_to(&mut self, stream_id: &str) {
+        if let Some(channel) = self.all_channels.iter().find(|ch| ch.stream_id == stream_id).cloned() {
+            self.play_channel(&channel);
+        }
+    }
+    
+    /// Sets or clears the reminder for `program` on `channel`.
+    fn toggle_reminder(&mut self, channel: &Channel, program: &EpgProgram) {
+        self.reminders.toggle(crate::reminders::Reminder::new(channel, program));
+    }
+    
+    /// "Surprise me": plays a random channel or movie from the current
+    /// list, or opens a random series' episodes.
+    fn surprise_me(&mut self) {
+        match self.current_content {
+            // Live TV picks from all matching channels, Favorites only from favorites
+            ContentType::LiveTV | ContentType::Favorites => {
+                if let Some(channel) = random_index(self.filtered_channels.len())
+                    .map(|i| self.filtered_channels[i].clone())
+                {
+                    self.play_channel(&channel);
+                }
+            }
+            ContentType::Movies => {
+                let movie = random_index(self.filtered_movies.len()).map(|i| self.filtered_movies[i].clone());
+                if let Some(movie) = movie {
+                    self.play_movie(movie.stream_id, &movie.name, movie.container(), movie.stream_icon.clone());
+                }
+            }
+            ContentType::Series => {
+                if let Some(series_id) = random_index(self.filtered_series.len())
+                    .map(|i| self.filtered_series[i].series_id)
+                {
+                    self.episode_dialog_state = Some(episode_dialog::EpisodeDialogState::new(
+                        series_id,
+                        self.server_url.clone(),
+                        self.username.clone(),
+                        self.password.clone(),
+                    ));
+                }
+            }
+            _ => {}
+        }
     }
     
     /// Resumes playback from continue watching
@@ -586,14 +2821,12 @@ impl IPTVPlayerApp {
                 // For movies, content_id is the stream_id
                 if let Ok(stream_id) = content_id.parse::<i64>() {
                     // Find the movie in our cache to get details
-                    let movie_data = self.all_movies.iter().find(|m| {
-                        m.get("stream_id").and_then(|v| v.as_i64()) == Some(stream_id)
-                    }).map(|movie| {
+                    let movie_data = self.all_movies.iter().find(|m| m.stream_id == stream_id).map(|movie| {
                         (
                             stream_id,
-                            movie.get("name").and_then(|v| v.as_str()).unwrap_or("Unknown").to_string(),
-                            movie.get("container_extension").and_then(|v| v.as_str()).unwrap_or("mp4").to_string(),
-                            movie.get("stream_icon").and_then(|v| v.as_str()).map(|s| s.to_string())
+                            movie.name.clone(),
+                            movie.container().to_string(),
+                            movie.stream_icon.clone(),
                         )
                     });
                     
@@ -628,6 +2861,7 @@ impl IPTVPlayerApp {
                     }
                 }
             }
+            "catchup" => self.resume_catchup(content_id),
             _ => {}
         }
     }
@@ -638,12 +2872,214 @@ impl IPTVPlayerApp {
     
     /// Toggles favorite status for a channel.
     fn toggle_favorite(&mut self, stream_id: &str) {
-        if self.config.favorites.contains(stream_id) {
-            self.config.favorites.remove(stream_id);
+        let name = self.all_channels.iter()
+            .find(|ch| ch.stream_id == stream_id)
+            .map(|ch| ch.name.clone())
+            .unwrap_or_else(|| "Channel".to_string());
+        if let Some(pos) = self.config.favorites.iter().position(|id| id == stream_id) {
+            self.config.favorites.remove(pos);
+            self.toasts.info(format!("Removed {} from My List", name));
+        } else {
+            self.config.favorites.push(stream_id.to_string());
+            self.toasts.success(format!("Added {} to My List", name));
+        }
+        if let Err(e) = self.config.save() {
+            self.toasts.error(format!("Couldn't save favorites: {}", e));
+        }
+        if self.favorites_only {
+            self.filter_content();
+        }
+    }
+    
+    /// Toggles favorite status for a movie (stream ID) or series (series ID).
+    fn toggle_title_favorite(&mut self, section: ContentType, id: String, name: &str) {
+        let favorites = match section {
+            ContentType::Series => &mut self.config.favorite_series,
+            _ => &mut self.config.favorite_movies,
+        };
+        if let Some(pos) = favorites.iter().position(|f| *f == id) {
+            favorites.remove(pos);
+            self.toasts.info(format!("Removed {} from My List", name));
+        } else {
+            favorites.push(id);
+            self.toasts.success(format!("Added {} to My List", name));
+        }
+        self.favorites_changed();
+    }
+    
+    /// Adds every channel in the current view to My List, or removes them
+    /// all. Saves once for the whole batch.
+    fn set_view_favorites(&mut self, favorite: bool) {
+        let favorites = &mut self.config.favorites;
+        let mut changed = 0;
+        for ch in &self.filtered_channels {
+            match (favorite, favorites.iter().position(|id| *id == ch.stream_id)) {
+                (true, None) => favorites.push(ch.stream_id.clone()),
+                (false, Some(pos)) => {
+                    favorites.remove(pos);
+                }
+                _ => continue,
+            }
+            changed += 1;
+        }
+        if changed == 0 {
+            return;
+        }
+        if favorite {
+            self.toasts.success(format!("Added {} channels to My List", changed));
         } else {
-            self.config.favorites.insert(stream_id.to_string());
+            self.toasts.info(format!("Removed {} channels from My List", changed));
+        }
+        self.favorites_changed();
+    }
+    
+    /// Empties My List.
+    fn clear_favorites(&mut self) {
+        let count = self.favorites_count();
+        self.config.favorites.clear();
+        self.config.favorite_movies.clear();
+        self.config.favorite_series.clear();
+        self.toasts.info(format!("Removed {} favorites from My List", count));
+        self.favorites_changed();
+    }
+    
+    /// Moves a favorite in front of (or after) another one in My List.
+    fn move_favorite(&mut self, stream_id: &str, target: &str, after: bool) {
+        let favorites = &mut self.config.favorites;
+        let Some(from) = favorites.iter().position(|id| id == stream_id) else {
+            return;
+        };
+        let moved = favorites.remove(from);
+        let to = favorites.iter()
+            .position(|id| id == target)
+            .map_or(from, |pos| if after { pos + 1 } else { pos });
+        favorites.insert(to, moved);
+        
+        if let Err(e) = self.config.save() {
+            self.toasts.error(format!("Couldn't save favorites: {}", e));
+        }
+        let page = self.current_page;
+        self.filter_content();
+        self.current_page = page;
+    }
+    
+    /// Channels, movies and series in My List.
+    fn favorites_count(&self) -> usize {
+        self.config.favorites.len() + self.config.favorite_movies.len() + self.config.favorite_series.len()
+    }
+    
+    /// Saves a bulk favorites change and refreshes views that depend on it.
+    fn favorites_changed(&mut self) {
+        if let Err(e) = self.config.save() {
+            self.toasts.error(format!("Couldn't save favorites: {}", e));
+        }
+        if self.favorites_only || self.current_content == ContentType::Favorites {
+            self.filter_content();
+        }
+    }
+    
+    /// Bulk My List actions above the channel grid: favorite or unfavorite
+    /// the filtered channels, or clear the whole list from My List.
+    fn render_favorite_actions(&mut self, ui: &mut egui::Ui, theme: &Theme) {
+        enum Bulk { Add, Remove, Clear }
+        let mut bulk = None;
+        
+        let narrowed = !self.selected_categories.is_empty() || !self.search_query.is_empty();
+        let in_view = self.filtered_channels.len();
+        let favorites_in_view = self.filtered_channels.iter()
+            .filter(|ch| self.config.favorites.contains(&ch.stream_id))
+            .count();
+        let applies = match self.current_content {
+            ContentType::LiveTV => narrowed && in_view > 0,
+            ContentType::Favorites => self.favorites_count() > 0,
+            _ => false,
+        };
+        if !applies {
+            return;
+        }
+        
+        ui.horizontal(|ui| {
+            match self.current_content {
+                // Only for a category or search, not the whole catalog
+                ContentType::LiveTV if narrowed && in_view > 0 => {
+                    if favorites_in_view < in_view
+                        && ui.small_button(format!("★ Favorite all {}", in_view))
+                            .on_hover_text("Add every channel shown to My List")
+                            .clicked()
+                    {
+                        bulk = Some(Bulk::Add);
+                    }
+                    if favorites_in_view > 0
+                        && ui.small_button(format!("☆ Unfavorite {}", favorites_in_view))
+                            .on_hover_text("Remove the channels shown from My List")
+                            .clicked()
+                    {
+                        bulk = Some(Bulk::Remove);
+                    }
+                }
+                ContentType::Favorites if self.favorites_count() > 0 => {
+                    if self.confirm_clear_favorites {
+                        ui.label(egui::RichText::new(format!("Remove all {} favorites?", self.favorites_count()))
+                            .size(12.0)
+                            .color(theme.warning_color));
+                        if ui.small_button("Remove all").clicked() {
+                            bulk = Some(Bulk::Clear);
+                        }
+                        if ui.small_button("Cancel").clicked() {
+                            self.confirm_clear_favorites = false;
+                        }
+                    } else if ui.small_button("🗑 Clear all favorites").clicked() {
+                        self.confirm_clear_favorites = true;
+                    }
+                }
+                _ => {}
+            }
+        });
+        
+        match bulk {
+            Some(Bulk::Add) => self.set_view_favorites(true),
+            Some(Bulk::Remove) => self.set_view_favorites(false),
+            Some(Bulk::Clear) => {
+                self.confirm_clear_favorites = false;
+                self.clear_favorites();
+            }
+            None => {}
+        }
+    }
+    
+    /// Marks a movie as watched, or clears it from the watch history if it
+    /// already was.
+    fn toggle_movie_watched(&mut self, stream_id: i64, name: &str, thumbnail: Option<String>) {
+        let content_id = stream_id.to_string();
+        if self.watch_history.get_progress(&content_id).is_some_and(|p| p.is_nearly_finished()) {
+            self.watch_history.remove(&content_id);
+            self.toasts.info(format!("Marked {} as unwatched", name));
+            return;
+        }
+        
+        // A full position/duration pair reads as finished everywhere
+        self.watch_history.update_progress(crate::models::watch_history::WatchProgress {
+            content_id,
+            content_name: name.to_string(),
+            content_type: "movie".to_string(),
+            position_seconds: 1.0,
+            duration_seconds: 1.0,
+            last_watched: chrono::Utc::now().timestamp(),
+            thumbnail,
+            season: None,
+            episode: None,
+        });
+        self.toasts.success(format!("Marked {} as watched", name));
+    }
+    
+    /// Toggles a football team in the favorite teams list.
+    fn toggle_favorite_team(&mut self, team: &str) {
+        let team = crate::api::football::team_key(team);
+        if !self.config.favorite_teams.remove(&team) {
+            self.config.favorite_teams.insert(team);
         }
-        let _ = self.config.save();
+        self.save_config();
+        self.football_cache.set_favorite_teams(self.config.favorite_teams.iter().cloned().collect());
     }
     
     // ═══════════════════════════════════════════════════════════════════════
@@ -655,8 +3091,84 @@ impl IPTVPlayerApp {
         self.config.server_url = self.server_url.clone();
         self.config.username = self.username.clone();
         self.config.password = self.password.clone();
-        self.config.auto_login = true;
-        let _ = self.config.save();
+        self.config.playlist_url = self.playlist_url.clone();
+        self.config.store_active_profile(&self.account_name);
+        self.account_name = self.config.active_profile.clone().unwrap_or_default();
+        self.save_config();
+    }
+    
+    /// Switches to the saved account `name`, or to an empty form for a new
+    /// one with `None`. The current account's favorites are saved to its
+    /// profile first; when signed in, signs out and in with the new account.
+    fn switch_account(&mut self, name: Option<&str>) {
+        if self.config.active_profile.is_some() {
+            self.config.store_active_profile("");
+        }
+        match name {
+            Some(name) => {
+                if !self.config.activate_profile(name) {
+                    return;
+                }
+            }
+            None => self.config.deactivate_profile(),
+        }
+        self.server_url = self.config.server_url.clone();
+        self.username = self.config.username.clone();
+        self.password = self.config.password.clone();
+        self.playlist_url = self.config.playlist_url.clone();
+        self.account_name = self.config.active_profile.clone().unwrap_or_default();
+        self.save_config();
+        
+        let was_connected = self.connected;
+        if was_connected {
+            self.disconnect();
+        }
+        // Nothing from the previous account should show in this one
+        self.library_generation += 1;
+        self.error_message = None;
+        self.connection_test.clear();
+        self.playlist_fallback = None;
+        self.library_status.clear();
+        self.library_state.clear();
+        self.search_query.clear();
+        self.selected_categories.clear();
+        if was_connected && name.is_some() {
+            self.connect(false);
+        }
+    }
+    
+    /// Sender for library fetches, tagged with the current generation.
+    fn library_tx(&self) -> UiSender {
+        self.tx.as_ref().unwrap().for_library(self.library_generation)
+    }
+    
+    /// Writes the config to disk, logging a failure.
+    fn save_config(&self) {
+        if let Err(e) = self.config.save() {
+            log::error!("[Config] Couldn't save settings: {}", e);
+        }
+    }
+    
+    /// Closes the release notes and remembers this version as seen.
+    fn dismiss_whats_new(&mut self) {
+        self.whats_new = None;
+        self.config.last_seen_version = Some(env!("CARGO_PKG_VERSION").to_string());
+        self.save_config();
+    }
+    
+    /// Removes the saved credentials (and the account's profile) from the
+    /// config and the login form.
+    fn forget_credentials(&mut self) {
+        self.config.remove_active_profile();
+        self.server_url.clear();
+        self.username.clear();
+        self.password.clear();
+        self.playlist_url.clear();
+        self.account_name.clear();
+        match self.config.save_replacing_backup() {
+            Ok(()) => self.toasts.info("Saved credentials removed"),
+            Err(e) => self.toasts.error(format!("Couldn't update the config: {}", e)),
+        }
     }
     
     /// Returns whether we're in touch-friendly mode (Steam Deck or tablet).
@@ -665,83 +3177,453 @@ impl IPTVPlayerApp {
     }
 
     // ═══════════════════════════════════════════════════════════════════════
-    // Steam Deck / Gamepad Input
+    // Steam Deck / Gamepad Input
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Handles gamepad and keyboard input for Steam Deck Game Mode.
+    /// This enables navigation via controller (D-pad maps to arrow keys,
+    /// A button maps to Enter, B button maps to Escape).
+    fn handle_gamepad_input(&mut self, ctx: &egui::Context, is_touch_mode: bool) {
+        // Configure egui for better gamepad/keyboard navigation
+        let mut style = (*ctx.style()).clone();
+
+        // Enable keyboard navigation (Tab to move between widgets)
+        // This is crucial for Steam Deck controller support as Steam maps
+        // D-pad to arrow keys and face buttons to keyboard keys
+        style.interaction.selectable_labels = true;
+
+        // Larger focus rectangles for better visibility on Steam Deck
+        if is_touch_mode {
+            style.visuals.widgets.hovered.expansion = 4.0;
+            style.visuals.widgets.active.expansion = 2.0;
+            style.visuals.selection.stroke.width = 2.0;
+        }
+
+        ctx.set_style(style);
+
+        let typing = ctx.wants_keyboard_input();
+        let widget_focused = ctx.memory(|m| m.focused().is_some());
+
+        // Handle keyboard shortcuts for navigation
+        // Popups and card menus close themselves on Escape
+        let popup_open = ctx.memory(|m| m.any_popup_open()) || CardContextMenu::is_open(ctx);
+
+        let mut toggle_fullscreen = false;
+        ctx.input(|i| {
+            // Escape key (controller B) or the mouse's back button
+            let back = i.key_pressed(egui::Key::Escape)
+                || i.pointer.button_pressed(egui::PointerButton::Extra1);
+            if back && !popup_open {
+                self.go_back(is_touch_mode);
+            }
+
+            // Tab navigation between content types (LB/RB on controller)
+            // Steam typically maps LB/RB to Q/E or Page Up/Down
+            if i.key_pressed(egui::Key::PageDown) || (i.modifiers.ctrl && i.key_pressed(egui::Key::Tab)) {
+                self.cycle_content_type(true);
+            }
+            if i.key_pressed(egui::Key::PageUp) || (i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::Tab)) {
+                self.cycle_content_type(false);
+            }
+
+            // Quick menu toggle (Start button often maps to Enter or F10),
+            // Shift+F10 opens the focused card's context menu instead
+            if i.key_pressed(egui::Key::F10) {
+                if i.modifiers.shift {
+                    self.context_menu_requested = true;
+                } else {
+                    self.sidebar_visible = !self.sidebar_visible;
+                }
+            }
+
+            // Home key to scroll to top / reset view
+            if i.key_pressed(egui::Key::Home) {
+                self.current_page = 0;
+            }
+
+            if i.key_pressed(egui::Key::F11) {
+                toggle_fullscreen = true;
+            }
+
+            // Page navigation with arrow keys when not typing. Plain arrows are
+            // left to egui's focus navigation while a widget has focus.
+            if !typing && (i.modifiers.alt || !widget_focused) {
+                if i.key_pressed(egui::Key::ArrowLeft) {
+                    self.change_page(false);
+                }
+                if i.key_pressed(egui::Key::ArrowRight) {
+                    self.change_page(true);
+                }
+            }
+        });
+        // Viewport commands can't be sent from inside `ctx.input`
+        if toggle_fullscreen {
+            self.toggle_fullscreen(ctx);
+        }
+    }
+
+    /// Switches the app window in or out of fullscreen and remembers it
+    /// for the next launch.
+    fn toggle_fullscreen(&mut self, ctx: &egui::Context) {
+        self.fullscreen = !self.fullscreen;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Fullscreen(self.fullscreen));
+        // Game Mode is always fullscreen on launch
+        if self.is_steam_deck_mode {
+            return;
+        }
+        if self.fullscreen {
+            self.config.startup_window = StartupWindowMode::Fullscreen;
+        } else if self.config.startup_window == StartupWindowMode::Fullscreen {
+            self.config.startup_window = StartupWindowMode::Windowed;
+        }
+        self.save_config();
+    }
+
+    /// Handles single-key shortcuts (`/`, `1`-`8`, `f`, `r`, `?`).
+    /// Ignored while a text field has keyboard focus.
+    fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
+        if !self.connected || ctx.wants_keyboard_input() {
+            return;
+        }
+
+        let content_keys = [
+            (egui::Key::Num1, ContentType::LiveTV),
+            (egui::Key::Num2, ContentType::ContinueWatching),
+            (egui::Key::Num3, ContentType::Series),
+            (egui::Key::Num4, ContentType::Movies),
+            (egui::Key::Num5, ContentType::Favorites),
+            (egui::Key::Num6, ContentType::OnNow),
+            (egui::Key::Num7, ContentType::Discover),
+            (egui::Key::Num8, ContentType::FootballFixtures),
+            (egui::Key::Num9, ContentType::RecentlyAdded),
+        ];
+
+        let (focus_search, switch_to, toggle_favorite, refresh, toggle_help) = ctx.input(|i| {
+            let plain = i.modifiers.is_none();
+            let question_mark = i.key_pressed(egui::Key::Questionmark)
+                || i.events.iter().any(|e| matches!(e, egui::Event::Text(t) if t == "?"));
+            (
+                plain && i.key_pressed(egui::Key::Slash),
+                content_keys.iter()
+                    .find(|(key, _)| plain && i.key_pressed(*key))
+                    .map(|(_, content)| *content),
+                plain && i.key_pressed(egui::Key::F),
+                plain && i.key_pressed(egui::Key::R),
+                question_mark,
+            )
+        });
+
+        if focus_search {
+            ctx.memory_mut(|m| m.request_focus(egui::Id::new(top_nav::SEARCH_INPUT_ID)));
+        }
+        if let Some(content) = switch_to {
+            self.switch_content(content);
+        }
+        if toggle_favorite {
+            if let Some(stream_id) = self.focused_channel.clone() {
+                self.toggle_favorite(&stream_id);
+            }
+        }
+        if refresh {
+            self.refresh_current_view();
+        }
+        if toggle_help {
+            self.show_shortcuts_help = !self.show_shortcuts_help;
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Back Navigation
     // ═══════════════════════════════════════════════════════════════════════
 
-    /// Handles gamepad and keyboard input for Steam Deck Game Mode.
-    /// This enables navigation via controller (D-pad maps to arrow keys,
-    /// A button maps to Enter, B button maps to Escape).
-    fn handle_gamepad_input(&mut self, ctx: &egui::Context, is_touch_mode: bool) {
-        // Configure egui for better gamepad/keyboard navigation
-        let mut style = (*ctx.style()).clone();
+    /// Steps back once: closes the topmost dialog, else clears the search,
+    /// else the category filter, else returns to the previous section and
+    /// finally to Live TV.
+    fn go_back(&mut self, is_touch_mode: bool) {
+        if self.close_top_dialog(is_touch_mode) {
+            return;
+        }
+        if !self.search_query.is_empty() {
+            self.search_query.clear();
+            self.filter_content();
+        } else if !self.selected_categories.is_empty() {
+            self.selected_categories.clear();
+            self.filter_content();
+        } else if let Some(previous) = self.nav_history.pop() {
+            self.show_content(previous);
+        } else if self.current_content != self.home_content() {
+            self.show_content(self.home_content());
+        }
+    }
 
-        // Enable keyboard navigation (Tab to move between widgets)
-        // This is crucial for Steam Deck controller support as Steam maps
-        // D-pad to arrow keys and face buttons to keyboard keys
-        style.interaction.selectable_labels = true;
+    /// The configured landing view. The M3U playlist fallback has live
+    /// channels only, so VOD views land on Live TV there.
+    fn home_content(&self) -> ContentType {
+        let home = ContentType::from(self.config.default_content);
+        match home {
+            ContentType::Series | ContentType::Movies | ContentType::RecentlyAdded
+                if self.live_only() => ContentType::LiveTV,
+            _ => home,
+        }
+    }
 
-        // Larger focus rectangles for better visibility on Steam Deck
-        if is_touch_mode {
-            style.visuals.widgets.hovered.expansion = 4.0;
-            style.visuals.widgets.active.expansion = 2.0;
-            style.visuals.selection.stroke.width = 2.0;
+    /// Closes the dialog or overlay on top, if any.
+    fn close_top_dialog(&mut self, is_touch_mode: bool) -> bool {
+        if self.show_shortcuts_help {
+            self.show_shortcuts_help = false;
+        } else if self.show_on_screen_keyboard {
+            self.show_on_screen_keyboard = false;
+        } else if self.track_selection.is_some() {
+            self.track_selection = None;
+        } else if self.catchup.is_some() {
+            self.catchup = None;
+        } else if self.episode_dialog_state.is_some() {
+            self.episode_dialog_state = None;
+        } else if self.show_player_settings {
+            self.show_player_settings = false;
+            self.temp_player_settings = None;
+        } else if self.show_app_settings {
+            self.show_app_settings = false;
+            self.temp_config = None;
+        } else if self.show_epg_settings {
+            self.show_epg_settings = false;
+        } else if self.show_scraper_settings {
+            self.show_scraper_settings = false;
+        } else if self.show_recordings {
+            self.show_recordings = false;
+        } else if self.watch_stats.is_some() {
+            self.watch_stats = None;
+        } else if self.whats_new.is_some() {
+            self.dismiss_whats_new();
+        } else if self.multiview_selecting {
+            self.multiview_selecting = false;
+        } else if self.sidebar_visible && is_touch_mode {
+            self.sidebar_visible = false;
+        } else {
+            return false;
         }
+        true
+    }
 
-        ctx.set_style(style);
+    /// Whether the back button has anything to go back to (dialogs have
+    /// their own close buttons).
+    fn can_go_back(&self) -> bool {
+        !self.search_query.is_empty()
+            || !self.selected_categories.is_empty()
+            || !self.nav_history.is_empty()
+            || self.current_content != self.home_content()
+    }
 
-        // Handle keyboard shortcuts for navigation
-        ctx.input(|i| {
-            // Escape key - close dialogs or go back
-            if i.key_pressed(egui::Key::Escape) {
-                if self.episode_dialog_state.is_some() {
-                    self.episode_dialog_state = None;
-                } else if self.show_player_settings {
-                    self.show_player_settings = false;
-                    self.temp_player_settings = None;
-                } else if self.show_epg_settings {
-                    self.show_epg_settings = false;
-                } else if self.show_scraper_settings {
-                    self.show_scraper_settings = false;
-                } else if self.sidebar_visible && is_touch_mode {
-                    self.sidebar_visible = false;
-                }
+    /// Switches the visible content type, remembering the current one for
+    /// "back".
+    fn switch_content(&mut self, content_type: ContentType) {
+        if content_type != self.current_content {
+            if self.nav_history.len() >= NAV_HISTORY_LIMIT {
+                self.nav_history.remove(0);
             }
+            self.nav_history.push(self.current_content);
+        }
+        self.show_content(content_type);
+    }
 
-            // Tab navigation between content types (LB/RB on controller)
-            // Steam typically maps LB/RB to Q/E or Page Up/Down
-            if i.key_pressed(egui::Key::PageDown) || (i.modifiers.ctrl && i.key_pressed(egui::Key::Tab)) {
-                self.cycle_content_type(true);
+    /// Shows a content type, loading its data if needed.
+    fn show_content(&mut self, content_type: ContentType) {
+        if content_type != self.current_content {
+            self.favorites_only = false;
+        }
+        self.current_content = content_type;
+        self.selected_categories.clear();
+        match content_type {
+            _ if self.live_only() => {}
+            ContentType::Series if self.all_series.is_empty() && self.series_categories.is_empty() => {
+                self.load_series(false);
             }
-            if i.key_pressed(egui::Key::PageUp) || (i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::Tab)) {
-                self.cycle_content_type(false);
+            ContentType::Movies if self.all_movies.is_empty() && self.movie_categories.is_empty() => {
+                self.load_movies(false);
             }
-
-            // Quick menu toggle (Start button often maps to Enter or F10)
-            if i.key_pressed(egui::Key::F10) {
-                self.sidebar_visible = !self.sidebar_visible;
+            ContentType::RecentlyAdded => self.load_missing_vod(),
+            ContentType::Favorites if !(self.config.favorite_movies.is_empty() && self.config.favorite_series.is_empty()) => {
+                self.load_missing_vod();
             }
+            _ => {}
+        }
+        self.filter_content();
+    }
 
-            // Home key to scroll to top / reset view
-            if i.key_pressed(egui::Key::Home) {
-                self.current_page = 0;
+    /// Re-fetches the whole library (channels, series, movies), bypassing the cache.
+    fn refresh_library(&mut self) {
+        self.refreshing_library = true;
+        self.refresh_requested.insert(ContentType::LiveTV);
+        if !self.live_only() {
+            self.refresh_requested.extend([ContentType::Series, ContentType::Movies]);
+        }
+        self.epg_cache.clear();
+        self.connect(true);
+    }
+
+    /// Deletes the cached library and images and forgets the loaded
+    /// programme guide, with a toast of the space freed. What's on screen
+    /// stays until the next refresh.
+    fn clear_all_caches(&mut self) {
+        self.epg_cache.clear();
+        let library = crate::models::ContentCache::clear_all()
+            .map_err(|e| format!("Couldn't clear the library cache: {}", e));
+        let images = crate::ui::image_cache::clear_disk_cache();
+        match (library, images) {
+            (Ok(library), Ok(images)) => self.toasts.success(format!(
+                "Cleared {:.1} MB of cached data",
+                (library + images) as f64 / (1024.0 * 1024.0),
+            )),
+            (Err(e), _) | (_, Err(e)) => self.toasts.error(e),
+        }
+    }
+
+    /// Re-fetches one section of the library, bypassing the cache, with a
+    /// toast when it's done. The cached copy is only replaced once the new
+    /// data arrives, so a failed refresh still leaves it for offline use.
+    fn refresh_section(&mut self, content: ContentType) {
+        self.refresh_requested.insert(content);
+        match content {
+            ContentType::LiveTV => {
+                self.epg_cache.clear();
+                self.connect(true);
+            }
+            ContentType::Series => self.load_series(true),
+            ContentType::Movies => self.load_movies(true),
+            _ => {
+                self.refresh_requested.remove(&content);
             }
+        }
+    }
 
-            // Page navigation with arrow keys when not in text input (no widget has focus)
-            if !i.focused {
-                if i.key_pressed(egui::Key::ArrowLeft) && i.modifiers.alt {
-                    if self.current_page > 0 {
-                        self.current_page -= 1;
-                    }
-                }
-                if i.key_pressed(egui::Key::ArrowRight) && i.modifiers.alt {
-                    let total_pages = self.calculate_total_pages();
-                    if self.current_page < total_pages.saturating_sub(1) {
-                        self.current_page += 1;
+    /// Looks for a newer release in the background. `manual` checks report
+    /// every outcome; startup checks only a new release.
+    fn check_for_updates(&mut self, manual: bool) {
+        let tx = self.tx.as_ref().unwrap().clone();
+        thread::spawn(move || {
+            let _ = tx.send(AppMessage::UpdateChecked(crate::api::updates::check_for_update(), manual));
+        });
+    }
+    
+    /// Banner announcing a newer release, with its notes and a dismiss button.
+    fn render_update_banner(&mut self, ctx: &egui::Context, theme: &Theme) {
+        let Some(release) = &self.available_update else {
+            return;
+        };
+        let mut dismissed = false;
+        egui::TopBottomPanel::top("update_banner")
+            .frame(egui::Frame::none()
+                .fill(theme.card_elevated)
+                .inner_margin(egui::Margin::symmetric(16.0, 8.0)))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let title = release.name.as_deref().filter(|n| !n.trim().is_empty()).unwrap_or(&release.tag_name);
+                    let label = ui.label(egui::RichText::new(format!(
+                        "⬆ {} is available (you have {})", title, env!("CARGO_PKG_VERSION"),
+                    ))
+                    .size(13.0)
+                    .color(theme.text_primary));
+                    if let Some(notes) = release.body.as_deref().filter(|b| !b.trim().is_empty()) {
+                        label.on_hover_text(notes);
                     }
-                }
+                    ui.hyperlink_to("Release notes", &release.html_url);
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        dismissed = ui.small_button("✕").on_hover_text("Dismiss").clicked();
+                    });
+                });
+            });
+        if dismissed {
+            self.config.dismissed_update = self.available_update.take().map(|r| r.tag_name);
+            self.save_config();
+        }
+    }
+    
+    /// While offline, checks every `OFFLINE_RETRY` whether the server is
+    /// reachable again.
+    fn poll_offline(&mut self, ctx: &egui::Context) {
+        if self.offline.is_none() {
+            return;
+        }
+        let since = self.offline_checked_at.get_or_insert_with(std::time::Instant::now).elapsed();
+        if since < OFFLINE_RETRY {
+            ctx.request_repaint_after(OFFLINE_RETRY - since);
+            return;
+        }
+        self.offline_checked_at = Some(std::time::Instant::now());
+        
+        let client = XtreamClient::new(
+            self.server_url.clone(),
+            self.username.clone(),
+            self.password.clone(),
+        );
+        let tx = self.library_tx();
+        thread::spawn(move || {
+            if matches!(client.authenticate(), Ok(true)) {
+                let _ = tx.send(AppMessage::BackOnline);
             }
         });
     }
+    
+    /// True (with a toast) when offline, for actions that need the server.
+    fn blocked_offline(&mut self) -> bool {
+        if self.offline.is_some() {
+            self.toasts.warn("Offline: playback needs a connection to the server");
+        }
+        self.offline.is_some()
+    }
+    
+    /// Reloads the data backing the current view.
+    fn refresh_current_view(&mut self) {
+        match self.current_content {
+            ContentType::LiveTV | ContentType::Series | ContentType::Movies => {
+                self.refresh_section(self.current_content);
+            }
+            ContentType::RecentlyAdded => {
+                self.refresh_section(ContentType::Series);
+                self.refresh_section(ContentType::Movies);
+            }
+            ContentType::Discover => self.discover_cache.clear(),
+            ContentType::FootballFixtures => self.football_cache.clear(),
+            ContentType::OnNow => self.epg_cache.refresh_all(),
+            ContentType::ContinueWatching | ContentType::Favorites => self.filter_content(),
+        }
+    }
+
+    /// Handles app-level controller buttons (bumpers, triggers, menu/view).
+    fn handle_gamepad_action(&mut self, action: super::gamepad::GamepadAction) {
+        use super::gamepad::GamepadAction;
+
+        match action {
+            GamepadAction::PreviousPage => self.change_page(false),
+            GamepadAction::NextPage => self.change_page(true),
+            GamepadAction::PreviousSection => self.cycle_content_type(false),
+            GamepadAction::NextSection => self.cycle_content_type(true),
+            GamepadAction::OpenSettings => {
+                if !self.show_player_settings {
+                    self.temp_player_settings = Some(self.config.player_settings.clone());
+                    self.show_player_settings = true;
+                }
+            }
+            GamepadAction::ToggleSidebar => {
+                self.sidebar_visible = !self.sidebar_visible;
+            }
+            GamepadAction::ContextMenu => {
+                self.context_menu_requested = true;
+            }
+        }
+    }
+
+    /// Moves one page forward or back, staying within bounds.
+    fn change_page(&mut self, forward: bool) {
+        if forward {
+            let total_pages = self.calculate_total_pages();
+            if self.current_page < total_pages.saturating_sub(1) {
+                self.current_page += 1;
+            }
+        } else if self.current_page > 0 {
+            self.current_page -= 1;
+        }
+    }
 
     /// Cycles through content types (for bumper button navigation)
     fn cycle_content_type(&mut self, forward: bool) {
@@ -750,7 +3632,9 @@ impl IPTVPlayerApp {
             ContentType::ContinueWatching,
             ContentType::Series,
             ContentType::Movies,
+            ContentType::RecentlyAdded,
             ContentType::Favorites,
+            ContentType::OnNow,
             ContentType::Discover,
             ContentType::FootballFixtures,
         ];
@@ -765,22 +3649,7 @@ impl IPTVPlayerApp {
             if current_idx == 0 { content_order.len() - 1 } else { current_idx - 1 }
         };
 
-        let new_content = content_order[new_idx];
-
-        // Load data if needed
-        match new_content {
-            ContentType::Series if self.all_series.is_empty() => {
-                self.load_series();
-            }
-            ContentType::Movies if self.all_movies.is_empty() => {
-                self.load_movies();
-            }
-            _ => {}
-        }
-
-        self.current_content = new_content;
-        self.selected_category = None;
-        self.filter_content();
+        self.switch_content(content_order[new_idx]);
     }
 
     /// Calculates total pages for current content
@@ -805,53 +3674,383 @@ impl IPTVPlayerApp {
     
     /// Processes messages from background threads.
     fn process_messages(&mut self) {
-        if let Some(rx) = &self.rx {
-            if let Ok(msg) = rx.try_recv() {
-                match msg {
-                    AppMessage::Connected(categories, channels) => {
-                        self.live_categories = categories;
-                        self.all_channels = channels;
-                        self.filtered_channels = self.all_channels.clone();
-                        self.connected = true;
-                        self.connecting = false;
-                        self.save_credentials();
-                        // Load series and movies in the background immediately
-                        self.load_series();
-                        self.load_movies();
-                    }
-                    AppMessage::Error(err) => {
-                        self.error_message = Some(err);
-                        self.connecting = false;
-                    }
-                    AppMessage::SeriesLoaded(categories, series) => {
-                        self.series_categories = categories;
-                        self.all_series = series;
-                        self.filtered_series = self.all_series.clone();
+        // Drain everything queued since the last frame
+        let messages: Vec<AppMessage> = self.rx.as_ref()
+            .map(|rx| rx.try_iter().collect())
+            .unwrap_or_default();
+        
+        let mut library_changed = false;
+        for msg in messages {
+            let msg = match msg {
+                AppMessage::Library(generation, msg) if generation == self.library_generation => *msg,
+                AppMessage::Library(..) => {
+                    log::debug!("[Library] Dropped a result fetched for a previous account");
+                    continue;
+                }
+                msg => msg,
+            };
+            if matches!(msg, AppMessage::Connected(..) | AppMessage::SeriesLoaded(..) | AppMessage::MoviesLoaded(..)
+                | AppMessage::MovieCategoryLoaded(..) | AppMessage::SeriesCategoryLoaded(..))
+            {
+                self.category_counts.clear();
+                library_changed = true;
+            }
+            match msg {
+                AppMessage::Connected(categories, channels) => {
+                    // A second Connected replaces stale cached channels
+                    let was_connected = self.connected;
+                    self.live_categories = categories;
+                    self.all_channels = channels;
+                    self.filtered_channels = self.all_channels.clone();
+                    sort::sort_channels(&mut self.filtered_channels, self.sort_order);
+                    self.connected = true;
+                    self.connecting = false;
+                    self.connect_stage = None;
+                    self.save_credentials();
+                    if !was_connected {
+                        self.current_content = self.home_content();
+                        self.selected_categories.clear();
+                    }
+                    if was_connected || self.current_content != ContentType::LiveTV {
+                        self.filter_content();
+                    }
+                    // Load series and movies in the background immediately
+                    // (the M3U playlist fallback has live channels only)
+                    let force = std::mem::take(&mut self.refreshing_library);
+                    if !self.live_only() && (!was_connected || force) {
+                        self.load_series(force);
+                        self.load_movies(force);
+                    }
+                }
+                AppMessage::LoginRejected(err) => {
+                    log::warn!("[Connect] {}", err);
+                    // Whatever was shown from the cache goes; the sign-in needs fixing
+                    if self.connected {
+                        self.disconnect();
+                    }
+                    self.error_message = Some(err);
+                    self.connecting = false;
+                    self.connect_stage = None;
+                    self.refreshing_library = false;
+                    self.refresh_requested.clear();
+                    self.library_state.clear();
+                }
+                AppMessage::Error(err) => {
+                    self.error_message = Some(err);
+                    self.connecting = false;
+                    self.connect_stage = None;
+                    self.refreshing_library = false;
+                    self.refresh_requested.clear();
+                    self.library_state.retain(|_, state| *state != LoadState::Loading);
+                }
+                AppMessage::SeriesLoaded(categories, series) => {
+                    let replacing = !self.all_series.is_empty();
+                    self.series_categories = categories;
+                    self.all_series = series;
+                    self.filtered_series = self.all_series.clone();
+                    sort::sort_series(&mut self.filtered_series, self.sort_order);
+                    // Per-category mode starts empty; fetch what's selected
+                    let by_category = !self.series_paging.complete;
+                    if by_category {
+                        self.series_paging.loaded.clear();
+                    }
+                    if replacing
+                        || self.current_content == ContentType::Favorites
+                        || (by_category && self.current_content == ContentType::Series)
+                    {
+                        self.filter_content();
                     }
-                    AppMessage::MoviesLoaded(categories, movies) => {
-                        self.movie_categories = categories;
+                }
+                AppMessage::MoviesLoaded(categories, movies) => {
+                    let replacing = !self.all_movies.is_empty();
+                    self.movie_categories = categories;
+                    self.all_movies = movies;
+                    self.filtered_movies = self.all_movies.clone();
+                    sort::sort_movies(&mut self.filtered_movies, self.sort_order);
+                    let by_category = !self.movie_paging.complete;
+                    if by_category {
+                        self.movie_paging.loaded.clear();
+                    }
+                    if replacing
+                        || self.current_content == ContentType::Favorites
+                        || (by_category && self.current_content == ContentType::Movies)
+                    {
+                        self.filter_content();
+                    }
+                }
+                AppMessage::MovieCategoryLoaded(category_id, movies) => {
+                    self.movie_paging.loading.remove(&category_id);
+                    if self.movie_paging.complete {
+                        continue;
+                    }
+                    if movies.iter().any(|m| m.category_id != category_id) {
+                        // The panel ignored the category and sent everything
                         self.all_movies = movies;
-                        self.filtered_movies = self.all_movies.clone();
+                        self.movie_paging.complete = true;
+                    } else {
+                        self.all_movies.extend(movies);
+                        self.movie_paging.loaded.insert(category_id);
+                    }
+                    if matches!(self.current_content, ContentType::Movies | ContentType::Favorites) {
+                        self.filter_content();
+                    }
+                }
+                AppMessage::SeriesCategoryLoaded(category_id, series) => {
+                    self.series_paging.loading.remove(&category_id);
+                    if self.series_paging.complete {
+                        continue;
+                    }
+                    if series.iter().any(|s| s.category_id != category_id) {
+                        self.all_series = series;
+                        self.series_paging.complete = true;
+                    } else {
+                        self.all_series.extend(series);
+                        self.series_paging.loaded.insert(category_id);
+                    }
+                    if matches!(self.current_content, ContentType::Series | ContentType::Favorites) {
+                        self.filter_content();
+                    }
+                }
+                AppMessage::LibraryLoadFailed(content_type, err) => {
+                    log::warn!("[Library] {}", err);
+                    self.refresh_requested.remove(&content_type);
+                    self.library_state.insert(content_type, LoadState::Error(err));
+                }
+                AppMessage::CategoryLoadFailed(content, category_id, err) => {
+                    let paging = if content == ContentType::Movies { &mut self.movie_paging } else { &mut self.series_paging };
+                    paging.loading.remove(&category_id);
+                    log::warn!("[Library] Failed to fetch category {}: {}", category_id, err);
+                    self.toasts.error(format!("Couldn't load this category: {}", err));
+                }
+                AppMessage::ConnectProgress(stage) => {
+                    self.connect_stage = Some(stage);
+                }
+                AppMessage::PlaylistFallback(reason) => {
+                    self.playlist_fallback = Some(reason);
+                }
+                AppMessage::WentOffline(reason) => {
+                    log::warn!("[Connect] Offline, browsing cached data: {}", reason);
+                    self.connecting = false;
+                    self.connect_stage = None;
+                    self.refreshing_library = false;
+                    self.refresh_requested.clear();
+                    self.library_state.insert(ContentType::LiveTV, LoadState::Error(reason.clone()));
+                    self.offline = Some(reason);
+                    self.offline_checked_at = Some(std::time::Instant::now());
+                    self.back_online = false;
+                }
+                AppMessage::UpdateChecked(result, manual) => match result {
+                    Ok(Some(release)) => {
+                        log::info!("[Updates] {} is available", release.tag_name);
+                        let dismissed = self.config.dismissed_update.as_deref() == Some(release.tag_name.as_str());
+                        if manual || !dismissed {
+                            self.available_update = Some(release);
+                        }
+                    }
+                    Ok(None) => {
+                        if manual {
+                            self.toasts.success(format!("You're up to date ({})", env!("CARGO_PKG_VERSION")));
+                        }
+                    }
+                    Err(e) => {
+                        // Startup checks fail silently, e.g. when offline
+                        log::warn!("[Updates] Check failed: {}", e);
+                        if manual {
+                            self.toasts.error(format!("Couldn't check for updates: {}", e));
+                        }
+                    }
+                },
+                AppMessage::BackOnline => {
+                    if self.offline.take().is_some() {
+                        self.back_online = true;
+                        self.toasts.success("Connection restored");
+                    }
+                }
+                AppMessage::DiagnosticUpdate(stage, status) => {
+                    if let Some(entry) = self.connection_test.iter_mut().find(|(s, _)| *s == stage) {
+                        entry.1 = status;
+                    }
+                }
+                AppMessage::LibraryUpdated(content_type, cached_at, source) => {
+                    self.library_status.insert(content_type, (cached_at, source));
+                    if source != LibrarySource::StaleCache {
+                        self.library_state.insert(content_type, LoadState::Loaded);
+                    }
+                    if content_type == ContentType::LiveTV && source == LibrarySource::Server {
+                        self.offline = None;
+                        self.back_online = false;
+                    }
+                    if source == LibrarySource::Server && self.refresh_requested.remove(&content_type) {
+                        self.toasts.success(format!("{} refreshed", content_type.title()));
                     }
-                    AppMessage::ScraperStarted => {
-                        self.scraping_in_progress = true;
-                        self.scraper_message = Some("Scraping fixtures...".to_string());
+                }
+                AppMessage::ScraperStarted => {
+                    self.scraping_in_progress = true;
+                    self.scraper_message = Some("Scraping fixtures...".to_string());
+                }
+                AppMessage::ScraperCompleted(msg) => {
+                    self.scraping_in_progress = false;
+                    self.scraper_message = Some(msg);
+                    self.football_cache.clear(); // Refresh the cache
+                }
+                AppMessage::ChannelProbed(stream_id, online) => {
+                    // Unknown results stay unchecked so the next check retries them
+                    if let Some(online) = online {
+                        self.channel_online.insert(stream_id, online);
+                    }
+                    if let Some((done, _)) = &mut self.channel_probe_progress {
+                        *done += 1;
+                    }
+                }
+                AppMessage::ChannelProbeFinished => {
+                    self.channel_probe_progress = None;
+                }
+                AppMessage::StreamChecked(url, result) => {
+                    // Ignore checks for streams the user has moved on from
+                    let Some((_, start)) = self.pending_preflight.take_if(|(pending, _)| *pending == url) else {
+                        continue;
+                    };
+                    match result {
+                        Ok(()) => {
+                            self.preflight_passed = Some(url);
+                            start(self);
+                        }
+                        Err(e) => self.toasts.error(e),
+                    }
+                }
+                AppMessage::PlaybackProgress { socket, position, duration } => {
+                    // Ignore players that have been replaced
+                    let Some(playback) = self.external_playback.as_mut()
+                        .filter(|p| p.ipc.as_ref().is_some_and(|ipc| ipc.path() == socket))
+                    else {
+                        continue;
+                    };
+                    playback.position = Some((position, duration));
+                    if playback.position_saved_at.is_none_or(|at| at.elapsed() >= POSITION_SAVE_INTERVAL) {
+                        self.save_external_position();
                     }
-                    AppMessage::ScraperCompleted(msg) => {
-                        self.scraping_in_progress = false;
-                        self.scraper_message = Some(msg);
-                        self.football_cache.clear(); // Refresh the cache
+                }
+                AppMessage::PlayerRemoteState { socket, mut state } => {
+                    if let Some(playback) = self.external_playback.as_mut()
+                        .filter(|p| p.ipc.as_ref().is_some_and(|ipc| ipc.path() == socket))
+                    {
+                        if let (true, Some(remote)) = (playback.adjusting_volume, playback.remote) {
+                            state.volume = remote.volume;
+                        }
+                        playback.remote = Some(state);
                     }
-                    AppMessage::ScraperFailed(err) => {
-                        self.scraping_in_progress = false;
-                        self.scraper_message = Some(format!("Error: {}", err));
+                }
+                AppMessage::SyncFinished(result) => {
+                    self.follow_sync_account();
+                    let Some(started_from) = self.sync.running.take() else {
+                        continue;
+                    };
+                    match result {
+                        Ok(merged) => self.apply_sync(&started_from, merged),
+                        Err(e) => {
+                            log::warn!("[Sync] {}; retrying in {}s", e, SYNC_RETRY.as_secs());
+                            if !self.sync.failing {
+                                self.toasts.warn(format!("Sync failed, will retry: {}", e));
+                            }
+                            self.sync.failing = true;
+                            self.sync.due = Some(std::time::Instant::now() + SYNC_RETRY);
+                        }
                     }
                 }
+                AppMessage::ScraperFailed(err) => {
+                    self.scraping_in_progress = false;
+                    self.scraper_message = Some(format!("Error: {}", err));
+                }
+                AppMessage::ScraperSetupChecked(setup) => {
+                    self.scraper_setup = Some(setup);
+                }
+                AppMessage::ScraperSetupFinished(result) => {
+                    self.scraper_installing = false;
+                    self.scraper_message = Some(match result {
+                        Ok(()) => "Scraper set up successfully".to_string(),
+                        Err(e) => format!("Error: setup failed: {}", e),
+                    });
+                    self.check_scraper_setup();
+                }
+                // Unwrapped above; a sender never nests them
+                AppMessage::Library(..) => {}
             }
         }
+        
+        // Keep "Search All" results in step with the library
+        if library_changed && !self.global_search.query.is_empty() {
+            self.filter_content();
+        }
     }
     
     /// Triggers the football fixtures scraper in a background thread
+    /// Live channel to watch a match on: one whose name contains any of
+    /// `queries`, preferring favorites, then higher quality, then earlier
+    /// (better) queries. Channels found offline or hidden are skipped.
+    fn best_channel_for(&self, queries: &[String]) -> Option<Channel> {
+        let queries: Vec<String> = queries.iter().map(|q| q.to_lowercase()).collect();
+        self.all_channels.iter()
+            .filter(|ch| self.channel_online.get(&ch.stream_id) != Some(&false))
+            .filter(|ch| !self.config.hidden_channels.contains_key(&ch.stream_id))
+            .filter_map(|ch| {
+                let name = ch.name.to_lowercase();
+                let rank = queries.iter().position(|q| !q.is_empty() && name.contains(q))?;
+                let is_favorite = self.config.favorites.contains(&ch.stream_id);
+                Some(((is_favorite, ch.quality(), std::cmp::Reverse(rank)), ch))
+            })
+            .max_by_key(|(key, _)| *key)
+            .map(|(_, ch)| ch.clone())
+    }
+    
+    /// Requests the selected fixtures category, reloading it and re-running
+    /// the scraper on the user's schedule. Schedules a repaint for the next
+    /// refresh so it also happens while the view sits idle.
+    fn request_football_fixtures(&mut self, ctx: &egui::Context) {
+        let category = self.football_category;
+        let refresh_every = std::time::Duration::from_secs(self.config.football_refresh_minutes as u64 * 60);
+        if refresh_every.is_zero() {
+            self.football_cache.request_category(category);
+        } else {
+            self.football_cache.request_category_max_age(category, refresh_every);
+            let loaded_ago = self.football_cache.loaded_ago(category).unwrap_or_default();
+            ctx.request_repaint_after(refresh_every.saturating_sub(loaded_ago));
+        }
+
+        if self.config.football_scrape_hours > 0 {
+            let scrape_every = std::time::Duration::from_secs(self.config.football_scrape_hours as u64 * 3600);
+            let since = *self.last_scrape_at.get_or_insert_with(std::time::Instant::now);
+            if since.elapsed() >= scrape_every {
+                self.trigger_scraper();
+            } else {
+                ctx.request_repaint_after(scrape_every - since.elapsed());
+            }
+        }
+    }
+    
+    /// Checks for Python and the scraper's venv in the background.
+    fn check_scraper_setup(&mut self) {
+        let Some(tx) = self.tx.clone() else { return };
+        let manager = self.scraper_manager.clone();
+        self.scraper_setup = None;
+        thread::spawn(move || {
+            let _ = tx.send(AppMessage::ScraperSetupChecked(manager.check_setup()));
+        });
+    }
+    
+    /// Creates the scraper's venv and installs its requirements.
+    fn install_scraper(&mut self) {
+        let Some(tx) = self.tx.clone() else { return };
+        if self.scraper_installing {
+            return;
+        }
+        let manager = self.scraper_manager.clone();
+        self.scraper_installing = true;
+        self.scraper_message = None;
+        thread::spawn(move || {
+            let _ = tx.send(AppMessage::ScraperSetupFinished(manager.install_blocking()));
+        });
+    }
+    
     fn trigger_scraper(&mut self) {
         if self.scraping_in_progress {
             return; // Already running
@@ -864,49 +4063,17 @@ impl IPTVPlayerApp {
         };
         
         self.scraping_in_progress = true;
+        self.last_scrape_at = Some(std::time::Instant::now());
         self.scraper_message = Some("Starting scraper...".to_string());
         
+        let mut manager = self.scraper_manager.clone();
         thread::spawn(move || {
-            // Determine Python executable path (cross-platform)
-            let python_path = if cfg!(windows) {
-                "Soccer-Scraper-main\\.venv\\Scripts\\python.exe"
-            } else {
-                "Soccer-Scraper-main/.venv/bin/python"
-            };
-            
-            // Try venv Python first, fall back to system Python
-            let python_cmd = if std::path::Path::new(python_path).exists() {
-                python_path.to_string()
-            } else if cfg!(windows) {
-                "python".to_string()
-            } else {
-                "python3".to_string()
+            let _ = tx.send(AppMessage::ScraperStarted);
+            let _ = match manager.scrape_blocking() {
+                ScrapingStatus::Success(msg) => tx.send(AppMessage::ScraperCompleted(msg)),
+                ScrapingStatus::Error(err) => tx.send(AppMessage::ScraperFailed(err)),
+                ScrapingStatus::Idle | ScrapingStatus::Scraping => Ok(()),
             };
-            
-            let result = std::process::Command::new(&python_cmd)
-                .args(["fixtures.py", "scrape"])
-                .current_dir("Soccer-Scraper-main")
-                .output();
-            
-            match result {
-                Ok(output) => {
-                    if output.status.success() {
-                        let _ = tx.send(AppMessage::ScraperCompleted(
-                            "Fixtures updated successfully!".to_string()
-                        ));
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        let _ = tx.send(AppMessage::ScraperFailed(
-                            format!("Scraper failed: {}", stderr.lines().next().unwrap_or("Unknown error"))
-                        ));
-                    }
-                }
-                Err(e) => {
-                    let _ = tx.send(AppMessage::ScraperFailed(
-                        format!("Failed to run scraper: {}. Ensure Python is installed.", e)
-                    ));
-                }
-            }
         });
     }
     
@@ -918,13 +4085,32 @@ impl IPTVPlayerApp {
     
     /// Renders the main content area.
     fn render_content(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
+        // Right analog stick scrolls the grid, proportional to deflection
+        let stick = self.gamepad.scroll_axis(self.config.stick_dead_zone);
+        
         egui::ScrollArea::vertical().show(ui, |ui| {
+            if stick != 0.0 {
+                let dt = ctx.input(|i| i.stable_dt).min(0.1);
+                let speed = STICK_SCROLL_SPEED * self.config.stick_scroll_sensitivity;
+                ui.scroll_with_delta(egui::vec2(0.0, stick * speed * dt));
+                ctx.request_repaint();
+            }
+            
             ui.add_space(20.0);
             
+            if !self.global_search.query.is_empty() {
+                self.render_search_all(ui, ctx, theme);
+                return;
+            }
+            
             match self.current_content {
-                ContentType::LiveTV | ContentType::Favorites => {
+                ContentType::LiveTV => {
                     self.render_channels(ui, ctx, theme);
                 }
+                ContentType::Favorites => {
+                    self.render_channels(ui, ctx, theme);
+                    self.render_favorite_titles(ui, ctx, theme);
+                }
                 ContentType::ContinueWatching => {
                     self.render_continue_watching(ui, ctx, theme);
                 }
@@ -934,18 +4120,67 @@ impl IPTVPlayerApp {
                 ContentType::Movies => {
                     self.render_movies(ui, ctx, theme);
                 }
+                ContentType::RecentlyAdded => {
+                    self.render_recently_added(ui, ctx, theme);
+                }
                 ContentType::Discover => {
                     self.render_discover(ui, ctx, theme);
                 }
                 ContentType::FootballFixtures => {
                     self.render_football_section(ui, theme);
                 }
+                ContentType::OnNow => {
+                    self.render_on_now(ui, ctx, theme);
+                }
             }
         });
     }
     
+    /// Names of the current paginated list, in display order.
+    /// `None` for views without a sorted list.
+    fn current_list_names(&self) -> Option<Vec<&str>> {
+        match self.current_content {
+            ContentType::LiveTV => {
+                Some(self.filtered_channels.iter().map(|ch| ch.name.as_str()).collect())
+            }
+            ContentType::Series => Some(self.filtered_series.iter().map(|s| s.name.as_str()).collect()),
+            ContentType::Movies => Some(self.filtered_movies.iter().map(|m| m.name.as_str()).collect()),
+            _ => None,
+        }
+    }
+    
+    /// A–Z strip on the right of the content; only shown when the list
+    /// is sorted by name and spans more than one page.
+    fn render_alpha_index(&mut self, ui: &mut egui::Ui, theme: &Theme, is_touch_mode: bool) {
+        if !self.sort_order.is_alphabetical() {
+            return;
+        }
+        let Some(names) = self.current_list_names() else { return };
+        if names.len() <= self.page_size {
+            return;
+        }
+        
+        let mut available: Vec<char> = names.iter().map(|n| alpha_index::index_letter(n)).collect();
+        available.dedup();
+        let current = names.get(self.current_page * self.page_size)
+            .map(|n| alpha_index::index_letter(n));
+        
+        let mut picked: Option<char> = None;
+        egui::SidePanel::right("alpha_index")
+            .resizable(false)
+            .show_separator_line(false)
+            .frame(egui::Frame::none().inner_margin(egui::Margin::symmetric(4.0, 20.0)))
+            .show_inside(ui, |ui| {
+                picked = AlphaIndex::show(ui, theme, &available, current, is_touch_mode);
+            });
+        
+        if let Some(index) = picked.and_then(|letter| alpha_index::first_index_for_letter(names, letter)) {
+            self.current_page = index / self.page_size;
+        }
+    }
+    
     /// Renders the continue watching section.
-    fn render_continue_watching(&mut self, ui: &mut egui::Ui, _ctx: &egui::Context, theme: &Theme) {
+    fn render_continue_watching(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
         ui.label(egui::RichText::new(self.current_content.title())
             .size(24.0)
             .color(theme.text_primary)
@@ -956,10 +4191,15 @@ impl IPTVPlayerApp {
         ui.label(egui::RichText::new(format!("{} items", items.len())).size(14.0).color(theme.text_secondary));
         ui.add_space(16.0);
         
-        if items.is_empty() {
+        let next_up = self.next_up(10);
+        if items.is_empty() && next_up.is_empty() {
             self.render_empty_state(ui, theme);
             return;
         }
+        if !next_up.is_empty() {
+            self.render_next_up(ui, ctx, theme, &next_up);
+            ui.add_space(16.0);
+        }
         
         let card_width = dimensions::card_width(self.screen_width);
         
@@ -972,13 +4212,21 @@ impl IPTVPlayerApp {
                     egui::vec2(card_width, 280.0),
                     egui::Sense::click(),
                 );
+                let resume_label = format!(
+                    "Resume {} ({:.0}% watched)",
+                    progress.content_name,
+                    progress.progress_percentage(),
+                );
+                response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &resume_label));
+                let response = response.on_hover_text(&resume_label);
                 
                 if response.clicked() {
                     clicked_content = Some(progress.clone());
                 }
                 
                 // Draw card with hover effect
-                let bg_color = if response.hovered() {
+                let has_focus = response.has_focus();
+                let bg_color = if response.hovered() || has_focus {
                     theme.card_bg.linear_multiply(1.1)
                 } else {
                     theme.card_bg
@@ -990,12 +4238,13 @@ impl IPTVPlayerApp {
                     bg_color,
                 );
                 
-                // Draw border
-                ui.painter().rect_stroke(
-                    rect,
-                    4.0,
-                    egui::Stroke::new(1.0, theme.border_color),
-                );
+                // Draw border, highlighted for keyboard/controller focus
+                let border = if has_focus {
+                    egui::Stroke::new(2.0, theme.accent_blue)
+                } else {
+                    egui::Stroke::new(1.0, theme.border_color)
+                };
+                ui.painter().rect_stroke(rect, 4.0, border);
                 
                 // Draw content inside the card
                 let mut child_ui = ui.new_child(egui::UiBuilder::new()
@@ -1050,6 +4299,15 @@ impl IPTVPlayerApp {
                     .color(theme.text_primary)
                     .strong());
                 
+                // Replays of live programmes get a LIVE tag
+                if progress.content_type == "catchup" {
+                    child_ui.label(egui::RichText::new(" LIVE ")
+                        .size(11.0)
+                        .strong()
+                        .color(egui::Color32::WHITE)
+                        .background_color(theme.error_color));
+                }
+                
                 // Episode info if series
                 if let (Some(season), Some(episode)) = (progress.season, progress.episode) {
                     child_ui.label(egui::RichText::new(format!("S{}:E{}", season, episode))
@@ -1058,10 +4316,12 @@ impl IPTVPlayerApp {
                 }
                 
                 // Progress info
-                let mins_watched = (progress.position_seconds / 60.0) as i32;
-                let mins_total = (progress.duration_seconds / 60.0) as i32;
-                child_ui.label(egui::RichText::new(format!("{} / {} min ({:.0}%)", 
-                    mins_watched, mins_total, progress.progress_percentage()))
+                let mins_watched = (progress.position_seconds / 60.0).floor();
+                let mins_total = (progress.duration_seconds / 60.0).floor();
+                child_ui.label(egui::RichText::new(format!("{} / {} min ({}%)",
+                    super::locale::format_number(mins_watched, 0),
+                    super::locale::format_number(mins_total, 0),
+                    super::locale::format_number(progress.progress_percentage() as f64, 0)))
                     .size(12.0)
                     .color(theme.text_secondary));
             }
@@ -1073,16 +4333,85 @@ impl IPTVPlayerApp {
         }
     }
     
+    /// The episode after the last finished one, for up to `limit` series.
+    /// Series whose episode list isn't known yet are fetched and show up
+    /// once it arrives.
+    fn next_up(&mut self, limit: usize) -> Vec<NextUp> {
+        let mut entries = Vec::new();
+        for last in self.watch_history.finished_series().into_iter().take(NEXT_UP_SERIES) {
+            let Some(series) = self.all_series.iter().find(|s| s.name == last.content_name) else {
+                continue;
+            };
+            let Some(episodes) = self.episode_index.get(series.series_id) else {
+                let client = XtreamClient::new(
+                    self.server_url.clone(),
+                    self.username.clone(),
+                    self.password.clone(),
+                );
+                self.episode_index.request(series.series_id, &last.content_name, client);
+                continue;
+            };
+            if let Some(index) = super::episode_index::next_up(&episodes, last, &self.watch_history) {
+                entries.push(NextUp { episodes, index, cover: series.cover.clone() });
+                if entries.len() == limit {
+                    break;
+                }
+            }
+        }
+        entries
+    }
+    
+    /// Renders the Next Up rows; a click plays the episode and queues the
+    /// ones after it for "Play next".
+    fn render_next_up(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme, entries: &[NextUp]) {
+        ui.label(egui::RichText::new("Next Up")
+            .size(18.0)
+            .color(theme.text_primary)
+            .strong());
+        ui.add_space(8.0);
+        
+        let is_touch_mode = self.is_touch_mode();
+        let mut clicked = None;
+        for (i, entry) in entries.iter().enumerate() {
+            let episode_dialog::EpisodeAction::PlayEpisode { series_name, season, episode, title, .. } = &entry.episodes[entry.index] else {
+                continue;
+            };
+            let row = MediaRow {
+                thumbnail: entry.cover.as_deref(),
+                title: series_name,
+                year: Some(format!("S{}:E{}", season, episode)),
+                rating: None,
+                genre: Some(title.as_str()),
+                progress: None,
+            };
+            if row.show(ui, ctx, theme, &self.image_cache, is_touch_mode).clicked() {
+                clicked = Some(i);
+            }
+        }
+        
+        if let Some(entry) = clicked.map(|i| &entries[i]) {
+            let action = entry.episodes[entry.index].clone();
+            let upcoming = entry.episodes[entry.index + 1..].to_vec();
+            self.start_episode(action, upcoming);
+        }
+    }
+    
     /// Renders the channels grid.
     fn render_channels(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
         ui.label(egui::RichText::new(self.current_content.title())
             .size(24.0)
             .color(theme.text_primary)
             .strong());
+        self.render_library_status(ui, theme);
         
         // Process pending EPG data
         self.epg_cache.process_pending();
         
+        ui.add_space(8.0);
+        self.render_multiview_bar(ui, ctx, theme);
+        self.render_channel_check(ui, theme);
+        self.render_favorite_actions(ui, theme);
+        
         // Pagination calculations
         let total_items = self.filtered_channels.len();
         let total_pages = (total_items + self.page_size - 1) / self.page_size;
@@ -1099,22 +4428,68 @@ impl IPTVPlayerApp {
             .cloned()
             .collect();
         
-        // Request EPG data for visible channels
-        for channel in &page_channels {
-            self.epg_cache.request_epg_with_tvg(&channel.stream_id, channel.epg_channel_id.as_deref());
+        // Request EPG data for visible channels (only when the page changes)
+        let requests: Vec<(String, Option<String>)> = page_channels.iter()
+            .map(|ch| (ch.stream_id.clone(), ch.epg_channel_id.clone()))
+            .collect();
+        self.epg_cache.request_page(&requests);
+        
+        // Redraw when a programme on this page starts or ends
+        let now = super::epg_cache::unix_now();
+        if let Some(boundary) = self.epg_cache.next_boundary(page_channels.iter().map(|c| c.stream_id.as_str()), now) {
+            ctx.request_repaint_after(std::time::Duration::from_secs((boundary - now) as u64));
+        }
+        
+        // My List can be rearranged by dragging cards; touch screens keep
+        // dragging for scrolling
+        let reorderable = self.current_content == ContentType::Favorites && !self.is_touch_mode();
+        self.render_channel_cards(ui, ctx, theme, &page_channels, reorderable);
+        
+        // Warm up the next page's logos while this one is on screen
+        self.image_cache.prefetch(ctx, self.filtered_channels.iter()
+            .skip(end_idx)
+            .take(self.page_size)
+            .map(|ch| ch.stream_icon.clone()));
+        
+        // Handle pagination
+        if let Some(new_page) = Pagination::show(ui, theme, self.current_page, total_pages, self.is_touch_mode()) {
+            self.current_page = new_page;
         }
         
+        // Empty state; My List may still have movies or series below
+        let has_titles = self.current_content == ContentType::Favorites
+            && !(self.filtered_movies.is_empty() && self.filtered_series.is_empty());
+        if self.filtered_channels.is_empty() && !has_titles {
+            self.render_empty_state(ui, theme);
+        }
+    }
+    
+    /// Renders channel cards and handles their actions. `reorderable`
+    /// lets My List be rearranged by dragging.
+    fn render_channel_cards(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme, channels: &[Channel], reorderable: bool) {
         let favorites = self.config.favorites.clone();
         let mut channel_to_play: Option<Channel> = None;
         let mut channel_to_toggle: Option<String> = None;
+        let mut channel_to_record: Option<Channel> = None;
+        let mut channel_to_browse: Option<Channel> = None;
+        let mut channel_to_hide: Option<Channel> = None;
+        let mut focused_channel: Option<String> = None;
+        let mut favorite_to_move: Option<(String, String, bool)> = None;
+        let mut card_rects: Vec<(String, egui::Rect)> = Vec::new();
+        let mut drag_released = false;
+        
+        // Rect of the keyboard-focused widget, to find the focused card
+        let focused_rect = ctx.memory(|m| m.focused())
+            .and_then(|id| ctx.read_response(id))
+            .map(|r| r.rect);
         
-        let screen_width = self.screen_width;
+        let now = super::epg_cache::unix_now();
+        let card_size = dimensions::CardSize::channel(self.screen_width, self.grid_column_width(ui));
         ui.horizontal_wrapped(|ui| {
-            for channel in &page_channels {
+            for channel in channels {
                 // Build EPG info for this channel
                 let epg_info = {
-                    let current = self.epg_cache.get_current_program(&channel.stream_id);
-                    let next = self.epg_cache.get_next_program(&channel.stream_id);
+                    let (current, next) = self.epg_cache.now_and_next(&channel.stream_id, now);
                     if current.is_some() || next.is_some() {
                         Some(channel_card::ChannelEpgInfo {
                             current_program: current,
@@ -1125,16 +4500,58 @@ impl IPTVPlayerApp {
                     }
                 };
                 
-                if let Some(action) = ChannelCard::show(
+                // In My List the card also senses drags, below its own
+                // buttons so clicks still reach them
+                let sense = if reorderable { egui::Sense::drag() } else { egui::Sense::hover() };
+                let card = ui.scope_builder(egui::UiBuilder::new().sense(sense), |ui| ChannelCard::show(
                     ui,
                     ctx,
                     theme,
                     channel,
-                    favorites.contains(&channel.stream_id),
+                    channel_card::ChannelBadges {
+                        favorite: favorites.contains(&channel.stream_id),
+                        recording: self.recordings.iter().any(|r| r.stream_id == channel.stream_id),
+                        offline: self.channel_online.get(&channel.stream_id) == Some(&false),
+                    },
                     &self.image_cache,
-                    screen_width,
+                    card_size,
                     epg_info.as_ref(),
-                ) {
+                ));
+                
+                let card_rect = card.response.rect;
+                if ui.rect_contains_pointer(card_rect)
+                    || focused_rect.is_some_and(|r| card_rect.contains(r.center()))
+                {
+                    focused_channel = Some(channel.stream_id.clone());
+                }
+                
+                if reorderable {
+                    if card.response.drag_started() {
+                        self.dragged_favorite = Some(channel.stream_id.clone());
+                    }
+                    drag_released |= card.response.drag_stopped();
+                    if self.dragged_favorite.as_ref() == Some(&channel.stream_id) {
+                        ui.painter().rect_filled(card_rect, 12.0, egui::Color32::from_black_alpha(140));
+                    }
+                    card_rects.push((channel.stream_id.clone(), card_rect));
+                }
+                
+                // Mark channels picked for multi-view with their tile number
+                if let Some(tile) = self.multiview_channels.iter().position(|c| c.stream_id == channel.stream_id) {
+                    let marked = card_rect.shrink(4.0);
+                    ui.painter().rect_stroke(marked, 12.0, egui::Stroke::new(2.0, theme.accent_blue));
+                    let badge = egui::pos2(marked.max.x - 14.0, marked.min.y + 14.0);
+                    ui.painter().circle_filled(badge, 10.0, theme.accent_blue);
+                    ui.painter().text(
+                        badge,
+                        egui::Align2::CENTER_CENTER,
+                        (tile + 1).to_string(),
+                        egui::FontId::proportional(12.0),
+                        egui::Color32::WHITE,
+                    );
+                }
+                
+                if let Some(action) = card.inner {
                     match action {
                         channel_card::ChannelAction::Play(ch) => {
                             channel_to_play = Some(ch);
@@ -1142,19 +4559,73 @@ impl IPTVPlayerApp {
                         channel_card::ChannelAction::ToggleFavorite(id) => {
                             channel_to_toggle = Some(id);
                         }
+                        channel_card::ChannelAction::ToggleRecording(ch) => {
+                            channel_to_record = Some(ch);
+                        }
+                        channel_card::ChannelAction::CopyUrl(ch) => {
+                            let url = self.live_stream_url(&ch);
+                            self.copy_stream_url(ctx, url, &ch.name);
+                        }
+                        channel_card::ChannelAction::BrowseCatchup(ch) => {
+                            channel_to_browse = Some(ch);
+                        }
+                        channel_card::ChannelAction::Hide(ch) => {
+                            channel_to_hide = Some(ch);
+                        }
+                    }
+                }
+            }
+            
+            if let Some(dragged) = self.dragged_favorite.clone() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+                // Drop before or after the card under the pointer, gaps included
+                let gap = ui.spacing().item_spacing / 2.0;
+                let target = ui.ctx().pointer_interact_pos().and_then(|pos| {
+                    card_rects.iter()
+                        .find(|(_, rect)| rect.expand2(gap).contains(pos))
+                        .map(|(id, rect)| (id.clone(), *rect, pos.x > rect.center().x))
+                });
+                if let Some((target, rect, after)) = target {
+                    if target != dragged {
+                        let x = if after { rect.right() + gap.x } else { rect.left() - gap.x };
+                        ui.painter().line_segment(
+                            [egui::pos2(x, rect.top()), egui::pos2(x, rect.bottom())],
+                            egui::Stroke::new(3.0, theme.accent_blue),
+                        );
+                        if drag_released {
+                            favorite_to_move = Some((dragged, target, after));
+                        }
                     }
                 }
+                // Also ends when the drag is lost, e.g. by switching page
+                if drag_released || ui.ctx().dragged_id().is_none() {
+                    self.dragged_favorite = None;
+                }
             }
         });
         
-        // Handle pagination
-        if let Some(new_page) = Pagination::show(ui, theme, self.current_page, total_pages, self.is_touch_mode()) {
-            self.current_page = new_page;
+        if let Some((stream_id, target, after)) = favorite_to_move {
+            self.move_favorite(&stream_id, &target, after);
         }
         
+        self.focused_channel = focused_channel;
+        
         // Process actions
         if let Some(channel) = channel_to_play {
-            self.play_channel(&channel);
+            if self.multiview_selecting {
+                self.toggle_multiview_channel(&channel);
+            } else {
+                self.play_channel(&channel);
+            }
+        }
+        if let Some(channel) = channel_to_record {
+            self.toggle_recording(&channel);
+        }
+        if let Some(channel) = channel_to_browse {
+            self.open_catchup(channel);
+        }
+        if let Some(channel) = channel_to_hide {
+            self.hide_channels(&[channel]);
         }
         if let Some(stream_id) = channel_to_toggle {
             self.toggle_favorite(&stream_id);
@@ -1162,10 +4633,105 @@ impl IPTVPlayerApp {
                 self.filter_content();
             }
         }
+    }
+    
+    /// Renders the "Search All" results: channels, movies and series
+    /// matching the query, each section capped with a "Show more" button.
+    fn render_search_all(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
+        ui.label(egui::RichText::new(format!("Results for \"{}\"", self.search_query.trim()))
+            .size(24.0)
+            .color(theme.text_primary)
+            .strong());
+        if !self.movie_paging.complete || !self.series_paging.complete {
+            ui.label(egui::RichText::new("Only categories loaded so far are included")
+                .size(12.0)
+                .color(theme.text_muted));
+        }
         
-        // Empty state
-        if self.filtered_channels.is_empty() {
-            self.render_empty_state(ui, theme);
+        let search = &self.global_search;
+        if search.channels.is_empty() && search.movies.is_empty() && search.series.is_empty() {
+            ui.add_space(40.0);
+            ui.vertical_centered(|ui| {
+                ui.label(egui::RichText::new("🔍").size(48.0).color(theme.text_secondary));
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new("No channels, movies or series match your search")
+                    .size(18.0)
+                    .color(theme.text_secondary));
+            });
+            return;
+        }
+        
+        let [shown_channels, shown_movies, shown_series] = search.shown;
+        let channels: Vec<Channel> = search.channels.iter().take(shown_channels).cloned().collect();
+        let movies: Vec<Movie> = search.movies.iter().take(shown_movies).cloned().collect();
+        let series: Vec<Series> = search.series.iter().take(shown_series).cloned().collect();
+        let totals = [search.channels.len(), search.movies.len(), search.series.len()];
+        
+        if !channels.is_empty() {
+            Self::search_section_header(ui, theme, "Channels", totals[0]);
+            let requests: Vec<(String, Option<String>)> = channels.iter()
+                .map(|ch| (ch.stream_id.clone(), ch.epg_channel_id.clone()))
+                .collect();
+            self.epg_cache.process_pending();
+            self.epg_cache.request_page(&requests);
+            self.render_channel_cards(ui, ctx, theme, &channels, false);
+            self.search_show_more(ui, 0, totals[0]);
+        }
+        if !movies.is_empty() {
+            Self::search_section_header(ui, theme, "Movies", totals[1]);
+            let list_view = self.config.movies_view == ViewMode::List;
+            self.render_movie_cards(ui, ctx, theme, &movies, list_view);
+            self.search_show_more(ui, 1, totals[1]);
+        }
+        if !series.is_empty() {
+            Self::search_section_header(ui, theme, "Series", totals[2]);
+            let list_view = self.config.series_view == ViewMode::List;
+            self.render_series_cards(ui, ctx, theme, &series, list_view);
+            self.search_show_more(ui, 2, totals[2]);
+        }
+    }
+    
+    /// Section title of the "Search All" results with its result count.
+    fn search_section_header(ui: &mut egui::Ui, theme: &Theme, title: &str, count: usize) {
+        ui.add_space(16.0);
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(title).size(18.0).color(theme.text_primary).strong());
+            ui.label(egui::RichText::new(super::locale::format_number(count as f64, 0))
+                .size(14.0)
+                .color(theme.text_secondary));
+        });
+        ui.add_space(8.0);
+    }
+    
+    /// "Show more" under a "Search All" section with hidden results.
+    fn search_show_more(&mut self, ui: &mut egui::Ui, section: usize, total: usize) {
+        let shown = self.global_search.shown[section];
+        if shown >= total {
+            return;
+        }
+        ui.add_space(8.0);
+        if ui.button(format!("Show more ({} more)", total - shown)).clicked() {
+            self.global_search.shown[section] = shown + SEARCH_ALL_LIMIT;
+        }
+    }
+    
+    /// Renders the movies and series of My List, under the channels.
+    fn render_favorite_titles(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
+        if !self.filtered_movies.is_empty() {
+            ui.add_space(16.0);
+            ui.label(egui::RichText::new("Movies").size(18.0).color(theme.text_primary).strong());
+            ui.add_space(8.0);
+            let movies = self.filtered_movies.clone();
+            let list_view = self.config.movies_view == ViewMode::List;
+            self.render_movie_cards(ui, ctx, theme, &movies, list_view);
+        }
+        if !self.filtered_series.is_empty() {
+            ui.add_space(16.0);
+            ui.label(egui::RichText::new("Series").size(18.0).color(theme.text_primary).strong());
+            ui.add_space(8.0);
+            let series = self.filtered_series.clone();
+            let list_view = self.config.series_view == ViewMode::List;
+            self.render_series_cards(ui, ctx, theme, &series, list_view);
         }
     }
     
@@ -1175,16 +4741,31 @@ impl IPTVPlayerApp {
             .size(24.0)
             .color(theme.text_primary)
             .strong());
+        self.render_library_status(ui, theme);
         
         if self.all_series.is_empty() {
+            if let Some(LoadState::Error(err)) = self.library_state.get(&ContentType::Series) {
+                let err = err.clone();
+                if Self::render_load_error(ui, theme, "series", &err) {
+                    self.load_series(true);
+                }
+                return;
+            }
+        }
+        
+        if self.all_series.is_empty() && (self.series_paging.complete || self.library_loading(ContentType::Series)) {
             ui.add_space(4.0);
             ui.label(egui::RichText::new("0 series").size(14.0).color(theme.text_secondary));
             ui.add_space(16.0);
             ui.vertical_centered(|ui| {
                 ui.add_space(40.0);
-                ui.spinner();
-                ui.add_space(8.0);
-                ui.label("Loading series...");
+                if self.live_only() {
+                    ui.label("Series aren't available in playlist mode");
+                } else {
+                    ui.spinner();
+                    ui.add_space(8.0);
+                    ui.label("Fetching series…");
+                }
             });
             return;
         }
@@ -1195,7 +4776,7 @@ impl IPTVPlayerApp {
         let start_idx = self.current_page * self.page_size;
         let end_idx = (start_idx + self.page_size).min(total_items);
         
-        Pagination::show_info(ui, theme, start_idx, end_idx, total_items, "series", self.current_page, total_pages);
+        self.render_list_header(ui, theme, start_idx, end_idx, total_items, total_pages);
         ui.add_space(16.0);
         
         let page_series: Vec<Series> = self.filtered_series.iter()
@@ -1204,24 +4785,13 @@ impl IPTVPlayerApp {
             .cloned()
             .collect();
         
-        let screen_width = self.screen_width;
-        ui.horizontal_wrapped(|ui| {
-            for series in &page_series {
-                if let Some(action) = SeriesCard::show(ui, ctx, theme, series, &self.image_cache, screen_width) {
-                    match action {
-                        series_card::SeriesAction::ViewEpisodes(id) => {
-                            // Create new episode dialog state - data loads in background
-                            self.episode_dialog_state = Some(episode_dialog::EpisodeDialogState::new(
-                                id,
-                                self.server_url.clone(),
-                                self.username.clone(),
-                                self.password.clone(),
-                            ));
-                        }
-                    }
-                }
-            }
-        });
+        let list_view = self.config.series_view == ViewMode::List;
+        self.render_series_cards(ui, ctx, theme, &page_series, list_view);
+        
+        self.image_cache.prefetch(ctx, self.filtered_series.iter()
+            .skip(end_idx)
+            .take(self.page_size)
+            .filter_map(|s| s.cover.clone()));
         
         if let Some(new_page) = Pagination::show(ui, theme, self.current_page, total_pages, self.is_touch_mode()) {
             self.current_page = new_page;
@@ -1234,16 +4804,31 @@ impl IPTVPlayerApp {
             .size(24.0)
             .color(theme.text_primary)
             .strong());
+        self.render_library_status(ui, theme);
         
         if self.all_movies.is_empty() {
+            if let Some(LoadState::Error(err)) = self.library_state.get(&ContentType::Movies) {
+                let err = err.clone();
+                if Self::render_load_error(ui, theme, "movies", &err) {
+                    self.load_movies(true);
+                }
+                return;
+            }
+        }
+        
+        if self.all_movies.is_empty() && (self.movie_paging.complete || self.library_loading(ContentType::Movies)) {
             ui.add_space(4.0);
             ui.label(egui::RichText::new("0 movies").size(14.0).color(theme.text_secondary));
             ui.add_space(16.0);
             ui.vertical_centered(|ui| {
                 ui.add_space(40.0);
-                ui.spinner();
-                ui.add_space(8.0);
-                ui.label("Loading movies...");
+                if self.live_only() {
+                    ui.label("Movies aren't available in playlist mode");
+                } else {
+                    ui.spinner();
+                    ui.add_space(8.0);
+                    ui.label("Fetching movies…");
+                }
             });
             return;
         }
@@ -1254,40 +4839,277 @@ impl IPTVPlayerApp {
         let start_idx = self.current_page * self.page_size;
         let end_idx = (start_idx + self.page_size).min(total_items);
         
-        Pagination::show_info(ui, theme, start_idx, end_idx, total_items, "movies", self.current_page, total_pages);
+        self.render_list_header(ui, theme, start_idx, end_idx, total_items, total_pages);
         ui.add_space(16.0);
         
-        let page_movies: Vec<serde_json::Value> = self.filtered_movies.iter()
+        let page_movies: Vec<Movie> = self.filtered_movies.iter()
             .skip(start_idx)
             .take(self.page_size)
             .cloned()
             .collect();
         
+        let list_view = self.config.movies_view == ViewMode::List;
+        self.render_movie_cards(ui, ctx, theme, &page_movies, list_view);
+        
+        self.image_cache.prefetch(ctx, self.filtered_movies.iter()
+            .skip(end_idx)
+            .take(self.page_size)
+            .filter_map(|m| m.stream_icon.clone()));
+        
+        if let Some(new_page) = Pagination::show(ui, theme, self.current_page, total_pages, self.is_touch_mode()) {
+            self.current_page = new_page;
+        }
+        
+        if self.filtered_movies.is_empty() {
+            self.render_empty_state(ui, theme);
+        }
+    }
+    
+    /// Oldest `added` time (unix seconds) that still counts as new.
+    fn new_content_cutoff(&self) -> i64 {
+        chrono::Utc::now().timestamp() - self.config.new_content_days as i64 * 86400
+    }
+    
+    /// Column width for the fixed "Columns" setting, None when automatic.
+    fn grid_column_width(&self, ui: &egui::Ui) -> Option<f32> {
+        dimensions::column_width(ui.available_width(), self.config.grid_columns, ui.spacing().item_spacing.x)
+    }
+    
+    /// IDs of the adult categories among `categories`; empty when blurring
+    /// is turned off.
+    fn adult_category_ids(&self, categories: &[Category]) -> std::collections::HashSet<String> {
+        if !self.config.blur_adult_content {
+            return std::collections::HashSet::new();
+        }
+        categories.iter()
+            .filter(|c| c.is_adult())
+            .map(|c| c.category_id.clone())
+            .collect()
+    }
+    
+    /// Veils the poster of a card or list row from an adult category.
+    fn paint_adult_cover(ui: &egui::Ui, card: egui::Rect, list_view: bool, card_width: f32, theme: &Theme) {
+        let poster = if list_view {
+            media_row::thumbnail_rect(card)
+        } else {
+            media_row::card_poster_rect(card, card_width)
+        };
+        media_row::paint_adult_cover(ui, card, poster, theme);
+    }
+    
+    /// Renders series as grid cards or list rows and handles their actions.
+    fn render_series_cards(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme, series_list: &[Series], list_view: bool) {
+        let card_size = dimensions::CardSize::poster(self.screen_width, self.grid_column_width(ui));
+        let is_touch_mode = self.is_touch_mode();
+        let new_since = self.new_content_cutoff();
+        let adult = self.adult_category_ids(&self.series_categories);
+        let layout = if list_view {
+            egui::Layout::top_down_justified(egui::Align::Min)
+        } else {
+            egui::Layout::left_to_right(egui::Align::Min).with_main_wrap(true)
+        };
+        let mut series_to_favorite = None;
+        ui.with_layout(layout, |ui| {
+            for series in series_list {
+                let state = media_row::TitleState {
+                    watch_history: &self.watch_history,
+                    is_favorite: self.config.favorite_series.contains(&series.series_id.to_string()),
+                };
+                let card = ui.scope(|ui| if list_view {
+                    SeriesCard::show_row(ui, ctx, theme, series, &self.image_cache, state, is_touch_mode)
+                } else {
+                    SeriesCard::show(ui, ctx, theme, series, &self.image_cache, state, card_size)
+                });
+                if adult.contains(series.category_id.as_str()) {
+                    Self::paint_adult_cover(ui, card.response.rect, list_view, card_size.width, theme);
+                }
+                if series.added_at().is_some_and(|added| added >= new_since) {
+                    media_row::paint_new_badge(ui, card.response.rect, theme);
+                }
+                if let Some(action) = card.inner {
+                    match action {
+                        series_card::SeriesAction::ViewEpisodes(id) => {
+                            // Create new episode dialog state - data loads in background
+                            self.episode_dialog_state = Some(episode_dialog::EpisodeDialogState::new(
+                                id,
+                                self.server_url.clone(),
+                                self.username.clone(),
+                                self.password.clone(),
+                            ));
+                        }
+                        series_card::SeriesAction::ToggleFavorite(id) => {
+                            series_to_favorite = Some((id.to_string(), series.name.clone()));
+                        }
+                    }
+                }
+            }
+        });
+        
+        if let Some((id, name)) = series_to_favorite {
+            self.toggle_title_favorite(ContentType::Series, id, &name);
+        }
+    }
+    
+    /// Renders movies as grid cards or list rows and handles their actions.
+    fn render_movie_cards(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme, movies: &[Movie], list_view: bool) {
         let mut movie_to_play: Option<(i64, String, String, Option<String>)> = None;
-        let screen_width = self.screen_width;
+        let mut movie_to_mark: Option<(i64, String, Option<String>)> = None;
+        let mut movie_to_favorite: Option<(String, String)> = None;
+        let card_size = dimensions::CardSize::poster(self.screen_width, self.grid_column_width(ui));
+        let is_touch_mode = self.is_touch_mode();
+        let new_since = self.new_content_cutoff();
+        let adult = self.adult_category_ids(&self.movie_categories);
+        let layout = if list_view {
+            egui::Layout::top_down_justified(egui::Align::Min)
+        } else {
+            egui::Layout::left_to_right(egui::Align::Min).with_main_wrap(true)
+        };
         
-        ui.horizontal_wrapped(|ui| {
-            for movie in &page_movies {
-                if let Some(action) = MovieCard::show(ui, ctx, theme, movie, &self.image_cache, screen_width) {
+        ui.with_layout(layout, |ui| {
+            for movie in movies {
+                let state = media_row::TitleState {
+                    watch_history: &self.watch_history,
+                    is_favorite: self.config.favorite_movies.contains(&movie.stream_id.to_string()),
+                };
+                let card = ui.scope(|ui| if list_view {
+                    MovieCard::show_row(ui, ctx, theme, movie, &self.image_cache, state, is_touch_mode)
+                } else {
+                    MovieCard::show(ui, ctx, theme, movie, &self.image_cache, state, card_size)
+                });
+                if adult.contains(movie.category_id.as_str()) {
+                    Self::paint_adult_cover(ui, card.response.rect, list_view, card_size.width, theme);
+                }
+                if movie.added.is_some_and(|added| added >= new_since) {
+                    media_row::paint_new_badge(ui, card.response.rect, theme);
+                }
+                if let Some(action) = card.inner {
                     match action {
                         movie_card::MovieAction::Play { stream_id, name, container_extension, thumbnail } => {
                             movie_to_play = Some((stream_id, name, container_extension, thumbnail));
                         }
+                        movie_card::MovieAction::CopyUrl { stream_id, name, container_extension } => {
+                            let url = XtreamClient::new(
+                                self.server_url.clone(),
+                                self.username.clone(),
+                                self.password.clone(),
+                            ).get_stream_url(&stream_id.to_string(), &container_extension);
+                            self.copy_stream_url(ctx, url, &name);
+                        }
+                        movie_card::MovieAction::ToggleWatched { stream_id, name, thumbnail } => {
+                            movie_to_mark = Some((stream_id, name, thumbnail));
+                        }
+                        movie_card::MovieAction::ToggleFavorite(stream_id) => {
+                            movie_to_favorite = Some((stream_id.to_string(), movie.name.clone()));
+                        }
                     }
                 }
             }
         });
         
-        if let Some(new_page) = Pagination::show(ui, theme, self.current_page, total_pages, self.is_touch_mode()) {
-            self.current_page = new_page;
+        if let Some((stream_id, name, ext, thumbnail)) = movie_to_play {
+            self.play_movie(stream_id, &name, &ext, thumbnail);
+        }
+        if let Some((stream_id, name, thumbnail)) = movie_to_mark {
+            self.toggle_movie_watched(stream_id, &name, thumbnail);
+        }
+        if let Some((stream_id, name)) = movie_to_favorite {
+            self.toggle_title_favorite(ContentType::Movies, stream_id, &name);
+        }
+    }
+    
+    /// Renders the newest movies and series of the library, newest first.
+    fn render_recently_added(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
+        ui.label(egui::RichText::new(self.current_content.title())
+            .size(24.0)
+            .color(theme.text_primary)
+            .strong());
+        ui.label(egui::RichText::new(format!(
+            "Items added in the last {} days are marked NEW",
+            self.config.new_content_days,
+        )).size(12.0).color(theme.text_muted));
+        if !self.movie_paging.complete || !self.series_paging.complete {
+            ui.label(egui::RichText::new("Only categories loaded so far are included")
+                .size(12.0)
+                .color(theme.text_muted));
+        }
+        
+        let mut movies: Vec<Movie> = self.all_movies.iter().filter(|m| m.added.is_some()).cloned().collect();
+        movies.sort_by_key(|m| std::cmp::Reverse(m.added));
+        movies.truncate(RECENTLY_ADDED_LIMIT);
+        let mut series: Vec<Series> = self.all_series.iter().filter(|s| s.added_at().is_some()).cloned().collect();
+        series.sort_by_key(|s| std::cmp::Reverse(s.added_at()));
+        series.truncate(RECENTLY_ADDED_LIMIT);
+        
+        if movies.is_empty() && series.is_empty() {
+            if self.library_loading(ContentType::Movies) || self.library_loading(ContentType::Series) {
+                ui.add_space(40.0);
+                ui.vertical_centered(|ui| {
+                    ui.spinner();
+                    ui.add_space(8.0);
+                    ui.label("Fetching the library…");
+                });
+            } else {
+                self.render_empty_state(ui, theme);
+            }
+            return;
+        }
+        
+        if !movies.is_empty() {
+            ui.add_space(16.0);
+            ui.label(egui::RichText::new("Movies").size(18.0).color(theme.text_primary).strong());
+            ui.add_space(8.0);
+            let list_view = self.config.movies_view == ViewMode::List;
+            self.render_movie_cards(ui, ctx, theme, &movies, list_view);
+        }
+        if !series.is_empty() {
+            ui.add_space(16.0);
+            ui.label(egui::RichText::new("Series").size(18.0).color(theme.text_primary).strong());
+            ui.add_space(8.0);
+            let list_view = self.config.series_view == ViewMode::List;
+            self.render_series_cards(ui, ctx, theme, &series, list_view);
+        }
+    }
+    
+    /// Starts loading movies and series that haven't been loaded yet.
+    fn load_missing_vod(&mut self) {
+        if self.all_series.is_empty() && self.series_categories.is_empty() {
+            self.load_series(false);
         }
-        
-        if let Some((stream_id, name, ext, thumbnail)) = movie_to_play {
-            self.play_movie(stream_id, &name, &ext, thumbnail);
+        if self.all_movies.is_empty() && self.movie_categories.is_empty() {
+            self.load_movies(false);
         }
+    }
+    
+    /// Item count line for the movie and series lists, with the Grid/List
+    /// switch on the right.
+    fn render_list_header(
+        &mut self,
+        ui: &mut egui::Ui,
+        theme: &Theme,
+        start_idx: usize,
+        end_idx: usize,
+        total_items: usize,
+        total_pages: usize,
+    ) {
+        let (item_type, current) = match self.current_content {
+            ContentType::Series => ("series", self.config.series_view),
+            _ => ("movies", self.config.movies_view),
+        };
         
-        if self.filtered_movies.is_empty() {
-            self.render_empty_state(ui, theme);
+        let mut picked = None;
+        ui.horizontal(|ui| {
+            Pagination::show_info(ui, theme, start_idx, end_idx, total_items, item_type, self.current_page, total_pages);
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                picked = media_row::view_mode_toggle(ui, current);
+            });
+        });
+        
+        if let Some(mode) = picked {
+            match self.current_content {
+                ContentType::Series => self.config.series_view = mode,
+                _ => self.config.movies_view = mode,
+            }
+            self.save_config();
         }
     }
     
@@ -1305,6 +5127,177 @@ impl IPTVPlayerApp {
         });
     }
     
+    /// Renders the "On Now" dashboard: what's airing on every favorite
+    /// channel, with a progress bar per programme. Clicking a row tunes in.
+    fn render_on_now(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
+        ui.label(egui::RichText::new(self.current_content.title())
+            .size(24.0)
+            .color(theme.text_primary)
+            .strong());
+        self.render_library_status(ui, theme);
+        ui.add_space(16.0);
+        
+        let favorites: Vec<Channel> = self.all_channels.iter()
+            .filter(|ch| self.config.favorites.contains(&ch.stream_id))
+            .cloned()
+            .collect();
+        if favorites.is_empty() {
+            self.render_empty_state(ui, theme);
+            return;
+        }
+        
+        let requests: Vec<(String, Option<String>)> = favorites.iter()
+            .map(|ch| (ch.stream_id.clone(), ch.epg_channel_id.clone()))
+            .collect();
+        self.epg_cache.request_page(&requests);
+        self.epg_cache.process_pending();
+        
+        // Redraw when a favourite's programme starts or ends
+        let now = super::epg_cache::unix_now();
+        if let Some(boundary) = self.epg_cache.next_boundary(favorites.iter().map(|c| c.stream_id.as_str()), now) {
+            ctx.request_repaint_after(std::time::Duration::from_secs((boundary - now) as u64));
+        }
+        
+        let row_width = ui.available_width().min(900.0);
+        let row_height = if self.is_touch_mode() { 92.0 } else { 78.0 };
+        let logo_size = 48.0;
+        let mut channel_to_play: Option<Channel> = None;
+        let mut reminder_to_toggle: Option<(Channel, EpgProgram)> = None;
+        
+        for channel in &favorites {
+            if !channel.stream_icon.is_empty() {
+                self.image_cache.load(ctx, channel.stream_icon.clone());
+            }
+            
+            let (rect, response) = ui.allocate_exact_size(
+                egui::vec2(row_width, row_height),
+                egui::Sense::click(),
+            );
+            response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, format!("Play {}", channel.name)));
+            let painter = ui.painter();
+            let bg = if response.hovered() || response.has_focus() { theme.card_elevated } else { theme.card_bg };
+            painter.rect_filled(rect, 8.0, bg);
+            if response.has_focus() {
+                painter.rect_stroke(rect, 8.0, egui::Stroke::new(2.0, theme.accent_blue));
+            }
+            
+            // Logo
+            let logo_rect = egui::Rect::from_min_size(
+                rect.min + egui::vec2(8.0, 8.0),
+                egui::vec2(logo_size, logo_size),
+            );
+            if !self.image_cache.paint(ui, &channel.stream_icon, logo_rect) {
+                painter.rect_filled(logo_rect, 6.0, theme.placeholder_bg());
+                painter.text(
+                    logo_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    super::image_cache::placeholder_initials(&channel.name),
+                    egui::FontId::proportional(logo_size * 0.35),
+                    theme.placeholder_icon(),
+                );
+            }
+            
+            // Names and titles stop short of the time and progress column
+            let bar_width = (row_width * 0.25).min(200.0);
+            let text_x = logo_rect.max.x + 12.0;
+            let text_painter = painter.with_clip_rect(rect.with_max_x(rect.max.x - bar_width - 24.0));
+            text_painter.text(
+                egui::pos2(text_x, rect.min.y + 10.0),
+                egui::Align2::LEFT_TOP,
+                &channel.name,
+                egui::FontId::proportional(12.0),
+                theme.text_secondary,
+            );
+            
+            let (program, next) = self.epg_cache.now_and_next(&channel.stream_id, now);
+            let title = match &program {
+                Some(program) => program.title.clone(),
+                None if self.epg_cache.get_epg(&channel.stream_id).is_none() => "Loading guide…".to_string(),
+                None => "No guide information".to_string(),
+            };
+            let title_color = if program.is_some() { theme.text_primary } else { theme.text_muted };
+            text_painter.text(
+                egui::pos2(text_x, rect.min.y + 26.0),
+                egui::Align2::LEFT_TOP,
+                title,
+                egui::FontId::proportional(15.0),
+                title_color,
+            );
+            
+            // Upcoming programme, with a bell to set a reminder
+            if let Some(next) = next {
+                let next_rect = text_painter.text(
+                    egui::pos2(text_x, rect.min.y + 50.0),
+                    egui::Align2::LEFT_TOP,
+                    format!("Next {} · {}", next.start_time_formatted(), next.title),
+                    egui::FontId::proportional(12.0),
+                    theme.text_tertiary,
+                );
+                let is_set = self.reminders.contains(&channel.stream_id, next.start_timestamp_unix());
+                let bell_rect = egui::Rect::from_center_size(
+                    egui::pos2(next_rect.max.x + 16.0, next_rect.center().y),
+                    egui::vec2(24.0, 24.0),
+                );
+                let bell = ui.interact(
+                    bell_rect,
+                    ui.id().with(("on_now_reminder", &channel.stream_id)),
+                    egui::Sense::click(),
+                );
+                let bell_color = if is_set {
+                    theme.accent_blue
+                } else if bell.hovered() {
+                    theme.text_primary
+                } else {
+                    theme.text_muted
+                };
+                ui.painter().text(
+                    bell_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    "🔔",
+                    egui::FontId::proportional(13.0),
+                    bell_color,
+                );
+                let hint = if is_set { "Cancel reminder" } else { "Remind me before it starts" };
+                bell.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, true, is_set, format!("Reminder for {}", next.title)));
+                if bell.on_hover_text(hint).clicked() {
+                    reminder_to_toggle = Some((channel.clone(), next));
+                }
+            }
+            
+            // Time range and elapsed bar on the right
+            if let Some(program) = &program {
+                let right = rect.max.x - 12.0;
+                painter.text(
+                    egui::pos2(right, rect.min.y + 10.0),
+                    egui::Align2::RIGHT_TOP,
+                    format!("{} – {}", program.start_time_formatted(), program.end_time_formatted()),
+                    egui::FontId::proportional(12.0),
+                    theme.text_tertiary,
+                );
+                let track = egui::Rect::from_min_size(
+                    egui::pos2(right - bar_width, rect.max.y - 18.0),
+                    egui::vec2(bar_width, 4.0),
+                );
+                painter.rect_filled(track, 2.0, theme.placeholder_bg());
+                let mut elapsed = track;
+                elapsed.set_width(bar_width * program.progress());
+                painter.rect_filled(elapsed, 2.0, theme.accent_blue);
+            }
+            
+            if response.on_hover_cursor(egui::CursorIcon::PointingHand).clicked() {
+                channel_to_play = Some(channel.clone());
+            }
+            ui.add_space(6.0);
+        }
+        
+        if let Some(channel) = channel_to_play {
+            self.play_channel(&channel);
+        }
+        if let Some((channel, program)) = reminder_to_toggle {
+            self.toggle_reminder(&channel, &program);
+        }
+    }
+    
     /// Renders the Discover section with movies and series.
     fn render_discover(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, theme: &Theme) {
         // Process pending data
@@ -1350,6 +5343,10 @@ impl IPTVPlayerApp {
             }
             
             // Show status message
+            if self.scraping_in_progress {
+                ui.add_space(8.0);
+                ui.spinner();
+            }
             if let Some(msg) = &self.scraper_message {
                 ui.add_space(8.0);
                 let color = if msg.starts_with("Error") {
@@ -1361,6 +5358,15 @@ impl IPTVPlayerApp {
                 };
                 ui.label(egui::RichText::new(msg).size(11.0).color(color));
             }
+            
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.checkbox(&mut self.config.football_local_time, "🕐 Local times")
+                    .on_hover_text("Show kick-off times in your timezone instead of the source's")
+                    .changed()
+                {
+                    self.save_config();
+                }
+            });
         });
         
         ui.add_space(8.0);
@@ -1387,7 +5393,7 @@ impl IPTVPlayerApp {
         ui.add_space(8.0);
         
         // Request data for current category
-        self.football_cache.request_category(self.football_category);
+        self.request_football_fixtures(ui.ctx());
         
         let is_loading = self.football_cache.is_loading(self.football_category);
         let fixtures = self.football_cache.get_category(self.football_category);
@@ -1400,14 +5406,26 @@ impl IPTVPlayerApp {
                     ui.label(egui::RichText::new("No fixtures found for this category")
                         .color(theme.text_secondary));
                     ui.add_space(8.0);
-                    ui.label(egui::RichText::new("Try selecting a different category or run the scraper")
+                    let hint = if self.football_category == FootballCategory::MyTeams {
+                        "Click ☆ next to a team name to add it to My Teams"
+                    } else {
+                        "Try selecting a different category or run the scraper"
+                    };
+                    ui.label(egui::RichText::new(hint)
                         .size(12.0)
                         .color(theme.text_secondary));
                 });
             } else {
                 let mut channel_to_search: Option<String> = None;
+                let mut team_to_toggle: Option<String> = None;
+                let mut match_to_play: Option<Vec<String>> = None;
                 
-                ui.label(egui::RichText::new(format!("Found {} matches", fixtures.len()))
+                let updated = match self.football_cache.loaded_ago(self.football_category) {
+                    Some(ago) if ago.as_secs() >= 60 => format!(" · updated {} min ago", ago.as_secs() / 60),
+                    Some(_) => " · updated just now".to_string(),
+                    None => String::new(),
+                };
+                ui.label(egui::RichText::new(format!("Found {} matches{}", fixtures.len(), updated))
                     .size(12.0)
                     .color(theme.text_secondary));
                 ui.add_space(8.0);
@@ -1416,7 +5434,7 @@ impl IPTVPlayerApp {
                 let mut fixtures_by_date: std::collections::HashMap<String, Vec<&crate::api::FootballFixture>> = std::collections::HashMap::new();
                 for fixture in fixtures.iter() {
                     fixtures_by_date
-                        .entry(fixture.fixture_date.clone())
+                        .entry(fixture.display_date(self.config.football_local_time))
                         .or_default()
                         .push(fixture);
                 }
@@ -1445,7 +5463,11 @@ impl IPTVPlayerApp {
                                             .size(16.0)
                                             .color(theme.accent_blue));
                                         ui.add_space(6.0);
-                                        ui.label(egui::RichText::new(date)
+                                        // Keys are YYYY-MM-DD so they sort by date
+                                        let header = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                                            .map(super::locale::format_date)
+                                            .unwrap_or_else(|_| date.clone());
+                                        ui.label(egui::RichText::new(header)
                                             .size(16.0)
                                             .color(theme.text_primary)
                                             .strong());
@@ -1461,6 +5483,9 @@ impl IPTVPlayerApp {
                                     theme,
                                     fixture,
                                     self.screen_width,
+                                    self.config.football_local_time,
+                                    &self.config.favorite_teams,
+                                    &self.config.channel_mappings,
                                 ) {
                                     match action {
                                         FootballAction::SearchChannel(channel) => {
@@ -1469,6 +5494,12 @@ impl IPTVPlayerApp {
                                         FootballAction::SearchTeam(team) => {
                                             channel_to_search = Some(team);
                                         }
+                                        FootballAction::ToggleFavoriteTeam(team) => {
+                                            team_to_toggle = Some(team);
+                                        }
+                                        FootballAction::PlayBestChannel(queries) => {
+                                            match_to_play = Some(queries);
+                                        }
                                     }
                                 }
                             }
@@ -1478,12 +5509,26 @@ impl IPTVPlayerApp {
                     ui.add_space(32.0); // Bottom padding
                 });
                 
+                if let Some(team) = team_to_toggle {
+                    self.toggle_favorite_team(&team);
+                }
+                
+                // Play the best channel for the match, or search when none matches
+                if let Some(queries) = match_to_play {
+                    if let Some(channel) = self.best_channel_for(&queries) {
+                        self.toasts.info(format!("Playing {}", channel.name));
+                        self.play_channel(&channel);
+                    } else if let Some(query) = queries.into_iter().next() {
+                        self.toasts.warn("No channel in your playlist matches this match's broadcasters");
+                        channel_to_search = Some(query);
+                    }
+                }
+                
                 // Handle search action - switch to Live TV and search
                 if let Some(query) = channel_to_search {
                     self.search_query = query;
-                    self.current_content = ContentType::LiveTV;
+                    self.switch_content(ContentType::LiveTV);
                     self.current_page = 0;
-                    self.filter_content();
                 }
             }
         } else if is_loading {
@@ -1562,6 +5607,7 @@ impl IPTVPlayerApp {
         // Show content - limit to 20 items for performance
         if let Some(items) = items {
             let mut search_query: Option<String> = None;
+            let mut trailer: Option<(String, String)> = None;
             let display_items: Vec<_> = items.iter().take(20).collect();
             
             // Display items in a grid
@@ -1583,17 +5629,27 @@ impl IPTVPlayerApp {
                             DiscoverAction::SearchInIptv(query) => {
                                 search_query = Some(query);
                             }
+                            DiscoverAction::PlayTrailer(url) => {
+                                trailer = Some((url, format!("{} - Trailer", item.title)));
+                            }
                         }
                     }
                 }
             });
             
+            // Play trailer in the configured player (or browser as fallback)
+            if let Some((url, title)) = trailer {
+                if let Err(e) = self.config.player_settings.launch_trailer(&url, &title) {
+                    log::warn!("[Discover] Failed to play trailer: {}", e);
+                    self.toasts.error(format!("Couldn't play the trailer: {}", e));
+                }
+            }
+            
             // Handle search action - switch to Series and search
             if let Some(query) = search_query {
                 self.search_query = query;
-                self.current_content = ContentType::Series;
+                self.switch_content(ContentType::Series);
                 self.current_page = 0;
-                self.filter_content();
             }
         } else if is_loading {
             ui.vertical_centered(|ui| {
@@ -1643,18 +5699,225 @@ impl IPTVPlayerApp {
             }
         }
     }
+
+    /// Renders the active recordings panel (top right), with a stop button
+    /// per recording and the last recording error, if any.
+    fn render_recordings_panel(&mut self, ctx: &egui::Context) {
+        let mut to_stop: Option<usize> = None;
+        let mut close = false;
+        let recordings_dir = self.config.recordings_path();
+        
+        egui::Window::new("")
+            .id(egui::Id::new("recordings_panel"))
+            .resizable(false)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(360.0)
+            .anchor(egui::Align2::RIGHT_TOP, [-20.0, 80.0])
+            .frame(egui::Frame::none()
+                .fill(egui::Color32::from_rgb(24, 24, 24))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(16.0)))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("⏺ Recordings")
+                        .size(16.0)
+                        .color(egui::Color32::WHITE)
+                        .strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.add(egui::Button::new(
+                            egui::RichText::new("✕").color(egui::Color32::WHITE)
+                        ).fill(egui::Color32::TRANSPARENT)).clicked() {
+                            close = true;
+                        }
+                    });
+                });
+                
+                ui.add_space(8.0);
+                
+                if self.recordings.is_empty() {
+                    ui.label(egui::RichText::new("No active recordings")
+                        .color(egui::Color32::from_rgb(150, 150, 150)));
+                }
+                
+                for (idx, recording) in self.recordings.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new("●").color(egui::Color32::from_rgb(255, 59, 48)));
+                        ui.vertical(|ui| {
+                            ui.label(egui::RichText::new(&recording.channel_name)
+                                .color(egui::Color32::WHITE));
+                            ui.label(egui::RichText::new(recording.elapsed_formatted())
+                                .size(11.0)
+                                .monospace()
+                                .color(egui::Color32::from_rgb(150, 150, 150)));
+                        });
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.add(egui::Button::new(
+                                egui::RichText::new("⏹ Stop recording").color(egui::Color32::WHITE)
+                            ).fill(egui::Color32::from_rgb(60, 60, 60)))
+                                .on_hover_text(recording.output_path.display().to_string())
+                                .clicked()
+                            {
+                                to_stop = Some(idx);
+                            }
+                        });
+                    });
+                    ui.add_space(4.0);
+                }
+                
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(recordings_dir.display().to_string())
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(120, 120, 120)));
+                    if ui.small_button("📂 Open").clicked() {
+                        if let Err(e) = std::fs::create_dir_all(&recordings_dir) {
+                            log::warn!("[Recording] Couldn't create {}: {}", recordings_dir.display(), e);
+                        }
+                        if let Err(e) = crate::models::config::open_in_browser(&recordings_dir.to_string_lossy()) {
+                            self.toasts.error(format!("Could not open folder: {}", e));
+                        }
+                    }
+                });
+            });
+        
+        if let Some(idx) = to_stop {
+            self.recordings.remove(idx).stop();
+        }
+        if close {
+            self.show_recordings = false;
+        }
+    }
+    
+    /// Bottom-right prompt offering the episode after the one that just ended.
+    fn render_next_episode_prompt(&mut self, ctx: &egui::Context) {
+        let Some(upcoming) = &self.next_episode_prompt else { return };
+        let Some(episode_dialog::EpisodeAction::PlayEpisode { series_name, season, episode, title, .. }) = upcoming.first() else {
+            self.next_episode_prompt = None;
+            return;
+        };
+        
+        let mut play = false;
+        let mut dismiss = false;
+        
+        egui::Window::new("")
+            .id(egui::Id::new("next_episode_prompt"))
+            .resizable(false)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(340.0)
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-20.0, -20.0])
+            .frame(egui::Frame::none()
+                .fill(egui::Color32::from_rgb(24, 24, 24))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(16.0)))
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new("UP NEXT")
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(150, 150, 150)));
+                ui.label(egui::RichText::new(series_name)
+                    .size(16.0)
+                    .color(egui::Color32::WHITE)
+                    .strong());
+                ui.label(egui::RichText::new(format!("S{}E{} · {}", season, episode, title))
+                    .size(13.0)
+                    .color(egui::Color32::from_rgb(200, 200, 200)));
+                ui.add_space(12.0);
+                
+                ui.horizontal(|ui| {
+                    let play_btn = egui::Button::new(
+                        egui::RichText::new(format!("▶ Play S{}E{}", season, episode)).color(egui::Color32::WHITE)
+                    )
+                    .fill(egui::Color32::from_rgb(255, 90, 95))
+                    .min_size(egui::vec2(140.0, 32.0));
+                    if ui.add(play_btn).clicked() {
+                        play = true;
+                    }
+                    
+                    let dismiss_btn = egui::Button::new(
+                        egui::RichText::new("Dismiss").color(egui::Color32::WHITE)
+                    )
+                    .fill(egui::Color32::from_rgb(60, 60, 60))
+                    .min_size(egui::vec2(80.0, 32.0));
+                    if ui.add(dismiss_btn).clicked() {
+                        dismiss = true;
+                    }
+                });
+            });
+        
+        if play {
+            if let Some(mut upcoming) = self.next_episode_prompt.take() {
+                let next = upcoming.remove(0);
+                self.start_episode(next, upcoming);
+            }
+        } else if dismiss {
+            self.next_episode_prompt = None;
+        }
+    }
+    
+    /// Renders the notifications for reminders that went off.
+    fn render_reminders(&mut self, ctx: &egui::Context) {
+        let now = chrono::Utc::now().timestamp();
+        match ReminderToast::show(ctx, &self.due_reminders, now) {
+            Some(ReminderToastAction::Watch(index)) => {
+                let reminder = self.due_reminders.remove(index);
+                self.tune_to(&reminder.stream_id);
+            }
+            Some(ReminderToastAction::Dismiss(index)) => {
+                self.due_reminders.remove(index);
+            }
+            None => {}
+        }
+    }
 }
 
 impl eframe::App for IPTVPlayerApp {
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        // Let ffmpeg finalize any running recordings; the stop threads
+        // would die with the process, so wait for them (each gives up on
+        // ffmpeg after its grace period)
+        let stopping: Vec<_> = self.recordings.drain(..).map(|r| r.stop()).collect();
+        let deadline = std::time::Instant::now() + RECORDINGS_EXIT_WAIT;
+        while stopping.iter().any(|t| !t.is_finished()) && std::time::Instant::now() < deadline {
+            thread::sleep(std::time::Duration::from_millis(50));
+        }
+        
+        // Reopen the window where it was left
+        if let Some(placement) = self.window_placement {
+            self.config.last_window = Some(placement);
+            self.save_config();
+        }
+    }
+
+    fn raw_input_hook(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        // Translate controller input before egui processes the frame
+        let has_focus = ctx.memory(|m| m.focused().is_some());
+        for action in self.gamepad.poll(raw_input, has_focus) {
+            self.handle_gamepad_action(action);
+        }
+
+        // Keep polling while a controller is connected
+        if self.gamepad.is_connected() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(33));
+        }
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Manage repaint frequency based on loading state
+        // Background results wake the UI themselves; this slow poll only
+        // catches state nothing reports, like a player or recording exiting
         if !self.image_cache.is_loading() {
-            ctx.request_repaint_after(std::time::Duration::from_millis(500));
+            ctx.request_repaint_after(IDLE_POLL);
         }
 
         // Create and apply theme
         let theme = Theme::new(self.dark_mode);
         theme.apply(ctx);
+        
+        self.track_window_placement(ctx);
+        self.playback_active.store(
+            self.external_playback.is_some() || self.embedded_playback.is_some(),
+            std::sync::atomic::Ordering::Relaxed,
+        );
 
         // Ensure Steam Deck scaling is maintained (Gamescope may try to reset it)
         if self.is_steam_deck_mode {
@@ -1665,36 +5928,137 @@ impl eframe::App for IPTVPlayerApp {
 
         // Process background messages
         self.process_messages();
+        self.poll_recordings();
+        self.poll_external_playback();
+        self.poll_track_selection();
+        self.poll_reminders(ctx);
+        self.poll_offline(ctx);
+        self.poll_sync(ctx);
 
         // Update screen dimensions for responsive layout
         self.screen_width = ctx.screen_rect().width();
         self.screen_height = ctx.screen_rect().height();
         let is_mobile = dimensions::is_mobile(self.screen_width);
         let is_touch_mode = dimensions::is_touch_mode(self.screen_width, self.screen_height);
+        
+        // A custom touch target raises the minimum height of every control
+        let min_height = dimensions::custom_touch_target()
+            .filter(|_| is_touch_mode)
+            .unwrap_or(egui::style::Spacing::default().interact_size.y);
+        if ctx.style().spacing.interact_size.y != min_height {
+            ctx.style_mut(|style| style.spacing.interact_size.y = min_height);
+        }
+
+        // The built-in player takes over the window while it plays
+        if self.embedded_playback.is_some() {
+            self.render_embedded_player(ctx, &theme);
+            self.toasts.show(ctx);
+            return;
+        }
 
         // Steam Deck / Gamepad input handling
         // Enable keyboard navigation for better controller support
         self.handle_gamepad_input(ctx, is_touch_mode);
+        self.handle_keyboard_shortcuts(ctx);
+        if std::mem::take(&mut self.context_menu_requested) {
+            CardContextMenu::open_for_focused(ctx);
+        }
+        
+        self.render_update_banner(ctx, &theme);
         
         if !self.connected {
+            let connect_status = self.connecting
+                .then(|| self.connect_stage.map_or("Connecting...", |stage| stage.label()));
+            
             // Show login screen
             egui::CentralPanel::default().show(ctx, |ui| {
-                if LoginScreen::show(
+                let has_saved_credentials = !self.config.username.is_empty()
+                    || !self.config.password.is_empty()
+                    || !self.config.playlist_url.is_empty()
+                    || self.config.active_profile.is_some();
+                let accounts: Vec<&str> = self.config.profiles.iter().map(|p| p.name.as_str()).collect();
+                let form = login::LoginForm {
+                    mode: &mut self.config.login_mode,
+                    server_url: &mut self.server_url,
+                    username: &mut self.username,
+                    password: &mut self.password,
+                    playlist_url: &mut self.playlist_url,
+                    account_name: &mut self.account_name,
+                    accounts: &accounts,
+                    active_account: self.config.active_profile.as_deref(),
+                    auto_connect: &mut self.config.auto_login,
+                    has_saved_credentials,
+                };
+                match LoginScreen::show(
                     ui,
                     &theme,
-                    &mut self.server_url,
-                    &mut self.username,
-                    &mut self.password,
-                    self.connecting,
+                    form,
+                    connect_status,
                     &self.error_message,
+                    &self.connection_test,
                     is_touch_mode,
                 ) {
-                    self.connect();
+                    Some(login::LoginAction::Connect) => self.connect(false),
+                    Some(login::LoginAction::TestConnection) => self.test_connection(),
+                    Some(login::LoginAction::ForgetCredentials) => self.forget_credentials(),
+                    Some(login::LoginAction::AutoConnectChanged) => {
+                        self.save_config();
+                    }
+                    Some(login::LoginAction::SelectAccount(index)) => {
+                        if let Some(name) = self.config.profiles.get(index).map(|p| p.name.clone()) {
+                            self.switch_account(Some(&name));
+                        }
+                    }
+                    Some(login::LoginAction::NewAccount) => self.switch_account(None),
+                    None => {}
                 }
             });
         } else {
             // Show main application
             
+            // Apply debounced search once typing pauses
+            if let Some(changed_at) = self.search_changed_at {
+                let elapsed = changed_at.elapsed();
+                if elapsed >= SEARCH_DEBOUNCE {
+                    self.search_changed_at = None;
+                    self.filter_content();
+                } else {
+                    ctx.request_repaint_after(SEARCH_DEBOUNCE - elapsed);
+                }
+            }
+            super::highlight::set_query(ctx, &self.search_query);
+            hover_preview::set_enabled(ctx, self.config.card_hover_preview);
+            
+            // On-screen keyboard - opens when the search field is focused in touch mode
+            let search_id = egui::Id::new(top_nav::SEARCH_INPUT_ID);
+            if is_touch_mode && ctx.memory(|m| m.has_focus(search_id)) {
+                self.show_on_screen_keyboard = true;
+            }
+            if self.show_on_screen_keyboard {
+                egui::TopBottomPanel::bottom("on_screen_keyboard")
+                    .frame(egui::Frame::none()
+                        .fill(if self.dark_mode {
+                            egui::Color32::from_rgb(22, 22, 22)
+                        } else {
+                            egui::Color32::from_rgb(250, 250, 250)
+                        })
+                        .inner_margin(egui::Margin::same(16.0)))
+                    .show(ctx, |ui| {
+                        match OnScreenKeyboard::show(ui, &theme, &mut self.search_query) {
+                            Some(KeyboardAction::Changed) => {
+                                self.search_changed_at = Some(std::time::Instant::now());
+                            }
+                            Some(KeyboardAction::Close) => {
+                                self.show_on_screen_keyboard = false;
+                                ctx.memory_mut(|m| m.surrender_focus(search_id));
+                            }
+                            None => {}
+                        }
+                    });
+            }
+            
+            self.render_mini_player(ctx, &theme);
+            
             // Sidebar - on mobile/touch show as overlay when sidebar_visible is true
             // On desktop, always show
             let show_sidebar = if is_mobile || is_touch_mode { self.sidebar_visible } else { true };
@@ -1734,24 +6098,41 @@ impl eframe::App for IPTVPlayerApp {
                             ContentType::Discover => Vec::new(), // Discover doesn't use categories
                             ContentType::FootballFixtures => Vec::new(), // Football fixtures don't use categories
                             ContentType::ContinueWatching => Vec::new(), // Continue watching doesn't use categories
+                            ContentType::OnNow => Vec::new(), // On Now lists all favorites
+                            ContentType::RecentlyAdded => Vec::new(), // Newest across all categories
                         };
                         
                         // Only show category sidebar if not in Discover, Football, or Continue Watching mode
-                        if !matches!(self.current_content, ContentType::Discover | ContentType::FootballFixtures | ContentType::ContinueWatching) {
-                            if let Some(selection) = CategorySidebar::show(
+                        if !matches!(self.current_content, ContentType::Discover | ContentType::FootballFixtures | ContentType::ContinueWatching | ContentType::OnNow | ContentType::RecentlyAdded) {
+                            let section = category_section(self.current_content);
+                            if !self.category_counts.contains_key(section) {
+                                let counts = self.count_categories();
+                                self.category_counts.insert(section, counts);
+                            }
+                            let prefs = CategoryPrefs {
+                                sort: &mut self.config.category_sort,
+                                pinned: self.config.pinned_categories.entry(section.to_string()).or_default(),
+                                counts: &self.category_counts[section],
+                            };
+                            match CategorySidebar::show(
                                 ui,
                                 &theme,
                                 self.current_content,
                                 &categories,
-                                &self.selected_category,
+                                &mut self.selected_categories,
                                 &mut self.category_search,
+                                prefs,
                             ) {
-                                self.selected_category = selection;
-                                self.filter_content();
-                                // Auto-close sidebar on mobile after selection
-                                if is_mobile {
-                                    self.sidebar_visible = false;
+                                Some(CategorySidebarAction::SelectionChanged) => {
+                                    self.filter_content();
+                                    // Auto-close sidebar on mobile after going back to all
+                                    // categories; picking several keeps it open
+                                    if is_mobile && self.selected_categories.is_empty() {
+                                        self.sidebar_visible = false;
+                                    }
                                 }
+                                Some(CategorySidebarAction::OrderChanged) => self.save_config(),
+                                None => {}
                             }
                         } else if matches!(self.current_content, ContentType::FootballFixtures) {
                             // Show Football category selector in sidebar
@@ -1771,9 +6152,20 @@ impl eframe::App for IPTVPlayerApp {
                             ui.add_space(8.0);
                             
                             egui::ScrollArea::vertical().show(ui, |ui| {
+                                let mut competitions_heading_shown = false;
                                 for category in FootballCategory::all() {
                                     let is_selected = self.football_category == *category;
                                     
+                                    // Heading between date filters and competitions
+                                    if category.is_competition() && !competitions_heading_shown {
+                                        competitions_heading_shown = true;
+                                        ui.add_space(8.0);
+                                        ui.label(egui::RichText::new("COMPETITIONS")
+                                            .size(10.0)
+                                            .color(theme.text_secondary));
+                                        ui.add_space(4.0);
+                                    }
+                                    
                                     // Netflix-style button with underline on hover
                                     let text_color = if is_selected {
                                         theme.accent_blue
@@ -1916,42 +6308,86 @@ impl eframe::App for IPTVPlayerApp {
                             .fill(theme.panel_bg)
                             .inner_margin(nav_margin))
                         .show_inside(ui, |ui| {
-                            if let Some(action) = TopNavigation::show(
-                                ui,
-                                &theme,
-                                self.current_content,
-                                &mut self.search_query,
-                                is_mobile,
-                                is_touch_mode,
-                            ) {
+                            let can_go_back = self.can_go_back();
+                            let mut back_clicked = false;
+                            let nav_action = ui.horizontal(|ui| {
+                                if can_go_back {
+                                    let size = if is_touch_mode { 48.0 } else { 40.0 };
+                                    let back_btn = egui::Button::new(
+                                        egui::RichText::new("←").size(20.0).color(theme.text_primary),
+                                    )
+                                    .fill(egui::Color32::TRANSPARENT)
+                                    .min_size(egui::vec2(size, size));
+                                    back_clicked = ui.add(back_btn).on_hover_text("Back (Esc)").clicked();
+                                }
+                                let accounts: Vec<&str> = self.config.profiles.iter().map(|p| p.name.as_str()).collect();
+                                TopNavigation::show(
+                                    ui,
+                                    &theme,
+                                    self.current_content,
+                                    &mut self.search_query,
+                                    is_mobile,
+                                    is_touch_mode,
+                                    top_nav::NavStatus {
+                                        recording_count: self.recordings.len(),
+                                        accounts: &accounts,
+                                        active_account: self.config.active_profile.as_deref(),
+                                    },
+                                    top_nav::NavFilters {
+                                        quality: &mut self.quality_filter,
+                                        sort: &mut self.sort_order,
+                                        favorites_only: &mut self.favorites_only,
+                                        search_all: &mut self.search_all,
+                                    },
+                                )
+                            }).inner;
+                            if back_clicked {
+                                self.go_back(is_touch_mode);
+                            }
+                            if let Some(action) = nav_action {
                                 match action {
                                     top_nav::NavAction::SwitchContent(content_type) => {
-                                        self.current_content = content_type;
-                                        self.selected_category = None;
-                                        match content_type {
-                                            ContentType::Series if self.all_series.is_empty() => {
-                                                self.load_series();
-                                            }
-                                            ContentType::Movies if self.all_movies.is_empty() => {
-                                                self.load_movies();
-                                            }
-                                            _ => {}
-                                        }
-                                        self.filter_content();
+                                        self.switch_content(content_type);
                                     }
                                     top_nav::NavAction::SearchChanged => {
+                                        self.search_changed_at = Some(std::time::Instant::now());
+                                    }
+                                    top_nav::NavAction::QualityFilterChanged
+                                    | top_nav::NavAction::FavoritesOnlyChanged
+                                    | top_nav::NavAction::SearchAllChanged => {
                                         self.filter_content();
                                     }
+                                    top_nav::NavAction::SurpriseMe => {
+                                        self.surprise_me();
+                                    }
+                                    top_nav::NavAction::SortChanged => {
+                                        self.sort_filtered();
+                                        self.current_page = 0;
+                                    }
                                     top_nav::NavAction::ToggleTheme => {
                                         self.dark_mode = !self.dark_mode;
                                     }
                                     top_nav::NavAction::Disconnect => {
                                         self.disconnect();
                                     }
+                                    top_nav::NavAction::SwitchAccount(name) => {
+                                        self.switch_account(Some(&name));
+                                    }
                                     top_nav::NavAction::OpenPlayerSettings => {
                                         self.temp_player_settings = Some(self.config.player_settings.clone());
                                         self.show_player_settings = true;
                                     }
+                                    top_nav::NavAction::OpenAppSettings => {
+                                        self.temp_config = Some(app_settings::AppSettings::from_config(&self.config));
+                                        self.show_app_settings = true;
+                                    }
+                                    top_nav::NavAction::ShowRecordings => {
+                                        self.show_recordings = !self.show_recordings;
+                                    }
+                                    top_nav::NavAction::ShowWatchStats => {
+                                        let now = chrono::Utc::now().timestamp();
+                                        self.watch_stats = Some(self.watch_history.stats(now, WATCH_STATS_TOP_SERIES));
+                                    }
                                     top_nav::NavAction::OpenEpgSettings => {
                                         self.temp_epg_enabled = self.config.epg_enabled;
                                         self.temp_epg_url = self.config.epg_url.clone().unwrap_or_default();
@@ -1959,24 +6395,83 @@ impl eframe::App for IPTVPlayerApp {
                                     }
                                     top_nav::NavAction::OpenScraperSettings => {
                                         self.show_scraper_settings = true;
+                                        self.check_scraper_setup();
                                     }
                                     top_nav::NavAction::ToggleSidebar => {
                                         self.sidebar_visible = !self.sidebar_visible;
                                     }
+                                    top_nav::NavAction::ToggleFullscreen => {
+                                        self.toggle_fullscreen(ctx);
+                                    }
                                 }
                             }
                         });
                     
+                    // A–Z index strip for long lists
+                    self.render_alpha_index(ui, &theme, is_touch_mode);
+                    
                     // Content area
                     self.render_content(ui, ctx, &theme);
                 });
             
+            // Active recordings panel
+            if self.show_recordings {
+                self.render_recordings_panel(ctx);
+            }
+            
+            // "Play next" prompt after an episode finishes
+            if self.next_episode_prompt.is_some() {
+                self.render_next_episode_prompt(ctx);
+            }
+            
+            // Programme reminders that went off
+            if !self.due_reminders.is_empty() {
+                self.render_reminders(ctx);
+            }
+            
+            if let Some(stats) = &self.watch_stats {
+                if WatchStatsDialog::show(ctx, stats, &theme) {
+                    self.watch_stats = None;
+                }
+            }
+            
+            if let Some(notes) = self.whats_new {
+                if WhatsNew::show(ctx, env!("CARGO_PKG_VERSION"), notes, &theme) {
+                    self.dismiss_whats_new();
+                }
+            }
+            
+            // Keyboard shortcuts overlay
+            if self.show_shortcuts_help && ShortcutsHelp::show(ctx) {
+                self.show_shortcuts_help = false;
+            }
+            
             // Episode dialog - use cached state for fast rendering
             if let Some(ref mut state) = self.episode_dialog_state {
-                if let Some(action) = EpisodeDialog::show(ctx, state, &self.config.player_settings) {
+                if let Some(action) = EpisodeDialog::show(ctx, state, &self.config.player_settings, &self.watch_history, &self.image_cache) {
                     match action {
-                        episode_dialog::EpisodeAction::PlayEpisode { episode_id, series_name, season, episode, title, container } => {
-                            self.play_episode(&episode_id, &series_name, season, episode, &title, &container);
+                        episode_dialog::EpisodeAction::PlayEpisode { season, episode, .. } => {
+                            let upcoming = state.episodes_after(season, episode);
+                            self.start_episode(action, upcoming);
+                        }
+                        episode_dialog::EpisodeAction::PlaySeasonFrom { season, episode } => {
+                            let queue = state.season_from(season, episode);
+                            // Whatever follows the season, for the "Play next" prompt
+                            let upcoming = match queue.last() {
+                                Some(episode_dialog::EpisodeAction::PlayEpisode { season, episode, .. }) => {
+                                    state.episodes_after(*season, *episode)
+                                }
+                                _ => Vec::new(),
+                            };
+                            self.play_season(queue, upcoming);
+                        }
+                        episode_dialog::EpisodeAction::CopyUrl { episode_id, title, container } => {
+                            let url = XtreamClient::new(
+                                self.server_url.clone(),
+                                self.username.clone(),
+                                self.password.clone(),
+                            ).get_episode_url(&episode_id, &container);
+                            self.copy_stream_url(ctx, url, &title);
                         }
                         episode_dialog::EpisodeAction::Close => {
                             self.episode_dialog_state = None;
@@ -1985,6 +6480,12 @@ impl eframe::App for IPTVPlayerApp {
                 }
             }
             
+            // Track picker before VOD playback
+            self.render_track_selection(ctx);
+            
+            // Catch-up archive browser
+            self.render_catchup(ctx);
+            
             // Player settings dialog
             if self.show_player_settings {
                 if let Some(ref mut temp_settings) = self.temp_player_settings {
@@ -1992,7 +6493,7 @@ impl eframe::App for IPTVPlayerApp {
                         match action {
                             player_settings::PlayerSettingsAction::Saved => {
                                 self.config.player_settings = temp_settings.clone();
-                                let _ = self.config.save();
+                                self.save_config();
                                 self.show_player_settings = false;
                                 self.temp_player_settings = None;
                             }
@@ -2008,6 +6509,67 @@ impl eframe::App for IPTVPlayerApp {
                 }
             }
 
+            // App settings dialog
+            if self.show_app_settings {
+                if let Some(ref mut temp_config) = self.temp_config {
+                    if let Some(action) = AppSettingsDialog::show(ctx, temp_config) {
+                        match action {
+                            app_settings::AppSettingsAction::Saved => {
+                                let catalog_mode_changed = self.config.load_vod_by_category != temp_config.load_vod_by_category;
+                                if self.config.content_cache_enabled && !temp_config.content_cache_enabled {
+                                    // Nothing reads the cache any more; don't leave it lying around
+                                    if let Err(e) = crate::models::ContentCache::clear_all() {
+                                        log::warn!("[Cache] Couldn't clear the library cache: {}", e);
+                                    }
+                                }
+                                let filters_changed = self.config.hide_duplicates != temp_config.hide_duplicates
+                                    || !temp_config.unhidden.is_empty();
+                                if self.config.sync_target != temp_config.sync_target {
+                                    self.sync.due = Some(std::time::Instant::now());
+                                    self.sync.failing = false;
+                                }
+                                temp_config.apply_to(&mut self.config);
+                                self.save_config();
+                                crate::api::http::configure(&self.config);
+                                crate::ui::image_cache::configure(&self.config);
+                                crate::ui::i18n::configure(&self.config);
+                                crate::models::watch_history::configure(&self.config);
+                                super::locale::configure(&self.config);
+                                dimensions::configure(&self.config);
+                                crate::logging::set_level(self.config.log_level);
+                                if catalog_mode_changed {
+                                    self.reload_vod_library();
+                                }
+                                if filters_changed {
+                                    self.filter_content();
+                                }
+                                self.show_app_settings = false;
+                                self.temp_config = None;
+                            }
+                            app_settings::AppSettingsAction::Cancelled => {
+                                self.show_app_settings = false;
+                                self.temp_config = None;
+                            }
+                            app_settings::AppSettingsAction::CheckForUpdates => {
+                                self.check_for_updates(true);
+                            }
+                            app_settings::AppSettingsAction::ClearAllCaches => {
+                                self.clear_all_caches();
+                            }
+                            app_settings::AppSettingsAction::ClearImageCache => {
+                                match crate::ui::image_cache::clear_disk_cache() {
+                                    Ok(freed) => self.toasts.success(format!(
+                                        "Cleared {} MB of cached images",
+                                        freed / (1024 * 1024),
+                                    )),
+                                    Err(e) => self.toasts.error(e),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // EPG settings dialog
             if self.show_epg_settings {
                 if let Some(action) = EpgSettingsDialog::show(
@@ -2023,7 +6585,7 @@ impl eframe::App for IPTVPlayerApp {
                             } else {
                                 Some(self.temp_epg_url.clone())
                             };
-                            let _ = self.config.save();
+                            self.save_config();
 
                             // Update EPG cache with new settings
                             if self.config.epg_enabled {
@@ -2043,63 +6605,33 @@ impl eframe::App for IPTVPlayerApp {
 
             // Scraper settings dialog
             if self.show_scraper_settings {
-                let mut should_close = false;
-                let mut should_trigger = false;
+                let mut action = None;
                 egui::Window::new("Football Fixtures Scraper")
                     .open(&mut self.show_scraper_settings)
                     .resizable(true)
                     .default_width(400.0)
                     .show(ctx, |ui| {
-                        // Show current scraping status
-                        ui.heading("📊 Football Fixtures Scraper");
-                        ui.separator();
-                        
-                        if !self.scraper_manager.is_available() {
-                            ui.label(egui::RichText::new("⚠️ Soccer Scraper not found")
-                                .color(theme.warning_color));
-                            ui.label("Ensure Soccer-Scraper-main directory exists in the app folder");
-                        } else if self.scraping_in_progress {
-                            ui.label(egui::RichText::new("⏳ Scraping in progress...")
-                                .color(theme.accent_blue));
-                            ui.label("Opening Chrome browser to bypass Cloudflare...");
-                            ui.label("Please wait, this may take 2 minutes");
-                        } else {
-                            ui.label("✅ Scraper ready");
-                            ui.label("Click below to download today's fixtures from LiveSoccerTV");
-                            
-                            ui.add_space(8.0);
-                            if ui.button("🔄 Scrape Fixtures Now").clicked() {
-                                should_trigger = true;
-                            }
-                            ui.label("⏱️ Takes approximately 2 minutes");
-                        }
-                        
-                        // Show last message
-                        if let Some(msg) = &self.scraper_message {
-                            ui.add_space(8.0);
-                            let color = if msg.starts_with("Error") {
-                                theme.error_color
-                            } else if msg.contains("successfully") {
-                                theme.success_color
-                            } else {
-                                theme.text_secondary
-                            };
-                            ui.label(egui::RichText::new(msg).color(color));
-                        }
-                        
-                        ui.separator();
-                        if ui.button("Close").clicked() {
-                            should_close = true;
-                        }
+                        action = ScraperSettingsDialog::show(
+                            ui,
+                            &theme,
+                            self.scraper_setup.as_ref(),
+                            self.scraping_in_progress,
+                            self.scraper_installing,
+                            self.scraper_message.as_deref(),
+                        );
                     });
                 
-                if should_trigger {
-                    self.trigger_scraper();
-                }
-                if should_close {
-                    self.show_scraper_settings = false;
+                match action {
+                    Some(scraper_settings::ScraperAction::TriggerScrape) => self.trigger_scraper(),
+                    Some(scraper_settings::ScraperAction::Install) => self.install_scraper(),
+                    Some(scraper_settings::ScraperAction::Recheck) => self.check_scraper_setup(),
+                    Some(scraper_settings::ScraperAction::Close) => self.show_scraper_settings = false,
+                    None => {}
                 }
             }
         }
+        
+        // Transient notifications, on top of everything else
+        self.toasts.show(ctx);
     }
 }
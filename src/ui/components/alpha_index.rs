@@ -0,0 +1,119 @@
+//! Alphabetical fast-scroll index - A–Z strip beside the content grid
+//!
+//! Tapping (or dragging across) a letter jumps to the page holding the first
+//! item under that letter, like the index on iOS contact lists. The lists it
+//! indexes are kept sorted by [`sort_key`].
+
+use eframe::egui;
+use crate::ui::theme::{Theme, typography};
+
+/// Index entries, top to bottom. `#` collects names starting with a digit or symbol.
+pub const INDEX_LETTERS: [char; 27] = [
+    '#', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+/// Sort key for a display name: lowercase, ignoring leading punctuation
+/// such as "|UK| " or "- ".
+pub fn sort_key(name: &str) -> String {
+    name.trim_start_matches(|c: char| !c.is_alphanumeric()).to_lowercase()
+}
+
+/// Sort items alphabetically by name (case-insensitive).
+pub fn sort_by_name<T>(items: &mut [T], name: impl Fn(&T) -> &str) {
+    items.sort_by_cached_key(|item| sort_key(name(item)));
+}
+
+/// Index letter a name is filed under (`#` for digits and other characters).
+pub fn index_letter(name: &str) -> char {
+    match name.chars().find(|c| c.is_alphanumeric()) {
+        Some(c) if c.is_ascii_alphabetic() => c.to_ascii_uppercase(),
+        _ => '#',
+    }
+}
+
+/// Position of the first name filed under `letter`.
+pub fn first_index_for_letter<'a>(names: impl IntoIterator<Item = &'a str>, letter: char) -> Option<usize> {
+    names.into_iter().position(|name| index_letter(name) == letter)
+}
+
+/// A–Z index strip component.
+pub struct AlphaIndex;
+
+impl AlphaIndex {
+    /// Renders the index strip. `available` holds the letters that have at
+    /// least one item, `current` the letter of the item at the top of the
+    /// current page. Returns the letter that was tapped.
+    pub fn show(
+        ui: &mut egui::Ui,
+        theme: &Theme,
+        available: &[char],
+        current: Option<char>,
+        is_touch_mode: bool,
+    ) -> Option<char> {
+        let width = if is_touch_mode { 44.0 } else { 24.0 };
+        let max_row = if is_touch_mode { 32.0 } else { 22.0 };
+        let row_height = (ui.available_height() / INDEX_LETTERS.len() as f32).clamp(12.0, max_row);
+        let font_size = if is_touch_mode { typography::BODY_SM } else { typography::LABEL };
+
+        let (rect, response) = ui.allocate_exact_size(
+            egui::vec2(width, row_height * INDEX_LETTERS.len() as f32),
+            egui::Sense::click_and_drag(),
+        );
+
+        // Letter under the pointer while tapping or scrubbing
+        let mut picked: Option<char> = None;
+        if response.clicked() || response.dragged() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                let row = ((pos.y - rect.min.y) / row_height).floor();
+                if row >= 0.0 {
+                    picked = INDEX_LETTERS.get(row as usize).copied();
+                }
+            }
+        }
+
+        for (i, letter) in INDEX_LETTERS.iter().enumerate() {
+            let center = egui::pos2(rect.center().x, rect.min.y + row_height * (i as f32 + 0.5));
+            let has_items = available.contains(letter);
+            let is_current = current == Some(*letter);
+
+            if is_current {
+                ui.painter().circle_filled(center, row_height.min(width) / 2.0, theme.accent_blue);
+            }
+
+            let color = if is_current {
+                egui::Color32::WHITE
+            } else if has_items {
+                theme.text_secondary
+            } else {
+                theme.text_muted.gamma_multiply(0.5)
+            };
+            ui.painter().text(
+                center,
+                egui::Align2::CENTER_CENTER,
+                letter,
+                egui::FontId::proportional(font_size),
+                color,
+            );
+        }
+
+        response.on_hover_cursor(egui::CursorIcon::PointingHand);
+
+        picked.filter(|letter| available.contains(letter) && current != Some(*letter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_index_for_letter() {
+        let mut names = vec!["Zulu", "alpha", "- Bravo", "42"];
+        sort_by_name(&mut names, |n| n);
+        assert_eq!(names, vec!["42", "alpha", "- Bravo", "Zulu"]);
+        assert_eq!(first_index_for_letter(names.iter().copied(), 'B'), Some(2));
+        assert_eq!(first_index_for_letter(names.iter().copied(), 'Q'), None);
+        assert_eq!(index_letter("|UK| BBC One"), 'U');
+    }
+}
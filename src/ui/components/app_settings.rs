@@ -0,0 +1,821 @@
+//! General application settings dialog (Netflix-style).
+//!
+//! Edits an [`AppSettings`] taken from the `Config`; the caller copies it
+//! back on save.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use eframe::egui;
+use crate::models::{CatchupUrlFormat, ChannelMapping, Config, DefaultContent, LiveStreamFormat, LogLevel, StartupWindowMode};
+use crate::ui::i18n::{self, t};
+use crate::ui::messages::ContentType;
+use crate::ui::theme::dimensions;
+
+/// Actions returned by the app settings dialog.
+#[derive(Debug, Clone)]
+pub enum AppSettingsAction {
+    /// Settings were saved
+    Saved,
+    /// Dialog was cancelled
+    Cancelled,
+    /// Look for a newer release now
+    CheckForUpdates,
+    /// Delete the posters and logos kept on disk
+    ClearImageCache,
+    /// Delete the cached library, images and programme guide
+    ClearAllCaches,
+}
+
+/// The part of the `Config` the dialog edits. Only these fields are
+/// copied back on save, so whatever the main window changes while the
+/// dialog is open (favorites, a sync, newly hidden channels) is kept.
+#[derive(Debug, Clone)]
+pub struct AppSettings {
+    pub allow_insecure_tls: bool,
+    pub blur_adult_content: bool,
+    pub card_hover_preview: bool,
+    pub catchup_url_format: CatchupUrlFormat,
+    pub channel_mappings: Vec<ChannelMapping>,
+    pub check_for_updates: bool,
+    pub content_cache_enabled: bool,
+    pub content_cache_ttl_hours: u64,
+    pub continue_min_percent: u32,
+    pub default_content: DefaultContent,
+    pub football_local_time: bool,
+    pub football_refresh_minutes: u32,
+    pub football_scrape_hours: u32,
+    pub grid_columns: u32,
+    pub hide_duplicates: bool,
+    pub image_cache_mb: u64,
+    pub image_downloads: usize,
+    pub language: Option<String>,
+    pub live_stream_format: LiveStreamFormat,
+    pub load_vod_by_category: bool,
+    pub locale: Option<String>,
+    pub log_level: LogLevel,
+    pub network_timeout_secs: u64,
+    pub new_content_days: u32,
+    pub proxy_url: Option<String>,
+    pub recordings_dir: Option<String>,
+    pub referer: Option<String>,
+    pub reminder_auto_tune: bool,
+    pub startup_window: StartupWindowMode,
+    pub stick_dead_zone: f32,
+    pub stick_scroll_sensitivity: f32,
+    pub stream_preflight: bool,
+    pub sync_target: Option<String>,
+    pub touch_mode: bool,
+    pub touch_target_size: f32,
+    pub user_agent: String,
+    pub watched_percent: u32,
+    /// Hidden channels, listed so they can be unhidden
+    pub hidden_channels: BTreeMap<String, String>,
+    /// Channels unhidden in the dialog
+    pub unhidden: BTreeSet<String>,
+}
+
+impl AppSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            allow_insecure_tls: config.allow_insecure_tls,
+            blur_adult_content: config.blur_adult_content,
+            card_hover_preview: config.card_hover_preview,
+            catchup_url_format: config.catchup_url_format,
+            channel_mappings: config.channel_mappings.clone(),
+            check_for_updates: config.check_for_updates,
+            content_cache_enabled: config.content_cache_enabled,
+            content_cache_ttl_hours: config.content_cache_ttl_hours,
+            continue_min_percent: config.continue_min_percent,
+            default_content: config.default_content,
+            football_local_time: config.football_local_time,
+            football_refresh_minutes: config.football_refresh_minutes,
+            football_scrape_hours: config.football_scrape_hours,
+            grid_columns: config.grid_columns,
+            hide_duplicates: config.hide_duplicates,
+            image_cache_mb: config.image_cache_mb,
+            image_downloads: config.image_downloads,
+            language: config.language.clone(),
+            live_stream_format: config.live_stream_format,
+            load_vod_by_category: config.load_vod_by_category,
+            locale: config.locale.clone(),
+            log_level: config.log_level,
+            network_timeout_secs: config.network_timeout_secs,
+            new_content_days: config.new_content_days,
+            proxy_url: config.proxy_url.clone(),
+            recordings_dir: config.recordings_dir.clone(),
+            referer: config.referer.clone(),
+            reminder_auto_tune: config.reminder_auto_tune,
+            startup_window: config.startup_window,
+            stick_dead_zone: config.stick_dead_zone,
+            stick_scroll_sensitivity: config.stick_scroll_sensitivity,
+            stream_preflight: config.stream_preflight,
+            sync_target: config.sync_target.clone(),
+            touch_mode: config.touch_mode,
+            touch_target_size: config.touch_target_size,
+            user_agent: config.user_agent.clone(),
+            watched_percent: config.watched_percent,
+            hidden_channels: config.hidden_channels.clone(),
+            unhidden: BTreeSet::new(),
+        }
+    }
+
+    /// Copies the edited settings into `config`.
+    pub fn apply_to(&self, config: &mut Config) {
+        config.allow_insecure_tls = self.allow_insecure_tls;
+        config.blur_adult_content = self.blur_adult_content;
+        config.card_hover_preview = self.card_hover_preview;
+        config.catchup_url_format = self.catchup_url_format;
+        config.channel_mappings = self.channel_mappings.clone();
+        config.check_for_updates = self.check_for_updates;
+        config.content_cache_enabled = self.content_cache_enabled;
+        config.content_cache_ttl_hours = self.content_cache_ttl_hours;
+        config.continue_min_percent = self.continue_min_percent;
+        config.default_content = self.default_content;
+        config.football_local_time = self.football_local_time;
+        config.football_refresh_minutes = self.football_refresh_minutes;
+        config.football_scrape_hours = self.football_scrape_hours;
+        config.grid_columns = self.grid_columns;
+        config.hide_duplicates = self.hide_duplicates;
+        config.image_cache_mb = self.image_cache_mb;
+        config.image_downloads = self.image_downloads;
+        config.language = self.language.clone();
+        config.live_stream_format = self.live_stream_format;
+        config.load_vod_by_category = self.load_vod_by_category;
+        config.locale = self.locale.clone();
+        config.log_level = self.log_level;
+        config.network_timeout_secs = self.network_timeout_secs;
+        config.new_content_days = self.new_content_days;
+        config.proxy_url = self.proxy_url.clone();
+        config.recordings_dir = self.recordings_dir.clone();
+        config.referer = self.referer.clone();
+        config.reminder_auto_tune = self.reminder_auto_tune;
+        config.startup_window = self.startup_window;
+        config.stick_dead_zone = self.stick_dead_zone;
+        config.stick_scroll_sensitivity = self.stick_scroll_sensitivity;
+        config.stream_preflight = self.stream_preflight;
+        config.sync_target = self.sync_target.clone();
+        config.touch_mode = self.touch_mode;
+        config.touch_target_size = self.touch_target_size;
+        config.user_agent = self.user_agent.clone();
+        config.watched_percent = self.watched_percent;
+        for stream_id in &self.unhidden {
+            config.hidden_channels.remove(stream_id);
+        }
+    }
+}
+
+/// App settings dialog component.
+pub struct AppSettingsDialog;
+
+impl AppSettingsDialog {
+    /// Renders a section header in Netflix style.
+    fn section_header(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .size(14.0)
+            .color(egui::Color32::from_rgb(180, 180, 180))
+            .strong());
+        ui.add_space(4.0);
+    }
+
+    /// Renders a grey hint next to a setting.
+    fn hint(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .size(11.0)
+            .color(egui::Color32::from_rgb(120, 120, 120)));
+    }
+
+    /// Runs `add_contents` inside a section card.
+    fn section(ui: &mut egui::Ui, add_contents: impl FnOnce(&mut egui::Ui)) {
+        egui::Frame::none()
+            .fill(egui::Color32::from_rgb(35, 35, 35))
+            .rounding(egui::Rounding::same(6.0))
+            .inner_margin(egui::Margin::same(16.0))
+            .show(ui, |ui| {
+                ui.set_min_width(460.0);
+                add_contents(ui);
+            });
+    }
+
+    /// Shows the app settings dialog.
+    /// Returns an action if the dialog was closed.
+    /// Drop-down of "Off" or "Every N <unit>" choices; 0 means off.
+    fn interval_combo(ui: &mut egui::Ui, id: &str, value: &mut u32, choices: &[u32], unit: &str) {
+        let label = |v: u32| if v == 0 { "Off".to_string() } else { format!("Every {} {}", v, unit) };
+        egui::ComboBox::from_id_salt(id)
+            .selected_text(label(*value))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(value, 0, label(0));
+                for &choice in choices {
+                    ui.selectable_value(value, choice, label(choice));
+                }
+            });
+    }
+
+    pub fn show(ctx: &egui::Context, settings: &mut AppSettings) -> Option<AppSettingsAction> {
+        let mut action = None;
+
+        egui::Window::new("")
+            .id(egui::Id::new("app_settings"))
+            .resizable(true)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(520.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .frame(egui::Frame::none()
+                .fill(egui::Color32::from_rgb(24, 24, 24))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(24.0)))
+            .show(ctx, |ui| {
+                // Header
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(t("settings.title"))
+                        .size(22.0)
+                        .color(egui::Color32::WHITE)
+                        .strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.add(egui::Button::new(
+                            egui::RichText::new("✕").size(16.0).color(egui::Color32::WHITE)
+                        ).fill(egui::Color32::TRANSPARENT)).clicked() {
+                            action = Some(AppSettingsAction::Cancelled);
+                        }
+                    });
+                });
+
+                ui.add_space(20.0);
+
+                egui::ScrollArea::vertical().max_height(450.0).show(ui, |ui| {
+                    ui.spacing_mut().item_spacing.y = 12.0;
+
+                    // Network Section
+                    Self::section_header(ui, t("settings.network"));
+                    Self::section(ui, |ui| {
+                        let mut proxy = settings.proxy_url.clone().unwrap_or_default();
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.proxy"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            let edit = egui::TextEdit::singleline(&mut proxy)
+                                .hint_text("http://host:8080 or socks5://host:1080")
+                                .desired_width(300.0);
+                            if ui.add(edit).changed() {
+                                settings.proxy_url = if proxy.trim().is_empty() { None } else { Some(proxy.clone()) };
+                            }
+                        });
+                        if let Some(Err(e)) = settings.proxy_url.as_deref().map(crate::api::http::validate_proxy) {
+                            ui.label(egui::RichText::new(e)
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(255, 99, 88)));
+                        } else {
+                            Self::hint(ui, t("settings.proxy_hint"));
+                        }
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.timeout"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.network_timeout_secs, 5..=300)
+                                .suffix(" s"));
+                        });
+                        Self::hint(ui, t("settings.timeout_hint"));
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("User-Agent:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::TextEdit::singleline(&mut settings.user_agent)
+                                .hint_text(crate::models::config::DEFAULT_USER_AGENT)
+                                .desired_width(260.0));
+                            if settings.user_agent != crate::models::config::DEFAULT_USER_AGENT
+                                && ui.small_button("Reset").clicked()
+                            {
+                                settings.user_agent = crate::models::config::DEFAULT_USER_AGENT.to_string();
+                            }
+                        });
+                        let mut referer = settings.referer.clone().unwrap_or_default();
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Referer:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            let edit = egui::TextEdit::singleline(&mut referer)
+                                .hint_text("Optional, e.g. http://provider.example/")
+                                .desired_width(260.0);
+                            if ui.add(edit).changed() {
+                                settings.referer = if referer.trim().is_empty() { None } else { Some(referer.clone()) };
+                            }
+                        });
+                        Self::hint(ui, t("settings.headers_hint"));
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.image_downloads"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.image_downloads, 1..=crate::ui::image_cache::MAX_WORKERS)
+                                .suffix(t("settings.at_a_time")));
+                        });
+                        Self::hint(ui, t("settings.image_downloads_hint"));
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.allow_insecure_tls, "");
+                            ui.label(egui::RichText::new(t("settings.insecure_tls"))
+                                .color(egui::Color32::WHITE));
+                        });
+                        if settings.allow_insecure_tls {
+                            ui.label(egui::RichText::new(t("settings.insecure_tls_warning"))
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(255, 180, 0)));
+                        } else {
+                            Self::hint(ui, t("settings.insecure_tls_hint"));
+                        }
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.live_format"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            egui::ComboBox::from_id_salt("live_stream_format")
+                                .selected_text(settings.live_stream_format.display_name())
+                                .show_ui(ui, |ui| {
+                                    for format in [LiveStreamFormat::Ts, LiveStreamFormat::Hls] {
+                                        ui.selectable_value(&mut settings.live_stream_format, format, format.display_name());
+                                    }
+                                });
+                        });
+                        Self::hint(ui, t("settings.live_format_hint"));
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.catchup_format"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            egui::ComboBox::from_id_salt("catchup_url_format")
+                                .selected_text(settings.catchup_url_format.display_name())
+                                .show_ui(ui, |ui| {
+                                    for format in CatchupUrlFormat::ALL {
+                                        ui.selectable_value(&mut settings.catchup_url_format, format, format.display_name());
+                                    }
+                                });
+                        });
+                        Self::hint(ui, t("settings.catchup_format_hint"));
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.stream_preflight, "");
+                            ui.label(egui::RichText::new(t("settings.preflight"))
+                                .color(egui::Color32::WHITE));
+                        });
+                        Self::hint(ui, t("settings.preflight_hint"));
+                    });
+
+                    // Window Section
+                    Self::section_header(ui, t("settings.window"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.open_window"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            for mode in StartupWindowMode::ALL {
+                                ui.selectable_value(&mut settings.startup_window, mode, mode.label());
+                            }
+                        });
+                        Self::hint(ui, t("settings.open_window_hint"));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.open_to"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            egui::ComboBox::from_id_salt("default_content")
+                                .selected_text(ContentType::from(settings.default_content).title())
+                                .show_ui(ui, |ui| {
+                                    for content in DefaultContent::ALL {
+                                        ui.selectable_value(&mut settings.default_content, content, ContentType::from(content).title());
+                                    }
+                                });
+                        });
+                        Self::hint(ui, t("settings.open_to_hint"));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.language"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            let selected = settings.language.as_deref()
+                                .and_then(|code| i18n::LANGUAGES.iter().find(|(c, _)| *c == code))
+                                .map_or(t("settings.system"), |(_, name)| *name);
+                            egui::ComboBox::from_id_salt("language")
+                                .selected_text(selected)
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut settings.language, None, t("settings.system"));
+                                    for (code, name) in i18n::LANGUAGES {
+                                        ui.selectable_value(&mut settings.language, Some(code.to_string()), *name);
+                                    }
+                                });
+                        });
+                        Self::hint(ui, t("settings.language_hint"));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.format"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            let system = format!(
+                                "{} ({})",
+                                t("settings.system"),
+                                crate::ui::locale::system_locale().unwrap_or_else(|| "unknown".to_string()),
+                            );
+                            let selected = match &settings.locale {
+                                Some(tag) => crate::ui::locale::PRESETS.iter()
+                                    .find(|(preset, _)| preset == tag)
+                                    .map_or_else(|| tag.clone(), |(_, name)| name.to_string()),
+                                None => system.clone(),
+                            };
+                            egui::ComboBox::from_id_salt("locale")
+                                .selected_text(selected)
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut settings.locale, None, system);
+                                    for (tag, name) in crate::ui::locale::PRESETS {
+                                        ui.selectable_value(&mut settings.locale, Some(tag.to_string()), *name);
+                                    }
+                                });
+                        });
+                        Self::hint(ui, t("settings.format_hint"));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.grid_columns"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.selectable_value(&mut settings.grid_columns, 0, t("settings.auto"));
+                            for columns in 3..=6 {
+                                ui.selectable_value(&mut settings.grid_columns, columns, columns.to_string());
+                            }
+                        });
+                        Self::hint(ui, t("settings.grid_columns_hint"));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.card_hover_preview, "");
+                            ui.label(egui::RichText::new(t("settings.hover_preview"))
+                                .color(egui::Color32::WHITE));
+                        });
+                        Self::hint(ui, t("settings.hover_preview_hint"));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.touch_mode, "");
+                            ui.label(egui::RichText::new(t("settings.touch_mode"))
+                                .color(egui::Color32::WHITE));
+                        });
+                        Self::hint(ui, t("settings.touch_mode_hint"));
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.touch_size"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.touch_target_size, 0.0..=80.0)
+                                .step_by(4.0)
+                                .custom_formatter(|v, _| if v < dimensions::MIN_TOUCH_TARGET as f64 {
+                                    t("settings.auto").to_string()
+                                } else {
+                                    format!("{:.0} pt", v)
+                                }));
+                        });
+                        Self::hint(ui, t("settings.touch_size_hint"));
+                    });
+
+                    // Library Section
+                    Self::section_header(ui, t("settings.library"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.load_vod_by_category, "");
+                            ui.label(egui::RichText::new("Load movies and series per category")
+                                .color(egui::Color32::WHITE));
+                        });
+                        Self::hint(ui, "For very large catalogs: only the categories you open are fetched. \
+                                        Use \"Load all\" in the view to search everything");
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Mark as New For:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.new_content_days, 1..=30).suffix(" days"));
+                        });
+                        Self::hint(ui, "Movies and series added this recently show a NEW badge");
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Counts as Watched At:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.watched_percent, 50..=100).suffix("%"));
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Continue Watching From:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.continue_min_percent, 0..=20).suffix("%"));
+                        });
+                        Self::hint(ui, "Titles past the first value are treated as watched and leave Continue Watching; \
+                                        ones under the second are left out as accidental starts");
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.blur_adult_content, "");
+                            ui.label(egui::RichText::new("Blur adult posters")
+                                .color(egui::Color32::WHITE));
+                        });
+                        Self::hint(ui, "Posters in adult categories stay covered until you hover or focus them");
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.hide_duplicates, "");
+                            ui.label(egui::RichText::new("Hide duplicates")
+                                .color(egui::Color32::WHITE));
+                        });
+                        Self::hint(ui, "Channels and movies listed in several categories, or under the same name, show once");
+                        ui.add_space(8.0);
+                        ui.label(egui::RichText::new(format!("Hidden Channels ({}):", settings.hidden_channels.len()))
+                            .color(egui::Color32::from_rgb(180, 180, 180)));
+                        let mut unhide = None;
+                        for (stream_id, name) in &settings.hidden_channels {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(name).color(egui::Color32::WHITE));
+                                if ui.small_button("Unhide").clicked() {
+                                    unhide = Some(stream_id.clone());
+                                }
+                            });
+                        }
+                        if let Some(stream_id) = unhide {
+                            settings.hidden_channels.remove(&stream_id);
+                            settings.unhidden.insert(stream_id);
+                        }
+                        if settings.hidden_channels.len() > 1 && ui.small_button("Unhide all").clicked() {
+                            let hidden = std::mem::take(&mut settings.hidden_channels);
+                            settings.unhidden.extend(hidden.into_keys());
+                        }
+                        Self::hint(ui, "Hide dead channels from a channel's right-click menu or with \"Hide offline\" after checking channels");
+                    });
+
+                    // Storage Section
+                    Self::section_header(ui, t("settings.storage"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.content_cache_enabled, "");
+                            ui.label(egui::RichText::new(t("settings.content_cache"))
+                                .color(egui::Color32::WHITE));
+                        });
+                        ui.add_enabled_ui(settings.content_cache_enabled, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new("Refresh Library Every:")
+                                    .color(egui::Color32::from_rgb(180, 180, 180)));
+                                ui.add_space(10.0);
+                                for (hours, label) in [(1, "1h"), (6, "6h"), (12, "12h"), (24, "24h"), (72, "3 days")] {
+                                    ui.selectable_value(&mut settings.content_cache_ttl_hours, hours, label);
+                                }
+                            });
+                        });
+                        Self::hint(ui, t("settings.content_cache_hint"));
+
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.image_cache"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.image_cache_mb, 0..=4000)
+                                .step_by(50.0)
+                                .suffix(" MB"));
+                            if ui.small_button(t("settings.clear_image_cache")).clicked() {
+                                action = Some(AppSettingsAction::ClearImageCache);
+                            }
+                        });
+                        Self::hint(ui, t("settings.image_cache_hint"));
+
+                        ui.add_space(8.0);
+                        if ui.button(t("settings.clear_all_caches")).clicked() {
+                            action = Some(AppSettingsAction::ClearAllCaches);
+                        }
+                        Self::hint(ui, t("settings.clear_all_caches_hint"));
+                    });
+
+                    // Sync Section
+                    Self::section_header(ui, t("settings.sync"));
+                    Self::section(ui, |ui| {
+                        let mut target = settings.sync_target.clone().unwrap_or_default();
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(t("settings.sync_target"))
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            let edit = egui::TextEdit::singleline(&mut target)
+                                .hint_text("/path/to/folder or https://host/dav/")
+                                .desired_width(300.0);
+                            if ui.add(edit).changed() {
+                                settings.sync_target = if target.trim().is_empty() { None } else { Some(target.trim().to_string()) };
+                            }
+                        });
+                        Self::hint(ui, t("settings.sync_hint"));
+                    });
+
+                    // Controller Section
+                    Self::section_header(ui, t("settings.controller"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Stick Dead Zone:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.stick_dead_zone, 0.0..=0.5)
+                                .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)));
+                        });
+                        Self::hint(ui, "Stick movement below this is ignored");
+
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Scroll Sensitivity:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            ui.add(egui::Slider::new(&mut settings.stick_scroll_sensitivity, 0.25..=3.0)
+                                .suffix("×"));
+                        });
+                        Self::hint(ui, "Right stick scrolls the content grid");
+                    });
+
+                    // Recording Section
+                    Self::section_header(ui, t("settings.recording"));
+                    Self::section(ui, |ui| {
+                        let default_dir = Config::default_recordings_path();
+                        let mut dir = settings.recordings_dir.clone().unwrap_or_default();
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Recordings Folder:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            let edit = egui::TextEdit::singleline(&mut dir)
+                                .hint_text(default_dir.display().to_string())
+                                .desired_width(260.0);
+                            if ui.add(edit).changed() {
+                                settings.recordings_dir = if dir.trim().is_empty() { None } else { Some(dir) };
+                            }
+                        });
+                        Self::hint(ui, "Live recordings are saved here as .ts files (requires ffmpeg)");
+                    });
+
+                    // Reminders Section
+                    Self::section_header(ui, t("settings.reminders"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.reminder_auto_tune, "");
+                            ui.label(egui::RichText::new("Switch to the channel when a reminder goes off")
+                                .color(egui::Color32::WHITE));
+                        });
+                        Self::hint(ui, "Reminders go off 5 minutes before the programme starts");
+                    });
+
+                    // Logs Section
+                    Self::section_header(ui, t("settings.logs"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Log Level:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            for level in LogLevel::ALL {
+                                ui.selectable_value(&mut settings.log_level, level, level.display_name());
+                            }
+                        });
+                        let log_path = crate::logging::log_path();
+                        ui.horizontal(|ui| {
+                            Self::hint(ui, &log_path.display().to_string());
+                            if ui.small_button("📂 Open").clicked() {
+                                if let Some(dir) = log_path.parent() {
+                                    if let Err(e) = crate::models::config::open_in_browser(&dir.to_string_lossy()) {
+                                        log::warn!("[Log] Couldn't open {}: {}", dir.display(), e);
+                                    }
+                                }
+                            }
+                        });
+                        Self::hint(ui, "Attach this file to bug reports. Debug also records API requests; passwords are masked");
+                    });
+
+                    // Updates Section
+                    Self::section_header(ui, t("settings.updates"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.check_for_updates, "");
+                            ui.label(egui::RichText::new("Check for updates on startup")
+                                .color(egui::Color32::WHITE));
+                        });
+                        ui.horizontal(|ui| {
+                            Self::hint(ui, concat!("Installed version ", env!("CARGO_PKG_VERSION")));
+                            if ui.small_button("Check for updates").clicked() {
+                                action = Some(AppSettingsAction::CheckForUpdates);
+                            }
+                        });
+                        Self::hint(ui, "Asks GitHub for the latest release and only tells you about it; nothing is downloaded");
+                    });
+
+                    // Sports Section
+                    Self::section_header(ui, t("settings.sports"));
+                    Self::section(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.football_local_time, "");
+                            ui.label(egui::RichText::new("Show kick-off times in local time")
+                                .color(egui::Color32::WHITE));
+                        });
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Refresh Fixtures:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            Self::interval_combo(ui, "football_refresh", &mut settings.football_refresh_minutes, &[5, 15, 30, 60], "min");
+                        });
+                        Self::hint(ui, "Reloads the fixtures shown in the sports view while it's open");
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Re-run Scraper:")
+                                .color(egui::Color32::from_rgb(180, 180, 180)));
+                            ui.add_space(10.0);
+                            Self::interval_combo(ui, "football_scrape", &mut settings.football_scrape_hours, &[2, 6, 12, 24], "h");
+                        });
+                        Self::hint(ui, "Fetches new fixtures and broadcasters in the background (about 2 minutes per run)");
+                        ui.add_space(8.0);
+                        ui.label(egui::RichText::new("Channel Search Mappings:")
+                            .color(egui::Color32::from_rgb(180, 180, 180)));
+                        let mut remove = None;
+                        for (index, mapping) in settings.channel_mappings.iter_mut().enumerate() {
+                            ui.horizontal(|ui| {
+                                ui.add(egui::TextEdit::singleline(&mut mapping.pattern)
+                                    .hint_text("channel name")
+                                    .desired_width(120.0));
+                                ui.add(egui::TextEdit::singleline(&mut mapping.country)
+                                    .hint_text("any country")
+                                    .desired_width(100.0));
+                                ui.label("→");
+                                ui.add(egui::TextEdit::singleline(&mut mapping.query)
+                                    .hint_text("search in Live TV")
+                                    .desired_width(140.0));
+                                if ui.small_button("🗑").on_hover_text("Remove mapping").clicked() {
+                                    remove = Some(index);
+                                }
+                            });
+                        }
+                        if let Some(index) = remove {
+                            settings.channel_mappings.remove(index);
+                        }
+                        if ui.button("➕ Add mapping").clicked() {
+                            settings.channel_mappings.push(ChannelMapping::default());
+                        }
+                        Self::hint(ui, "Match your provider's channel names, e.g. \"sky sports\" in \"United Kingdom\" → \"|UK| Sky Sports\". \
+                                        Tried before the built-in mappings");
+                    });
+
+                    ui.add_space(16.0);
+
+                    // Action buttons
+                    ui.horizontal(|ui| {
+                        // Save button - Netflix red
+                        if ui.add(egui::Button::new(
+                            egui::RichText::new(t("settings.save"))
+                                .color(egui::Color32::WHITE)
+                                .strong()
+                        ).fill(egui::Color32::from_rgb(229, 9, 20))
+                            .rounding(egui::Rounding::same(4.0))
+                            .min_size(egui::vec2(80.0, 36.0)))
+                            .clicked() {
+                            action = Some(AppSettingsAction::Saved);
+                        }
+
+                        ui.add_space(8.0);
+
+                        // Cancel button - dark
+                        if ui.add(egui::Button::new(
+                            egui::RichText::new(t("settings.cancel"))
+                                .color(egui::Color32::WHITE)
+                        ).fill(egui::Color32::from_rgb(60, 60, 60))
+                            .rounding(egui::Rounding::same(4.0))
+                            .min_size(egui::vec2(80.0, 36.0)))
+                            .clicked() {
+                            action = Some(AppSettingsAction::Cancelled);
+                        }
+                    });
+                }); // End ScrollArea
+            });
+
+        action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_keeps_outside_changes() {
+        let mut config = Config::default();
+        config.hidden_channels.insert("1".to_string(), "One".to_string());
+        config.hidden_channels.insert("2".to_string(), "Two".to_string());
+
+        // The dialog unhides 1 and changes a setting...
+        let mut settings = AppSettings::from_config(&config);
+        settings.hidden_channels.remove("1");
+        settings.unhidden.insert("1".to_string());
+        settings.content_cache_ttl_hours = 6;
+
+        // ...while a favorite is added and 3 hidden from the main window
+        config.favorites.push("42".to_string());
+        config.hidden_channels.insert("3".to_string(), "Three".to_string());
+
+        settings.apply_to(&mut config);
+        assert_eq!(config.content_cache_ttl_hours, 6);
+        assert_eq!(config.favorites, vec!["42".to_string()]);
+        assert_eq!(config.hidden_channels.keys().collect::<Vec<_>>(), vec!["2", "3"]);
+    }
+}
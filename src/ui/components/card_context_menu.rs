@@ -0,0 +1,122 @@
+//! Card context menu - per-item actions for channel, movie and series cards
+//!
+//! Opens on right-click, long press, or Shift+F10 / the controller's Y
+//! button on the focused card. Entries are plain buttons, so the D-pad and
+//! Enter work inside the menu as well. The selection is returned as the
+//! card's own action type.
+
+use eframe::egui;
+
+/// Egui memory key of the currently open menu.
+const MENU_STATE: &str = "card_context_menu";
+
+/// Egui memory key of a pending "open for the focused card" request.
+const FOCUS_REQUEST: &str = "card_context_menu_request";
+
+/// Which card the menu belongs to and where it's drawn.
+#[derive(Debug, Clone, Copy)]
+struct OpenMenu {
+    card: egui::Id,
+    pos: egui::Pos2,
+    /// Opened from the keyboard or controller, so focus moves into the
+    /// menu and back to the card afterwards
+    keyboard: bool,
+    /// Whether focus has been moved into the menu yet
+    focused: bool,
+    /// Frame the menu opened in, so the opening click doesn't close it
+    opened_frame: u64,
+}
+
+/// Context menu component shared by the content cards.
+pub struct CardContextMenu;
+
+impl CardContextMenu {
+    /// Opens the menu of whichever card has keyboard focus this frame.
+    /// Called for Shift+F10 and the controller's menu button.
+    pub fn open_for_focused(ctx: &egui::Context) {
+        let frame = ctx.cumulative_pass_nr();
+        ctx.data_mut(|d| d.insert_temp(egui::Id::new(FOCUS_REQUEST), frame));
+    }
+
+    /// Whether a card's menu is currently open.
+    pub fn is_open(ctx: &egui::Context) -> bool {
+        ctx.data(|d| d.get_temp::<OpenMenu>(egui::Id::new(MENU_STATE)).is_some())
+    }
+
+    /// Shows the menu for the card behind `response` when it is open and
+    /// returns the picked entry. `entries` is only built while the menu
+    /// is showing.
+    pub fn show<A>(
+        ui: &egui::Ui,
+        response: &egui::Response,
+        entries: impl FnOnce() -> Vec<(String, A)>,
+    ) -> Option<A> {
+        let ctx = ui.ctx();
+        let state_id = egui::Id::new(MENU_STATE);
+        let frame = ctx.cumulative_pass_nr();
+
+        let keyboard_request = response.has_focus()
+            && ctx.data(|d| d.get_temp::<u64>(egui::Id::new(FOCUS_REQUEST))) == Some(frame);
+        if response.secondary_clicked() || response.long_touched() || keyboard_request {
+            let pos = if keyboard_request {
+                response.rect.center()
+            } else {
+                response.interact_pointer_pos().unwrap_or(response.rect.center())
+            };
+            let open = OpenMenu {
+                card: response.id,
+                pos,
+                keyboard: keyboard_request,
+                focused: false,
+                opened_frame: frame,
+            };
+            ctx.data_mut(|d| d.insert_temp(state_id, open));
+        }
+
+        let mut open = ctx.data(|d| d.get_temp::<OpenMenu>(state_id)).filter(|m| m.card == response.id)?;
+
+        let mut picked = None;
+        let area = egui::Area::new(state_id.with("area"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(open.pos)
+            .constrain(true)
+            .show(ctx, |ui| {
+                egui::Frame::menu(ui.style()).show(ui, |ui| {
+                    ui.set_min_width(200.0);
+                    for (index, (label, action)) in entries().into_iter().enumerate() {
+                        let button = ui.add(
+                            egui::Button::new(label)
+                                .frame(false)
+                                .min_size(egui::vec2(ui.available_width(), 32.0)),
+                        );
+                        if index == 0 && open.keyboard && !open.focused {
+                            button.request_focus();
+                        }
+                        if button.clicked() {
+                            picked = Some(action);
+                        }
+                    }
+                });
+            });
+        open.focused = true;
+
+        // Escape (controller B) or a click anywhere else closes the menu
+        let dismissed = ctx.input(|i| {
+            i.key_pressed(egui::Key::Escape)
+                || (i.pointer.any_click()
+                    && open.opened_frame != frame
+                    && !i.pointer.interact_pos().is_some_and(|p| area.response.rect.contains(p)))
+        });
+
+        if picked.is_some() || dismissed {
+            ctx.data_mut(|d| d.remove::<OpenMenu>(state_id));
+            if open.keyboard {
+                // Hand focus back to the card for controller navigation
+                response.request_focus();
+            }
+        } else {
+            ctx.data_mut(|d| d.insert_temp(state_id, open));
+        }
+        picked
+    }
+}
@@ -0,0 +1,164 @@
+//! Catch-up browser - past programmes of a channel with a `tv_archive`
+//!
+//! Shows one tab per day of the channel's archive window with the guide of
+//! that day. Programmes inside the window can be replayed through the
+//! provider's timeshift URL; older ones are greyed out.
+
+use chrono::{Local, TimeZone};
+use eframe::egui;
+
+use crate::models::{Channel, EpgProgram};
+
+/// Which channel and day the browser is showing.
+#[derive(Debug, Clone)]
+pub struct CatchupState {
+    pub channel: Channel,
+    /// Days back from today, 0 = today
+    pub day: i64,
+}
+
+impl CatchupState {
+    pub fn new(channel: Channel) -> Self {
+        Self { channel, day: 0 }
+    }
+}
+
+/// Actions returned by the catch-up browser.
+#[derive(Debug, Clone)]
+pub enum CatchupAction {
+    /// Replay a past programme from its start
+    Play(Box<EpgProgram>),
+    /// Close the browser
+    Close,
+}
+
+/// Catch-up browser component.
+pub struct CatchupDialog;
+
+impl CatchupDialog {
+    /// Renders the browser. `programs` is `None` while the guide is loading.
+    pub fn show(
+        ctx: &egui::Context,
+        state: &mut CatchupState,
+        programs: Option<&[EpgProgram]>,
+    ) -> Option<CatchupAction> {
+        let mut action = None;
+        let label_color = egui::Color32::from_rgb(180, 180, 180);
+        let now = Local::now();
+        let archive_start = now.timestamp() - state.channel.archive_days() * 86400;
+
+        egui::Window::new("")
+            .id(egui::Id::new("catchup_dialog"))
+            .resizable(false)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(560.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .frame(egui::Frame::none()
+                .fill(egui::Color32::from_rgb(24, 24, 24))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(24.0)))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format!("⏪ {}", state.channel.name))
+                        .size(20.0)
+                        .color(egui::Color32::WHITE)
+                        .strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("✕").clicked() {
+                            action = Some(CatchupAction::Close);
+                        }
+                    });
+                });
+                ui.label(egui::RichText::new(format!(
+                    "Catch-up archive · last {} day{}",
+                    state.channel.archive_days(),
+                    if state.channel.archive_days() == 1 { "" } else { "s" },
+                )).color(label_color));
+                ui.add_space(12.0);
+
+                // Day tabs, today first
+                let today = now.date_naive();
+                egui::ScrollArea::horizontal().id_salt("catchup_days").show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        for day in 0..=state.channel.archive_days() {
+                            let date = today - chrono::Days::new(day as u64);
+                            let label = match day {
+                                0 => "Today".to_string(),
+                                1 => "Yesterday".to_string(),
+                                _ => date.format("%a %d %b").to_string(),
+                            };
+                            ui.selectable_value(&mut state.day, day, label);
+                        }
+                    });
+                });
+                ui.add_space(8.0);
+                ui.separator();
+
+                let Some(programs) = programs else {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(egui::RichText::new("Loading the archive guide...").color(label_color));
+                    });
+                    return;
+                };
+
+                let date = today - chrono::Days::new(state.day as u64);
+                let day_programs: Vec<&EpgProgram> = programs.iter()
+                    .filter(|p| p.start_timestamp_unix() < now.timestamp())
+                    .filter(|p| {
+                        Local.timestamp_opt(p.start_timestamp_unix(), 0)
+                            .single()
+                            .is_some_and(|t| t.date_naive() == date)
+                    })
+                    .collect();
+
+                if day_programs.is_empty() {
+                    ui.add_space(12.0);
+                    ui.label(egui::RichText::new("No guide data for this day.").color(label_color));
+                    return;
+                }
+
+                egui::ScrollArea::vertical().max_height(420.0).show(ui, |ui| {
+                    for program in day_programs {
+                        let start = program.start_timestamp_unix();
+                        let available = start >= archive_start;
+                        let airing = program.is_now_playing();
+                        let time = Local.timestamp_opt(start, 0)
+                            .single()
+                            .map(|t| t.format("%H:%M").to_string())
+                            .unwrap_or_default();
+
+                        ui.add_enabled_ui(available, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(time).monospace().color(label_color));
+                                let mut title = egui::RichText::new(&program.title).color(egui::Color32::WHITE);
+                                if airing {
+                                    title = title.strong();
+                                }
+                                let button = ui.add(egui::Button::new(title).frame(false));
+                                let button = if available {
+                                    button.on_hover_text("Play from the start")
+                                } else {
+                                    button.on_disabled_hover_text("Outside the archive window")
+                                };
+                                if button.clicked() {
+                                    action = Some(CatchupAction::Play(Box::new(program.clone())));
+                                }
+                                if airing {
+                                    ui.label(egui::RichText::new("LIVE")
+                                        .small()
+                                        .color(egui::Color32::from_rgb(255, 90, 95)));
+                                }
+                            });
+                        });
+                    }
+                });
+            });
+
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            action = Some(CatchupAction::Close);
+        }
+        action
+    }
+}
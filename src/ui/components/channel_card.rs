@@ -4,9 +4,11 @@
 //! Features clean typography, subtle hover effects, and efficient space usage.
 
 use eframe::egui;
-use crate::models::{Channel, EpgProgram};
+use crate::models::{Channel, EpgProgram, Quality};
+use crate::ui::highlight;
 use crate::ui::theme::{Theme, dimensions, spacing, typography, radius};
-use crate::ui::image_cache::ImageCache;
+use crate::ui::image_cache::{paint_shimmer, placeholder_initials, ImageCache};
+use super::CardContextMenu;
 
 /// Actions that can be triggered from a channel card.
 #[derive(Debug, Clone)]
@@ -15,6 +17,14 @@ pub enum ChannelAction {
     Play(Channel),
     /// Toggle favorite status
     ToggleFavorite(String),
+    /// Start or stop recording the channel
+    ToggleRecording(Channel),
+    /// Copy the channel's stream URL to the clipboard
+    CopyUrl(Channel),
+    /// Browse the channel's catch-up archive
+    BrowseCatchup(Channel),
+    /// Hide the channel from the lists
+    Hide(Channel),
 }
 
 /// EPG info to display on a channel card
@@ -33,6 +43,15 @@ impl ChannelEpgInfo {
     }
 }
 
+/// State shown as badges and toggles on a channel card.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChannelBadges {
+    pub favorite: bool,
+    pub recording: bool,
+    /// The last stream check found the channel dead
+    pub offline: bool,
+}
+
 /// Channel card component - Modern horizontal card design
 pub struct ChannelCard;
 
@@ -44,20 +63,17 @@ impl ChannelCard {
         ctx: &egui::Context,
         theme: &Theme,
         channel: &Channel,
-        is_favorite: bool,
+        badges: ChannelBadges,
         image_cache: &ImageCache,
-        screen_width: f32,
+        size: dimensions::CardSize,
         epg_info: Option<&ChannelEpgInfo>,
     ) -> Option<ChannelAction> {
+        let ChannelBadges { favorite: is_favorite, recording: is_recording, offline: is_offline } = badges;
         let mut action: Option<ChannelAction> = None;
-        let is_mobile = dimensions::is_mobile(screen_width);
+        let is_mobile = size.is_mobile;
 
         // Card dimensions - wider for better content display
-        let card_width = if is_mobile {
-            (screen_width - 40.0).max(300.0)
-        } else {
-            360.0
-        };
+        let card_width = size.width;
         let card_height = if is_mobile { 88.0 } else { 96.0 };
         let icon_size = if is_mobile { 52.0 } else { 60.0 };
 
@@ -71,6 +87,7 @@ impl ChannelCard {
             egui::vec2(card_width + spacing::MD, card_height + spacing::SM),
             egui::Sense::click(),
         );
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, format!("Play {}", channel.name)));
 
         if response.clicked() {
             action = Some(ChannelAction::Play(channel.clone()));
@@ -122,40 +139,57 @@ impl ChannelCard {
         );
 
         if !channel.stream_icon.is_empty() {
-            if let Some(texture) = image_cache.get(&channel.stream_icon) {
-                // Draw circular image with clipping
-                let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
-                ui.painter().image(texture.id(), icon_rect, uv, egui::Color32::WHITE);
+            if image_cache.paint(ui, &channel.stream_icon, icon_rect) {
                 // Circular border
                 ui.painter().rect_stroke(
                     icon_rect,
                     radius::MD,
                     egui::Stroke::new(1.0, theme.border_color),
                 );
+            } else if image_cache.is_pending(&channel.stream_icon) {
+                paint_shimmer(ui, icon_rect, radius::MD, theme.placeholder_bg());
             } else {
-                Self::paint_placeholder(ui, theme, icon_rect);
+                // Gave up on the image, show the initials instead
+                let initials = placeholder_initials(&channel.name);
+                Self::paint_placeholder(ui, theme, icon_rect, &initials);
             }
         } else {
-            Self::paint_placeholder(ui, theme, icon_rect);
+            Self::paint_placeholder(ui, theme, icon_rect, "TV");
         }
 
         // Content area (right side of icon)
         let content_x = icon_rect.max.x + spacing::MD;
-        let content_width = card_width - icon_size - spacing::LG * 2.0 - spacing::MD - 32.0; // Reserve space for favorite
+        // Reserve space for catch-up, record and favorite
+        let buttons_width = if channel.has_catchup() { 96.0 } else { 64.0 };
+        let content_width = card_width - icon_size - spacing::LG * 2.0 - spacing::MD - buttons_width;
 
         // Channel name - truncated with ellipsis
         let name_text = truncate_text(&channel.name, if is_mobile { 25 } else { 32 });
-        let name_galley = ui.painter().layout_no_wrap(
-            name_text,
+        let name_galley = ui.painter().layout_job(highlight::title_job(
+            ctx,
+            &name_text,
             egui::FontId::proportional(if is_mobile { typography::BODY_SM } else { typography::BODY }),
             theme.text_primary,
-        );
+            theme.accent_blue,
+        ));
+        let name_width = name_galley.size().x;
+        let name_height = name_galley.size().y;
         ui.painter().galley(
             egui::pos2(content_x, card_rect.min.y + spacing::MD),
             name_galley,
             theme.text_primary,
         );
 
+        // Quality badge after the name (replaced by OFFLINE further down)
+        let badge_anchor = egui::pos2(
+            content_x + name_width + spacing::SM,
+            card_rect.min.y + spacing::MD + name_height / 2.0,
+        );
+        let badge_max_x = content_x + content_width;
+        if let (false, Some(quality)) = (is_offline, channel.quality()) {
+            Self::paint_badge(ui, quality.label(), Self::quality_color(theme, quality), badge_anchor, badge_max_x);
+        }
+
         // EPG info
         let has_epg = epg_info.map(|e| e.current_program.is_some()).unwrap_or(false);
 
@@ -214,7 +248,7 @@ impl ChannelCard {
             }
         }
 
-        // If no EPG, show LIVE badge
+        // If no EPG, show LIVE badge (REC while recording)
         if !has_epg {
             let badge_rect = egui::Rect::from_min_size(
                 egui::pos2(content_x, card_rect.min.y + spacing::MD + 24.0),
@@ -225,7 +259,7 @@ impl ChannelCard {
             ui.painter().rect_filled(badge_rect, radius::SM, theme.live_badge());
 
             let live_galley = ui.painter().layout_no_wrap(
-                "LIVE".to_string(),
+                if is_recording { "REC" } else { "LIVE" }.to_string(),
                 egui::FontId::proportional(typography::LABEL),
                 egui::Color32::WHITE,
             );
@@ -236,6 +270,12 @@ impl ChannelCard {
             ui.painter().galley(text_pos, live_galley, egui::Color32::WHITE);
         }
 
+        // Dim unreachable channels, leaving the buttons below untouched
+        if is_offline {
+            ui.painter().rect_filled(card_rect, radius::LG, card_bg.gamma_multiply(0.6));
+            Self::paint_badge(ui, "OFFLINE", theme.error_color, badge_anchor, badge_max_x);
+        }
+
         // Favorite star (right side)
         let star_pos = egui::pos2(card_rect.max.x - spacing::LG - 6.0, card_rect.center().y);
         let star_rect = egui::Rect::from_center_size(star_pos, egui::vec2(28.0, 28.0));
@@ -243,12 +283,65 @@ impl ChannelCard {
             star_rect,
             ui.id().with(&channel.stream_id),
             egui::Sense::click(),
-        );
+        ).on_hover_text(if is_favorite { "Remove from favorites" } else { "Add to favorites" });
+        star_response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, true, is_favorite, "Favorite"));
 
         if star_response.clicked() {
             action = Some(ChannelAction::ToggleFavorite(channel.stream_id.clone()));
         }
 
+        // Record button (left of the star)
+        let rec_pos = egui::pos2(star_pos.x - 32.0, star_pos.y);
+        let rec_rect = egui::Rect::from_center_size(rec_pos, egui::vec2(28.0, 28.0));
+        let rec_response = ui.interact(
+            rec_rect,
+            ui.id().with(("record", &channel.stream_id)),
+            egui::Sense::click(),
+        ).on_hover_text(if is_recording { "Stop recording" } else { "Record" });
+        rec_response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, true, is_recording, "Record"));
+
+        if rec_response.clicked() {
+            action = Some(ChannelAction::ToggleRecording(channel.clone()));
+        }
+
+        if is_recording {
+            ui.painter().circle_filled(rec_pos, 7.0, theme.live_badge());
+        } else if rec_response.hovered() || is_hovered || has_focus {
+            let rec_color = if rec_response.hovered() { theme.live_badge() } else { theme.text_muted };
+            ui.painter().circle_stroke(rec_pos, 7.0, egui::Stroke::new(1.5, rec_color));
+        }
+
+        // Catch-up button (left of record), only on channels with an archive
+        if channel.has_catchup() {
+            let catchup_pos = egui::pos2(rec_pos.x - 32.0, rec_pos.y);
+            let catchup_rect = egui::Rect::from_center_size(catchup_pos, egui::vec2(28.0, 28.0));
+            let catchup_response = ui.interact(
+                catchup_rect,
+                ui.id().with(("catchup", &channel.stream_id)),
+                egui::Sense::click(),
+            ).on_hover_text("Catch up on past programmes");
+            catchup_response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, "Catch up"));
+
+            if catchup_response.clicked() {
+                action = Some(ChannelAction::BrowseCatchup(channel.clone()));
+            }
+
+            let catchup_color = if catchup_response.hovered() { theme.accent_blue } else { theme.text_muted };
+            let catchup_galley = ui.painter().layout_no_wrap(
+                "⏪".to_string(),
+                egui::FontId::proportional(16.0),
+                catchup_color,
+            );
+            ui.painter().galley(
+                egui::pos2(
+                    catchup_pos.x - catchup_galley.size().x / 2.0,
+                    catchup_pos.y - catchup_galley.size().y / 2.0,
+                ),
+                catchup_galley,
+                catchup_color,
+            );
+        }
+
         // Star icon
         let star_color = if is_favorite {
             theme.warning_color
@@ -272,16 +365,63 @@ impl ChannelCard {
             star_color,
         );
 
-        action
+        // Context menu: right-click, long press, Shift+F10 or controller Y
+        let menu_pick = CardContextMenu::show(ui, &response, || {
+            let mut entries = vec![
+                ("▶ Play".to_string(), ChannelAction::Play(channel.clone())),
+                (
+                    if is_favorite { "★ Remove from My List" } else { "☆ Add to My List" }.to_string(),
+                    ChannelAction::ToggleFavorite(channel.stream_id.clone()),
+                ),
+                (
+                    if is_recording { "⏹ Stop recording" } else { "⏺ Record" }.to_string(),
+                    ChannelAction::ToggleRecording(channel.clone()),
+                ),
+                ("📋 Copy stream URL".to_string(), ChannelAction::CopyUrl(channel.clone())),
+            ];
+            if channel.has_catchup() {
+                entries.push(("⏪ Catch-up".to_string(), ChannelAction::BrowseCatchup(channel.clone())));
+            }
+            entries.push(("🚫 Hide channel".to_string(), ChannelAction::Hide(channel.clone())));
+            entries
+        });
+        action.or(menu_pick)
+    }
+
+    /// Paints a small label badge whose left edge is vertically centered
+    /// on `anchor`. Skipped if it would extend past `max_x`.
+    fn paint_badge(ui: &egui::Ui, label: &str, color: egui::Color32, anchor: egui::Pos2, max_x: f32) {
+        let galley = ui.painter().layout_no_wrap(
+            label.to_string(),
+            egui::FontId::proportional(typography::LABEL),
+            egui::Color32::WHITE,
+        );
+        let size = galley.size() + egui::vec2(8.0, 2.0);
+        if anchor.x + size.x > max_x {
+            return;
+        }
+        let rect = egui::Rect::from_min_size(anchor - egui::vec2(0.0, size.y / 2.0), size);
+        ui.painter().rect_filled(rect, radius::SM, color);
+        ui.painter().galley(rect.center() - galley.size() / 2.0, galley, egui::Color32::WHITE);
+    }
+
+    /// Badge color for a stream quality - brighter for higher resolutions.
+    fn quality_color(theme: &Theme, quality: Quality) -> egui::Color32 {
+        match quality {
+            Quality::Sd => theme.text_muted,
+            Quality::Hd => theme.accent_blue,
+            Quality::Fhd => theme.success_color,
+            Quality::Uhd => egui::Color32::from_rgb(168, 85, 247),
+        }
     }
 
     /// Paints a placeholder for missing icons
-    fn paint_placeholder(ui: &egui::Ui, theme: &Theme, rect: egui::Rect) {
+    fn paint_placeholder(ui: &egui::Ui, theme: &Theme, rect: egui::Rect, label: &str) {
         ui.painter().rect_filled(rect, radius::MD, theme.placeholder_bg());
 
         let center = rect.center();
         let galley = ui.painter().layout_no_wrap(
-            "TV".to_string(),
+            label.to_string(),
             egui::FontId::proportional(rect.width() * 0.35),
             theme.placeholder_icon(),
         );
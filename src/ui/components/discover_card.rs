@@ -1,13 +1,15 @@
 use egui::{self, Color32, Vec2};
 use crate::api::{DiscoverItem, DiscoverContentType};
 use crate::ui::theme::{Theme, dimensions};
-use crate::ui::image_cache::ImageCache;
+use crate::ui::image_cache::{paint_shimmer, ImageCache};
 
 /// Actions that can be triggered from a discover card.
 #[derive(Debug, Clone)]
 pub enum DiscoverAction {
     /// Search for this content in the IPTV library.
     SearchInIptv(String),
+    /// Play the trailer at this URL.
+    PlayTrailer(String),
 }
 
 /// A card component for displaying discover content (Netflix-style).
@@ -33,6 +35,7 @@ impl DiscoverCard {
             Vec2::new(card_width + 8.0, total_height),
             egui::Sense::click(),
         );
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &item.title));
         
         if ui.is_rect_visible(rect) {
             let painter = ui.painter_at(rect);
@@ -43,7 +46,8 @@ impl DiscoverCard {
                 Vec2::new(card_width, card_height),
             );
             
-            let is_hovered = response.hovered();
+            // Keyboard/controller focus gets the same lift as hover
+            let is_hovered = response.hovered() || response.has_focus();
             
             // Shadow on hover
             if is_hovered {
@@ -53,24 +57,12 @@ impl DiscoverCard {
             
             // Draw poster image or placeholder
             if let Some(poster_url) = &item.poster_url {
-                if let Some(texture) = image_cache.get(poster_url) {
-                    painter.image(
-                        texture.id(),
-                        poster_rect,
-                        egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
-                        Color32::WHITE,
-                    );
+                if image_cache.paint(ui, poster_url, poster_rect) {
                     painter.rect_stroke(poster_rect, 6.0, egui::Stroke::new(1.0, theme.bg_color));
+                } else if image_cache.is_pending(poster_url) {
+                    paint_shimmer(ui, poster_rect, 6.0, theme.placeholder_bg());
                 } else {
-                    // Loading placeholder
                     painter.rect_filled(poster_rect, 6.0, theme.placeholder_bg());
-                    painter.text(
-                        poster_rect.center(),
-                        egui::Align2::CENTER_CENTER,
-                        "⏳",
-                        egui::FontId::proportional(card_width * 0.2),
-                        theme.placeholder_icon(),
-                    );
                 }
             } else {
                 // No poster placeholder
@@ -101,23 +93,51 @@ impl DiscoverCard {
                     Color32::from_rgba_unmultiplied(0, 0, 0, 180),
                 );
                 
-                // Search button inside poster on hover
-                let button_rect = egui::Rect::from_min_size(
+                // Search button inside poster on hover, with a trailer
+                // button alongside it when a trailer is available
+                let row_rect = egui::Rect::from_min_size(
                     egui::pos2(poster_rect.min.x + 10.0, poster_rect.max.y - 32.0),
                     Vec2::new(card_width - 20.0, 24.0),
                 );
-                painter.rect_filled(button_rect, 4.0, theme.accent_blue);
+                let (search_rect, trailer_rect) = if item.trailer_url.is_some() {
+                    let trailer_width = (row_rect.width() * 0.4).max(56.0);
+                    let (search, trailer) = row_rect.split_left_right_at_x(row_rect.max.x - trailer_width - 4.0);
+                    (search, Some(trailer.with_min_x(trailer.min.x + 4.0)))
+                } else {
+                    (row_rect, None)
+                };
+                
+                painter.rect_filled(search_rect, 4.0, theme.accent_blue);
                 painter.text(
-                    button_rect.center(),
+                    search_rect.center(),
                     egui::Align2::CENTER_CENTER,
-                    "🔍 Find in IPTV",
+                    if trailer_rect.is_some() { "🔍 Find" } else { "🔍 Find in IPTV" },
                     egui::FontId::proportional(11.0),
                     Color32::WHITE,
                 );
                 
-                // Handle click on button
+                if let Some(trailer_rect) = trailer_rect {
+                    painter.rect_filled(trailer_rect, 4.0, Color32::from_rgb(229, 9, 20));
+                    painter.text(
+                        trailer_rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        "▶ Trailer",
+                        egui::FontId::proportional(11.0),
+                        Color32::WHITE,
+                    );
+                }
+                
+                // Handle click on either button
                 if response.clicked() {
-                    action = Some(DiscoverAction::SearchInIptv(item.title.clone()));
+                    let clicked_trailer = match (trailer_rect, response.interact_pointer_pos()) {
+                        (Some(trailer_rect), Some(pos)) => trailer_rect.contains(pos),
+                        _ => false,
+                    };
+                    
+                    action = match (&item.trailer_url, clicked_trailer) {
+                        (Some(url), true) => Some(DiscoverAction::PlayTrailer(url.clone())),
+                        _ => Some(DiscoverAction::SearchInIptv(item.title.clone())),
+                    };
                 }
             }
             
@@ -0,0 +1,149 @@
+//! Built-in player view - the playing video with a control bar
+//!
+//! Takes over the whole window while the built-in player is active.
+//! Space pauses, Left/Right seek 10 seconds and Escape closes the player.
+
+use eframe::egui;
+
+use crate::embedded_player::EmbeddedPlayer;
+use crate::ui::theme::{spacing, Theme};
+
+/// Seconds jumped by the seek buttons and arrow keys.
+const SEEK_STEP: f64 = 10.0;
+
+/// Actions returned by the player view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddedPlayerAction {
+    /// Stop playback and return to browsing
+    Close,
+    /// Start or stop recording the live channel playing
+    ToggleRecording,
+}
+
+/// Built-in player view component.
+pub struct EmbeddedPlayerView;
+
+impl EmbeddedPlayerView {
+    /// Renders the video and its controls for `player`. `recording` is
+    /// whether the live channel playing is being recorded, `None` when it
+    /// isn't a live channel.
+    pub fn show(
+        ctx: &egui::Context,
+        player: &mut EmbeddedPlayer,
+        theme: &Theme,
+        recording: Option<bool>,
+    ) -> Option<EmbeddedPlayerAction> {
+        let mut action = None;
+
+        if !ctx.wants_keyboard_input() {
+            ctx.input(|i| {
+                if i.key_pressed(egui::Key::Space) {
+                    player.toggle_pause();
+                }
+                if i.key_pressed(egui::Key::ArrowLeft) {
+                    player.seek_by(-SEEK_STEP);
+                }
+                if i.key_pressed(egui::Key::ArrowRight) {
+                    player.seek_by(SEEK_STEP);
+                }
+                if i.key_pressed(egui::Key::Escape) {
+                    action = Some(EmbeddedPlayerAction::Close);
+                }
+            });
+        }
+
+        egui::TopBottomPanel::bottom("embedded_player_controls")
+            .frame(egui::Frame::none()
+                .fill(egui::Color32::from_rgb(24, 24, 24))
+                .inner_margin(egui::Margin::symmetric(spacing::LG, spacing::SM)))
+            .show(ctx, |ui| {
+                let position = player.position().unwrap_or(0.0);
+                let duration = player.duration();
+
+                // Live streams have no duration to seek in
+                if let Some(duration) = duration {
+                    let mut target = position;
+                    ui.spacing_mut().slider_width = ui.available_width();
+                    let slider = ui.add(egui::Slider::new(&mut target, 0.0..=duration).show_value(false));
+                    if slider.drag_stopped() || (slider.changed() && !slider.dragged()) {
+                        player.seek_to(target);
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    let pause_icon = if player.is_paused() { "▶" } else { "⏸" };
+                    if ui.button(egui::RichText::new(pause_icon).size(18.0)).clicked() {
+                        player.toggle_pause();
+                    }
+                    if ui.button("⏪ 10s").clicked() {
+                        player.seek_by(-SEEK_STEP);
+                    }
+                    if ui.button("10s ⏩").clicked() {
+                        player.seek_by(SEEK_STEP);
+                    }
+                    if let Some(is_recording) = recording {
+                        if super::mini_player::record_button(ui, theme, is_recording).clicked() {
+                            action = Some(EmbeddedPlayerAction::ToggleRecording);
+                        }
+                    }
+
+                    let time = match duration {
+                        Some(duration) => format!("{} / {}", format_time(position), format_time(duration)),
+                        None => format!("● LIVE  {}", format_time(position)),
+                    };
+                    ui.label(egui::RichText::new(time).color(egui::Color32::from_rgb(180, 180, 180)));
+                    ui.label(egui::RichText::new(player.title()).color(egui::Color32::WHITE).strong());
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("✕ Close").clicked() {
+                            action = Some(EmbeddedPlayerAction::Close);
+                        }
+                        let mut volume = player.volume();
+                        if ui.add(egui::Slider::new(&mut volume, 0.0..=100.0).show_value(false)).changed() {
+                            player.set_volume(volume);
+                        }
+                        ui.label("🔊");
+                    });
+                });
+            });
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::none().fill(egui::Color32::BLACK))
+            .show(ctx, |ui| {
+                let available = ui.available_rect_before_wrap();
+                let pixels = available.size() * ctx.pixels_per_point();
+                match player.frame(ctx, pixels) {
+                    Some(texture) => {
+                        // Keep the aspect ratio, centered in the panel
+                        let size = texture.size_vec2();
+                        let scale = (available.width() / size.x).min(available.height() / size.y);
+                        let rect = egui::Rect::from_center_size(available.center(), size * scale);
+                        ui.painter().image(
+                            texture.id(),
+                            rect,
+                            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+                            egui::Color32::WHITE,
+                        );
+                    }
+                    None => {
+                        ui.centered_and_justified(|ui| {
+                            ui.add(egui::Spinner::new().size(32.0).color(theme.accent_blue));
+                        });
+                    }
+                }
+            });
+
+        action
+    }
+}
+
+/// Seconds as "m:ss", or "h:mm:ss" from an hour up.
+fn format_time(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    let (hours, minutes, secs) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, secs)
+    } else {
+        format!("{}:{:02}", minutes, secs)
+    }
+}
@@ -7,9 +7,16 @@ use eframe::egui;
 use std::sync::mpsc::{channel, Receiver};
 use std::thread;
 use crate::api::XtreamClient;
-use crate::models::PlayerSettings;
+use crate::models::{PlayerSettings, WatchHistory};
+use crate::ui::image_cache::{paint_shimmer, ImageCache};
 use crate::ui::theme::{spacing, typography, radius};
 
+/// Episode thumbnails are 16:9 at this height.
+const THUMBNAIL_HEIGHT: f32 = 48.0;
+
+/// Size of the series cover in the overview.
+const COVER_SIZE: egui::Vec2 = egui::vec2(120.0, 180.0);
+
 /// Actions that can be triggered from the episode dialog.
 #[derive(Debug, Clone)]
 pub enum EpisodeAction {
@@ -22,6 +29,14 @@ pub enum EpisodeAction {
         title: String,
         container: String,
     },
+    /// Play this episode and the rest of its season as one playlist
+    PlaySeasonFrom { season: i32, episode: i32 },
+    /// Copy the episode's stream URL to the clipboard
+    CopyUrl {
+        episode_id: String,
+        title: String,
+        container: String,
+    },
     /// Close the dialog
     Close,
 }
@@ -34,15 +49,59 @@ struct EpisodeData {
     title: String,
     container: String,
     season: String,
+    /// Still from the episode, when the provider has one
+    thumbnail: Option<String>,
+}
+
+impl EpisodeData {
+    fn play_action(&self, series_name: &str) -> EpisodeAction {
+        EpisodeAction::PlayEpisode {
+            episode_id: self.id.clone(),
+            series_name: series_name.to_string(),
+            season: self.season.parse().unwrap_or(0),
+            episode: self.num as i32,
+            title: self.title.clone(),
+            container: self.container.clone(),
+        }
+    }
+
+    /// Watch history key, matches the one `play_episode` records.
+    fn history_key(&self, series_name: &str) -> String {
+        format!("{}_{}_{}", series_name, self.season.parse::<i32>().unwrap_or(0), self.num)
+    }
 }
 
 /// Cached series data.
 struct SeriesData {
     name: String,
     plot: String,
+    /// Overview details from the `info` block; empty when not provided
+    cover: Option<String>,
+    genre: String,
+    cast: String,
+    release_date: String,
+    /// Rating out of 10
+    rating: Option<f64>,
     seasons: Vec<(String, Vec<EpisodeData>)>,
 }
 
+impl SeriesData {
+    /// The most recently played episode that wasn't watched to the end.
+    fn resume_episode<'a>(&'a self, watch_history: &WatchHistory) -> Option<&'a EpisodeData> {
+        self.seasons
+            .iter()
+            .flat_map(|(_, episodes)| episodes)
+            .filter_map(|ep| {
+                watch_history
+                    .get_progress(&ep.history_key(&self.name))
+                    .filter(|p| !p.is_nearly_finished())
+                    .map(|p| (p.last_watched, ep))
+            })
+            .max_by_key(|(last_watched, _)| *last_watched)
+            .map(|(_, ep)| ep)
+    }
+}
+
 /// Loading state for the dialog.
 enum LoadingState {
     Loading,
@@ -86,7 +145,6 @@ impl EpisodeDialogState {
                 Ok(info) => {
                     let mut name = String::new();
                     let mut plot = String::new();
-
                     if let Some(info_obj) = info.get("info") {
                         name = info_obj
                             .get("name")
@@ -100,62 +158,26 @@ impl EpisodeDialogState {
                             .to_string();
                     }
 
-                    let mut seasons: Vec<(String, Vec<EpisodeData>)> = Vec::new();
-
-                    if let Some(episodes) = info.get("episodes").and_then(|v| v.as_object()) {
-                        let mut season_keys: Vec<_> = episodes.keys().collect();
-                        season_keys.sort_by(|a, b| {
-                            a.parse::<i32>()
-                                .unwrap_or(0)
-                                .cmp(&b.parse::<i32>().unwrap_or(0))
-                        });
-
-                        for season_key in season_keys {
-                            if let Some(season_episodes) =
-                                episodes.get(season_key).and_then(|v| v.as_array())
-                            {
-                                let eps: Vec<EpisodeData> = season_episodes
-                                    .iter()
-                                    .map(|ep| {
-                                        let id = if let Some(id_str) =
-                                            ep.get("id").and_then(|v| v.as_str())
-                                        {
-                                            id_str.to_string()
-                                        } else if let Some(id_num) =
-                                            ep.get("id").and_then(|v| v.as_i64())
-                                        {
-                                            id_num.to_string()
-                                        } else {
-                                            String::new()
-                                        };
-
-                                        EpisodeData {
-                                            id,
-                                            num: ep
-                                                .get("episode_num")
-                                                .and_then(|v| v.as_i64())
-                                                .unwrap_or(0),
-                                            title: ep
-                                                .get("title")
-                                                .and_then(|v| v.as_str())
-                                                .unwrap_or("Unknown")
-                                                .to_string(),
-                                            container: ep
-                                                .get("container_extension")
-                                                .and_then(|v| v.as_str())
-                                                .unwrap_or("mp4")
-                                                .to_string(),
-                                            season: season_key.clone(),
-                                        }
-                                    })
-                                    .collect();
-
-                                seasons.push((season_key.clone(), eps));
-                            }
-                        }
-                    }
-
-                    let _ = tx.send(Ok(SeriesData { name, plot, seasons }));
+                    let seasons = parse_seasons(&info);
+
+                    let details = info.get("info").unwrap_or(&serde_json::Value::Null);
+                    let _ = tx.send(Ok(SeriesData {
+                        name,
+                        plot,
+                        cover: ["cover", "cover_big"]
+                            .iter()
+                            .map(|key| info_text(details, key))
+                            .find(|url| url.starts_with("http")),
+                        genre: info_text(details, "genre"),
+                        cast: info_text(details, "cast"),
+                        release_date: ["releaseDate", "release_date"]
+                            .iter()
+                            .map(|key| info_text(details, key))
+                            .find(|date| !date.is_empty())
+                            .unwrap_or_default(),
+                        rating: series_rating(details),
+                        seasons,
+                    }));
                 }
                 Err(e) => {
                     let _ = tx.send(Err(format!("Failed to load: {}", e)));
@@ -174,6 +196,38 @@ impl EpisodeDialogState {
         }
     }
 
+    /// Episodes following S{season}E{episode}, in order, continuing into
+    /// the next seasons. Empty if the episode isn't in the loaded list.
+    pub fn episodes_after(&self, season: i32, episode: i32) -> Vec<EpisodeAction> {
+        let LoadingState::Loaded(data) = &self.state else {
+            return Vec::new();
+        };
+
+        let mut episodes = data.seasons.iter().flat_map(|(_, eps)| eps.iter());
+        if !episodes.any(|ep| ep.season.parse() == Ok(season) && ep.num as i32 == episode) {
+            return Vec::new();
+        }
+        episodes.map(|ep| ep.play_action(&data.name)).collect()
+    }
+
+    /// The given episode followed by the rest of its season, in order.
+    pub fn season_from(&self, season: i32, episode: i32) -> Vec<EpisodeAction> {
+        let LoadingState::Loaded(data) = &self.state else {
+            return Vec::new();
+        };
+
+        data.seasons
+            .iter()
+            .find(|(key, _)| key.parse() == Ok(season))
+            .map(|(_, eps)| {
+                eps.iter()
+                    .skip_while(|ep| ep.num as i32 != episode)
+                    .map(|ep| ep.play_action(&data.name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     fn check_for_data(&mut self) {
         if let Some(rx) = &self.rx {
             if let Ok(result) = rx.try_recv() {
@@ -203,6 +257,8 @@ impl EpisodeDialog {
         ctx: &egui::Context,
         state: &mut EpisodeDialogState,
         _player_settings: &PlayerSettings,
+        watch_history: &WatchHistory,
+        image_cache: &ImageCache,
     ) -> Option<EpisodeAction> {
         state.check_for_data();
 
@@ -215,6 +271,7 @@ impl EpisodeDialog {
         let text_secondary = egui::Color32::from_rgb(170, 170, 170);
         let text_tertiary = egui::Color32::from_rgb(128, 128, 128);
         let accent = egui::Color32::from_rgb(255, 90, 95);
+        let watched_green = egui::Color32::from_rgb(52, 199, 89);
 
         egui::Window::new("")
             .resizable(true)
@@ -297,6 +354,11 @@ impl EpisodeDialog {
                                 });
                             }
                             LoadingState::Loaded(data) => {
+                                let resume = data.resume_episode(watch_history);
+                                let is_watched = |ep: &EpisodeData| {
+                                    watch_history.get_progress(&ep.history_key(&data.name)).is_some()
+                                };
+
                                 // Header
                                 ui.horizontal(|ui| {
                                     ui.label(
@@ -323,20 +385,76 @@ impl EpisodeDialog {
                                             {
                                                 action = Some(EpisodeAction::Close);
                                             }
+
+                                            // Jump straight back into the last unfinished episode
+                                            if let Some(ep) = resume {
+                                                let resume_btn = egui::Button::new(
+                                                    egui::RichText::new(format!(
+                                                        "▶ Resume S{}:E{}",
+                                                        ep.season, ep.num
+                                                    ))
+                                                    .size(typography::BODY_SM)
+                                                    .color(egui::Color32::WHITE),
+                                                )
+                                                .fill(accent)
+                                                .min_size(egui::vec2(120.0, 36.0))
+                                                .rounding(egui::Rounding::same(radius::MD));
+
+                                                if ui
+                                                    .add(resume_btn)
+                                                    .on_hover_text(truncate_text(&ep.title, 60))
+                                                    .clicked()
+                                                {
+                                                    state.selected_season = Some(ep.season.clone());
+                                                    action = Some(ep.play_action(&data.name));
+                                                }
+                                            }
                                         },
                                     );
                                 });
 
-                                // Plot
-                                if !data.plot.is_empty() {
-                                    ui.add_space(spacing::SM);
-                                    let plot_display = truncate_text(&data.plot, 200);
-                                    ui.label(
-                                        egui::RichText::new(plot_display)
-                                            .size(typography::BODY_SM)
-                                            .color(text_tertiary),
-                                    );
+                                // Overview: cover on the left, details and plot beside it
+                                ui.add_space(spacing::MD);
+                                if let Some(url) = &data.cover {
+                                    image_cache.load(ctx, url.clone());
                                 }
+                                ui.horizontal_top(|ui| {
+                                    paint_cover(ui, image_cache, data.cover.as_deref());
+                                    ui.add_space(spacing::LG);
+                                    ui.vertical(|ui| {
+                                        ui.label(
+                                            egui::RichText::new(overview_line(data))
+                                                .size(typography::BODY_SM)
+                                                .color(text_secondary),
+                                        );
+                                        if !data.genre.is_empty() {
+                                            ui.label(
+                                                egui::RichText::new(&data.genre)
+                                                    .size(typography::BODY_SM)
+                                                    .color(text_secondary),
+                                            );
+                                        }
+                                        if !data.cast.is_empty() {
+                                            ui.label(
+                                                egui::RichText::new(format!(
+                                                    "Cast: {}",
+                                                    truncate_text(&data.cast, 120)
+                                                ))
+                                                .size(typography::CAPTION)
+                                                .color(text_tertiary),
+                                            );
+                                        }
+                                        if !data.plot.is_empty() {
+                                            ui.add_space(spacing::SM);
+                                            let plot_display = truncate_text(&data.plot, 300);
+                                            ui.label(
+                                                egui::RichText::new(plot_display)
+                                                    .size(typography::BODY_SM)
+                                                    .color(text_tertiary),
+                                            );
+                                        }
+                                    });
+                                });
 
                                 ui.add_space(spacing::XL);
 
@@ -344,10 +462,21 @@ impl EpisodeDialog {
                                 ui.horizontal_wrapped(|ui| {
                                     ui.spacing_mut().item_spacing = egui::vec2(spacing::SM, spacing::SM);
 
-                                    for (season_key, _) in &data.seasons {
+                                    for (season_key, episodes) in &data.seasons {
                                         let is_selected =
                                             state.selected_season.as_ref() == Some(season_key);
-                                        let btn_text = format!("Season {}", season_key);
+                                        let watched =
+                                            episodes.iter().filter(|ep| is_watched(ep)).count();
+                                        let btn_text = if watched > 0 {
+                                            format!(
+                                                "Season {} · {} of {} watched",
+                                                season_key,
+                                                watched,
+                                                episodes.len()
+                                            )
+                                        } else {
+                                            format!("Season {}", season_key)
+                                        };
 
                                         let (bg_color, fg_color) = if is_selected {
                                             (text_primary, egui::Color32::BLACK)
@@ -390,6 +519,9 @@ impl EpisodeDialog {
                                         ui.add_space(spacing::MD);
 
                                         let row_height = 72.0;
+                                        // Keep titles aligned when only some episodes have stills
+                                        let has_thumbnails =
+                                            episodes.iter().any(|ep| ep.thumbnail.is_some());
 
                                         egui::ScrollArea::vertical()
                                             .auto_shrink([false, false])
@@ -400,9 +532,23 @@ impl EpisodeDialog {
                                                 |ui, row_range| {
                                                     for idx in row_range {
                                                         let ep = &episodes[idx];
+                                                        // Only rows in view get here, so only their stills download
+                                                        if let Some(url) = &ep.thumbnail {
+                                                            image_cache.load(ctx, url.clone());
+                                                        }
+                                                        let watched = is_watched(ep);
+                                                        let is_resume = resume.is_some_and(|r| {
+                                                            r.season == ep.season && r.num == ep.num
+                                                        });
+                                                        // Watched episodes recede a little
+                                                        let (row_bg, title_color) = if watched {
+                                                            (egui::Color32::from_rgb(22, 22, 22), text_secondary)
+                                                        } else {
+                                                            (card_bg, text_primary)
+                                                        };
 
                                                         egui::Frame::none()
-                                                            .fill(card_bg)
+                                                            .fill(row_bg)
                                                             .rounding(egui::Rounding::same(
                                                                 radius::MD,
                                                             ))
@@ -412,13 +558,21 @@ impl EpisodeDialog {
                                                             ))
                                                             .show(ui, |ui| {
                                                                 ui.horizontal(|ui| {
-                                                                    // Episode number
+                                                                    if has_thumbnails {
+                                                                        paint_thumbnail(ui, image_cache, ep.thumbnail.as_deref());
+                                                                        ui.add_space(spacing::MD);
+                                                                    }
+
+                                                                    // Episode number, or a checkmark once watched
+                                                                    let (marker, marker_color) = if watched {
+                                                                        ("✓".to_string(), watched_green)
+                                                                    } else {
+                                                                        (format!("{}", ep.num), text_tertiary)
+                                                                    };
                                                                     ui.label(
-                                                                        egui::RichText::new(
-                                                                            format!("{}", ep.num),
-                                                                        )
-                                                                        .size(typography::H2)
-                                                                        .color(text_tertiary),
+                                                                        egui::RichText::new(marker)
+                                                                            .size(typography::H2)
+                                                                            .color(marker_color),
                                                                     );
 
                                                                     ui.add_space(spacing::LG);
@@ -435,14 +589,22 @@ impl EpisodeDialog {
                                                                                 &title_display,
                                                                             )
                                                                             .size(typography::BODY)
-                                                                            .color(text_primary),
+                                                                            .color(title_color),
                                                                         );
+                                                                        let status = if is_resume {
+                                                                            " · In progress"
+                                                                        } else if watched {
+                                                                            " · Watched"
+                                                                        } else {
+                                                                            ""
+                                                                        };
                                                                         ui.label(
                                                                             egui::RichText::new(
                                                                                 format!(
-                                                                                    "S{}:E{}",
+                                                                                    "S{}:E{}{}",
                                                                                     ep.season,
-                                                                                    ep.num
+                                                                                    ep.num,
+                                                                                    status
                                                                                 ),
                                                                             )
                                                                             .size(
@@ -461,7 +623,11 @@ impl EpisodeDialog {
                                                                             let play_btn =
                                                                                 egui::Button::new(
                                                                                     egui::RichText::new(
-                                                                                        "▶ Play",
+                                                                                        if is_resume {
+                                                                                            "▶ Resume"
+                                                                                        } else {
+                                                                                            "▶ Play"
+                                                                                        },
                                                                                     )
                                                                                     .size(
                                                                                         typography::BODY_SM,
@@ -484,16 +650,55 @@ impl EpisodeDialog {
                                                                                 .add(play_btn)
                                                                                 .clicked()
                                                                             {
-                                                                                action = Some(
-                                                                                    EpisodeAction::PlayEpisode {
-                                                                                        episode_id: ep.id.clone(),
-                                                                                        series_name: data.name.clone(),
+                                                                                action = Some(ep.play_action(&data.name));
+                                                                            }
+
+                                                                            // Marathon the rest of the season
+                                                                            if idx + 1 < total_episodes {
+                                                                                let season_btn =
+                                                                                    egui::Button::new(
+                                                                                        egui::RichText::new("⏩ From here")
+                                                                                            .size(typography::BODY_SM)
+                                                                                            .color(text_secondary),
+                                                                                    )
+                                                                                    .fill(egui::Color32::from_rgb(40, 40, 40))
+                                                                                    .min_size(egui::vec2(80.0, 36.0))
+                                                                                    .rounding(egui::Rounding::same(radius::MD));
+
+                                                                                if ui
+                                                                                    .add(season_btn)
+                                                                                    .on_hover_text(format!(
+                                                                                        "Play season from episode {}",
+                                                                                        ep.num
+                                                                                    ))
+                                                                                    .clicked()
+                                                                                {
+                                                                                    action = Some(EpisodeAction::PlaySeasonFrom {
                                                                                         season: ep.season.parse().unwrap_or(0),
                                                                                         episode: ep.num as i32,
-                                                                                        title: ep.title.clone(),
-                                                                                        container: ep.container.clone(),
-                                                                                    },
-                                                                                );
+                                                                                    });
+                                                                                }
+                                                                            }
+
+                                                                            let copy_btn = egui::Button::new(
+                                                                                egui::RichText::new("📋")
+                                                                                    .size(typography::BODY_SM)
+                                                                                    .color(text_secondary),
+                                                                            )
+                                                                            .fill(egui::Color32::from_rgb(40, 40, 40))
+                                                                            .min_size(egui::vec2(36.0, 36.0))
+                                                                            .rounding(egui::Rounding::same(radius::MD));
+
+                                                                            if ui
+                                                                                .add(copy_btn)
+                                                                                .on_hover_text("Copy stream URL")
+                                                                                .clicked()
+                                                                            {
+                                                                                action = Some(EpisodeAction::CopyUrl {
+                                                                                    episode_id: ep.id.clone(),
+                                                                                    title: format!("{} S{}:E{}", data.name, ep.season, ep.num),
+                                                                                    container: ep.container.clone(),
+                                                                                });
                                                                             }
                                                                         },
                                                                     );
@@ -515,6 +720,170 @@ impl EpisodeDialog {
     }
 }
 
+/// Seasons of a `get_series_info` response with their episodes, both in
+/// order.
+fn parse_seasons(info: &serde_json::Value) -> Vec<(String, Vec<EpisodeData>)> {
+    let mut seasons: Vec<(String, Vec<EpisodeData>)> = Vec::new();
+
+    if let Some(episodes) = info.get("episodes").and_then(|v| v.as_object()) {
+        let mut season_keys: Vec<_> = episodes.keys().collect();
+        season_keys.sort_by(|a, b| {
+            a.parse::<i32>()
+                .unwrap_or(0)
+                .cmp(&b.parse::<i32>().unwrap_or(0))
+        });
+
+        for season_key in season_keys {
+            if let Some(season_episodes) =
+                episodes.get(season_key).and_then(|v| v.as_array())
+            {
+                let eps: Vec<EpisodeData> = season_episodes
+                    .iter()
+                    .map(|ep| {
+                        let id = if let Some(id_str) =
+                            ep.get("id").and_then(|v| v.as_str())
+                        {
+                            id_str.to_string()
+                        } else if let Some(id_num) =
+                            ep.get("id").and_then(|v| v.as_i64())
+                        {
+                            id_num.to_string()
+                        } else {
+                            String::new()
+                        };
+
+                        EpisodeData {
+                            id,
+                            num: ep
+                                .get("episode_num")
+                                .and_then(|v| v.as_i64())
+                                .unwrap_or(0),
+                            title: ep
+                                .get("title")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("Unknown")
+                                .to_string(),
+                            container: ep
+                                .get("container_extension")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("mp4")
+                                .to_string(),
+                            season: season_key.clone(),
+                            thumbnail: episode_thumbnail(ep),
+                        }
+                    })
+                    .collect();
+
+                seasons.push((season_key.clone(), eps));
+            }
+        }
+    }
+
+    seasons
+}
+
+/// Every episode of a `get_series_info` response as a play action, in
+/// viewing order. Used where the dialog isn't open, e.g. for Next Up.
+pub fn episode_actions(info: &serde_json::Value, series_name: &str) -> Vec<EpisodeAction> {
+    parse_seasons(info)
+        .iter()
+        .flat_map(|(_, episodes)| episodes)
+        .map(|ep| ep.play_action(series_name))
+        .collect()
+}
+
+/// The episode still from a `get_series_info` episode entry. Providers
+/// put it in `info.movie_image`, sometimes `info.cover_big`.
+fn episode_thumbnail(ep: &serde_json::Value) -> Option<String> {
+    let info = ep.get("info")?;
+    ["movie_image", "cover_big"]
+        .iter()
+        .filter_map(|key| info.get(*key).and_then(|v| v.as_str()))
+        .map(str::trim)
+        .find(|url| url.starts_with("http"))
+        .map(str::to_string)
+}
+
+/// A text field of the series `info` block, trimmed ("" if missing).
+fn info_text(info: &serde_json::Value, key: &str) -> String {
+    info.get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default()
+}
+
+/// Rating out of 10 from `rating` (string or number), else `rating_5based`.
+fn series_rating(info: &serde_json::Value) -> Option<f64> {
+    let number = |key: &str| {
+        info.get(key).and_then(|v| {
+            v.as_f64().or_else(|| v.as_str().and_then(|s| s.trim().parse().ok()))
+        })
+    };
+    number("rating")
+        .or_else(|| number("rating_5based").map(|r| r * 2.0))
+        .filter(|r| *r > 0.0)
+}
+
+/// "Released 21/09/2019 · ★ 8.1 · 3 seasons", leaving out what's unknown.
+fn overview_line(data: &SeriesData) -> String {
+    let mut parts = Vec::new();
+    if !data.release_date.is_empty() {
+        let date = chrono::NaiveDate::parse_from_str(&data.release_date, "%Y-%m-%d")
+            .map(crate::ui::locale::format_date)
+            .unwrap_or_else(|_| data.release_date.clone());
+        parts.push(format!("Released {}", date));
+    }
+    if let Some(rating) = data.rating {
+        parts.push(format!("★ {}", crate::ui::locale::format_number(rating, 1)));
+    }
+    let seasons = data.seasons.len();
+    parts.push(format!("{} season{}", seasons, if seasons == 1 { "" } else { "s" }));
+    parts.join(" · ")
+}
+
+/// Draws the series cover, a shimmer while it loads, or an empty frame.
+fn paint_cover(ui: &mut egui::Ui, image_cache: &ImageCache, url: Option<&str>) {
+    let (rect, _) = ui.allocate_exact_size(COVER_SIZE, egui::Sense::hover());
+    let placeholder = egui::Color32::from_rgb(40, 40, 40);
+    match url {
+        Some(url) if image_cache.paint(ui, url, rect) => {}
+        Some(url) if image_cache.is_pending(url) => paint_shimmer(ui, rect, radius::MD, placeholder),
+        _ => {
+            ui.painter().rect_filled(rect, radius::MD, placeholder);
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "📺",
+                egui::FontId::proportional(typography::H1),
+                egui::Color32::from_rgb(90, 90, 90),
+            );
+        }
+    }
+}
+
+/// Draws an episode still, a shimmer while it loads, or an empty frame.
+fn paint_thumbnail(ui: &mut egui::Ui, image_cache: &ImageCache, url: Option<&str>) {
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(THUMBNAIL_HEIGHT * 16.0 / 9.0, THUMBNAIL_HEIGHT),
+        egui::Sense::hover(),
+    );
+    let placeholder = egui::Color32::from_rgb(40, 40, 40);
+    match url {
+        Some(url) if image_cache.paint(ui, url, rect) => {}
+        Some(url) if image_cache.is_pending(url) => paint_shimmer(ui, rect, radius::SM, placeholder),
+        _ => {
+            ui.painter().rect_filled(rect, radius::SM, placeholder);
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "🎞",
+                egui::FontId::proportional(typography::BODY),
+                egui::Color32::from_rgb(90, 90, 90),
+            );
+        }
+    }
+}
+
 fn truncate_text(text: &str, max_len: usize) -> String {
     if text.chars().count() > max_len {
         format!(
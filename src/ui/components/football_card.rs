@@ -1,8 +1,11 @@
 // Football Fixture Card Component (Netflix-style)
 // Displays a football match with broadcast channels and allows searching IPTV channels
 
+use std::collections::HashSet;
 use egui::{self, Color32, Rounding, Vec2};
+use crate::api::football::{broadcaster_search_query, team_key, Broadcaster};
 use crate::api::FootballFixture;
+use crate::models::ChannelMapping;
 use crate::ui::theme::Theme;
 
 /// Channels to ignore (betting sites, not actual TV channels)
@@ -15,6 +18,11 @@ pub enum FootballAction {
     SearchChannel(String),
     /// Search for team name in live TV
     SearchTeam(String),
+    /// Play the best live channel matching any of these searches (the
+    /// fixture's broadcasters), falling back to searching for the first
+    PlayBestChannel(Vec<String>),
+    /// Add or remove a team from the favorite teams
+    ToggleFavoriteTeam(String),
 }
 
 /// A card component for displaying football fixtures (Netflix-style)
@@ -28,56 +36,10 @@ impl FootballCard {
         )
     }
     
-    /// Map channel name to IPTV search query
-    fn map_channel_to_search_query(channel: &str) -> String {
-        let channel_lower = channel.to_lowercase();
-
-        // DAZN mappings with country codes
-        if channel_lower.contains("dazn") {
-            // Extract country from channel name
-            if channel_lower.contains("italy") || channel_lower.contains("italia") {
-                return "|IT| DAZN".to_string();
-            } else if channel_lower.contains("germany") || channel_lower.contains("deutschland") {
-                return "|DE| DAZN".to_string();
-            } else if channel_lower.contains("spain") || channel_lower.contains("españa") || channel_lower.contains("espana") {
-                return "|ES| DAZN".to_string();
-            } else if channel_lower.contains("portugal") {
-                return "|PT| DAZN".to_string();
-            } else if channel_lower.contains("france") {
-                return "|FR| DAZN".to_string();
-            } else if channel_lower.contains("uk") || channel_lower.contains("united kingdom") {
-                return "|UK| DAZN".to_string();
-            } else if channel_lower.contains("belgium") {
-                return "|BE| DAZN".to_string();
-            } else if channel_lower.contains("austria") {
-                return "|AT| DAZN".to_string();
-            } else if channel_lower.contains("switzerland") || channel_lower.contains("swiss") {
-                return "|CH| DAZN".to_string();
-            } else if channel_lower.contains("japan") {
-                return "|JP| DAZN".to_string();
-            } else if channel_lower.contains("canada") {
-                return "|CA| DAZN".to_string();
-            } else if channel_lower.contains("usa") || channel_lower.contains("us ") {
-                return "|US| DAZN".to_string();
-            }
-            // If no country detected, just return "DAZN"
-            return "DAZN".to_string();
-        }
-
-        // Tring mappings
-        if channel_lower.contains("tring") {
-            return "Tring Sport".to_string();
-        }
-
-        // Default: return original channel name
-        channel.to_string()
-    }
-    
     /// Get filtered channels (excluding betting sites)
-    fn get_filtered_channels(fixture: &FootballFixture) -> Vec<String> {
+    fn get_filtered_channels(fixture: &FootballFixture) -> Vec<&Broadcaster> {
         fixture.broadcasters.iter()
             .filter(|b| !Self::should_ignore_channel(&b.channel))
-            .map(|b| b.channel.clone())
             .collect()
     }
 
@@ -87,6 +49,9 @@ impl FootballCard {
         theme: &Theme,
         fixture: &FootballFixture,
         _screen_width: f32,
+        local_time: bool,
+        favorite_teams: &HashSet<String>,
+        mappings: &[ChannelMapping],
     ) -> Option<FootballAction> {
         let mut action = None;
 
@@ -114,18 +79,26 @@ impl FootballCard {
                         ui.add_space(4.0);
 
                         // Time
-                        ui.label(egui::RichText::new(&fixture.display_time())
+                        ui.label(egui::RichText::new(fixture.display_time(local_time))
                             .size(14.0)
                             .color(badge_color)
                             .strong());
 
                         ui.add_space(6.0);
 
-                        // Teams
-                        ui.label(egui::RichText::new(format!("{} vs {}",
-                            fixture.home_team, fixture.away_team))
-                            .size(13.0)
-                            .color(Color32::WHITE));
+                        // Teams, each with a favorite star
+                        ui.horizontal_wrapped(|ui| {
+                            ui.spacing_mut().item_spacing.x = 4.0;
+                            if let Some(team) = Self::team_label(ui, &fixture.home_team, favorite_teams) {
+                                action = Some(FootballAction::ToggleFavoriteTeam(team));
+                            }
+                            ui.label(egui::RichText::new("vs")
+                                .size(12.0)
+                                .color(theme.text_secondary));
+                            if let Some(team) = Self::team_label(ui, &fixture.away_team, favorite_teams) {
+                                action = Some(FootballAction::ToggleFavoriteTeam(team));
+                            }
+                        });
                     });
 
                     ui.add_space(16.0);
@@ -135,9 +108,32 @@ impl FootballCard {
                         let filtered_channels = Self::get_filtered_channels(fixture);
 
                         if !filtered_channels.is_empty() {
-                            ui.label(egui::RichText::new("📡 Watch on:")
-                                .size(9.0)
-                                .color(theme.text_secondary));
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new("📡 Watch on:")
+                                    .size(9.0)
+                                    .color(theme.text_secondary));
+                                let watch_btn = egui::Button::new(
+                                    egui::RichText::new("▶ Watch this match")
+                                        .size(10.0)
+                                        .color(Color32::WHITE)
+                                )
+                                .fill(theme.accent_blue)
+                                .rounding(Rounding::same(12.0))
+                                .min_size(egui::vec2(0.0, 22.0));
+                                if ui.add(watch_btn)
+                                    .on_hover_text("Play the best matching channel from your playlist")
+                                    .clicked()
+                                {
+                                    let mut queries: Vec<String> = Vec::new();
+                                    for broadcaster in &filtered_channels {
+                                        let query = broadcaster_search_query(mappings, broadcaster);
+                                        if !queries.contains(&query) {
+                                            queries.push(query);
+                                        }
+                                    }
+                                    action = Some(FootballAction::PlayBestChannel(queries));
+                                }
+                            });
 
                             ui.add_space(4.0);
 
@@ -145,10 +141,11 @@ impl FootballCard {
                             ui.horizontal_wrapped(|ui| {
                                 let badge_color = Self::competition_color(&fixture.competition);
 
-                                for (i, channel) in filtered_channels.iter().take(6).enumerate() {
+                                for (i, broadcaster) in filtered_channels.iter().take(6).enumerate() {
                                     if i >= 6 { break; }
 
-                                    let search_query = Self::map_channel_to_search_query(channel);
+                                    let channel = &broadcaster.channel;
+                                    let search_query = broadcaster_search_query(mappings, broadcaster);
                                     let display_name = if channel.len() > 15 {
                                         format!("{}...", &channel[..12])
                                     } else {
@@ -193,6 +190,31 @@ impl FootballCard {
         action
     }
 
+    /// Team name with a clickable star; returns the team when the star is toggled.
+    /// `favorite_teams` holds [`team_key`]s, as the My Teams query does.
+    fn team_label(ui: &mut egui::Ui, team: &str, favorite_teams: &HashSet<String>) -> Option<String> {
+        let is_favorite = favorite_teams.contains(&team_key(team));
+        let (star, star_color, hover) = if is_favorite {
+            ("★", Color32::from_rgb(255, 200, 0), "Remove from My Teams")
+        } else {
+            ("☆", Color32::from_rgb(120, 120, 120), "Add to My Teams")
+        };
+
+        let clicked = ui.add(
+            egui::Button::new(egui::RichText::new(star).size(13.0).color(star_color))
+                .frame(false)
+        )
+        .on_hover_cursor(egui::CursorIcon::PointingHand)
+        .on_hover_text(hover)
+        .clicked();
+
+        ui.label(egui::RichText::new(team)
+            .size(13.0)
+            .color(Color32::WHITE));
+
+        clicked.then(|| team.to_string())
+    }
+
     /// Show a Netflix-style football fixture card (OLD - unused)
     #[allow(dead_code)]
     pub fn show_old(
@@ -200,6 +222,7 @@ impl FootballCard {
         theme: &Theme,
         fixture: &FootballFixture,
         _screen_width: f32,
+        local_time: bool,
     ) -> Option<FootballAction> {
         let mut action = None;
 
@@ -270,7 +293,7 @@ impl FootballCard {
             ui.painter().text(
                 egui::pos2(left_rect.min.x, y_pos),
                 egui::Align2::LEFT_TOP,
-                &fixture.display_time(),
+                fixture.display_time(local_time),
                 egui::FontId::proportional(16.0),
                 badge_color.linear_multiply(1.2),
             );
@@ -335,8 +358,9 @@ impl FootballCard {
                 let button_height = 28.0;
                 let button_spacing = 8.0;
 
-                for (i, channel) in filtered_channels.iter().take(channels_to_show).enumerate() {
-                    let search_query = Self::map_channel_to_search_query(channel);
+                for (i, broadcaster) in filtered_channels.iter().take(channels_to_show).enumerate() {
+                    let channel = &broadcaster.channel;
+                    let search_query = broadcaster_search_query(&[], broadcaster);
 
                     // Calculate button width
                     let display_name = if channel.chars().count() > 22 {
@@ -355,7 +379,9 @@ impl FootballCard {
                         egui::vec2(button_width, button_height),
                     );
 
-                    let btn_response = ui.allocate_rect(btn_rect, egui::Sense::click());
+                    let btn_response = ui.allocate_rect(btn_rect, egui::Sense::click())
+                        .on_hover_text(format!("Search for {}", channel));
+                    btn_response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, format!("Watch on {}", channel)));
                     let btn_hovered = btn_response.hovered();
 
                     // Button styling
@@ -418,6 +444,11 @@ impl FootballCard {
             s if s.contains("ligue 1") => Color32::from_rgb(30, 80, 150), // Dark Blue
             s if s.contains("champions") => Color32::from_rgb(0, 80, 150), // UEFA Blue
             s if s.contains("europa") => Color32::from_rgb(255, 140, 0), // Orange
+            s if s.contains("fa cup") => Color32::from_rgb(200, 30, 45), // FA Red
+            s if s.contains("copa del rey") => Color32::from_rgb(170, 21, 27), // Spanish Red
+            s if s.contains("eredivisie") => Color32::from_rgb(230, 100, 20), // Dutch Orange
+            s if s.contains("primeira") || s.contains("liga portugal") => Color32::from_rgb(0, 102, 51), // Portuguese Green
+            s if s.contains("mls") || s.contains("major league soccer") => Color32::from_rgb(0, 45, 100), // Navy
             _ => Color32::from_rgb(70, 130, 80), // Default green
         }
     }
@@ -428,6 +459,7 @@ impl FootballCard {
         ui: &mut egui::Ui,
         theme: &Theme,
         fixture: &FootballFixture,
+        local_time: bool,
     ) -> Option<FootballAction> {
         let mut action = None;
         let filtered_channels = Self::get_filtered_channels(fixture);
@@ -435,7 +467,7 @@ impl FootballCard {
         ui.horizontal(|ui| {
             // Time
             ui.label(
-                egui::RichText::new(&fixture.display_time())
+                egui::RichText::new(fixture.display_time(local_time))
                     .size(12.0)
                     .color(theme.accent_blue)
                     .strong()
@@ -471,8 +503,9 @@ impl FootballCard {
             
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 // Show first 2 channels as buttons
-                for channel in filtered_channels.iter().take(2) {
-                    let search_query = Self::map_channel_to_search_query(channel);
+                for broadcaster in filtered_channels.iter().take(2) {
+                    let channel = &broadcaster.channel;
+                    let search_query = broadcaster_search_query(&[], broadcaster);
                     if ui.small_button(channel).on_hover_text(format!("Search '{}' in Live TV", search_query)).clicked() {
                         action = Some(FootballAction::SearchChannel(search_query));
                     }
@@ -0,0 +1,113 @@
+//! Hover preview for poster cards.
+//!
+//! When the pointer or focus rests on a movie or series card, the poster
+//! zooms in a little and an info panel with the title, year and plot
+//! fades in over it. Timing uses egui's clock, so it runs at the same
+//! speed at any frame rate. Can be turned off in App Settings.
+
+use eframe::egui;
+use crate::ui::theme::{Theme, spacing, typography, radius};
+
+/// How long a card has to be hovered before the preview opens.
+const DWELL_SECS: f64 = 0.4;
+
+/// Duration of the zoom / fade animation.
+const ANIMATION_SECS: f32 = 0.15;
+
+/// Poster scale when the preview is fully open.
+const ZOOM: f32 = 1.05;
+
+/// Plots longer than this are cut with an ellipsis.
+const MAX_PLOT_CHARS: usize = 140;
+
+fn enabled_id() -> egui::Id {
+    egui::Id::new("card_hover_preview_enabled")
+}
+
+/// Turns the preview on or off for the cards drawn this frame.
+pub fn set_enabled(ctx: &egui::Context, enabled: bool) {
+    ctx.data_mut(|d| d.insert_temp(enabled_id(), enabled));
+}
+
+/// How far the card's preview is open, from 0 (closed) to 1.
+pub fn progress(ui: &egui::Ui, response: &egui::Response) -> f32 {
+    let ctx = ui.ctx();
+    let enabled = ctx.data(|d| d.get_temp::<bool>(enabled_id())).unwrap_or(true);
+    let since_id = response.id.with("hover_since");
+    let now = ctx.input(|i| i.time);
+
+    let active = enabled && (response.hovered() || response.has_focus());
+    let open = if active {
+        let since = ctx.data_mut(|d| *d.get_temp_mut_or_insert_with(since_id, || now));
+        let waited = now - since;
+        if waited < DWELL_SECS {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(DWELL_SECS - waited));
+        }
+        waited >= DWELL_SECS
+    } else {
+        ctx.data_mut(|d| d.remove::<f64>(since_id));
+        false
+    };
+    ctx.animate_bool_with_time(response.id.with("hover_preview"), open, ANIMATION_SECS)
+}
+
+/// `poster` scaled up around its center for preview `progress`.
+pub fn zoom(poster: egui::Rect, progress: f32) -> egui::Rect {
+    poster.expand2(poster.size() * (ZOOM - 1.0) / 2.0 * progress)
+}
+
+/// Info panel along the bottom of the poster: title, a details line
+/// ("2023 · Drama") and the plot, faded in with `progress`.
+pub fn paint_info(
+    ui: &egui::Ui,
+    poster: egui::Rect,
+    progress: f32,
+    title: &str,
+    details: Option<&str>,
+    plot: Option<&str>,
+    theme: &Theme,
+) {
+    if progress <= 0.0 {
+        return;
+    }
+    let painter = ui.painter().with_clip_rect(poster);
+    let wrap_width = poster.width() - spacing::MD * 2.0;
+    let white = egui::Color32::WHITE.gamma_multiply(progress);
+    let layout = |text: String, size: f32, color: egui::Color32| {
+        painter.layout(text, egui::FontId::proportional(size), color, wrap_width)
+    };
+
+    let mut galleys = vec![layout(title.to_string(), typography::BODY, white)];
+    if let Some(details) = details.filter(|d| !d.is_empty()) {
+        galleys.push(layout(details.to_string(), typography::CAPTION, theme.text_secondary.gamma_multiply(progress)));
+    }
+    if let Some(plot) = plot.map(str::trim).filter(|p| !p.is_empty()) {
+        galleys.push(layout(truncate(plot, MAX_PLOT_CHARS), typography::CAPTION, white));
+    }
+
+    let content_height: f32 = galleys.iter().map(|g| g.size().y + spacing::XS).sum();
+    let panel = egui::Rect::from_min_max(
+        egui::pos2(poster.min.x, (poster.max.y - content_height - spacing::MD * 2.0).max(poster.min.y)),
+        poster.max,
+    );
+    painter.rect_filled(
+        panel,
+        egui::Rounding { nw: 0.0, ne: 0.0, sw: radius::LG, se: radius::LG },
+        egui::Color32::from_black_alpha((220.0 * progress) as u8),
+    );
+
+    let mut y = panel.min.y + spacing::MD;
+    for galley in galleys {
+        let height = galley.size().y;
+        painter.galley(egui::pos2(panel.min.x + spacing::MD, y), galley, white);
+        y += height + spacing::XS;
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() > max_chars {
+        format!("{}…", text.chars().take(max_chars - 1).collect::<String>().trim_end())
+    } else {
+        text.to_string()
+    }
+}
@@ -4,25 +4,63 @@
 //! Features clean typography, generous spacing, and subtle interactions.
 
 use eframe::egui;
+use crate::models::LoginMode;
+use crate::ui::messages::{DiagnosticStage, DiagnosticStatus};
 use crate::ui::theme::{Theme, dimensions, spacing, typography, radius};
 
+/// Actions that can be triggered from the login screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginAction {
+    /// Sign in with the entered credentials
+    Connect,
+    /// Run the connection self-test
+    TestConnection,
+    /// Remove the saved server, username and password
+    ForgetCredentials,
+    /// The auto-connect checkbox was toggled
+    AutoConnectChanged,
+    /// Fill in the saved account at this index
+    SelectAccount(usize),
+    /// Clear the form for an account that isn't saved yet
+    NewAccount,
+}
+
+/// Values edited on the login screen.
+pub struct LoginForm<'a> {
+    pub mode: &'a mut LoginMode,
+    pub server_url: &'a mut String,
+    pub username: &'a mut String,
+    pub password: &'a mut String,
+    /// M3U playlist URL or file path for `LoginMode::Playlist`
+    pub playlist_url: &'a mut String,
+    /// Name the account is saved under
+    pub account_name: &'a mut String,
+    /// Saved account names
+    pub accounts: &'a [&'a str],
+    /// The saved account in the form, if any
+    pub active_account: Option<&'a str>,
+    /// Sign in by itself on the next start
+    pub auto_connect: &'a mut bool,
+    /// Whether credentials are saved, to offer forgetting them
+    pub has_saved_credentials: bool,
+}
+
 /// Login screen component for initial authentication.
 pub struct LoginScreen;
 
 impl LoginScreen {
     /// Renders a modern, premium login screen.
-    /// Returns true if connect was clicked.
+    /// Returns any action that was triggered.
     pub fn show(
         ui: &mut egui::Ui,
         theme: &Theme,
-        server_url: &mut String,
-        username: &mut String,
-        password: &mut String,
-        connecting: bool,
+        form: LoginForm<'_>,
+        connect_status: Option<&str>,
         error_message: &Option<String>,
+        diagnostics: &[(DiagnosticStage, DiagnosticStatus)],
         is_touch_mode: bool,
-    ) -> bool {
-        let mut should_connect = false;
+    ) -> Option<LoginAction> {
+        let mut action: Option<LoginAction> = None;
         let screen_width = ui.available_width();
         let is_mobile = dimensions::is_mobile(screen_width);
 
@@ -89,36 +127,119 @@ impl LoginScreen {
 
                         ui.add_space(spacing::SM);
 
+                        let subtitle = match form.mode {
+                            LoginMode::Xtream => "Enter your Xtream Codes credentials",
+                            LoginMode::Playlist => "Paste your provider's M3U link or a playlist file path",
+                        };
                         ui.label(
-                            egui::RichText::new("Enter your Xtream Codes credentials")
+                            egui::RichText::new(subtitle)
                                 .size(typography::BODY_SM)
                                 .color(theme.text_secondary),
                         );
 
-                        ui.add_space(spacing::XL);
+                        ui.add_space(spacing::MD);
 
-                        // Server URL field
-                        Self::input_field(
-                            ui,
-                            theme,
-                            server_url,
-                            "Server URL",
-                            "http://server:port",
-                            input_width,
-                            input_height,
-                            font_size,
-                            false,
-                        );
+                        if !form.accounts.is_empty() {
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Account")
+                                        .size(typography::BODY_SM)
+                                        .color(theme.text_secondary),
+                                );
+                                egui::ComboBox::from_id_salt("login_account")
+                                    .selected_text(form.active_account.unwrap_or("New account"))
+                                    .show_ui(ui, |ui| {
+                                        for (index, name) in form.accounts.iter().enumerate() {
+                                            if ui.selectable_label(form.active_account == Some(*name), *name).clicked() {
+                                                action = Some(LoginAction::SelectAccount(index));
+                                            }
+                                        }
+                                        if ui.selectable_label(form.active_account.is_none(), "➕ New account").clicked() {
+                                            action = Some(LoginAction::NewAccount);
+                                        }
+                                    });
+                            });
+                            ui.add_space(spacing::MD);
+                        }
+
+                        // Xtream is the default; playlists are for providers without it
+                        ui.horizontal(|ui| {
+                            for mode in LoginMode::ALL {
+                                ui.selectable_value(
+                                    form.mode,
+                                    mode,
+                                    egui::RichText::new(mode.display_name()).size(typography::BODY_SM),
+                                );
+                            }
+                        });
+
+                        ui.add_space(spacing::LG);
+
+                        if *form.mode == LoginMode::Playlist {
+                            Self::input_field(
+                                ui,
+                                theme,
+                                form.playlist_url,
+                                "Playlist URL or file",
+                                "http://provider/playlist.m3u8 or /path/to/list.m3u",
+                                input_width,
+                                input_height,
+                                font_size,
+                                false,
+                            );
+                        } else {
+                            // Server URL field
+                            Self::input_field(
+                                ui,
+                                theme,
+                                form.server_url,
+                                "Server URL",
+                                "http://server:port",
+                                input_width,
+                                input_height,
+                                font_size,
+                                false,
+                            );
+
+                            ui.add_space(spacing::MD);
+
+                            // Username field
+                            Self::input_field(
+                                ui,
+                                theme,
+                                form.username,
+                                "Username",
+                                "Enter your username",
+                                input_width,
+                                input_height,
+                                font_size,
+                                false,
+                            );
+
+                            ui.add_space(spacing::MD);
+
+                            // Password field
+                            Self::input_field(
+                                ui,
+                                theme,
+                                form.password,
+                                "Password",
+                                "Enter your password",
+                                input_width,
+                                input_height,
+                                font_size,
+                                true,
+                            );
+                        }
 
                         ui.add_space(spacing::MD);
 
-                        // Username field
                         Self::input_field(
                             ui,
                             theme,
-                            username,
-                            "Username",
-                            "Enter your username",
+                            form.account_name,
+                            "Account name",
+                            "Optional, e.g. your provider's name",
                             input_width,
                             input_height,
                             font_size,
@@ -127,31 +248,44 @@ impl LoginScreen {
 
                         ui.add_space(spacing::MD);
 
-                        // Password field
-                        Self::input_field(
-                            ui,
-                            theme,
-                            password,
-                            "Password",
-                            "Enter your password",
-                            input_width,
-                            input_height,
-                            font_size,
-                            true,
-                        );
+                        ui.horizontal(|ui| {
+                            if ui.checkbox(form.auto_connect, egui::RichText::new("Auto-connect on startup")
+                                .size(typography::BODY_SM)
+                                .color(theme.text_secondary))
+                                .changed()
+                            {
+                                action = Some(LoginAction::AutoConnectChanged);
+                            }
+                            if form.has_saved_credentials {
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    let forget = egui::Button::new(
+                                        egui::RichText::new("Forget credentials")
+                                            .size(typography::BODY_SM)
+                                            .color(theme.text_tertiary),
+                                    )
+                                    .frame(false);
+                                    if ui.add(forget)
+                                        .on_hover_text("Remove this account and its saved sign-in from this device")
+                                        .clicked()
+                                    {
+                                        action = Some(LoginAction::ForgetCredentials);
+                                    }
+                                });
+                            }
+                        });
 
                         ui.add_space(spacing::XL);
 
-                        // Connect button or loading state
-                        if connecting {
+                        // Connect button or loading state (with the current stage)
+                        if let Some(status) = connect_status {
                             ui.vertical_centered(|ui| {
                                 ui.add_space(spacing::SM);
                                 ui.horizontal(|ui| {
-                                    ui.add_space((input_width - 120.0) / 2.0);
+                                    ui.add_space((input_width - 180.0).max(0.0) / 2.0);
                                     ui.spinner();
                                     ui.add_space(spacing::SM);
                                     ui.label(
-                                        egui::RichText::new("Connecting...")
+                                        egui::RichText::new(status)
                                             .size(typography::BODY_SM)
                                             .color(theme.text_secondary),
                                     );
@@ -171,9 +305,39 @@ impl LoginScreen {
                             .rounding(egui::Rounding::same(radius::MD));
 
                             if ui.add(button).clicked() {
-                                should_connect = true;
+                                action = Some(LoginAction::Connect);
+                            }
+
+                            // The self-test checks the Xtream API
+                            if *form.mode == LoginMode::Xtream {
+                                ui.add_space(spacing::SM);
+
+                                let testing = diagnostics.iter().any(|(_, status)| {
+                                    matches!(status, DiagnosticStatus::Pending | DiagnosticStatus::Running)
+                                });
+                                let test_button = egui::Button::new(
+                                    egui::RichText::new(if testing { "Testing…" } else { "Test connection" })
+                                        .size(typography::BODY_SM)
+                                        .color(theme.text_secondary),
+                                )
+                                .fill(egui::Color32::TRANSPARENT)
+                                .stroke(egui::Stroke::new(1.0, theme.border_color))
+                                .min_size(egui::vec2(input_width, button_height * 0.75))
+                                .rounding(egui::Rounding::same(radius::MD));
+
+                                if ui.add_enabled(!testing, test_button)
+                                    .on_hover_text("Check DNS, network, server and credentials step by step")
+                                    .clicked()
+                                {
+                                    action = Some(LoginAction::TestConnection);
+                                }
                             }
                         }
+
+                        if !diagnostics.is_empty() {
+                            ui.add_space(spacing::LG);
+                            Self::diagnostics_checklist(ui, theme, diagnostics);
+                        }
                     });
                 });
 
@@ -200,13 +364,59 @@ impl LoginScreen {
             // Footer
             ui.add_space(spacing::XXL);
             ui.label(
-                egui::RichText::new("Compatible with Xtream Codes API")
+                egui::RichText::new("Compatible with Xtream Codes API and M3U playlists")
                     .size(typography::CAPTION)
                     .color(theme.text_muted),
             );
         });
 
-        should_connect
+        action
+    }
+
+    /// Self-test results, one row per stage with the detail underneath.
+    fn diagnostics_checklist(ui: &mut egui::Ui, theme: &Theme, diagnostics: &[(DiagnosticStage, DiagnosticStatus)]) {
+        for (stage, status) in diagnostics {
+            ui.horizontal(|ui| {
+                match status {
+                    DiagnosticStatus::Running => {
+                        ui.spinner();
+                    }
+                    DiagnosticStatus::Passed(_) => {
+                        ui.label(egui::RichText::new("✔").color(theme.success_color));
+                    }
+                    DiagnosticStatus::Failed(_) => {
+                        ui.label(egui::RichText::new("✖").color(theme.error_color));
+                    }
+                    DiagnosticStatus::Pending | DiagnosticStatus::Skipped => {
+                        ui.label(egui::RichText::new("○").color(theme.text_muted));
+                    }
+                }
+                let label_color = if matches!(status, DiagnosticStatus::Skipped) {
+                    theme.text_muted
+                } else {
+                    theme.text_primary
+                };
+                ui.label(
+                    egui::RichText::new(stage.label())
+                        .size(typography::BODY_SM)
+                        .color(label_color),
+                );
+            });
+
+            let detail = match status {
+                DiagnosticStatus::Passed(text) => Some((text.as_str(), theme.text_tertiary)),
+                DiagnosticStatus::Failed(text) => Some((text.as_str(), theme.error_color)),
+                DiagnosticStatus::Skipped => Some(("Skipped", theme.text_muted)),
+                _ => None,
+            };
+            if let Some((text, color)) = detail {
+                ui.horizontal_wrapped(|ui| {
+                    ui.add_space(spacing::XL);
+                    ui.label(egui::RichText::new(text).size(typography::CAPTION).color(color));
+                });
+            }
+            ui.add_space(spacing::XS);
+        }
     }
 
     /// Renders a modern input field with floating label effect
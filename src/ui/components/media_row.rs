@@ -0,0 +1,270 @@
+//! Media row component - dense list layout for movies and series
+//!
+//! The List view mode draws one row per title instead of a poster card:
+//! small thumbnail, title, year, rating and genre. Much quicker to scan
+//! than the grid when looking for a known title in a big catalog.
+
+use eframe::egui;
+use crate::models::{ViewMode, WatchHistory};
+use crate::ui::theme::{Theme, spacing, typography, radius};
+use crate::ui::image_cache::{paint_shimmer, placeholder_initials, ImageCache};
+
+/// Height of a row on desktop; touch mode adds room for fingers.
+const ROW_HEIGHT: f32 = 56.0;
+const ROW_HEIGHT_TOUCH: f32 = 72.0;
+
+/// What the user did with a movie or series, shown on its card or row.
+#[derive(Clone, Copy)]
+pub struct TitleState<'a> {
+    pub watch_history: &'a WatchHistory,
+    /// In My List
+    pub is_favorite: bool,
+}
+
+/// One row of the list view.
+pub struct MediaRow<'a> {
+    pub thumbnail: Option<&'a str>,
+    pub title: &'a str,
+    pub year: Option<String>,
+    /// Rating out of 10
+    pub rating: Option<f32>,
+    pub genre: Option<&'a str>,
+    /// Watch progress (0.0-1.0) of a started, unfinished title
+    pub progress: Option<f32>,
+}
+
+impl MediaRow<'_> {
+    /// Renders the row across the available width. The returned response
+    /// is clicked when the row is activated (mouse, touch or controller).
+    pub fn show(
+        &self,
+        ui: &mut egui::Ui,
+        ctx: &egui::Context,
+        theme: &Theme,
+        image_cache: &ImageCache,
+        is_touch_mode: bool,
+    ) -> egui::Response {
+        if let Some(url) = self.thumbnail.filter(|url| !url.is_empty()) {
+            image_cache.load(ctx, url.to_string());
+        }
+
+        let height = if is_touch_mode { ROW_HEIGHT_TOUCH } else { ROW_HEIGHT };
+        let (rect, response) = ui.allocate_exact_size(
+            egui::vec2(ui.available_width(), height),
+            egui::Sense::click(),
+        );
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, self.title));
+        let has_focus = response.has_focus();
+
+        let bg = if response.hovered() || has_focus { theme.card_elevated } else { theme.card_bg };
+        ui.painter().rect_filled(rect, radius::MD, bg);
+        if has_focus {
+            ui.painter().rect_stroke(rect, radius::MD, egui::Stroke::new(2.0, theme.accent_blue));
+        }
+
+        let thumb_rect = thumbnail_rect(rect);
+        match self.thumbnail.filter(|url| !url.is_empty()) {
+            Some(url) if image_cache.paint(ui, url, thumb_rect) => {}
+            Some(url) if image_cache.is_pending(url) => {
+                paint_shimmer(ui, thumb_rect, radius::SM, theme.placeholder_bg());
+            }
+            _ => {
+                ui.painter().rect_filled(thumb_rect, radius::SM, theme.placeholder_bg());
+                ui.painter().text(
+                    thumb_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    placeholder_initials(self.title),
+                    egui::FontId::proportional(typography::LABEL),
+                    theme.placeholder_icon(),
+                );
+            }
+        }
+        if let Some(fraction) = self.progress {
+            paint_resume_bar(ui, thumb_rect, fraction, theme);
+        }
+
+        // Rating column on the right, title and details on the left
+        let right = rect.max.x - spacing::LG;
+        if let Some(rating) = self.rating.filter(|r| *r > 0.0) {
+            ui.painter().text(
+                egui::pos2(right, rect.center().y),
+                egui::Align2::RIGHT_CENTER,
+                format!("★ {:.1}", rating),
+                egui::FontId::proportional(typography::BODY_SM),
+                theme.warning_color,
+            );
+        }
+
+        let text_x = thumb_rect.max.x + spacing::MD;
+        let text_painter = ui.painter().with_clip_rect(rect.with_max_x(right - 64.0));
+        text_painter.text(
+            egui::pos2(text_x, rect.center().y - 2.0),
+            egui::Align2::LEFT_BOTTOM,
+            self.title,
+            egui::FontId::proportional(typography::BODY),
+            theme.text_primary,
+        );
+
+        let details: Vec<&str> = [self.year.as_deref(), self.genre]
+            .into_iter()
+            .flatten()
+            .filter(|s| !s.trim().is_empty())
+            .collect();
+        if !details.is_empty() {
+            text_painter.text(
+                egui::pos2(text_x, rect.center().y + 2.0),
+                egui::Align2::LEFT_TOP,
+                details.join(" · "),
+                egui::FontId::proportional(typography::CAPTION),
+                theme.text_tertiary,
+            );
+        }
+
+        response.on_hover_cursor(egui::CursorIcon::PointingHand)
+    }
+}
+
+/// Thumbnail area of a row, in poster proportions.
+pub fn thumbnail_rect(row: egui::Rect) -> egui::Rect {
+    let thumb_height = row.height() - spacing::SM * 2.0;
+    egui::Rect::from_min_size(
+        row.min + egui::vec2(spacing::SM, spacing::SM),
+        egui::vec2(thumb_height * 2.0 / 3.0, thumb_height),
+    )
+}
+
+/// Poster area of a grid card of the given width allocated at `card`.
+pub fn card_poster_rect(card: egui::Rect, width: f32) -> egui::Rect {
+    egui::Rect::from_min_size(
+        card.min + egui::vec2(spacing::XS, 0.0),
+        egui::vec2(width, crate::ui::theme::dimensions::poster_height(width)),
+    )
+}
+
+/// Covers an adult poster with a near-opaque veil and an eye icon, unless
+/// the pointer or keyboard/controller focus is on the card.
+pub fn paint_adult_cover(ui: &egui::Ui, card: egui::Rect, poster: egui::Rect, theme: &Theme) {
+    let ctx = ui.ctx();
+    let focused = ctx
+        .memory(|m| m.focused())
+        .and_then(|id| ctx.read_response(id))
+        .is_some_and(|r| card.contains_rect(r.rect));
+    if focused || ui.rect_contains_pointer(card) {
+        return;
+    }
+    let rounding = if poster.width() < 60.0 { radius::SM } else { radius::LG };
+    ui.painter().rect_filled(poster, rounding, theme.placeholder_bg().gamma_multiply(0.97));
+    ui.painter().text(
+        poster.center(),
+        egui::Align2::CENTER_CENTER,
+        "👁",
+        egui::FontId::proportional((poster.width() * 0.3).clamp(14.0, 32.0)),
+        theme.text_muted,
+    );
+}
+
+/// Thin progress bar along the bottom of a poster, marking a title the
+/// user started but didn't finish.
+pub fn paint_resume_bar(ui: &egui::Ui, rect: egui::Rect, fraction: f32, theme: &Theme) {
+    let inset = (rect.width() * 0.06).min(spacing::SM);
+    let bar = egui::Rect::from_min_max(
+        egui::pos2(rect.min.x + inset, rect.max.y - inset - 4.0),
+        egui::pos2(rect.max.x - inset, rect.max.y - inset),
+    );
+    ui.painter().rect_filled(bar, radius::FULL, egui::Color32::from_rgba_unmultiplied(0, 0, 0, 170));
+    // Always show a sliver, the position isn't known until the player reports it
+    let filled = bar.with_max_x(bar.min.x + bar.width() * fraction.clamp(0.04, 1.0));
+    ui.painter().rect_filled(filled, radius::FULL, theme.accent_blue);
+}
+
+/// "★ 8.2 · 2023 · Drama" from whichever parts are known; `None` when
+/// there is nothing to show.
+pub fn card_metadata(rating: Option<f32>, year: Option<String>, genre: Option<&str>) -> Option<String> {
+    let parts: Vec<String> = [
+        rating.filter(|r| *r > 0.0).map(|r| format!("★ {}", crate::ui::locale::format_number(r as f64, 1))),
+        year,
+        genre.map(str::trim).filter(|g| !g.is_empty()).map(str::to_string),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
+
+/// Paints `text` on a dark strip along the bottom of a poster.
+pub fn paint_metadata_strip(ui: &egui::Ui, poster: egui::Rect, text: &str, theme: &Theme) {
+    let strip = egui::Rect::from_min_max(egui::pos2(poster.min.x, poster.max.y - 22.0), poster.max);
+    ui.painter().rect_filled(
+        strip,
+        egui::Rounding { nw: 0.0, ne: 0.0, sw: radius::LG, se: radius::LG },
+        theme.gradient_overlay(),
+    );
+    ui.painter().with_clip_rect(strip.shrink2(egui::vec2(spacing::SM, 0.0))).text(
+        egui::pos2(strip.min.x + spacing::SM, strip.center().y),
+        egui::Align2::LEFT_CENTER,
+        text,
+        egui::FontId::proportional(typography::CAPTION),
+        egui::Color32::WHITE,
+    );
+}
+
+/// Star in the top-right corner of a poster that adds the title to My
+/// List or removes it. Shown while the title is in My List or the pointer
+/// is on the card. Returns true when clicked.
+pub fn favorite_star(ui: &mut egui::Ui, poster: egui::Rect, id: egui::Id, is_favorite: bool, card_active: bool, theme: &Theme) -> bool {
+    if !is_favorite && !card_active {
+        return false;
+    }
+    let center = egui::pos2(poster.max.x - 18.0, poster.min.y + 18.0);
+    let response = ui
+        .interact(egui::Rect::from_center_size(center, egui::vec2(28.0, 28.0)), id, egui::Sense::click())
+        .on_hover_text(if is_favorite { "Remove from My List" } else { "Add to My List" });
+    response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, true, is_favorite, "Favorite"));
+
+    let color = if is_favorite {
+        theme.warning_color
+    } else if response.hovered() {
+        egui::Color32::WHITE
+    } else {
+        theme.text_secondary
+    };
+    ui.painter().circle_filled(center, 13.0, egui::Color32::from_black_alpha(150));
+    ui.painter().text(
+        center,
+        egui::Align2::CENTER_CENTER,
+        if is_favorite { "★" } else { "☆" },
+        egui::FontId::proportional(16.0),
+        color,
+    );
+    response.clicked()
+}
+
+/// Paints a "NEW" pill in the top-left corner of a poster or list row.
+pub fn paint_new_badge(ui: &egui::Ui, rect: egui::Rect, theme: &Theme) {
+    let galley = ui.painter().layout_no_wrap(
+        "NEW".to_string(),
+        egui::FontId::proportional(typography::CAPTION),
+        egui::Color32::WHITE,
+    );
+    let pill = egui::Rect::from_min_size(
+        rect.min + egui::vec2(spacing::SM, spacing::SM),
+        galley.size() + egui::vec2(spacing::SM * 2.0, spacing::XS * 2.0),
+    );
+    ui.painter().rect_filled(pill, radius::FULL, theme.success_color);
+    ui.painter().galley(pill.min + egui::vec2(spacing::SM, spacing::XS), galley, egui::Color32::WHITE);
+}
+
+/// Grid/List switch shown above the movie and series lists.
+/// Returns the newly picked mode.
+pub fn view_mode_toggle(ui: &mut egui::Ui, current: ViewMode) -> Option<ViewMode> {
+    let mut picked = None;
+    for (mode, label, hint) in [
+        (ViewMode::Grid, "▦", "Poster grid"),
+        (ViewMode::List, "☰", "Compact list"),
+    ] {
+        if ui.selectable_label(current == mode, label).on_hover_text(hint).clicked() && current != mode {
+            picked = Some(mode);
+        }
+    }
+    picked
+}
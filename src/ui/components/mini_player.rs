@@ -0,0 +1,93 @@
+//! Mini player - remote control bar for the external player
+//!
+//! Shown at the bottom of the window while a launched player is running.
+//! MPV is controlled over its IPC socket (pause and volume); other players
+//! can only be stopped. A live channel can be recorded from here too.
+
+use eframe::egui;
+
+use crate::ui::theme::{spacing, typography, Theme};
+
+/// Playback state read from a player that can be remote-controlled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemoteState {
+    pub paused: bool,
+    /// Volume in percent
+    pub volume: f64,
+}
+
+/// Actions returned by the mini player.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MiniPlayerAction {
+    TogglePause,
+    /// Close the player
+    Stop,
+    /// Volume slider being dragged; only moves the slider
+    AdjustVolume(f64),
+    /// Volume picked, to be sent to the player
+    SetVolume(f64),
+    /// Start or stop recording the live channel playing
+    ToggleRecording,
+}
+
+/// Mini player component.
+pub struct MiniPlayer;
+
+impl MiniPlayer {
+    /// Renders the bar for the stream `title`. `remote` is `None` for
+    /// players without remote control, which only get Stop. `recording`
+    /// is whether the live channel playing is being recorded, `None` when
+    /// it isn't a live channel.
+    pub fn show(
+        ui: &mut egui::Ui,
+        theme: &Theme,
+        title: &str,
+        remote: Option<RemoteState>,
+        recording: Option<bool>,
+    ) -> Option<MiniPlayerAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            if let Some(state) = remote {
+                let (icon, hint) = if state.paused { ("▶", "Play") } else { ("⏸", "Pause") };
+                if ui.button(egui::RichText::new(icon).size(typography::H3)).on_hover_text(hint).clicked() {
+                    action = Some(MiniPlayerAction::TogglePause);
+                }
+            }
+            if ui.button(egui::RichText::new("⏹").size(typography::H3)).on_hover_text("Stop").clicked() {
+                action = Some(MiniPlayerAction::Stop);
+            }
+            if let Some(is_recording) = recording {
+                if record_button(ui, theme, is_recording).clicked() {
+                    action = Some(MiniPlayerAction::ToggleRecording);
+                }
+            }
+            ui.add_space(spacing::SM);
+            ui.label(egui::RichText::new("Now playing").size(typography::CAPTION).color(theme.text_tertiary));
+            ui.label(egui::RichText::new(title).color(theme.text_primary).strong());
+
+            if let Some(state) = remote {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let mut volume = state.volume;
+                    let slider = ui.add(egui::Slider::new(&mut volume, 0.0..=100.0).show_value(false));
+                    // One command when the drag ends rather than one per frame
+                    if slider.drag_stopped() || (slider.changed() && !slider.dragged()) {
+                        action = Some(MiniPlayerAction::SetVolume(volume));
+                    } else if slider.changed() {
+                        action = Some(MiniPlayerAction::AdjustVolume(volume));
+                    }
+                    ui.label("🔊");
+                });
+            }
+        });
+
+        action
+    }
+}
+
+/// Record toggle for the live channel playing, red while recording.
+pub fn record_button(ui: &mut egui::Ui, theme: &Theme, is_recording: bool) -> egui::Response {
+    let color = if is_recording { theme.error_color } else { theme.text_secondary };
+    ui.button(egui::RichText::new("⏺").size(typography::H3).color(color))
+        .on_hover_text(if is_recording { "Stop recording" } else { "Record" })
+}
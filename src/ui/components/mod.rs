@@ -9,6 +9,20 @@
 //! - Discover cards for TV show discovery
 //! - Football fixture cards for live sports
 //! - Scraper settings for fixture scraping
+//! - Keyboard shortcuts help overlay
+//! - On-screen keyboard for touch / Game Mode search
+//! - General app settings
+//! - A–Z fast-scroll index
+//! - Programme reminder notifications
+//! - Compact list rows for movies and series
+//! - Hover preview for poster cards
+//! - Audio/subtitle track picker before VOD playback
+//! - Context menu with per-card actions
+//! - Catch-up archive browser for channels with timeshift
+//! - Built-in player view with playback controls
+//! - Watch statistics
+//! - Mini player remote control for the external player
+//! - What's new notes after an update
 //! - Common UI utilities
 
 pub mod login;
@@ -24,9 +38,23 @@ pub mod epg_settings;
 pub mod discover_card;
 pub mod football_card;
 pub mod scraper_settings;
+pub mod shortcuts_help;
+pub mod on_screen_keyboard;
+pub mod app_settings;
+pub mod alpha_index;
+pub mod reminder_toast;
+pub mod media_row;
+pub mod hover_preview;
+pub mod track_picker;
+pub mod card_context_menu;
+pub mod catchup_dialog;
+pub mod embedded_player_view;
+pub mod watch_stats;
+pub mod mini_player;
+pub mod whats_new;
 
 pub use login::LoginScreen;
-pub use sidebar::CategorySidebar;
+pub use sidebar::{CategoryPrefs, CategorySidebar, CategorySidebarAction};
 pub use top_nav::TopNavigation;
 pub use channel_card::ChannelCard;
 pub use series_card::SeriesCard;
@@ -38,3 +66,16 @@ pub use player_settings::PlayerSettingsDialog;
 pub use epg_settings::EpgSettingsDialog;
 pub use discover_card::{DiscoverCard, DiscoverAction};
 pub use scraper_settings::ScraperSettingsDialog;
+pub use shortcuts_help::ShortcutsHelp;
+pub use on_screen_keyboard::{OnScreenKeyboard, KeyboardAction};
+pub use app_settings::AppSettingsDialog;
+pub use alpha_index::AlphaIndex;
+pub use reminder_toast::{ReminderToast, ReminderToastAction};
+pub use media_row::MediaRow;
+pub use track_picker::TrackPicker;
+pub use card_context_menu::CardContextMenu;
+pub use catchup_dialog::{CatchupDialog, CatchupAction};
+pub use embedded_player_view::{EmbeddedPlayerView, EmbeddedPlayerAction};
+pub use watch_stats::WatchStatsDialog;
+pub use mini_player::{MiniPlayer, MiniPlayerAction};
+pub use whats_new::WhatsNew;
@@ -4,8 +4,12 @@
 //! Consistent with series card styling for a unified experience.
 
 use eframe::egui;
+use crate::models::{Movie, WatchHistory};
+use crate::ui::highlight;
 use crate::ui::theme::{Theme, dimensions, spacing, typography, radius};
-use crate::ui::image_cache::ImageCache;
+use crate::ui::image_cache::{paint_shimmer, placeholder_initials, ImageCache};
+use super::{hover_preview, CardContextMenu, MediaRow};
+use super::media_row::{card_metadata, favorite_star, paint_metadata_strip, paint_resume_bar, TitleState};
 
 /// Actions that can be triggered from a movie card.
 #[derive(Debug, Clone)]
@@ -17,6 +21,20 @@ pub enum MovieAction {
         container_extension: String,
         thumbnail: Option<String>,
     },
+    /// Copy the movie's stream URL to the clipboard
+    CopyUrl {
+        stream_id: i64,
+        name: String,
+        container_extension: String,
+    },
+    /// Mark the movie as watched, or unwatched if it already is
+    ToggleWatched {
+        stream_id: i64,
+        name: String,
+        thumbnail: Option<String>,
+    },
+    /// Add the movie to My List, or remove it
+    ToggleFavorite(i64),
 }
 
 /// Movie card component - Modern poster style
@@ -29,27 +47,25 @@ impl MovieCard {
         ui: &mut egui::Ui,
         ctx: &egui::Context,
         theme: &Theme,
-        movie: &serde_json::Value,
+        movie: &Movie,
         image_cache: &ImageCache,
-        screen_width: f32,
+        state: TitleState,
+        size: dimensions::CardSize,
     ) -> Option<MovieAction> {
         let mut action: Option<MovieAction> = None;
-        let is_mobile = dimensions::is_mobile(screen_width);
+        let watch_history = state.watch_history;
+        let is_mobile = size.is_mobile;
 
         // Card dimensions
-        let card_width = dimensions::card_width(screen_width);
+        let card_width = size.width;
         let poster_height = dimensions::poster_height(card_width);
         let total_height = poster_height + 48.0;
 
         // Extract movie data
-        let movie_name = movie.get("name").and_then(|v| v.as_str()).unwrap_or("Unknown");
-        let stream_id = movie.get("stream_id").and_then(|v| v.as_i64()).unwrap_or(0);
-        let container_extension = movie
-            .get("container_extension")
-            .and_then(|v| v.as_str())
-            .unwrap_or("mp4");
-        let cover = movie.get("stream_icon").and_then(|v| v.as_str());
-        let rating = movie.get("rating").and_then(|v| v.as_f64());
+        let movie_name = if movie.name.is_empty() { "Unknown" } else { movie.name.as_str() };
+        let stream_id = movie.stream_id;
+        let container_extension = movie.container();
+        let cover = movie.stream_icon.as_deref();
 
         // Load cover image
         if let Some(cover_url) = cover {
@@ -63,6 +79,7 @@ impl MovieCard {
             egui::vec2(card_width + spacing::SM, total_height + spacing::SM),
             egui::Sense::click(),
         );
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, format!("Play {}", movie_name)));
 
         if response.clicked() {
             action = Some(MovieAction::Play {
@@ -75,9 +92,13 @@ impl MovieCard {
 
         let is_hovered = response.hovered();
         let has_focus = response.has_focus();
-        let poster_rect = egui::Rect::from_min_size(
-            rect.min + egui::vec2(spacing::XS, 0.0),
-            egui::vec2(card_width, poster_height),
+        let preview = hover_preview::progress(ui, &response);
+        let poster_rect = hover_preview::zoom(
+            egui::Rect::from_min_size(
+                rect.min + egui::vec2(spacing::XS, 0.0),
+                egui::vec2(card_width, poster_height),
+            ),
+            preview,
         );
 
         // Shadow on hover or focus (for gamepad navigation) - subtle lift effect
@@ -92,10 +113,7 @@ impl MovieCard {
 
         // Draw poster or placeholder
         if let Some(cover_url) = cover {
-            if let Some(texture) = image_cache.get(cover_url) {
-                let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
-                ui.painter().image(texture.id(), poster_rect, uv, egui::Color32::WHITE);
-
+            if image_cache.paint(ui, cover_url, poster_rect) {
                 // Border - accent color on focus for gamepad navigation
                 let border_color = if has_focus { theme.accent_blue } else { theme.border_color };
                 let border_width = if has_focus { 2.0 } else { 1.0 };
@@ -104,11 +122,15 @@ impl MovieCard {
                     radius::LG,
                     egui::Stroke::new(border_width, border_color),
                 );
+            } else if image_cache.is_pending(cover_url) {
+                paint_shimmer(ui, poster_rect, radius::LG, theme.placeholder_bg());
             } else {
-                Self::paint_placeholder(ui, theme, poster_rect);
+                // Gave up on the image, show the initials instead
+                let initials = placeholder_initials(movie_name);
+                Self::paint_placeholder(ui, theme, poster_rect, &initials);
             }
         } else {
-            Self::paint_placeholder(ui, theme, poster_rect);
+            Self::paint_placeholder(ui, theme, poster_rect, "Film");
         }
 
         // Hover/focus overlay with play button (for gamepad navigation)
@@ -159,47 +181,153 @@ impl MovieCard {
             ));
         }
 
-        // Title below poster
-        let title_y = poster_rect.max.y + spacing::SM;
+        let card_active = has_focus || ui.rect_contains_pointer(rect);
+        if favorite_star(ui, poster_rect, response.id.with("favorite"), state.is_favorite, card_active, theme) {
+            action = Some(MovieAction::ToggleFavorite(stream_id));
+        }
+
+        // Resume indicator for a partially watched movie
+        let resume = watch_history
+            .get_progress(&stream_id.to_string())
+            .and_then(|p| p.resume_fraction());
+
+        // Rating and year, hidden under the hover overlay
+        if !(is_hovered || has_focus) {
+            if let Some(metadata) = card_metadata(movie.rating_out_of_10(), movie.release_year(), None) {
+                let lift = if resume.is_some() { spacing::MD } else { 0.0 };
+                paint_metadata_strip(ui, poster_rect.with_max_y(poster_rect.max.y - lift), &metadata, theme);
+            }
+        }
+        if let Some(fraction) = resume {
+            paint_resume_bar(ui, poster_rect, fraction, theme);
+        }
+
+        // Title, year and plot once the pointer rests on the card
+        let details = card_metadata(movie.rating_out_of_10(), movie.release_year(), movie.genre.as_deref());
+        hover_preview::paint_info(ui, poster_rect, preview, movie_name, details.as_deref(), None, theme);
+
+        // Title below poster (unaffected by the preview zoom)
+        let title_y = rect.min.y + poster_height + spacing::SM;
         let display_name = truncate_text(movie_name, if is_mobile { 18 } else { 22 });
-        let title_galley = ui.painter().layout_no_wrap(
-            display_name,
+        let title_galley = ui.painter().layout_job(highlight::title_job(
+            ctx,
+            &display_name,
             egui::FontId::proportional(if is_mobile { typography::BODY_SM } else { typography::BODY }),
             theme.text_primary,
-        );
+            theme.accent_blue,
+        ));
         ui.painter().galley(
             egui::pos2(poster_rect.min.x, title_y),
             title_galley,
             theme.text_primary,
         );
 
-        // Rating below title
-        if let Some(rating_val) = rating {
-            if rating_val > 0.0 {
-                let rating_text = format!("★ {:.1}", rating_val);
-                let rating_galley = ui.painter().layout_no_wrap(
-                    rating_text,
-                    egui::FontId::proportional(typography::CAPTION),
-                    theme.warning_color,
-                );
-                ui.painter().galley(
-                    egui::pos2(poster_rect.min.x, title_y + 20.0),
-                    rating_galley,
-                    theme.warning_color,
-                );
-            }
+        // Context menu: right-click, long press, Shift+F10 or controller Y
+        let watched = Self::is_watched(watch_history, stream_id);
+        let menu_pick = CardContextMenu::show(ui, &response, || {
+            Self::menu_entries(stream_id, movie_name, container_extension, cover, watched, state.is_favorite)
+        });
+        action.or(menu_pick)
+    }
+
+    /// Renders the movie as a row of the compact list view.
+    pub fn show_row(
+        ui: &mut egui::Ui,
+        ctx: &egui::Context,
+        theme: &Theme,
+        movie: &Movie,
+        image_cache: &ImageCache,
+        state: TitleState,
+        is_touch_mode: bool,
+    ) -> Option<MovieAction> {
+        let watch_history = state.watch_history;
+        let name = if movie.name.is_empty() { "Unknown" } else { movie.name.as_str() };
+        let stream_id = movie.stream_id;
+        let row = MediaRow {
+            thumbnail: movie.stream_icon.as_deref(),
+            title: name,
+            year: movie.release_year(),
+            rating: movie.rating_out_of_10(),
+            genre: movie.genre.as_deref(),
+            progress: watch_history
+                .get_progress(&stream_id.to_string())
+                .and_then(|p| p.resume_fraction()),
+        };
+
+        let container_extension = movie.container();
+        let thumbnail = movie.stream_icon.as_deref();
+        let response = row.show(ui, ctx, theme, image_cache, is_touch_mode);
+        let watched = Self::is_watched(watch_history, stream_id);
+        let menu_pick = CardContextMenu::show(ui, &response, || {
+            Self::menu_entries(stream_id, name, container_extension, thumbnail, watched, state.is_favorite)
+        });
+        if !response.clicked() {
+            return menu_pick;
         }
+        Some(MovieAction::Play {
+            stream_id,
+            name: name.to_string(),
+            container_extension: container_extension.to_string(),
+            thumbnail: thumbnail.map(str::to_string),
+        })
+    }
+
+    /// Whether the movie was watched to the end.
+    fn is_watched(watch_history: &WatchHistory, stream_id: i64) -> bool {
+        watch_history
+            .get_progress(&stream_id.to_string())
+            .is_some_and(|p| p.is_nearly_finished())
+    }
 
-        action
+    /// Entries of the movie's context menu.
+    fn menu_entries(
+        stream_id: i64,
+        name: &str,
+        container_extension: &str,
+        thumbnail: Option<&str>,
+        watched: bool,
+        is_favorite: bool,
+    ) -> Vec<(String, MovieAction)> {
+        vec![
+            (
+                "▶ Play".to_string(),
+                MovieAction::Play {
+                    stream_id,
+                    name: name.to_string(),
+                    container_extension: container_extension.to_string(),
+                    thumbnail: thumbnail.map(str::to_string),
+                },
+            ),
+            (
+                "📋 Copy stream URL".to_string(),
+                MovieAction::CopyUrl {
+                    stream_id,
+                    name: name.to_string(),
+                    container_extension: container_extension.to_string(),
+                },
+            ),
+            (
+                if watched { "↺ Mark as unwatched" } else { "✓ Mark as watched" }.to_string(),
+                MovieAction::ToggleWatched {
+                    stream_id,
+                    name: name.to_string(),
+                    thumbnail: thumbnail.map(str::to_string),
+                },
+            ),
+            (
+                if is_favorite { "★ Remove from My List" } else { "☆ Add to My List" }.to_string(),
+                MovieAction::ToggleFavorite(stream_id),
+            ),
+        ]
     }
 
     /// Paints a placeholder for missing posters
-    fn paint_placeholder(ui: &egui::Ui, theme: &Theme, rect: egui::Rect) {
+    fn paint_placeholder(ui: &egui::Ui, theme: &Theme, rect: egui::Rect, label: &str) {
         ui.painter().rect_filled(rect, radius::LG, theme.placeholder_bg());
 
         let center = rect.center();
         let galley = ui.painter().layout_no_wrap(
-            "Film".to_string(),
+            label.to_string(),
             egui::FontId::proportional(rect.width() * 0.18),
             theme.placeholder_icon(),
         );
@@ -0,0 +1,120 @@
+//! On-screen keyboard for touch / Steam Deck Game Mode
+//!
+//! Lets the search query be typed without a physical keyboard or
+//! Steam's overlay keyboard.
+
+use eframe::egui;
+use crate::ui::theme::{Theme, spacing, typography, radius, dimensions};
+
+/// Key rows, top to bottom. Backspace is appended to the last row.
+const KEY_ROWS: &[&str] = &[
+    "1234567890",
+    "qwertyuiop",
+    "asdfghjkl",
+    "zxcvbnm",
+];
+
+/// Actions returned by the on-screen keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardAction {
+    /// The text was edited
+    Changed,
+    /// The keyboard should be hidden
+    Close,
+}
+
+/// On-screen keyboard component
+pub struct OnScreenKeyboard;
+
+impl OnScreenKeyboard {
+    /// Shows the keyboard, editing `text` in place.
+    pub fn show(ui: &mut egui::Ui, theme: &Theme, text: &mut String) -> Option<KeyboardAction> {
+        let mut action = None;
+
+        // Fit the widest row (10 keys) into the available width
+        let gap = spacing::SM;
+        let key_size = ((ui.available_width() - gap * 9.0) / 10.0)
+            .clamp(36.0, dimensions::STEAM_DECK_TOUCH_TARGET);
+
+        ui.spacing_mut().item_spacing = egui::vec2(gap, gap);
+
+        ui.vertical_centered(|ui| {
+            // Current query preview
+            ui.label(
+                egui::RichText::new(if text.is_empty() { "Type to search..." } else { text.as_str() })
+                    .size(typography::H3)
+                    .color(if text.is_empty() { theme.text_muted } else { theme.text_primary }),
+            );
+            ui.add_space(spacing::SM);
+
+            for (row_idx, row) in KEY_ROWS.iter().enumerate() {
+                let is_last = row_idx == KEY_ROWS.len() - 1;
+                let keys = row.chars().count() + usize::from(is_last);
+                let row_width = keys as f32 * key_size + (keys - 1) as f32 * gap;
+
+                Self::centered_row(ui, row_width, |ui| {
+                    for c in row.chars() {
+                        if Self::key(ui, theme, &c.to_string(), key_size, key_size) {
+                            text.push(c);
+                            action = Some(KeyboardAction::Changed);
+                        }
+                    }
+                    if is_last && Self::key(ui, theme, "⌫", key_size, key_size) && text.pop().is_some() {
+                        action = Some(KeyboardAction::Changed);
+                    }
+                });
+            }
+
+            // Bottom row: clear, space, done
+            let side_width = key_size * 2.0 + gap;
+            let space_width = key_size * 5.0 + gap * 4.0;
+            let row_width = side_width * 2.0 + space_width + gap * 2.0;
+
+            Self::centered_row(ui, row_width, |ui| {
+                if Self::key(ui, theme, "Clear", side_width, key_size) && !text.is_empty() {
+                    text.clear();
+                    action = Some(KeyboardAction::Changed);
+                }
+                if Self::key(ui, theme, "Space", space_width, key_size) {
+                    text.push(' ');
+                    action = Some(KeyboardAction::Changed);
+                }
+                let done = egui::Button::new(
+                    egui::RichText::new("Done")
+                        .size(typography::BODY)
+                        .color(egui::Color32::WHITE),
+                )
+                .fill(theme.accent_blue)
+                .rounding(egui::Rounding::same(radius::MD))
+                .min_size(egui::vec2(side_width, key_size));
+                if ui.add(done).clicked() {
+                    action = Some(KeyboardAction::Close);
+                }
+            });
+        });
+
+        action
+    }
+
+    /// Lay out a row of keys centered horizontally.
+    fn centered_row(ui: &mut egui::Ui, row_width: f32, add_keys: impl FnOnce(&mut egui::Ui)) {
+        ui.horizontal(|ui| {
+            ui.add_space(((ui.available_width() - row_width) / 2.0).max(0.0));
+            add_keys(ui);
+        });
+    }
+
+    /// A single key button. Returns true when pressed.
+    fn key(ui: &mut egui::Ui, theme: &Theme, label: &str, width: f32, height: f32) -> bool {
+        let button = egui::Button::new(
+            egui::RichText::new(label)
+                .size(typography::BODY)
+                .color(theme.text_primary),
+        )
+        .fill(theme.inactive_bg())
+        .rounding(egui::Rounding::same(radius::MD))
+        .min_size(egui::vec2(width, height));
+
+        ui.add(button).clicked()
+    }
+}
@@ -1,7 +1,7 @@
 //! Player settings dialog for audio and subtitle configuration (Netflix-style).
 
 use eframe::egui;
-use crate::models::{PlayerSettings, PlayerType};
+use crate::models::{ConnectionPreset, PlayerSettings, PlayerType};
 
 /// Common audio language options.
 const AUDIO_LANGUAGES: &[(&str, &str)] = &[
@@ -145,6 +145,7 @@ impl PlayerSettingsDialog {
                                 PlayerType::MpcHc => "Classic Windows media player.",
                                 PlayerType::PotPlayer => "Advanced player with many features.",
                                 PlayerType::Custom => "Use your own player executable.",
+                                PlayerType::Embedded => "Plays inside the app window. Needs libmpv; falls back to MPV without it.",
                             };
                             ui.label(egui::RichText::new(info_text)
                                 .size(11.0)
@@ -264,6 +265,11 @@ impl PlayerSettingsDialog {
                                 settings.audio_sync_offset = 0.0;
                             }
                         });
+                        
+                        // Per-title track picker
+                        ui.checkbox(&mut settings.choose_tracks_before_playback,
+                            "Choose tracks before playing movies and episodes")
+                            .on_hover_text("Lists the audio and subtitle tracks actually in the stream (needs ffprobe)");
                     });
                     
                     ui.add_space(8.0);
@@ -372,33 +378,63 @@ impl PlayerSettingsDialog {
                                 .color(egui::Color32::from_rgb(120, 120, 120)));
                         });
                         
-                        // Low latency mode
-                        ui.horizontal(|ui| {
-                            ui.checkbox(&mut settings.low_latency_mode, "");
-                            ui.label(egui::RichText::new("Low Latency Mode")
-                                .color(egui::Color32::WHITE));
-                            ui.label(egui::RichText::new("(Best for live streams)")
-                                .size(11.0)
-                                .color(egui::Color32::from_rgb(120, 120, 120)));
-                        });
-                        
-                        // Buffer size
+                        // Connection quality preset
                         ui.horizontal(|ui| {
-                            ui.label(egui::RichText::new("Buffer Size:")
+                            ui.label(egui::RichText::new("Connection Quality:")
                                 .color(egui::Color32::from_rgb(180, 180, 180)));
                             ui.add_space(10.0);
                             
-                            let mut buffer_f32 = settings.buffer_size_kb as f32;
-                            if ui.add(egui::Slider::new(&mut buffer_f32, 0.0..=8192.0)
-                                .suffix(" KB")
-                                .custom_formatter(|v, _| {
-                                    if v == 0.0 { "Auto".to_string() }
-                                    else { format!("{:.0}", v) }
-                                }))
-                                .changed() {
-                                settings.buffer_size_kb = buffer_f32.clamp(0.0, 8192.0) as u32;
-                            }
+                            egui::ComboBox::from_id_salt("connection_preset")
+                                .selected_text(settings.connection_preset.display_name())
+                                .show_ui(ui, |ui| {
+                                    for preset in ConnectionPreset::all() {
+                                        if ui.selectable_label(
+                                            settings.connection_preset == *preset,
+                                            preset.display_name()
+                                        ).clicked() {
+                                            settings.apply_connection_preset(*preset);
+                                        }
+                                    }
+                                });
                         });
+                        
+                        // Advanced buffering, driven by the preset above
+                        egui::CollapsingHeader::new(egui::RichText::new("Advanced buffering")
+                            .color(egui::Color32::from_rgb(180, 180, 180)))
+                            .id_salt("advanced_buffering")
+                            .default_open(settings.connection_preset == ConnectionPreset::Custom)
+                            .show(ui, |ui| {
+                                // Low latency mode
+                                ui.horizontal(|ui| {
+                                    if ui.checkbox(&mut settings.low_latency_mode, "").changed() {
+                                        settings.connection_preset = ConnectionPreset::Custom;
+                                    }
+                                    ui.label(egui::RichText::new("Low Latency Mode")
+                                        .color(egui::Color32::WHITE));
+                                    ui.label(egui::RichText::new("(Best for live streams)")
+                                        .size(11.0)
+                                        .color(egui::Color32::from_rgb(120, 120, 120)));
+                                });
+                                
+                                // Buffer size
+                                ui.horizontal(|ui| {
+                                    ui.label(egui::RichText::new("Buffer Size:")
+                                        .color(egui::Color32::from_rgb(180, 180, 180)));
+                                    ui.add_space(10.0);
+                                    
+                                    let mut buffer_f32 = settings.buffer_size_kb as f32;
+                                    if ui.add(egui::Slider::new(&mut buffer_f32, 0.0..=8192.0)
+                                        .suffix(" KB")
+                                        .custom_formatter(|v, _| {
+                                            if v == 0.0 { "Auto".to_string() }
+                                            else { format!("{:.0}", v) }
+                                        }))
+                                        .changed() {
+                                        settings.buffer_size_kb = buffer_f32.clamp(0.0, 8192.0) as u32;
+                                        settings.connection_preset = ConnectionPreset::Custom;
+                                    }
+                                });
+                            });
                     });
                     
                     ui.add_space(16.0);
@@ -0,0 +1,85 @@
+//! Reminder notifications - stacked cards in the top-right corner
+//!
+//! Shown when a programme the user set a reminder for is about to start.
+//! Each card offers to tune in or dismiss the reminder.
+
+use eframe::egui;
+use crate::reminders::Reminder;
+
+/// Actions returned by the reminder notifications, with the index of the
+/// reminder they apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderToastAction {
+    /// Tune to the reminder's channel
+    Watch(usize),
+    /// Close the notification
+    Dismiss(usize),
+}
+
+/// Reminder notification component.
+pub struct ReminderToast;
+
+impl ReminderToast {
+    /// Renders one card per reminder, newest at the bottom. `now` is the
+    /// current Unix time, used for the "starts in" line.
+    pub fn show(ctx: &egui::Context, reminders: &[Reminder], now: i64) -> Option<ReminderToastAction> {
+        let mut action = None;
+
+        for (index, reminder) in reminders.iter().enumerate() {
+            let offset_y = 72.0 + index as f32 * 120.0;
+            egui::Window::new("")
+                .id(egui::Id::new(("reminder_toast", index)))
+                .resizable(false)
+                .collapsible(false)
+                .title_bar(false)
+                .default_width(320.0)
+                .anchor(egui::Align2::RIGHT_TOP, [-20.0, offset_y])
+                .frame(egui::Frame::none()
+                    .fill(egui::Color32::from_rgb(24, 24, 24))
+                    .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(60, 60, 60)))
+                    .rounding(egui::Rounding::same(8.0))
+                    .inner_margin(egui::Margin::same(14.0)))
+                .show(ctx, |ui| {
+                    let minutes = (reminder.start - now) / 60;
+                    let when = if minutes > 0 {
+                        format!("🔔 STARTS IN {} MIN", minutes)
+                    } else {
+                        "🔔 STARTING NOW".to_string()
+                    };
+                    ui.label(egui::RichText::new(when)
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(150, 150, 150)));
+                    ui.label(egui::RichText::new(&reminder.title)
+                        .size(16.0)
+                        .color(egui::Color32::WHITE)
+                        .strong());
+                    ui.label(egui::RichText::new(&reminder.channel_name)
+                        .size(13.0)
+                        .color(egui::Color32::from_rgb(200, 200, 200)));
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        let watch_btn = egui::Button::new(
+                            egui::RichText::new("▶ Watch").color(egui::Color32::WHITE)
+                        )
+                        .fill(egui::Color32::from_rgb(255, 90, 95))
+                        .min_size(egui::vec2(100.0, 30.0));
+                        if ui.add(watch_btn).clicked() {
+                            action = Some(ReminderToastAction::Watch(index));
+                        }
+
+                        let dismiss_btn = egui::Button::new(
+                            egui::RichText::new("Dismiss").color(egui::Color32::WHITE)
+                        )
+                        .fill(egui::Color32::from_rgb(60, 60, 60))
+                        .min_size(egui::vec2(80.0, 30.0));
+                        if ui.add(dismiss_btn).clicked() {
+                            action = Some(ReminderToastAction::Dismiss(index));
+                        }
+                    });
+                });
+        }
+
+        action
+    }
+}
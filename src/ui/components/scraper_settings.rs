@@ -1,14 +1,19 @@
-//! Scraper settings dialog component for triggering fixture scraping.
+//! Scraper settings dialog component for setting up and triggering
+//! fixture scraping.
 
 use eframe::egui;
 use crate::ui::theme::Theme;
-use crate::api::ScrapingStatus;
+use crate::api::ScraperSetup;
 
 /// Actions that can be triggered from the scraper settings dialog.
 #[derive(Debug, Clone)]
 pub enum ScraperAction {
     /// Trigger a scraping operation
     TriggerScrape,
+    /// Create the venv and install the scraper's requirements
+    Install,
+    /// Look for Python and the venv again
+    Recheck,
     /// Close the dialog
     Close,
 }
@@ -17,63 +22,102 @@ pub enum ScraperAction {
 pub struct ScraperSettingsDialog;
 
 impl ScraperSettingsDialog {
-    /// Renders the scraper settings dialog.
-    /// Returns any action that was triggered.
+    /// Renders the scraper settings dialog. `setup` is `None` while it is
+    /// being checked. Returns any action that was triggered.
     pub fn show(
         ui: &mut egui::Ui,
         theme: &Theme,
-        scraper_available: bool,
-        scraping_status: &ScrapingStatus,
+        setup: Option<&ScraperSetup>,
+        scraping: bool,
+        installing: bool,
+        message: Option<&str>,
     ) -> Option<ScraperAction> {
         let mut action: Option<ScraperAction> = None;
 
         ui.heading("📊 Football Fixtures Scraper");
         ui.separator();
 
-        if !scraper_available {
-            ui.label(egui::RichText::new("⚠️ Soccer Scraper not found")
-                .color(theme.warning_color));
-            ui.label("Ensure Soccer-Scraper-main directory exists in the app folder");
-        } else {
-            match scraping_status {
-                ScrapingStatus::Idle => {
-                    ui.label("✅ Scraper ready");
-                    ui.label("Click below to download today's fixtures from LiveSoccerTV");
-                    
-                    if ui.button("🔄 Scrape Fixtures Now").clicked() {
-                        action = Some(ScraperAction::TriggerScrape);
-                    }
-                    ui.label("⏱️ Takes approximately 2 minutes");
+        let Some(setup) = setup else {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Checking scraper setup...");
+            });
+            return None;
+        };
+
+        // Setup checklist
+        let check = |ui: &mut egui::Ui, ok: bool, label: &str, fix: &str| {
+            ui.horizontal(|ui| {
+                if ok {
+                    ui.label(egui::RichText::new("✓").color(theme.success_color));
+                    ui.label(label);
+                } else {
+                    ui.label(egui::RichText::new("✗").color(theme.error_color));
+                    ui.label(egui::RichText::new(fix).color(theme.text_secondary));
                 }
-                ScrapingStatus::Scraping => {
-                    ui.label(egui::RichText::new("⏳ Scraping in progress...")
-                        .color(theme.accent_blue));
-                    ui.label("Opening Chrome browser to bypass Cloudflare...");
-                    ui.label("Please wait, this may take 2 minutes");
+            });
+        };
+        check(ui, setup.scraper_found, "Scraper found",
+            "Soccer-Scraper-main not found: place it in the app folder");
+        let python_label = setup.python.as_deref().unwrap_or("Python found");
+        check(ui, setup.python.is_some(), python_label,
+            "Python 3 not found: install it from python.org (tick \"Add to PATH\")");
+        check(ui, setup.venv, "Virtual environment ready",
+            "No virtual environment: \"Set up\" creates one");
+        check(ui, setup.requirements, "Requirements installed",
+            "Requirements not installed: \"Set up\" installs them");
+
+        ui.add_space(8.0);
+        if installing {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Installing requirements, this can take a few minutes...");
+            });
+        } else {
+            ui.horizontal(|ui| {
+                if setup.can_install() && ui.button("📦 Set up scraper").clicked() {
+                    action = Some(ScraperAction::Install);
                 }
-                ScrapingStatus::Success(msg) => {
-                    ui.label(egui::RichText::new("✅ Success!").color(theme.success_color));
-                    ui.label(msg);
-                    ui.label("Refresh the app to see updated fixtures");
-                    
-                    if ui.button("🔄 Scrape Again").clicked() {
-                        action = Some(ScraperAction::TriggerScrape);
-                    }
+                if ui.button("🔄 Re-check").clicked() {
+                    action = Some(ScraperAction::Recheck);
                 }
-                ScrapingStatus::Error(err) => {
-                    ui.label(egui::RichText::new("❌ Error").color(theme.error_color));
-                    ui.label(err);
-                    ui.label("Ensure Python is installed and Soccer-Scraper-main has requirements installed");
-                    
-                    if ui.button("🔄 Try Again").clicked() {
-                        action = Some(ScraperAction::TriggerScrape);
-                    }
+            });
+        }
+
+        if setup.is_ready() && !installing {
+            ui.add_space(8.0);
+            if scraping {
+                ui.label(egui::RichText::new("⏳ Scraping in progress...")
+                    .color(theme.accent_blue));
+                ui.label("Opening Chrome browser to bypass Cloudflare...");
+                ui.label("Please wait, this may take 2 minutes");
+            } else {
+                ui.label("✅ Scraper ready");
+                ui.label("Click below to download today's fixtures from LiveSoccerTV");
+
+                ui.add_space(8.0);
+                if ui.button("🔄 Scrape Fixtures Now").clicked() {
+                    action = Some(ScraperAction::TriggerScrape);
                 }
+                ui.label("⏱️ Takes approximately 2 minutes");
             }
         }
 
+        // Last scrape or setup result
+        if let Some(msg) = message {
+            ui.add_space(8.0);
+            let color = if msg.starts_with("Error") {
+                theme.error_color
+            } else if msg.contains("successfully") {
+                theme.success_color
+            } else {
+                theme.text_secondary
+            };
+            ui.label(egui::RichText::new(msg).color(color));
+        }
+
         ui.separator();
-        
+
         if ui.button("Close").clicked() {
             action = Some(ScraperAction::Close);
         }
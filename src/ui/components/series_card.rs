@@ -5,14 +5,19 @@
 
 use eframe::egui;
 use crate::models::Series;
+use crate::ui::highlight;
 use crate::ui::theme::{Theme, dimensions, spacing, typography, radius};
-use crate::ui::image_cache::ImageCache;
+use crate::ui::image_cache::{paint_shimmer, placeholder_initials, ImageCache};
+use super::{hover_preview, CardContextMenu, MediaRow};
+use super::media_row::{card_metadata, favorite_star, paint_metadata_strip, paint_resume_bar, TitleState};
 
 /// Actions that can be triggered from a series card.
 #[derive(Debug, Clone)]
 pub enum SeriesAction {
     /// View episodes for this series
     ViewEpisodes(i32),
+    /// Add the series to My List, or remove it
+    ToggleFavorite(i32),
 }
 
 /// Series card component - Modern poster style
@@ -27,13 +32,15 @@ impl SeriesCard {
         theme: &Theme,
         series: &Series,
         image_cache: &ImageCache,
-        screen_width: f32,
+        state: TitleState,
+        size: dimensions::CardSize,
     ) -> Option<SeriesAction> {
         let mut action: Option<SeriesAction> = None;
-        let is_mobile = dimensions::is_mobile(screen_width);
+        let watch_history = state.watch_history;
+        let is_mobile = size.is_mobile;
 
         // Card dimensions
-        let card_width = dimensions::card_width(screen_width);
+        let card_width = size.width;
         let poster_height = dimensions::poster_height(card_width);
         let total_height = poster_height + 48.0; // Space for title and metadata
 
@@ -49,6 +56,7 @@ impl SeriesCard {
             egui::vec2(card_width + spacing::SM, total_height + spacing::SM),
             egui::Sense::click(),
         );
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &series.name));
 
         if response.clicked() {
             action = Some(SeriesAction::ViewEpisodes(series.series_id));
@@ -56,9 +64,13 @@ impl SeriesCard {
 
         let is_hovered = response.hovered();
         let has_focus = response.has_focus();
-        let poster_rect = egui::Rect::from_min_size(
-            rect.min + egui::vec2(spacing::XS, 0.0),
-            egui::vec2(card_width, poster_height),
+        let preview = hover_preview::progress(ui, &response);
+        let poster_rect = hover_preview::zoom(
+            egui::Rect::from_min_size(
+                rect.min + egui::vec2(spacing::XS, 0.0),
+                egui::vec2(card_width, poster_height),
+            ),
+            preview,
         );
 
         // Shadow on hover or focus (for gamepad navigation) - subtle lift effect
@@ -73,10 +85,7 @@ impl SeriesCard {
 
         // Draw poster or placeholder
         if let Some(cover_url) = &series.cover {
-            if let Some(texture) = image_cache.get(cover_url) {
-                let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
-                ui.painter().image(texture.id(), poster_rect, uv, egui::Color32::WHITE);
-
+            if image_cache.paint(ui, cover_url, poster_rect) {
                 // Border - accent color on focus for gamepad navigation
                 let border_color = if has_focus { theme.accent_blue } else { theme.border_color };
                 let border_width = if has_focus { 2.0 } else { 1.0 };
@@ -85,11 +94,15 @@ impl SeriesCard {
                     radius::LG,
                     egui::Stroke::new(border_width, border_color),
                 );
+            } else if image_cache.is_pending(cover_url) {
+                paint_shimmer(ui, poster_rect, radius::LG, theme.placeholder_bg());
             } else {
-                Self::paint_placeholder(ui, theme, poster_rect);
+                // Gave up on the image, show the initials instead
+                let initials = placeholder_initials(&series.name);
+                Self::paint_placeholder(ui, theme, poster_rect, &initials);
             }
         } else {
-            Self::paint_placeholder(ui, theme, poster_rect);
+            Self::paint_placeholder(ui, theme, poster_rect, "TV");
         }
 
         // Hover/focus overlay with play button (for gamepad navigation)
@@ -140,8 +153,8 @@ impl SeriesCard {
                 egui::Stroke::NONE,
             ));
 
-            // Genre badge at bottom of poster
-            if let Some(genre) = &series.genre {
+            // Genre badge at bottom of poster, until the preview shows it
+            if let Some(genre) = series.genre.as_ref().filter(|_| preview == 0.0) {
                 let genre_text = truncate_text(genre, 18);
                 let galley = ui.painter().layout_no_wrap(
                     genre_text,
@@ -162,47 +175,100 @@ impl SeriesCard {
             }
         }
 
-        // Title below poster
-        let title_y = poster_rect.max.y + spacing::SM;
+        let card_active = has_focus || ui.rect_contains_pointer(rect);
+        if favorite_star(ui, poster_rect, response.id.with("favorite"), state.is_favorite, card_active, theme) {
+            action = Some(SeriesAction::ToggleFavorite(series.series_id));
+        }
+
+        // Resume indicator when an episode was left part-way through
+        let resume = watch_history
+            .series_progress(&series.name)
+            .and_then(|p| p.resume_fraction());
+
+        // Rating, year and genre; the hover overlay has its own genre badge
+        if !(is_hovered || has_focus) {
+            if let Some(metadata) = card_metadata(series.rating_out_of_10(), series.release_year(), series.genre.as_deref()) {
+                let lift = if resume.is_some() { spacing::MD } else { 0.0 };
+                paint_metadata_strip(ui, poster_rect.with_max_y(poster_rect.max.y - lift), &metadata, theme);
+            }
+        }
+        if let Some(fraction) = resume {
+            paint_resume_bar(ui, poster_rect, fraction, theme);
+        }
+
+        // Title, year and plot once the pointer rests on the card
+        let details = card_metadata(series.rating_out_of_10(), series.release_year(), series.genre.as_deref());
+        hover_preview::paint_info(ui, poster_rect, preview, &series.name, details.as_deref(), series.plot.as_deref(), theme);
+
+        // Title below poster (unaffected by the preview zoom)
+        let title_y = rect.min.y + poster_height + spacing::SM;
         let display_name = truncate_text(&series.name, if is_mobile { 18 } else { 22 });
-        let title_galley = ui.painter().layout_no_wrap(
-            display_name,
+        let title_galley = ui.painter().layout_job(highlight::title_job(
+            ctx,
+            &display_name,
             egui::FontId::proportional(if is_mobile { typography::BODY_SM } else { typography::BODY }),
             theme.text_primary,
-        );
+            theme.accent_blue,
+        ));
         ui.painter().galley(
             egui::pos2(poster_rect.min.x, title_y),
             title_galley,
             theme.text_primary,
         );
 
-        // Rating below title
-        if let Some(rating) = &series.rating {
-            if !rating.is_empty() {
-                let rating_text = format!("★ {}", rating);
-                let rating_galley = ui.painter().layout_no_wrap(
-                    rating_text,
-                    egui::FontId::proportional(typography::CAPTION),
-                    theme.warning_color,
-                );
-                ui.painter().galley(
-                    egui::pos2(poster_rect.min.x, title_y + 20.0),
-                    rating_galley,
-                    theme.warning_color,
-                );
-            }
-        }
+        // Context menu: right-click, long press, Shift+F10 or controller Y
+        let menu_pick = CardContextMenu::show(ui, &response, || Self::menu_entries(series, state.is_favorite));
+        action.or(menu_pick)
+    }
+
+    /// Renders the series as a row of the compact list view.
+    pub fn show_row(
+        ui: &mut egui::Ui,
+        ctx: &egui::Context,
+        theme: &Theme,
+        series: &Series,
+        image_cache: &ImageCache,
+        state: TitleState,
+        is_touch_mode: bool,
+    ) -> Option<SeriesAction> {
+        let watch_history = state.watch_history;
+        let row = MediaRow {
+            thumbnail: series.cover.as_deref(),
+            title: &series.name,
+            year: series.release_year(),
+            rating: series.rating_out_of_10(),
+            genre: series.genre.as_deref(),
+            progress: watch_history
+                .series_progress(&series.name)
+                .and_then(|p| p.resume_fraction()),
+        };
+
+        let response = row.show(ui, ctx, theme, image_cache, is_touch_mode);
+        let menu_pick = CardContextMenu::show(ui, &response, || Self::menu_entries(series, state.is_favorite));
+        response
+            .clicked()
+            .then_some(SeriesAction::ViewEpisodes(series.series_id))
+            .or(menu_pick)
+    }
 
-        action
+    /// Entries of the series' context menu.
+    fn menu_entries(series: &Series, is_favorite: bool) -> Vec<(String, SeriesAction)> {
+        vec![
+            ("☰ View episodes".to_string(), SeriesAction::ViewEpisodes(series.series_id)),
+            (
+                if is_favorite { "★ Remove from My List" } else { "☆ Add to My List" }.to_string(),
+                SeriesAction::ToggleFavorite(series.series_id),
+            ),
+        ]
     }
 
     /// Paints a placeholder for missing posters
-    fn paint_placeholder(ui: &egui::Ui, theme: &Theme, rect: egui::Rect) {
+    fn paint_placeholder(ui: &egui::Ui, theme: &Theme, rect: egui::Rect, label: &str) {
         ui.painter().rect_filled(rect, radius::LG, theme.placeholder_bg());
 
         let center = rect.center();
         let galley = ui.painter().layout_no_wrap(
-            "TV".to_string(),
+            label.to_string(),
             egui::FontId::proportional(rect.width() * 0.2),
             theme.placeholder_icon(),
         );
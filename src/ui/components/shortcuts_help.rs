@@ -0,0 +1,117 @@
+//! Keyboard shortcuts overlay
+//!
+//! Lists the available keyboard shortcuts. Opened with `?`.
+
+use eframe::egui;
+use crate::ui::theme::{spacing, typography, radius};
+
+/// Keyboard shortcuts shown in the overlay, as (keys, description).
+pub const SHORTCUTS: &[(&str, &str)] = &[
+    ("/", "Focus search"),
+    ("Esc", "Back: close dialog, clear search or filter, previous section"),
+    ("1 – 9", "Switch section (Live, Continue, Series, Movies, My List, On Now, Discover, Sports, New)"),
+    ("F", "Toggle favorite on the focused card"),
+    ("R", "Refresh the current view"),
+    ("← / →", "Previous / next page"),
+    ("PgUp / PgDn", "Previous / next section"),
+    ("F10", "Toggle sidebar"),
+    ("Shift+F10", "Actions for the focused card"),
+    ("F11", "Toggle fullscreen"),
+    ("?", "Show this help"),
+];
+
+/// Keyboard shortcuts help overlay
+pub struct ShortcutsHelp;
+
+impl ShortcutsHelp {
+    /// Shows the shortcuts overlay. Returns `true` when it should be closed.
+    pub fn show(ctx: &egui::Context) -> bool {
+        let mut close = false;
+
+        // Colors
+        let bg = egui::Color32::from_rgb(18, 18, 18);
+        let key_bg = egui::Color32::from_rgb(38, 38, 38);
+        let text_primary = egui::Color32::WHITE;
+        let text_secondary = egui::Color32::from_rgb(170, 170, 170);
+
+        egui::Window::new("")
+            .id(egui::Id::new("shortcuts_help"))
+            .resizable(false)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(440.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(bg)
+                    .rounding(egui::Rounding::same(radius::XL))
+                    .inner_margin(egui::Margin::same(spacing::XL))
+                    .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(38, 38, 38))),
+            )
+            .show(ctx, |ui| {
+                // Header
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new("Keyboard Shortcuts")
+                            .size(typography::H2)
+                            .color(text_primary)
+                            .strong(),
+                    );
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .add(
+                                egui::Button::new(
+                                    egui::RichText::new("✕")
+                                        .size(18.0)
+                                        .color(text_secondary),
+                                )
+                                .fill(egui::Color32::TRANSPARENT)
+                                .min_size(egui::vec2(36.0, 36.0)),
+                            )
+                            .clicked()
+                        {
+                            close = true;
+                        }
+                    });
+                });
+
+                ui.add_space(spacing::LG);
+
+                egui::Grid::new("shortcuts_grid")
+                    .num_columns(2)
+                    .spacing([spacing::LG, spacing::SM])
+                    .show(ui, |ui| {
+                        for (keys, description) in SHORTCUTS {
+                            egui::Frame::none()
+                                .fill(key_bg)
+                                .rounding(egui::Rounding::same(radius::SM))
+                                .inner_margin(egui::Margin::symmetric(spacing::SM, spacing::XS))
+                                .show(ui, |ui| {
+                                    ui.label(
+                                        egui::RichText::new(*keys)
+                                            .monospace()
+                                            .size(typography::BODY_SM)
+                                            .color(text_primary),
+                                    );
+                                });
+                            ui.label(
+                                egui::RichText::new(*description)
+                                    .size(typography::BODY_SM)
+                                    .color(text_secondary),
+                            );
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(spacing::MD);
+                ui.label(
+                    egui::RichText::new("Shortcuts are ignored while typing in a text field.")
+                        .size(typography::CAPTION)
+                        .color(text_secondary),
+                );
+            });
+
+        close
+    }
+}
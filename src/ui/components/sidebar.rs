@@ -1,28 +1,76 @@
 //! Category sidebar component - Modern, clean design
 //!
 //! A refined sidebar with clean category buttons and subtle interactions.
-//! Features smooth scrolling and clear visual hierarchy.
+//! Features smooth scrolling and clear visual hierarchy. Categories work as
+//! checkboxes, so several can be combined into one view. Categories can be
+//! pinned to the top (right-click or long-press) and the rest sorted.
+
+use std::collections::{HashMap, HashSet};
 
 use eframe::egui;
-use crate::models::Category;
+use crate::models::{Category, CategorySort};
+use crate::ui::components::alpha_index;
 use crate::ui::theme::{Theme, spacing, typography, radius};
 use crate::ui::messages::ContentType;
 
+/// Sort and pins of the category list, edited from the sidebar.
+pub struct CategoryPrefs<'a> {
+    pub sort: &'a mut CategorySort,
+    /// Pinned category IDs of this section, in pin order
+    pub pinned: &'a mut Vec<String>,
+    /// Loaded items per category ID, for sorting by item count
+    pub counts: &'a HashMap<String, usize>,
+}
+
+/// What changed in the sidebar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategorySidebarAction {
+    /// The selected categories changed
+    SelectionChanged,
+    /// The sort or the pins changed and should be saved
+    OrderChanged,
+}
+
+/// Splits `categories` into the pinned ones, in pin order, and the rest
+/// in `sort` order.
+pub fn order_categories<'a>(
+    categories: Vec<&'a Category>,
+    sort: CategorySort,
+    pinned: &[String],
+    counts: &HashMap<String, usize>,
+) -> (Vec<&'a Category>, Vec<&'a Category>) {
+    let (mut top, mut rest): (Vec<_>, Vec<_>) = categories
+        .into_iter()
+        .partition(|c| pinned.contains(&c.category_id));
+    top.sort_by_key(|c| pinned.iter().position(|id| *id == c.category_id));
+
+    match sort {
+        CategorySort::Provider => {}
+        CategorySort::Alphabetical => alpha_index::sort_by_name(&mut rest, |c| &c.category_name),
+        CategorySort::ItemCount => {
+            rest.sort_by_key(|c| std::cmp::Reverse(counts.get(&c.category_id).copied().unwrap_or(0)));
+        }
+    }
+    (top, rest)
+}
+
 /// Category sidebar component - Modern design
 pub struct CategorySidebar;
 
 impl CategorySidebar {
-    /// Renders the sidebar with category list.
-    /// Returns Some(category_id) if a category was selected, or Some(None) for "All".
+    /// Renders the sidebar with category list. Clicking a category toggles
+    /// it in `selected`; an empty selection means all categories.
     pub fn show(
         ui: &mut egui::Ui,
         theme: &Theme,
         _content_type: ContentType,
         categories: &[Category],
-        selected_category: &Option<String>,
+        selected: &mut HashSet<String>,
         category_search: &mut String,
-    ) -> Option<Option<String>> {
-        let mut selection_changed: Option<Option<String>> = None;
+        prefs: CategoryPrefs,
+    ) -> Option<CategorySidebarAction> {
+        let mut changed = false;
+        let mut reordered = false;
 
         // Sizing
         let item_height = 44.0;
@@ -30,12 +78,24 @@ impl CategorySidebar {
 
         ui.add_space(spacing::SM);
 
-        // Header
-        ui.label(
-            egui::RichText::new("Categories")
-                .size(typography::CAPTION)
-                .color(theme.text_tertiary),
-        );
+        // Header with the sort choice
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new("Categories")
+                    .size(typography::CAPTION)
+                    .color(theme.text_tertiary),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                egui::ComboBox::from_id_salt("category_sort")
+                    .selected_text(egui::RichText::new(prefs.sort.label()).size(typography::LABEL))
+                    .width(110.0)
+                    .show_ui(ui, |ui| {
+                        for sort in CategorySort::ALL {
+                            reordered |= ui.selectable_value(prefs.sort, sort, sort.label()).changed();
+                        }
+                    });
+            });
+        });
 
         ui.add_space(spacing::MD);
 
@@ -80,17 +140,19 @@ impl CategorySidebar {
 
                     // "All" option (only if no search)
                     if category_search.is_empty() {
-                        let is_all_selected = selected_category.is_none();
+                        let is_all_selected = selected.is_empty();
                         let all_response = Self::category_button(
                             ui,
                             theme,
                             "All Categories",
                             is_all_selected,
+                            false,
                             item_height,
                             font_size,
                         );
-                        if all_response.clicked() {
-                            selection_changed = Some(None);
+                        if all_response.clicked() && !is_all_selected {
+                            selected.clear();
+                            changed = true;
                         }
 
                         // Divider
@@ -109,34 +171,90 @@ impl CategorySidebar {
                         })
                         .collect();
 
-                    // Category count
-                    ui.label(
-                        egui::RichText::new(format!("{} categories", filtered_categories.len()))
-                            .size(typography::LABEL)
-                            .color(theme.text_muted),
-                    );
+                    // Category count with bulk selection controls
+                    ui.horizontal(|ui| {
+                        let count = if selected.is_empty() {
+                            format!("{} categories", filtered_categories.len())
+                        } else {
+                            format!("{} of {} selected", selected.len(), categories.len())
+                        };
+                        ui.label(
+                            egui::RichText::new(count)
+                                .size(typography::LABEL)
+                                .color(theme.text_muted),
+                        );
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if !selected.is_empty() && Self::text_link(ui, theme, "Clear").clicked() {
+                                selected.clear();
+                                changed = true;
+                            }
+                            let all_shown = filtered_categories.iter().all(|c| selected.contains(&c.category_id));
+                            if !all_shown && Self::text_link(ui, theme, "Select all").clicked() {
+                                selected.extend(filtered_categories.iter().map(|c| c.category_id.clone()));
+                                changed = true;
+                            }
+                        });
+                    });
 
                     ui.add_space(spacing::SM);
 
-                    // Category buttons
-                    for category in filtered_categories {
-                        let is_selected = selected_category
-                            .as_ref()
-                            .map(|id| id == &category.category_id)
-                            .unwrap_or(false);
+                    // Category buttons, pinned ones above a divider
+                    let (pinned, rest) =
+                        order_categories(filtered_categories, *prefs.sort, prefs.pinned, prefs.counts);
+                    let show_counts = *prefs.sort == CategorySort::ItemCount;
+                    for (i, category) in pinned.iter().chain(&rest).enumerate() {
+                        if i == pinned.len() && !pinned.is_empty() {
+                            ui.add_space(spacing::XS);
+                            ui.add(egui::Separator::default().spacing(0.0));
+                            ui.add_space(spacing::XS);
+                        }
+                        let is_selected = selected.contains(&category.category_id);
+                        let is_pinned = i < pinned.len();
 
                         let response = Self::category_button(
                             ui,
                             theme,
                             &category.category_name,
                             is_selected,
+                            true,
                             item_height,
                             font_size,
                         );
+                        let count = prefs.counts.get(&category.category_id).copied().unwrap_or(0);
+                        let trailing = match (is_pinned, show_counts) {
+                            (true, true) => format!("{} 📌", count),
+                            (true, false) => "📌".to_string(),
+                            (false, true) => count.to_string(),
+                            (false, false) => String::new(),
+                        };
+                        ui.painter().text(
+                            egui::pos2(response.rect.max.x - spacing::MD, response.rect.center().y),
+                            egui::Align2::RIGHT_CENTER,
+                            trailing,
+                            egui::FontId::proportional(typography::LABEL),
+                            theme.text_muted,
+                        );
 
                         if response.clicked() {
-                            selection_changed = Some(Some(category.category_id.clone()));
+                            if is_selected {
+                                selected.remove(&category.category_id);
+                            } else {
+                                selected.insert(category.category_id.clone());
+                            }
+                            changed = true;
                         }
+                        response.context_menu(|ui| {
+                            let label = if is_pinned { "Unpin" } else { "📌 Pin to top" };
+                            if ui.button(label).clicked() {
+                                if is_pinned {
+                                    prefs.pinned.retain(|id| *id != category.category_id);
+                                } else {
+                                    prefs.pinned.push(category.category_id.clone());
+                                }
+                                reordered = true;
+                                ui.close_menu();
+                            }
+                        });
                     }
 
                     // Bottom padding
@@ -144,15 +262,33 @@ impl CategorySidebar {
                 });
             });
 
-        selection_changed
+        if changed {
+            Some(CategorySidebarAction::SelectionChanged)
+        } else if reordered {
+            Some(CategorySidebarAction::OrderChanged)
+        } else {
+            None
+        }
     }
 
-    /// Creates a category button with modern styling
+    /// Small clickable caption used for "Select all" / "Clear".
+    fn text_link(ui: &mut egui::Ui, theme: &Theme, text: &str) -> egui::Response {
+        ui.add(egui::Label::new(
+            egui::RichText::new(text)
+                .size(typography::LABEL)
+                .color(theme.accent_blue),
+        ).sense(egui::Sense::click()))
+            .on_hover_cursor(egui::CursorIcon::PointingHand)
+    }
+
+    /// Creates a category button with modern styling. `checkbox` draws a
+    /// check box in front of the label.
     fn category_button(
         ui: &mut egui::Ui,
         theme: &Theme,
         text: &str,
         is_selected: bool,
+        checkbox: bool,
         height: f32,
         font_size: f32,
     ) -> egui::Response {
@@ -161,6 +297,8 @@ impl CategorySidebar {
 
         let (rect, response) =
             ui.allocate_exact_size(egui::vec2(available_width, height), egui::Sense::click());
+        let widget_type = if checkbox { egui::WidgetType::Checkbox } else { egui::WidgetType::SelectableLabel };
+        response.widget_info(|| egui::WidgetInfo::selected(widget_type, true, is_selected, text));
 
         let is_hovered = response.hovered();
 
@@ -197,8 +335,29 @@ impl CategorySidebar {
             text_color,
         );
 
+        let mut text_x = rect.min.x + spacing::LG;
+        if checkbox {
+            let box_rect = egui::Rect::from_center_size(
+                egui::pos2(text_x + 7.0, rect.center().y),
+                egui::vec2(14.0, 14.0),
+            );
+            if is_selected {
+                ui.painter().rect_filled(box_rect, radius::SM, theme.accent_blue);
+                ui.painter().text(
+                    box_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    "✔",
+                    egui::FontId::proportional(10.0),
+                    egui::Color32::WHITE,
+                );
+            } else {
+                ui.painter().rect_stroke(box_rect, radius::SM, egui::Stroke::new(1.0, theme.text_muted));
+            }
+            text_x = box_rect.max.x + spacing::SM;
+        }
+
         let text_pos = egui::pos2(
-            rect.min.x + spacing::LG,
+            text_x,
             rect.center().y - galley.size().y / 2.0,
         );
         ui.painter().galley(text_pos, galley, text_color);
@@ -218,3 +377,30 @@ fn truncate_text(text: &str, max_len: usize) -> String {
         text.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(id: &str, name: &str) -> Category {
+        Category { category_id: id.to_string(), category_name: name.to_string(), parent_id: 0 }
+    }
+
+    #[test]
+    fn test_order_categories() {
+        let categories = [category("1", "Sports"), category("2", "Movies"), category("3", "Kids"), category("4", "News")];
+        let pinned = vec!["4".to_string(), "1".to_string()];
+        let counts = HashMap::from([("2".to_string(), 5), ("3".to_string(), 40)]);
+        let ids = |list: Vec<&Category>| list.iter().map(|c| c.category_id.clone()).collect::<Vec<_>>();
+
+        let (top, rest) = order_categories(categories.iter().collect(), CategorySort::Provider, &pinned, &counts);
+        assert_eq!(ids(top), ["4", "1"]);
+        assert_eq!(ids(rest), ["2", "3"]);
+
+        let (_, rest) = order_categories(categories.iter().collect(), CategorySort::ItemCount, &[], &counts);
+        assert_eq!(ids(rest), ["3", "2", "1", "4"]);
+
+        let (_, rest) = order_categories(categories.iter().collect(), CategorySort::Alphabetical, &pinned, &counts);
+        assert_eq!(ids(rest), ["3", "2"]);
+    }
+}
@@ -45,12 +45,13 @@ impl TmdbCard {
             Vec2::new(card_width, total_height),
             egui::Sense::click(),
         );
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &item.title));
         
         if ui.is_rect_visible(rect) {
             let painter = ui.painter_at(rect);
             
             // Card background
-            let bg_color = if response.hovered() {
+            let bg_color = if response.hovered() || response.has_focus() {
                 Color32::from_rgb(50, 50, 55)
             } else {
                 theme.card_bg
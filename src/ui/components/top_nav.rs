@@ -5,7 +5,35 @@
 
 use eframe::egui;
 use crate::ui::theme::{Theme, spacing, typography, radius};
+use crate::models::Quality;
 use crate::ui::messages::ContentType;
+use crate::ui::i18n::t;
+use crate::ui::sort::SortOrder;
+
+/// Widget id of the search field, used to focus it from keyboard shortcuts.
+pub const SEARCH_INPUT_ID: &str = "top_nav_search";
+
+/// Filters of the current view edited from the navigation bar.
+pub struct NavFilters<'a> {
+    /// Live TV stream quality filter
+    pub quality: &'a mut Option<Quality>,
+    /// Grid sort order
+    pub sort: &'a mut SortOrder,
+    /// Show only favorited items of the current content type
+    pub favorites_only: &'a mut bool,
+    /// Search channels, movies and series at once instead of the current view
+    pub search_all: &'a mut bool,
+}
+
+/// App state shown in the navigation bar.
+pub struct NavStatus<'a> {
+    /// Number of running recordings
+    pub recording_count: usize,
+    /// Saved account names, for switching between them
+    pub accounts: &'a [&'a str],
+    /// The account signed in with
+    pub active_account: Option<&'a str>,
+}
 
 /// Actions that can be triggered from the navigation bar.
 #[derive(Debug, Clone, PartialEq)]
@@ -14,18 +42,38 @@ pub enum NavAction {
     SwitchContent(ContentType),
     /// Search query changed
     SearchChanged,
+    /// Live TV quality filter changed
+    QualityFilterChanged,
+    /// Grid sort order changed
+    SortChanged,
+    /// The favorites-only toggle changed
+    FavoritesOnlyChanged,
+    /// The "Search All" toggle changed
+    SearchAllChanged,
+    /// Play (or open) a random item from the current list
+    SurpriseMe,
     /// Toggle dark/light mode
     ToggleTheme,
     /// Disconnect from server
     Disconnect,
+    /// Sign out and sign in with another saved account
+    SwitchAccount(String),
     /// Open player settings
     OpenPlayerSettings,
+    /// Open general app settings
+    OpenAppSettings,
+    /// Show the active recordings panel
+    ShowRecordings,
+    /// Show the watch statistics
+    ShowWatchStats,
     /// Open EPG settings
     OpenEpgSettings,
     /// Open scraper settings
     OpenScraperSettings,
     /// Toggle sidebar visibility (for mobile)
     ToggleSidebar,
+    /// Enter or leave fullscreen
+    ToggleFullscreen,
 }
 
 /// Top navigation bar component - Modern design
@@ -41,7 +89,11 @@ impl TopNavigation {
         search_query: &mut String,
         is_mobile: bool,
         is_touch_mode: bool,
+        status: NavStatus<'_>,
+        filters: NavFilters<'_>,
     ) -> Option<NavAction> {
+        let NavStatus { recording_count, accounts, active_account } = status;
+        let NavFilters { quality: quality_filter, sort: sort_order, favorites_only, search_all } = filters;
         let mut action: Option<NavAction> = None;
 
         // Touch-friendly sizing
@@ -81,13 +133,15 @@ impl TopNavigation {
 
                 // Navigation tabs - clean, minimal
                 let tabs = [
-                    ("Live", ContentType::LiveTV),
-                    ("Continue", ContentType::ContinueWatching),
-                    ("Series", ContentType::Series),
-                    ("Movies", ContentType::Movies),
-                    ("My List", ContentType::Favorites),
-                    ("Discover", ContentType::Discover),
-                    ("Sports", ContentType::FootballFixtures),
+                    (t("nav.live"), ContentType::LiveTV),
+                    (t("nav.continue"), ContentType::ContinueWatching),
+                    (t("nav.series"), ContentType::Series),
+                    (t("nav.movies"), ContentType::Movies),
+                    (t("nav.new"), ContentType::RecentlyAdded),
+                    (t("nav.my_list"), ContentType::Favorites),
+                    (t("nav.on_now"), ContentType::OnNow),
+                    (t("nav.discover"), ContentType::Discover),
+                    (t("nav.sports"), ContentType::FootballFixtures),
                 ];
 
                 for (label, content_type) in tabs {
@@ -101,13 +155,15 @@ impl TopNavigation {
                 ui.add_space(spacing::MD);
 
                 let mobile_tabs = [
-                    ("Live", ContentType::LiveTV),
-                    ("Continue", ContentType::ContinueWatching),
-                    ("Series", ContentType::Series),
-                    ("Movies", ContentType::Movies),
-                    ("List", ContentType::Favorites),
-                    ("Hot", ContentType::Discover),
-                    ("Sports", ContentType::FootballFixtures),
+                    (t("nav.live"), ContentType::LiveTV),
+                    (t("nav.continue"), ContentType::ContinueWatching),
+                    (t("nav.series"), ContentType::Series),
+                    (t("nav.movies"), ContentType::Movies),
+                    (t("nav.new"), ContentType::RecentlyAdded),
+                    (t("nav.list"), ContentType::Favorites),
+                    (t("nav.now"), ContentType::OnNow),
+                    (t("nav.hot"), ContentType::Discover),
+                    (t("nav.sports"), ContentType::FootballFixtures),
                 ];
 
                 for (label, content_type) in mobile_tabs {
@@ -135,12 +191,32 @@ impl TopNavigation {
                     if ui.add(settings_btn).clicked() {
                         action = Some(NavAction::OpenPlayerSettings);
                     }
+
+                    let app_settings_btn = egui::Button::new(
+                        egui::RichText::new("🛠")
+                            .size(icon_size)
+                            .color(theme.text_secondary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .min_size(egui::vec2(btn_size, btn_size));
+
+                    if ui.add(app_settings_btn).clicked() {
+                        action = Some(NavAction::OpenAppSettings);
+                    }
+
+                    if Self::shows_sort(current_content) && Self::surprise_button(ui, theme, icon_size, btn_size) {
+                        action = Some(NavAction::SurpriseMe);
+                    }
+
+                    if recording_count > 0 && Self::recording_indicator(ui, theme, recording_count, btn_size) {
+                        action = Some(NavAction::ShowRecordings);
+                    }
                 } else {
                     // Desktop controls
 
                     // Sign out
                     let signout_btn = egui::Button::new(
-                        egui::RichText::new("Sign out")
+                        egui::RichText::new(t("nav.sign_out"))
                             .size(typography::CAPTION)
                             .color(theme.text_tertiary),
                     )
@@ -150,6 +226,23 @@ impl TopNavigation {
                         action = Some(NavAction::Disconnect);
                     }
 
+                    if accounts.len() > 1 {
+                        ui.menu_button(
+                            egui::RichText::new(t("nav.switch_account"))
+                                .size(typography::CAPTION)
+                                .color(theme.text_tertiary),
+                            |ui| {
+                                for name in accounts {
+                                    let active = active_account == Some(*name);
+                                    if ui.add_enabled(!active, egui::SelectableLabel::new(active, *name)).clicked() {
+                                        action = Some(NavAction::SwitchAccount(name.to_string()));
+                                        ui.close_menu();
+                                    }
+                                }
+                            },
+                        );
+                    }
+
                     ui.add_space(spacing::MD);
 
                     // Theme toggle
@@ -164,7 +257,7 @@ impl TopNavigation {
 
                     if ui
                         .add(theme_btn)
-                        .on_hover_text("Toggle theme")
+                        .on_hover_text(t("nav.toggle_theme"))
                         .clicked()
                     {
                         action = Some(NavAction::ToggleTheme);
@@ -181,12 +274,29 @@ impl TopNavigation {
 
                     if ui
                         .add(settings_btn)
-                        .on_hover_text("Player settings")
+                        .on_hover_text(t("nav.player_settings"))
                         .clicked()
                     {
                         action = Some(NavAction::OpenPlayerSettings);
                     }
 
+                    // App settings
+                    let app_settings_btn = egui::Button::new(
+                        egui::RichText::new("🛠")
+                            .size(icon_size)
+                            .color(theme.text_secondary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .min_size(egui::vec2(36.0, 36.0));
+
+                    if ui
+                        .add(app_settings_btn)
+                        .on_hover_text(t("nav.app_settings"))
+                        .clicked()
+                    {
+                        action = Some(NavAction::OpenAppSettings);
+                    }
+
                     // EPG settings
                     let epg_btn = egui::Button::new(
                         egui::RichText::new("📺")
@@ -198,7 +308,7 @@ impl TopNavigation {
 
                     if ui
                         .add(epg_btn)
-                        .on_hover_text("EPG settings")
+                        .on_hover_text(t("nav.epg_settings"))
                         .clicked()
                     {
                         action = Some(NavAction::OpenEpgSettings);
@@ -215,12 +325,53 @@ impl TopNavigation {
 
                     if ui
                         .add(scraper_btn)
-                        .on_hover_text("Sports scraper")
+                        .on_hover_text(t("nav.scraper"))
                         .clicked()
                     {
                         action = Some(NavAction::OpenScraperSettings);
                     }
 
+                    // Watch statistics
+                    let stats_btn = egui::Button::new(
+                        egui::RichText::new("📈")
+                            .size(icon_size)
+                            .color(theme.text_secondary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .min_size(egui::vec2(36.0, 36.0));
+
+                    if ui
+                        .add(stats_btn)
+                        .on_hover_text(t("nav.watch_stats"))
+                        .clicked()
+                    {
+                        action = Some(NavAction::ShowWatchStats);
+                    }
+
+                    // Fullscreen
+                    let fullscreen_btn = egui::Button::new(
+                        egui::RichText::new("⛶")
+                            .size(icon_size)
+                            .color(theme.text_secondary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .min_size(egui::vec2(36.0, 36.0));
+
+                    if ui
+                        .add(fullscreen_btn)
+                        .on_hover_text(t("nav.fullscreen"))
+                        .clicked()
+                    {
+                        action = Some(NavAction::ToggleFullscreen);
+                    }
+
+                    if recording_count > 0 {
+                        ui.add_space(spacing::SM);
+                        if Self::recording_indicator(ui, theme, recording_count, 36.0) {
+                            action = Some(NavAction::ShowRecordings);
+                        }
+                    }
+
                     ui.add_space(spacing::MD);
 
                     // Search bar
@@ -238,8 +389,9 @@ impl TopNavigation {
                                 ui.add_space(spacing::XS);
 
                                 let search_edit = egui::TextEdit::singleline(search_query)
+                                    .id(egui::Id::new(SEARCH_INPUT_ID))
                                     .hint_text(
-                                        egui::RichText::new("Search...")
+                                        egui::RichText::new(t("nav.search"))
                                             .color(theme.text_muted)
                                             .size(typography::BODY_SM),
                                     )
@@ -250,8 +402,36 @@ impl TopNavigation {
                                 if ui.add(search_edit).changed() {
                                     action = Some(NavAction::SearchChanged);
                                 }
+
+                                if Self::search_all_toggle(ui, theme, search_all) {
+                                    action = Some(NavAction::SearchAllChanged);
+                                }
                             });
                         });
+
+                    if Self::shows_quality_filter(current_content) {
+                        ui.add_space(spacing::SM);
+                        if Self::quality_dropdown(ui, theme, quality_filter) {
+                            action = Some(NavAction::QualityFilterChanged);
+                        }
+                    }
+
+                    if Self::shows_sort(current_content) {
+                        ui.add_space(spacing::SM);
+                        if Self::sort_dropdown(ui, theme, sort_order) {
+                            action = Some(NavAction::SortChanged);
+                        }
+
+                        if Self::surprise_button(ui, theme, icon_size, 36.0) {
+                            action = Some(NavAction::SurpriseMe);
+                        }
+                    }
+
+                    if Self::shows_favorites_only(current_content)
+                        && Self::favorites_only_toggle(ui, theme, favorites_only, icon_size, 36.0)
+                    {
+                        action = Some(NavAction::FavoritesOnlyChanged);
+                    }
                 }
             });
         });
@@ -277,8 +457,9 @@ impl TopNavigation {
                         ui.add_space(spacing::SM);
 
                         let search_edit = egui::TextEdit::singleline(search_query)
+                            .id(egui::Id::new(SEARCH_INPUT_ID))
                             .hint_text(
-                                egui::RichText::new("Search channels, movies, series...")
+                                egui::RichText::new(t("nav.search_all"))
                                     .size(if is_touch_mode {
                                         typography::BODY
                                     } else {
@@ -286,7 +467,7 @@ impl TopNavigation {
                                     })
                                     .color(theme.text_muted),
                             )
-                            .desired_width(ui.available_width() - 40.0)
+                            .desired_width(ui.available_width() - 80.0)
                             .font(egui::FontId::proportional(if is_touch_mode {
                                 typography::BODY
                             } else {
@@ -297,13 +478,169 @@ impl TopNavigation {
                         if ui.add(search_edit).changed() {
                             action = Some(NavAction::SearchChanged);
                         }
+
+                        if Self::search_all_toggle(ui, theme, search_all) {
+                            action = Some(NavAction::SearchAllChanged);
+                        }
                     });
                 });
+
+            if Self::shows_quality_filter(current_content) || Self::shows_sort(current_content) {
+                ui.add_space(spacing::SM);
+                ui.horizontal(|ui| {
+                    if Self::shows_favorites_only(current_content)
+                        && Self::favorites_only_toggle(ui, theme, favorites_only, icon_size, btn_size)
+                    {
+                        action = Some(NavAction::FavoritesOnlyChanged);
+                    }
+                    if Self::shows_sort(current_content) && Self::sort_dropdown(ui, theme, sort_order) {
+                        action = Some(NavAction::SortChanged);
+                    }
+                    if Self::shows_quality_filter(current_content) && Self::quality_dropdown(ui, theme, quality_filter) {
+                        action = Some(NavAction::QualityFilterChanged);
+                    }
+                });
+            }
         }
 
         action
     }
 
+    /// Whether the quality filter applies to the given content.
+    fn shows_quality_filter(content: ContentType) -> bool {
+        matches!(content, ContentType::LiveTV | ContentType::Favorites)
+    }
+
+    /// Whether the favorites-only toggle applies to the given content.
+    fn shows_favorites_only(content: ContentType) -> bool {
+        matches!(content, ContentType::LiveTV | ContentType::Series | ContentType::Movies)
+    }
+
+    /// Whether the sort dropdown applies to the given content.
+    fn shows_sort(content: ContentType) -> bool {
+        matches!(
+            content,
+            ContentType::LiveTV | ContentType::Favorites | ContentType::Series | ContentType::Movies
+        )
+    }
+
+    /// Sort order dropdown. Returns true when the selection changed.
+    fn sort_dropdown(ui: &mut egui::Ui, theme: &Theme, sort_order: &mut SortOrder) -> bool {
+        let before = *sort_order;
+
+        egui::ComboBox::from_id_salt("top_nav_sort_order")
+            .selected_text(
+                egui::RichText::new(sort_order.label())
+                    .size(typography::BODY_SM)
+                    .color(theme.text_secondary),
+            )
+            .width(120.0)
+            .show_ui(ui, |ui| {
+                for order in SortOrder::ALL {
+                    ui.selectable_value(sort_order, order, order.label());
+                }
+            });
+
+        *sort_order != before
+    }
+
+    /// Quality filter dropdown ("All qualities", SD, HD, FHD, 4K).
+    /// Returns true when the selection changed.
+    fn quality_dropdown(ui: &mut egui::Ui, theme: &Theme, quality_filter: &mut Option<Quality>) -> bool {
+        let before = *quality_filter;
+        let selected_text = quality_filter.map(|q| q.label()).unwrap_or("All qualities");
+
+        egui::ComboBox::from_id_salt("top_nav_quality_filter")
+            .selected_text(
+                egui::RichText::new(selected_text)
+                    .size(typography::BODY_SM)
+                    .color(theme.text_secondary),
+            )
+            .width(110.0)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(quality_filter, None, "All qualities");
+                for quality in Quality::ALL {
+                    ui.selectable_value(quality_filter, Some(quality), quality.label());
+                }
+            });
+
+        *quality_filter != before
+    }
+
+    /// Star button restricting the view to favorites. Returns true when toggled.
+    fn favorites_only_toggle(ui: &mut egui::Ui, theme: &Theme, favorites_only: &mut bool, icon_size: f32, size: f32) -> bool {
+        let (icon, color) = if *favorites_only {
+            ("★", egui::Color32::from_rgb(255, 200, 0))
+        } else {
+            ("☆", theme.text_secondary)
+        };
+        let button = egui::Button::new(egui::RichText::new(icon).size(icon_size).color(color))
+            .fill(egui::Color32::TRANSPARENT)
+            .min_size(egui::vec2(size, size));
+
+        let hint = if *favorites_only { "Show everything" } else { "Show favorites only" };
+        let response = ui.add(button).on_hover_text(hint);
+        response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, true, *favorites_only, "Favorites only"));
+        if response.clicked() {
+            *favorites_only = !*favorites_only;
+        }
+        response.clicked()
+    }
+
+    /// "All" toggle next to the search field: search the whole library
+    /// rather than the current view. Returns true when toggled.
+    fn search_all_toggle(ui: &mut egui::Ui, theme: &Theme, search_all: &mut bool) -> bool {
+        let color = if *search_all { theme.accent_blue } else { theme.text_muted };
+        let response = ui.add(egui::SelectableLabel::new(
+            *search_all,
+            egui::RichText::new(t("nav.search_everywhere")).size(typography::CAPTION).color(color),
+        )).on_hover_text(t("nav.search_everywhere_hint"));
+        response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, true, *search_all, "Search all"));
+        if response.clicked() {
+            *search_all = !*search_all;
+        }
+        response.clicked()
+    }
+
+    /// Dice button that picks something at random. Returns true when clicked.
+    fn surprise_button(ui: &mut egui::Ui, theme: &Theme, icon_size: f32, size: f32) -> bool {
+        let button = egui::Button::new(
+            egui::RichText::new("🎲")
+                .size(icon_size)
+                .color(theme.text_secondary),
+        )
+        .fill(egui::Color32::TRANSPARENT)
+        .min_size(egui::vec2(size, size));
+
+        ui.add(button)
+            .on_hover_text("Surprise me")
+            .clicked()
+    }
+
+    /// Red "● REC" indicator shown while recordings are running.
+    /// Returns true when clicked.
+    fn recording_indicator(ui: &mut egui::Ui, theme: &Theme, count: usize, height: f32) -> bool {
+        let label = if count > 1 {
+            format!("● REC {}", count)
+        } else {
+            "● REC".to_string()
+        };
+
+        let button = egui::Button::new(
+            egui::RichText::new(label)
+                .size(typography::CAPTION)
+                .color(egui::Color32::WHITE)
+                .strong(),
+        )
+        .fill(theme.live_badge())
+        .rounding(egui::Rounding::same(radius::SM))
+        .min_size(egui::vec2(0.0, height * 0.75));
+
+        ui.add(button)
+            .on_hover_text("Active recordings")
+            .clicked()
+    }
+
     /// Creates a navigation tab (desktop)
     fn nav_tab(
         ui: &mut egui::Ui,
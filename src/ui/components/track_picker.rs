@@ -0,0 +1,137 @@
+//! "Select tracks" sheet shown before a movie or episode starts
+//!
+//! Lists the audio and subtitle tracks ffprobe found in the stream, by
+//! language and title, so the choice means something. Only shown when the
+//! stream actually has more than one option.
+
+use eframe::egui;
+use crate::track_probe::StreamTracks;
+
+/// Tracks picked for one playback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrackChoice {
+    /// Audio track index, 0 = first
+    pub audio: i32,
+    /// Subtitle track index, `None` = subtitles off
+    pub subtitle: Option<i32>,
+}
+
+/// Actions returned by the track picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackPickerAction {
+    /// Start playback with the chosen tracks
+    Play,
+    /// Start playback with the usual player settings, skipping the probe
+    Skip,
+    /// Don't play anything
+    Cancel,
+}
+
+/// Track picker component.
+pub struct TrackPicker;
+
+impl TrackPicker {
+    /// Renders the sheet for `title`. `tracks` is `None` while the stream is
+    /// still being probed.
+    pub fn show(
+        ctx: &egui::Context,
+        title: &str,
+        tracks: Option<&StreamTracks>,
+        choice: &mut TrackChoice,
+    ) -> Option<TrackPickerAction> {
+        let mut action = None;
+        let label_color = egui::Color32::from_rgb(180, 180, 180);
+
+        egui::Window::new("")
+            .id(egui::Id::new("track_picker"))
+            .resizable(false)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(420.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .frame(egui::Frame::none()
+                .fill(egui::Color32::from_rgb(24, 24, 24))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(24.0)))
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new("Select tracks")
+                    .size(20.0)
+                    .color(egui::Color32::WHITE)
+                    .strong());
+                ui.label(egui::RichText::new(title).color(label_color));
+                ui.add_space(16.0);
+
+                let Some(tracks) = tracks else {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(egui::RichText::new("Checking the stream's tracks...").color(label_color));
+                    });
+                    ui.add_space(16.0);
+                    ui.horizontal(|ui| {
+                        if ui.button("Play now").clicked() {
+                            action = Some(TrackPickerAction::Skip);
+                        }
+                        if ui.button("Cancel").clicked() {
+                            action = Some(TrackPickerAction::Cancel);
+                        }
+                    });
+                    return;
+                };
+
+                egui::Grid::new("track_picker_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 10.0])
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new("Audio:").color(label_color));
+                        let selected_audio = tracks.audio.iter()
+                            .find(|t| t.index == choice.audio)
+                            .map(|t| t.label())
+                            .unwrap_or_else(|| "Default".to_string());
+                        egui::ComboBox::from_id_salt("track_picker_audio")
+                            .width(280.0)
+                            .selected_text(selected_audio)
+                            .show_ui(ui, |ui| {
+                                for track in &tracks.audio {
+                                    ui.selectable_value(&mut choice.audio, track.index, track.label());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(egui::RichText::new("Subtitles:").color(label_color));
+                        let selected_subtitle = choice.subtitle
+                            .and_then(|index| tracks.subtitles.iter().find(|t| t.index == index))
+                            .map(|t| t.label())
+                            .unwrap_or_else(|| "Off".to_string());
+                        ui.add_enabled_ui(!tracks.subtitles.is_empty(), |ui| {
+                            egui::ComboBox::from_id_salt("track_picker_subtitle")
+                                .width(280.0)
+                                .selected_text(selected_subtitle)
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut choice.subtitle, None, "Off");
+                                    for track in &tracks.subtitles {
+                                        ui.selectable_value(&mut choice.subtitle, Some(track.index), track.label());
+                                    }
+                                });
+                        });
+                        ui.end_row();
+                    });
+
+                ui.add_space(20.0);
+                ui.horizontal(|ui| {
+                    let play_btn = egui::Button::new(
+                        egui::RichText::new("▶ Play").color(egui::Color32::WHITE)
+                    )
+                    .fill(egui::Color32::from_rgb(255, 90, 95))
+                    .min_size(egui::vec2(100.0, 32.0));
+                    if ui.add(play_btn).clicked() {
+                        action = Some(TrackPickerAction::Play);
+                    }
+                    if ui.button("Cancel").clicked() {
+                        action = Some(TrackPickerAction::Cancel);
+                    }
+                });
+            });
+
+        action
+    }
+}
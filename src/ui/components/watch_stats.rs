@@ -0,0 +1,170 @@
+//! Watch statistics - totals over the watch history
+//!
+//! Shows the total watch time, titles per content type, the most-watched
+//! series and a bar chart of the last 30 days. The figures are computed
+//! once when the view opens (see `WatchHistory::stats`).
+
+use eframe::egui;
+
+use crate::models::watch_history::ACTIVITY_DAYS;
+use crate::models::WatchStats;
+use crate::ui::theme::{radius, spacing, typography, Theme};
+
+/// Height of the activity chart.
+const CHART_HEIGHT: f32 = 64.0;
+
+/// Watch statistics component.
+pub struct WatchStatsDialog;
+
+impl WatchStatsDialog {
+    /// Renders the statistics. Returns true when it should close.
+    pub fn show(ctx: &egui::Context, stats: &WatchStats, theme: &Theme) -> bool {
+        let mut close = false;
+
+        egui::Window::new("")
+            .id(egui::Id::new("watch_stats"))
+            .resizable(false)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(440.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .frame(egui::Frame::none()
+                .fill(theme.panel_bg)
+                .stroke(egui::Stroke::new(1.0, theme.border_color))
+                .rounding(egui::Rounding::same(radius::MD))
+                .inner_margin(egui::Margin::same(spacing::XL)))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("📈 Watch Statistics")
+                        .size(typography::H2)
+                        .color(theme.text_primary)
+                        .strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("✕").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+                ui.add_space(spacing::MD);
+
+                if stats.titles == 0 {
+                    ui.label(egui::RichText::new("Nothing watched yet").color(theme.text_secondary));
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    Self::figure(ui, theme, &format_hours(stats.total_seconds), "watched");
+                    ui.add_space(spacing::XL);
+                    Self::figure(ui, theme, &stats.titles.to_string(), "titles");
+                });
+                ui.add_space(spacing::MD);
+                ui.horizontal_wrapped(|ui| {
+                    for (kind, count) in &stats.by_type {
+                        ui.label(egui::RichText::new(format!("{} {}", type_label(kind), count))
+                            .size(typography::BODY_SM)
+                            .color(theme.text_secondary));
+                        ui.add_space(spacing::MD);
+                    }
+                });
+
+                if !stats.top_series.is_empty() {
+                    Self::heading(ui, theme, "MOST-WATCHED SERIES");
+                    for (name, episodes, seconds) in &stats.top_series {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(name).color(theme.text_primary));
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.label(egui::RichText::new(format!(
+                                    "{} episode{} · {}",
+                                    episodes,
+                                    if *episodes == 1 { "" } else { "s" },
+                                    format_hours(*seconds),
+                                ))
+                                .size(typography::CAPTION)
+                                .color(theme.text_tertiary));
+                            });
+                        });
+                    }
+                }
+
+                Self::heading(ui, theme, "LAST 30 DAYS");
+                let recent: usize = stats.daily.iter().sum();
+                ui.label(egui::RichText::new(format!(
+                    "{} title{} · {}",
+                    recent,
+                    if recent == 1 { "" } else { "s" },
+                    format_hours(stats.recent_seconds),
+                ))
+                .size(typography::BODY_SM)
+                .color(theme.text_secondary));
+                ui.add_space(spacing::SM);
+                Self::activity_chart(ui, theme, &stats.daily);
+            });
+
+        close
+    }
+
+    fn heading(ui: &mut egui::Ui, theme: &Theme, text: &str) {
+        ui.add_space(spacing::LG);
+        ui.label(egui::RichText::new(text)
+            .size(typography::LABEL)
+            .color(theme.text_muted)
+            .strong());
+        ui.add_space(spacing::XS);
+    }
+
+    /// A large number with a caption under it.
+    fn figure(ui: &mut egui::Ui, theme: &Theme, value: &str, caption: &str) {
+        ui.vertical(|ui| {
+            ui.label(egui::RichText::new(value)
+                .size(typography::DISPLAY)
+                .color(theme.accent_blue)
+                .strong());
+            ui.label(egui::RichText::new(caption)
+                .size(typography::CAPTION)
+                .color(theme.text_tertiary));
+        });
+    }
+
+    /// One bar per day, today on the right.
+    fn activity_chart(ui: &mut egui::Ui, theme: &Theme, daily: &[usize; ACTIVITY_DAYS]) {
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), CHART_HEIGHT), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        let max = daily.iter().copied().max().unwrap_or(0).max(1) as f32;
+        let slot = rect.width() / ACTIVITY_DAYS as f32;
+
+        painter.line_segment([rect.left_bottom(), rect.right_bottom()], egui::Stroke::new(1.0, theme.divider));
+        for (day, &count) in daily.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let height = (count as f32 / max * rect.height()).max(2.0);
+            let left = rect.left() + day as f32 * slot + 1.0;
+            let bar = egui::Rect::from_min_max(
+                egui::pos2(left, rect.bottom() - height),
+                egui::pos2(left + slot - 2.0, rect.bottom()),
+            );
+            painter.rect_filled(bar, egui::Rounding::same(radius::SM / 2.0), theme.accent_blue);
+        }
+    }
+}
+
+/// Display name for a watch history content type.
+fn type_label(kind: &str) -> String {
+    match kind {
+        "movie" => "🎬 Movies".to_string(),
+        "series" => "📚 Episodes".to_string(),
+        "catchup" => "⏪ Catch-up".to_string(),
+        "channel" => "📺 Channels".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Seconds as "12h 30m", or "45m" under an hour.
+fn format_hours(seconds: f64) -> String {
+    let minutes = (seconds.max(0.0) / 60.0).round() as u64;
+    if minutes >= 60 {
+        format!("{}h {}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
@@ -0,0 +1,82 @@
+//! What's new - changelog shown once after an update
+//!
+//! The notes for each release are compiled in; the dialog opens on the
+//! first launch of a version that has notes and the user hasn't seen yet.
+
+use eframe::egui;
+
+use crate::ui::theme::{radius, spacing, typography, Theme};
+
+/// Release notes, newest first, as (version, changes).
+pub const CHANGELOG: &[(&str, &[&str])] = &[
+    ("0.1.0", &[
+        "Built-in player (optional libmpv build) with pause, seek and volume",
+        "Mini player bar to pause, stop and set the volume of an external MPV",
+        "Drag favorites in My List into your own order",
+        "Series overview with cover, genre, cast and rating above the episodes",
+        "Watch statistics from the watch history",
+        "Hide broken channels, and hide duplicates across categories",
+        "Custom User-Agent and Referer for providers that need them",
+        "Dates and numbers follow your locale",
+        "Touch mode and touch target size for undetected touchscreens",
+        "Lower CPU use: the guide only redraws when a programme changes",
+    ]),
+];
+
+/// The release notes for `version`, if there are any.
+pub fn notes_for(version: &str) -> Option<&'static [&'static str]> {
+    CHANGELOG.iter().find(|(v, _)| *v == version).map(|(_, notes)| *notes)
+}
+
+/// What's new dialog component.
+pub struct WhatsNew;
+
+impl WhatsNew {
+    /// Shows the notes for `version`. Returns true when dismissed.
+    pub fn show(ctx: &egui::Context, version: &str, notes: &[&str], theme: &Theme) -> bool {
+        let mut close = false;
+
+        egui::Window::new("")
+            .id(egui::Id::new("whats_new"))
+            .resizable(false)
+            .collapsible(false)
+            .title_bar(false)
+            .default_width(440.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .frame(egui::Frame::none()
+                .fill(theme.panel_bg)
+                .stroke(egui::Stroke::new(1.0, theme.border_color))
+                .rounding(egui::Rounding::same(radius::MD))
+                .inner_margin(egui::Margin::same(spacing::XL)))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format!("✨ What's new in {}", version))
+                        .size(typography::H2)
+                        .color(theme.text_primary)
+                        .strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("✕").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+                ui.add_space(spacing::MD);
+
+                for note in notes {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(egui::RichText::new("•").color(theme.accent_blue));
+                        ui.label(egui::RichText::new(*note).color(theme.text_secondary));
+                    });
+                }
+
+                ui.add_space(spacing::LG);
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button("Don't show again").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        close
+    }
+}
@@ -1,14 +1,19 @@
 //! EPG (Electronic Program Guide) cache for loading EPG data in background.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+use eframe::egui;
+
 use crate::api::XtreamClient;
 use crate::models::EpgProgram;
 use crate::xmltv::XmltvParser;
 
+/// Number of guide requests a bulk load runs at the same time.
+const BULK_WORKERS: usize = 4;
+
 /// Message type for EPG loading
 struct EpgLoadResult {
     stream_id: String,
@@ -37,12 +42,21 @@ pub struct EpgCache {
     xmltv_url: Arc<Mutex<Option<String>>>,
     /// Whether XMLTV has been loaded
     xmltv_loaded: Arc<Mutex<bool>>,
+    /// Full guide including past programmes, for catch-up: stream_id -> programs
+    archive: Arc<Mutex<HashMap<String, Vec<EpgProgram>>>>,
+    /// Stream IDs whose archive guide is being loaded
+    archive_loading: Arc<Mutex<std::collections::HashSet<String>>>,
+    /// Stream IDs last passed to `request_page`; emptied when the guide
+    /// data changes so the page is requested again
+    requested_page: Arc<Mutex<Vec<String>>>,
+    /// Woken when a background load finishes
+    ctx: egui::Context,
 }
 
 #[allow(dead_code)]
 impl EpgCache {
-    /// Create a new EPG cache
-    pub fn new() -> Self {
+    /// Create a new EPG cache; `ctx` is repainted as guide data arrives
+    pub fn new(ctx: egui::Context) -> Self {
         let (tx, rx) = channel();
         Self {
             cache: Arc::new(Mutex::new(HashMap::new())),
@@ -56,6 +70,10 @@ impl EpgCache {
             xmltv_cache: Arc::new(Mutex::new(HashMap::new())),
             xmltv_url: Arc::new(Mutex::new(None)),
             xmltv_loaded: Arc::new(Mutex::new(false)),
+            archive: Arc::new(Mutex::new(HashMap::new())),
+            archive_loading: Arc::new(Mutex::new(std::collections::HashSet::new())),
+            requested_page: Arc::new(Mutex::new(Vec::new())),
+            ctx,
         }
     }
     
@@ -68,6 +86,10 @@ impl EpgCache {
         if let Ok(mut cache) = self.cache.lock() {
             cache.clear();
         }
+        if let Ok(mut archive) = self.archive.lock() {
+            archive.clear();
+        }
+        self.forget_requested_page();
     }
     
     /// Check for completed background loads and update cache
@@ -113,41 +135,87 @@ impl EpgCache {
         let server_url = self.server_url.clone();
         let username = self.username.clone();
         let password = self.password.clone();
+        let ctx = self.ctx.clone();
         
         // Load in background thread with retry
         thread::spawn(move || {
             if let Some(tx) = tx {
                 let client = XtreamClient::new(server_url, username, password);
-                
-                // Try up to 2 times with a small delay
-                let mut programs = Vec::new();
-                for attempt in 0..2 {
-                    match client.get_short_epg(&stream_id) {
-                        Ok(p) => {
-                            programs = p;
-                            if !programs.is_empty() {
-                                eprintln!("[EPG] Loaded {} programs for stream {}", programs.len(), stream_id);
-                            }
-                            break;
-                        }
-                        Err(e) => {
-                            if attempt == 0 {
-                                // Wait a bit before retry
-                                std::thread::sleep(std::time::Duration::from_millis(500));
-                            } else {
-                                // Only log on final failure, and silently for 503 errors
-                                if !e.to_string().contains("503") {
-                                    eprintln!("[EPG] Error loading stream {}: {}", stream_id, e);
-                                }
-                            }
-                        }
-                    }
-                }
+                let programs = fetch_short_epg(&client, &stream_id);
                 let _ = tx.send(EpgLoadResult { stream_id, programs });
+                ctx.request_repaint();
             }
         });
     }
     
+    /// Request EPG for many channels at once, as `(stream_id, tvg_id)`
+    /// pairs. XMLTV hits are filled in straight away; the rest are fetched
+    /// by a few workers on a single background thread rather than one
+    /// thread per channel.
+    pub fn request_epg_bulk(&self, channels: &[(String, Option<String>)]) {
+        self.start_xmltv_if_configured();
+        
+        let mut missing = VecDeque::new();
+        for (stream_id, tvg_id) in channels {
+            if self.cache.lock().map(|c| c.contains_key(stream_id)).unwrap_or(false) {
+                continue;
+            }
+            
+            let xmltv = tvg_id.as_deref()
+                .filter(|id| !id.is_empty())
+                .and_then(|id| self.get_xmltv_epg(id));
+            if let Some(programs) = xmltv {
+                if let Ok(mut cache) = self.cache.lock() {
+                    cache.insert(stream_id.clone(), programs);
+                }
+                continue;
+            }
+            
+            if let Ok(mut loading) = self.loading.lock() {
+                if loading.insert(stream_id.clone()) {
+                    missing.push_back(stream_id.clone());
+                }
+            }
+        }
+        
+        if missing.is_empty() || self.server_url.is_empty() {
+            return;
+        }
+        let Some(tx) = self.tx.clone() else { return };
+        
+        let client = XtreamClient::new(self.server_url.clone(), self.username.clone(), self.password.clone());
+        let ctx = self.ctx.clone();
+        thread::spawn(move || {
+            let workers = BULK_WORKERS.min(missing.len());
+            let queue = Mutex::new(missing);
+            thread::scope(|scope| {
+                for _ in 0..workers {
+                    let tx = tx.clone();
+                    let (client, queue, ctx) = (&client, &queue, &ctx);
+                    scope.spawn(move || loop {
+                        let next = queue.lock().ok().and_then(|mut q| q.pop_front());
+                        let Some(stream_id) = next else { break };
+                        let programs = fetch_short_epg(client, &stream_id);
+                        let _ = tx.send(EpgLoadResult { stream_id, programs });
+                        ctx.request_repaint();
+                    });
+                }
+            });
+        });
+    }
+    
+    /// Request EPG for the channels on screen. Does nothing while the
+    /// page stays the same, so it is cheap to call every frame.
+    pub fn request_page(&self, channels: &[(String, Option<String>)]) {
+        if let Ok(mut requested) = self.requested_page.lock() {
+            if requested.iter().eq(channels.iter().map(|(id, _)| id)) {
+                return;
+            }
+            *requested = channels.iter().map(|(id, _)| id.clone()).collect();
+        }
+        self.request_epg_bulk(channels);
+    }
+    
     /// Get cached EPG for a stream (returns None if not loaded yet)
     pub fn get_epg(&self, stream_id: &str) -> Option<Vec<EpgProgram>> {
         if let Ok(cache) = self.cache.lock() {
@@ -159,25 +227,42 @@ impl EpgCache {
     
     /// Get the current program for a stream
     pub fn get_current_program(&self, stream_id: &str) -> Option<EpgProgram> {
-        self.get_epg(stream_id).and_then(|programs| {
-            programs.into_iter().find(|p| p.is_now_playing())
-        })
+        self.now_and_next(stream_id, unix_now()).0
     }
     
     /// Get the next program for a stream
     pub fn get_next_program(&self, stream_id: &str) -> Option<EpgProgram> {
-        if let Some(programs) = self.get_epg(stream_id) {
-            let now = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_secs() as i64)
-                .unwrap_or(0);
-            
-            // Find the first program that starts after now
-            programs.into_iter()
-                .find(|p| p.start_timestamp_unix() > now)
-        } else {
-            None
-        }
+        self.now_and_next(stream_id, unix_now()).1
+    }
+    
+    /// The programme airing at `now` and the first one starting after it,
+    /// worked out from the stored start/stop times.
+    pub fn now_and_next(&self, stream_id: &str, now: i64) -> (Option<EpgProgram>, Option<EpgProgram>) {
+        let Ok(cache) = self.cache.lock() else {
+            return (None, None);
+        };
+        let Some(programs) = cache.get(stream_id) else {
+            return (None, None);
+        };
+        let current = programs.iter()
+            .find(|p| p.start_timestamp_unix() <= now && now < p.end_timestamp_unix())
+            .cloned();
+        let next = programs.iter()
+            .find(|p| p.start_timestamp_unix() > now)
+            .cloned();
+        (current, next)
+    }
+    
+    /// The earliest programme start or end after `now` across `stream_ids`,
+    /// i.e. when the now/next shown for them changes next.
+    pub fn next_boundary<'a>(&self, stream_ids: impl IntoIterator<Item = &'a str>, now: i64) -> Option<i64> {
+        let cache = self.cache.lock().ok()?;
+        stream_ids.into_iter()
+            .filter_map(|id| cache.get(id))
+            .flatten()
+            .flat_map(|p| [p.start_timestamp_unix(), p.end_timestamp_unix()])
+            .filter(|&t| t > now)
+            .min()
     }
     
     /// Clear the EPG cache (e.g., on disconnect)
@@ -188,9 +273,62 @@ impl EpgCache {
         if let Ok(mut loading) = self.loading.lock() {
             loading.clear();
         }
+        if let Ok(mut archive) = self.archive.lock() {
+            archive.clear();
+        }
+        self.forget_requested_page();
         self.last_refresh = std::time::Instant::now();
     }
     
+    /// Make the next `request_page` call go through, even for the same page
+    fn forget_requested_page(&self) {
+        if let Ok(mut requested) = self.requested_page.lock() {
+            requested.clear();
+        }
+    }
+    
+    /// Request the full guide of a catch-up channel, past programmes
+    /// included. Always re-fetched so the archive window stays current.
+    pub fn request_archive(&self, stream_id: &str) {
+        if self.server_url.is_empty() {
+            return;
+        }
+        if let Ok(mut loading) = self.archive_loading.lock() {
+            if !loading.insert(stream_id.to_string()) {
+                return;
+            }
+        }
+        
+        let archive = self.archive.clone();
+        let archive_loading = self.archive_loading.clone();
+        let stream_id = stream_id.to_string();
+        let client = XtreamClient::new(self.server_url.clone(), self.username.clone(), self.password.clone());
+        thread::spawn(move || {
+            let mut programs = match client.get_simple_data_table(&stream_id) {
+                Ok(programs) => programs,
+                Err(e) => {
+                    log::warn!("[EPG] Error loading archive guide for stream {}: {}", stream_id, e);
+                    Vec::new()
+                }
+            };
+            programs.sort_by_key(|p| p.start_timestamp_unix());
+            if let Ok(mut archive) = archive.lock() {
+                archive.insert(stream_id.clone(), programs);
+            }
+            if let Ok(mut loading) = archive_loading.lock() {
+                loading.remove(&stream_id);
+            }
+        });
+    }
+    
+    /// Get the loaded catch-up guide for a stream (None while loading)
+    pub fn get_archive(&self, stream_id: &str) -> Option<Vec<EpgProgram>> {
+        if self.archive_loading.lock().map(|l| l.contains(stream_id)).unwrap_or(false) {
+            return None;
+        }
+        self.archive.lock().ok().and_then(|a| a.get(stream_id).cloned())
+    }
+    
     /// Bulk request EPG for multiple streams
     pub fn request_epg_batch(&self, stream_ids: &[String]) {
         for stream_id in stream_ids {
@@ -232,6 +370,7 @@ impl EpgCache {
         if let Ok(mut cache) = self.xmltv_cache.lock() {
             cache.clear();
         }
+        self.forget_requested_page();
     }
 
     /// Load XMLTV EPG data from configured URL
@@ -259,18 +398,25 @@ impl EpgCache {
 
             let xmltv_cache = self.xmltv_cache.clone();
             let xmltv_loaded = self.xmltv_loaded.clone();
+            let requested_page = self.requested_page.clone();
+            let ctx = self.ctx.clone();
 
             thread::spawn(move || {
-                eprintln!("[EPG] Loading XMLTV from: {}", url);
+                log::info!("[EPG] Loading XMLTV from: {}", url);
                 match XmltvParser::parse_url(&url) {
                     Ok(programs_map) => {
-                        eprintln!("[EPG] Successfully loaded XMLTV data for {} channels", programs_map.len());
+                        log::info!("[EPG] Successfully loaded XMLTV data for {} channels", programs_map.len());
                         if let Ok(mut cache) = xmltv_cache.lock() {
                             *cache = programs_map;
                         }
+                        // Let the page on screen pick up the XMLTV guide
+                        if let Ok(mut requested) = requested_page.lock() {
+                            requested.clear();
+                        }
+                        ctx.request_repaint();
                     }
                     Err(e) => {
-                        eprintln!("[EPG] Error loading XMLTV: {}", e);
+                        log::warn!("[EPG] Error loading XMLTV: {}", e);
                         // Reset loaded flag on error so we can retry later
                         if let Ok(mut loaded) = xmltv_loaded.lock() {
                             *loaded = false;
@@ -293,17 +439,7 @@ impl EpgCache {
     /// Request EPG for a channel with tvg-id (tries XMLTV first, then Xtream API)
     pub fn request_epg_with_tvg(&self, stream_id: &str, tvg_id: Option<&str>) {
         // First, try to load XMLTV if we have a URL and haven't loaded yet
-        if let Ok(loaded) = self.xmltv_loaded.lock() {
-            if !*loaded {
-                drop(loaded);
-                if let Ok(url) = self.xmltv_url.lock() {
-                    if url.is_some() {
-                        drop(url);
-                        self.load_xmltv();
-                    }
-                }
-            }
-        }
+        self.start_xmltv_if_configured();
 
         // If we have a tvg-id, try to get EPG from XMLTV cache
         if let Some(tvg_id) = tvg_id {
@@ -321,10 +457,54 @@ impl EpgCache {
         // Fallback to Xtream API
         self.request_epg(stream_id);
     }
+    
+    /// Start loading XMLTV if a URL is set and it hasn't been loaded yet
+    fn start_xmltv_if_configured(&self) {
+        if let Ok(loaded) = self.xmltv_loaded.lock() {
+            if !*loaded {
+                drop(loaded);
+                if let Ok(url) = self.xmltv_url.lock() {
+                    if url.is_some() {
+                        drop(url);
+                        self.load_xmltv();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Current time as a Unix timestamp in seconds.
+pub fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
 }
 
-impl Default for EpgCache {
-    fn default() -> Self {
-        Self::new()
+/// Fetch the short EPG for one stream, retrying once after a short delay.
+/// Returns an empty list if the provider has no guide data or keeps failing.
+fn fetch_short_epg(client: &XtreamClient, stream_id: &str) -> Vec<EpgProgram> {
+    for attempt in 0..2 {
+        match client.get_short_epg(stream_id) {
+            Ok(programs) => {
+                if !programs.is_empty() {
+                    log::debug!("[EPG] Loaded {} programs for stream {}", programs.len(), stream_id);
+                }
+                return programs;
+            }
+            Err(e) => {
+                if attempt == 0 {
+                    // Wait a bit before retry
+                    std::thread::sleep(std::time::Duration::from_millis(500));
+                } else {
+                    // Only log on final failure, and silently for 503 errors
+                    if !e.to_string().contains("503") {
+                        log::warn!("[EPG] Error loading stream {}: {}", stream_id, e);
+                    }
+                }
+            }
+        }
     }
+    Vec::new()
 }
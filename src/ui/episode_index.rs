@@ -0,0 +1,128 @@
+//! Episode lists of watched series, for the Next Up row.
+//!
+//! `get_series_info` is slow and large, so each series is fetched at most
+//! once per session and only its episode order is kept.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use eframe::egui;
+
+use crate::api::XtreamClient;
+use crate::models::watch_history::{WatchHistory, WatchProgress};
+use crate::ui::components::episode_dialog::{self, EpisodeAction};
+
+/// Episodes by series ID, fetched in the background on first request.
+pub struct EpisodeIndex {
+    /// Every episode of a series as a play action, in viewing order
+    episodes: Arc<Mutex<HashMap<i32, Arc<Vec<EpisodeAction>>>>>,
+    /// Series fetched or being fetched; failures stay here until `clear`
+    requested: HashSet<i32>,
+    /// Woken when a list arrives
+    ctx: egui::Context,
+}
+
+impl EpisodeIndex {
+    pub fn new(ctx: egui::Context) -> Self {
+        Self {
+            episodes: Arc::new(Mutex::new(HashMap::new())),
+            requested: HashSet::new(),
+            ctx,
+        }
+    }
+
+    /// The episodes of `series_id`, if they have been fetched.
+    pub fn get(&self, series_id: i32) -> Option<Arc<Vec<EpisodeAction>>> {
+        self.episodes.lock().unwrap().get(&series_id).cloned()
+    }
+
+    /// Fetches the episodes of `series_id` unless that was done already.
+    /// `series_name` must be the name the watch history uses.
+    pub fn request(&mut self, series_id: i32, series_name: &str, client: XtreamClient) {
+        if !self.requested.insert(series_id) {
+            return;
+        }
+        let episodes = self.episodes.clone();
+        let series_name = series_name.to_string();
+        let ctx = self.ctx.clone();
+        thread::spawn(move || match client.get_series_info(series_id) {
+            Ok(info) => {
+                let actions = episode_dialog::episode_actions(&info, &series_name);
+                episodes.lock().unwrap().insert(series_id, Arc::new(actions));
+                ctx.request_repaint();
+            }
+            Err(e) => log::warn!("[NextUp] Couldn't load episodes of {}: {}", series_name, e),
+        });
+    }
+
+    /// Forgets every list, e.g. after switching provider or refreshing.
+    pub fn clear(&mut self) {
+        self.episodes.lock().unwrap().clear();
+        self.requested.clear();
+    }
+}
+
+/// Index in `episodes` of the one to watch after `last`, the latest
+/// finished episode of the series: the first that follows it and hasn't
+/// been watched to the end. Continues into the next season.
+pub fn next_up(episodes: &[EpisodeAction], last: &WatchProgress, history: &WatchHistory) -> Option<usize> {
+    let position = |action: &EpisodeAction| match action {
+        EpisodeAction::PlayEpisode { season, episode, .. } => Some((*season, *episode)),
+        _ => None,
+    };
+    let last_position = Some((last.season?, last.episode?));
+    let current = episodes.iter().position(|action| position(action) == last_position)?;
+
+    (current + 1..episodes.len()).find(|&i| {
+        let Some((season, episode)) = position(&episodes[i]) else {
+            return false;
+        };
+        let key = format!("{}_{}_{}", last.content_name, season, episode);
+        !history.get_progress(&key).is_some_and(|p| p.is_nearly_finished())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn episode(season: i32, episode: i32) -> EpisodeAction {
+        EpisodeAction::PlayEpisode {
+            episode_id: format!("{}{}", season, episode),
+            series_name: "Lost".to_string(),
+            season,
+            episode,
+            title: String::new(),
+            container: "mkv".to_string(),
+        }
+    }
+
+    fn watched(season: i32, episode: i32, position: f64) -> WatchProgress {
+        WatchProgress {
+            content_id: format!("Lost_{}_{}", season, episode),
+            content_name: "Lost".to_string(),
+            content_type: "series".to_string(),
+            position_seconds: position,
+            duration_seconds: 100.0,
+            season: Some(season),
+            episode: Some(episode),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_next_up_crosses_seasons_and_skips_watched() {
+        let episodes = [episode(1, 1), episode(1, 2), episode(2, 1), episode(2, 2)];
+        let history: WatchHistory = serde_json::from_value(serde_json::json!({
+            "history": { "Lost_2_1": watched(2, 1, 100.0) }
+        }))
+        .unwrap();
+
+        assert_eq!(next_up(&episodes, &watched(1, 1, 100.0), &history), Some(1));
+        // S2E1 was already watched out of order
+        assert_eq!(next_up(&episodes, &watched(1, 2, 100.0), &history), Some(3));
+        assert_eq!(next_up(&episodes, &watched(2, 2, 100.0), &history), None);
+        assert_eq!(next_up(&episodes, &watched(3, 1, 100.0), &history), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! Fuzzy matching of search queries.
+//!
+//! Titles match with missing letters and partial words ("breakin bad"),
+//! ignoring case and accents. Each word of the query is matched on its
+//! own, so word order doesn't matter; the word scores add up to rank
+//! the results.
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+
+/// Lowest score per query character for a fuzzy (non-substring) match.
+/// Below it the letters are spread too thin over the title to mean much.
+const MIN_SCORE_PER_CHAR: i64 = 10;
+
+/// Added when a word appears as typed, so exact matches rank first.
+const SUBSTRING_BONUS: i64 = 100;
+
+/// `text` in lowercase with accents and other diacritics removed.
+pub fn normalize(text: &str) -> String {
+    deunicode::deunicode(text).to_lowercase()
+}
+
+/// [`normalize`]d `text`, with the position in `text` of the char each
+/// normalized char came from ("ß" becomes "ss", both from the same char).
+fn normalize_mapped(text: &str) -> (String, Vec<usize>) {
+    let mut normalized = String::new();
+    let mut origin = Vec::new();
+    for (i, ch) in text.chars().enumerate() {
+        for folded in deunicode::deunicode_char(ch).unwrap_or("[?]").chars().flat_map(char::to_lowercase) {
+            normalized.push(folded);
+            origin.push(i);
+        }
+    }
+    (normalized, origin)
+}
+
+/// A search query, prepared for matching many titles.
+pub struct Query {
+    words: Vec<String>,
+    matcher: SkimMatcherV2,
+}
+
+impl Query {
+    /// `None` for a blank query, which matches everything.
+    pub fn new(query: &str) -> Option<Self> {
+        let words: Vec<String> = normalize(query).split_whitespace().map(str::to_string).collect();
+        if words.is_empty() {
+            return None;
+        }
+        Some(Self { words, matcher: SkimMatcherV2::default().ignore_case() })
+    }
+
+    /// How well `title` matches, higher is better; `None` when it doesn't.
+    pub fn score(&self, title: &str) -> Option<i64> {
+        let title = normalize(title);
+        self.words.iter().map(|word| self.word_score(&title, word)).sum()
+    }
+
+    /// Positions of the chars of `title` the query's words matched, in
+    /// order; empty when the title doesn't match.
+    pub fn matched_chars(&self, title: &str) -> Vec<usize> {
+        let (normalized, origin) = normalize_mapped(title);
+        let scores: Option<i64> = self.words.iter().map(|word| self.word_score(&normalized, word)).sum();
+        if scores.is_none() {
+            return Vec::new();
+        }
+        let mut chars: Vec<usize> = self.words.iter()
+            .filter_map(|word| self.matcher.fuzzy_indices(&normalized, word))
+            .flat_map(|(_, indices)| indices)
+            .filter_map(|i| origin.get(i).copied())
+            .collect();
+        chars.sort_unstable();
+        chars.dedup();
+        chars
+    }
+
+    fn word_score(&self, title: &str, word: &str) -> Option<i64> {
+        let score = self.matcher.fuzzy_match(title, word)?;
+        if title.contains(word) {
+            Some(score + SUBSTRING_BONUS)
+        } else {
+            (score >= MIN_SCORE_PER_CHAR * word.len() as i64).then_some(score)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_query() {
+        let query = Query::new("breakin bad").unwrap();
+        assert!(query.score("Breaking Bad").is_some());
+        assert!(query.score("Bad Breaking").is_some());
+        assert!(query.score("Better Call Saul").is_none());
+
+        let query = Query::new("pokemon").unwrap();
+        assert!(query.score("Pokémon: The Series").is_some());
+
+        // The closer match ranks first
+        let query = Query::new("news").unwrap();
+        assert!(query.score("BBC News") > query.score("Nature Wildlife Shows"));
+
+        assert!(Query::new("   ").is_none());
+    }
+
+    #[test]
+    fn test_matched_chars() {
+        let query = Query::new("pokemon").unwrap();
+        assert_eq!(query.matched_chars("Pokémon"), (0..7).collect::<Vec<_>>());
+
+        let query = Query::new("strasse").unwrap();
+        assert_eq!(query.matched_chars("Straße 1"), (0..6).collect::<Vec<_>>());
+
+        let query = Query::new("bad breakin").unwrap();
+        assert_eq!(query.matched_chars("Breaking Bad"), vec![0, 1, 2, 3, 4, 5, 6, 9, 10, 11]);
+        assert!(query.matched_chars("Better Call Saul").is_empty());
+    }
+}
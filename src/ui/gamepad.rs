@@ -0,0 +1,180 @@
+//! Gamepad input layer for Steam Deck Game Mode.
+//!
+//! Reads controller state through `gilrs` (enabled with the `gamepad` cargo
+//! feature) and translates it for the UI:
+//!
+//! - D-pad → arrow keys (egui focus movement between cards)
+//! - A → Enter (activate the focused card/button)
+//! - B → Escape (go back / close dialog)
+//! - Y → context menu of the focused card
+//! - Bumpers, triggers and the menu/view buttons → [`GamepadAction`]s
+//! - Right stick → smooth scrolling (see [`GamepadInput::scroll_axis`])
+//!
+//! Without the feature this is a no-op, so desktop builds don't need libudev.
+
+use eframe::egui;
+
+/// App-level actions triggered by controller buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(feature = "gamepad"), allow(dead_code))]
+pub enum GamepadAction {
+    /// Left bumper
+    PreviousPage,
+    /// Right bumper
+    NextPage,
+    /// Left trigger
+    PreviousSection,
+    /// Right trigger
+    NextSection,
+    /// Menu (Start) button
+    OpenSettings,
+    /// View (Select) button
+    ToggleSidebar,
+    /// Y button
+    ContextMenu,
+}
+
+/// Polls connected controllers and converts their input.
+pub struct GamepadInput {
+    #[cfg(feature = "gamepad")]
+    gilrs: Option<gilrs::Gilrs>,
+}
+
+impl GamepadInput {
+    /// Create the gamepad layer. Controller support failing to initialize
+    /// is logged and leaves the layer inactive.
+    pub fn new() -> Self {
+        #[cfg(feature = "gamepad")]
+        {
+            let gilrs = match gilrs::Gilrs::new() {
+                Ok(gilrs) => Some(gilrs),
+                Err(e) => {
+                    log::warn!("[Gamepad] Controller support unavailable: {}", e);
+                    None
+                }
+            };
+            Self { gilrs }
+        }
+
+        #[cfg(not(feature = "gamepad"))]
+        Self {}
+    }
+
+    /// Whether at least one controller is connected.
+    pub fn is_connected(&self) -> bool {
+        #[cfg(feature = "gamepad")]
+        {
+            self.gilrs.as_ref().is_some_and(|g| g.gamepads().next().is_some())
+        }
+
+        #[cfg(not(feature = "gamepad"))]
+        false
+    }
+
+    /// Drain pending controller events. Navigation buttons are pushed into
+    /// `raw_input` as key events; everything else is returned as actions.
+    ///
+    /// `has_focus` tells whether an egui widget currently has keyboard focus:
+    /// without it the D-pad first moves focus onto the first widget (Tab),
+    /// since arrow keys only navigate between widgets once one is focused.
+    pub fn poll(&mut self, raw_input: &mut egui::RawInput, has_focus: bool) -> Vec<GamepadAction> {
+        #[allow(unused_mut)]
+        let mut actions = Vec::new();
+
+        #[cfg(feature = "gamepad")]
+        if let Some(gilrs) = self.gilrs.as_mut() {
+            use gilrs::{Button, EventType};
+
+            while let Some(gilrs::Event { event, .. }) = gilrs.next_event() {
+                let EventType::ButtonPressed(button, _) = event else {
+                    continue;
+                };
+
+                let dpad_key = match button {
+                    Button::DPadUp => Some(egui::Key::ArrowUp),
+                    Button::DPadDown => Some(egui::Key::ArrowDown),
+                    Button::DPadLeft => Some(egui::Key::ArrowLeft),
+                    Button::DPadRight => Some(egui::Key::ArrowRight),
+                    _ => None,
+                };
+
+                if let Some(key) = dpad_key {
+                    push_key(raw_input, if has_focus { key } else { egui::Key::Tab });
+                    continue;
+                }
+
+                match button {
+                    Button::South => push_key(raw_input, egui::Key::Enter),
+                    Button::East => push_key(raw_input, egui::Key::Escape),
+                    Button::LeftTrigger => actions.push(GamepadAction::PreviousPage),
+                    Button::RightTrigger => actions.push(GamepadAction::NextPage),
+                    Button::LeftTrigger2 => actions.push(GamepadAction::PreviousSection),
+                    Button::RightTrigger2 => actions.push(GamepadAction::NextSection),
+                    Button::Start => actions.push(GamepadAction::OpenSettings),
+                    Button::Select => actions.push(GamepadAction::ToggleSidebar),
+                    Button::North => actions.push(GamepadAction::ContextMenu),
+                    _ => {}
+                }
+            }
+        }
+
+        #[cfg(not(feature = "gamepad"))]
+        let _ = (raw_input, has_focus);
+
+        actions
+    }
+
+    /// Vertical right-stick deflection in -1.0..=1.0 (up is positive),
+    /// with the dead zone removed and the remaining range rescaled so
+    /// scrolling starts smoothly at the edge of the dead zone.
+    pub fn scroll_axis(&self, dead_zone: f32) -> f32 {
+        #[cfg(feature = "gamepad")]
+        {
+            let value = self.gilrs.as_ref()
+                .and_then(|g| {
+                    g.gamepads()
+                        .map(|(_, pad)| pad.value(gilrs::Axis::RightStickY))
+                        .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+                })
+                .unwrap_or(0.0);
+            apply_dead_zone(value, dead_zone)
+        }
+
+        #[cfg(not(feature = "gamepad"))]
+        {
+            let _ = dead_zone;
+            0.0
+        }
+    }
+}
+
+/// Zero out `value` inside the dead zone and rescale the rest to 0..=1.
+#[cfg(feature = "gamepad")]
+fn apply_dead_zone(value: f32, dead_zone: f32) -> f32 {
+    let dead_zone = dead_zone.clamp(0.0, 0.95);
+    if value.abs() <= dead_zone {
+        0.0
+    } else {
+        value.signum() * (value.abs() - dead_zone) / (1.0 - dead_zone)
+    }
+}
+
+impl Default for GamepadInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Push a full key press (down + up) into this frame's input.
+#[cfg(feature = "gamepad")]
+fn push_key(raw_input: &mut egui::RawInput, key: egui::Key) {
+    for pressed in [true, false] {
+        raw_input.events.push(egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        });
+    }
+}
@@ -0,0 +1,90 @@
+//! Search match highlighting for card titles.
+//!
+//! The app publishes the active search query once per frame; cards lay
+//! out their titles through [`title_job`], which colors the parts that
+//! matched. Purely visual: filtering stays in `filter_content`.
+
+use eframe::egui;
+use std::ops::Range;
+
+fn query_id() -> egui::Id {
+    egui::Id::new("search_highlight_query")
+}
+
+/// Makes `query` the text highlighted in card titles this frame.
+pub fn set_query(ctx: &egui::Context, query: &str) {
+    let query = query.trim().to_lowercase();
+    ctx.data_mut(|d| {
+        if query.is_empty() {
+            d.remove::<String>(query_id());
+        } else {
+            d.insert_temp(query_id(), query);
+        }
+    });
+}
+
+/// Byte ranges of `text` the search `query` matched, in order and without
+/// overlap. The same fuzzy matching as the search itself decides them, so
+/// "breakin bad" marks the letters it found in "Breaking Bad".
+pub fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    let Some(query) = super::fuzzy::Query::new(query) else {
+        return Vec::new();
+    };
+    let matched = query.matched_chars(text);
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (i, (start, ch)) in text.char_indices().enumerate() {
+        if matched.binary_search(&i).is_err() {
+            continue;
+        }
+        let end = start + ch.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    ranges
+}
+
+/// Single-line layout of `text` with search matches in `accent`.
+pub fn title_job(
+    ctx: &egui::Context,
+    text: &str,
+    font: egui::FontId,
+    color: egui::Color32,
+    accent: egui::Color32,
+) -> egui::text::LayoutJob {
+    let query = ctx.data(|d| d.get_temp::<String>(query_id())).unwrap_or_default();
+    let plain = egui::TextFormat::simple(font.clone(), color);
+    let matched = egui::TextFormat {
+        underline: egui::Stroke::new(1.0, accent),
+        ..egui::TextFormat::simple(font, accent)
+    };
+
+    let mut job = egui::text::LayoutJob {
+        break_on_newline: false,
+        ..Default::default()
+    };
+    let mut pos = 0;
+    for range in match_ranges(text, &query) {
+        job.append(&text[pos..range.start], 0.0, plain.clone());
+        job.append(&text[range.clone()], 0.0, matched.clone());
+        pos = range.end;
+    }
+    job.append(&text[pos..], 0.0, plain);
+    job
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_ranges() {
+        assert_eq!(match_ranges("BBC One HD", "one"), vec![4..7]);
+        assert_eq!(match_ranges("Ça Ira", "ca"), vec![0..3]);
+        assert_eq!(match_ranges("Pokémon", "pokemon"), vec![0..8]);
+        assert_eq!(match_ranges("Breaking Bad", "breakin bad"), vec![0..7, 9..12]);
+        assert!(match_ranges("CNN", "").is_empty());
+        assert!(match_ranges("CNN", "bbc").is_empty());
+    }
+}
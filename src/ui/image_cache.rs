@@ -2,11 +2,63 @@
 //!
 //! This module provides thread-safe image caching with background loading
 //! to avoid blocking the UI while fetching remote images.
+//!
+//! Downloaded images are also kept on disk (in the cache directory, named
+//! by a hash of the URL), so posters don't download again after a
+//! restart. The disk cache is trimmed to the configured size by dropping
+//! the least recently used files first.
 
 use eframe::egui;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How long a freshly loaded image takes to fade in.
+const FADE_IN: Duration = Duration::from_millis(150);
+
+/// One full sweep of the loading shimmer, in seconds.
+const SHIMMER_PERIOD: f64 = 1.2;
+
+/// Retries after the first failed download of an image.
+const MAX_RETRIES: u32 = 3;
+
+/// Wait before the first retry; doubles with every further failure.
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+
+/// Most simultaneous downloads the setting allows.
+pub const MAX_WORKERS: usize = 12;
+
+/// Simultaneous downloads, from the config.
+static WORKERS: AtomicUsize = AtomicUsize::new(4);
+
+/// Size limit of the disk cache in bytes, from the config; 0 turns it off.
+static DISK_LIMIT: AtomicU64 = AtomicU64::new(0);
+
+/// Bytes written to the disk cache since it was last trimmed.
+static DISK_WRITTEN: AtomicU64 = AtomicU64::new(0);
+
+/// Applies the download limit and disk cache size from the config, and
+/// trims the disk cache in the background if it grew past a smaller limit.
+pub fn configure(config: &crate::models::Config) {
+    WORKERS.store(config.image_downloads.clamp(1, MAX_WORKERS), Ordering::Relaxed);
+    DISK_LIMIT.store(config.image_cache_mb * 1024 * 1024, Ordering::Relaxed);
+    thread::spawn(trim_disk_cache);
+}
+
+/// Images waiting for a download worker.
+#[derive(Default)]
+struct Queue {
+    /// On-screen images, in the order they were requested
+    visible: VecDeque<String>,
+    /// Off-screen images, only taken while no visible one is waiting
+    prefetch: VecDeque<String>,
+    /// Worker threads currently running
+    workers: usize,
+}
 
 /// Thread-safe image cache manager.
 ///
@@ -14,11 +66,18 @@ use std::thread;
 /// textures for efficient reuse.
 #[derive(Clone)]
 pub struct ImageCache {
-    /// Cached textures by URL
-    cache: Arc<Mutex<HashMap<String, egui::TextureHandle>>>,
+    /// Cached textures by URL, with the time each finished loading
+    cache: Arc<Mutex<HashMap<String, (egui::TextureHandle, Instant)>>>,
     
-    /// URLs currently being loaded
+    /// URLs waiting for or being downloaded
     loading: Arc<Mutex<HashSet<String>>>,
+    
+    /// Failed URLs with their failure count and the time of the last failure
+    failures: Arc<Mutex<HashMap<String, (u32, Instant)>>>,
+    
+    /// Downloads waiting for a worker, so a page of posters doesn't fire
+    /// dozens of requests at the provider at once
+    queue: Arc<Mutex<Queue>>,
 }
 
 impl ImageCache {
@@ -27,6 +86,8 @@ impl ImageCache {
         Self {
             cache: Arc::new(Mutex::new(HashMap::new())),
             loading: Arc::new(Mutex::new(HashSet::new())),
+            failures: Arc::new(Mutex::new(HashMap::new())),
+            queue: Arc::new(Mutex::new(Queue::default())),
         }
     }
     
@@ -39,26 +100,55 @@ impl ImageCache {
     /// Gets a cached texture by URL if available
     pub fn get(&self, url: &str) -> Option<egui::TextureHandle> {
         let cache = self.cache.lock().unwrap();
-        cache.get(url).cloned()
+        cache.get(url).map(|(texture, _)| texture.clone())
+    }
+    
+    /// Whether `url` is still being downloaded or waiting for a retry.
+    /// False once it has loaded or failed for good.
+    pub fn is_pending(&self, url: &str) -> bool {
+        if self.loading.lock().unwrap().contains(url) {
+            return true;
+        }
+        match self.failures.lock().unwrap().get(url) {
+            Some(&(attempts, _)) => attempts <= MAX_RETRIES,
+            None => false,
+        }
+    }
+    
+    /// Forgets every failed download so they are all attempted again,
+    /// e.g. after reconnecting.
+    pub fn clear_failures(&self) {
+        self.failures.lock().unwrap().clear();
     }
     
-    /// Starts loading an image from URL in the background
+    /// Draws the image for `url` stretched over `rect`, fading it in over
+    /// the first moments after it loaded. Returns false and draws nothing
+    /// if the image isn't available.
+    pub fn paint(&self, ui: &egui::Ui, url: &str, rect: egui::Rect) -> bool {
+        let Some((texture, loaded_at)) = self.cache.lock().unwrap().get(url).cloned() else {
+            return false;
+        };
+        
+        let opacity = (loaded_at.elapsed().as_secs_f32() / FADE_IN.as_secs_f32()).min(1.0);
+        if opacity < 1.0 {
+            ui.ctx().request_repaint();
+        }
+        
+        let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
+        ui.painter().image(texture.id(), rect, uv, egui::Color32::WHITE.gamma_multiply(opacity));
+        true
+    }
+    
+    /// Queues an on-screen image for download, ahead of any prefetches
     ///
-    /// Does nothing if the image is already cached or loading.
+    /// Does nothing if the image is already cached or loading. A URL that
+    /// failed is retried with a growing delay, up to [`MAX_RETRIES`] times.
     pub fn load(&self, ctx: &egui::Context, url: String) {
         // Skip empty URLs
-        if url.is_empty() {
+        if url.is_empty() || !self.wants(&url) {
             return;
         }
         
-        // Check if already in cache
-        {
-            let cache = self.cache.lock().unwrap();
-            if cache.contains_key(&url) {
-                return;
-            }
-        }
-        
         // Check if already loading
         {
             let mut loading = self.loading.lock().unwrap();
@@ -68,42 +158,124 @@ impl ImageCache {
             loading.insert(url.clone());
         }
         
-        let cache = self.cache.clone();
-        let loading = self.loading.clone();
-        let ctx = ctx.clone();
+        self.queue.lock().unwrap().visible.push_back(url);
+        self.spawn_workers(ctx);
+    }
+    
+    /// Whether `url` should be downloaded: not cached, and not a recent
+    /// failure or one that has run out of retries.
+    fn wants(&self, url: &str) -> bool {
+        if let Some(&(attempts, failed_at)) = self.failures.lock().unwrap().get(url) {
+            if attempts > MAX_RETRIES || failed_at.elapsed() < retry_delay(attempts) {
+                return false;
+            }
+        }
+        !self.cache.lock().unwrap().contains_key(url)
+    }
+    
+    /// Starts workers for the waiting downloads, up to the configured limit.
+    /// Each worker keeps taking downloads until the queue is empty.
+    fn spawn_workers(&self, ctx: &egui::Context) {
+        let mut queue = self.queue.lock().unwrap();
+        let waiting = queue.visible.len() + queue.prefetch.len();
+        let idle = WORKERS.load(Ordering::Relaxed).saturating_sub(queue.workers);
+        for _ in 0..idle.min(waiting) {
+            queue.workers += 1;
+            let cache = self.clone();
+            let ctx = ctx.clone();
+            thread::spawn(move || {
+                while let Some(url) = cache.next_job() {
+                    cache.download(&ctx, &url);
+                }
+            });
+        }
+    }
+    
+    /// The next URL for a worker: visible images first, in request order,
+    /// then prefetches. `None` retires the worker.
+    fn next_job(&self) -> Option<String> {
+        let mut queue = self.queue.lock().unwrap();
+        if let Some(url) = queue.visible.pop_front() {
+            return Some(url);
+        }
+        while let Some(url) = queue.prefetch.pop_front() {
+            // May have been loaded as a visible image meanwhile
+            if self.wants(&url) && self.loading.lock().unwrap().insert(url.clone()) {
+                return Some(url);
+            }
+        }
+        queue.workers -= 1;
+        None
+    }
+    
+    /// Loads one image from the disk cache or else downloads it,
+    /// recording a failure if neither works.
+    fn download(&self, ctx: &egui::Context, url: &str) {
+        let mut loaded = disk_read(url).is_some_and(|bytes| self.decode(ctx, url, &bytes));
+        
+        if !loaded {
+            if let Some(bytes) = crate::api::http::client().get(url).send().ok().and_then(|r| r.bytes().ok()) {
+                loaded = self.decode(ctx, url, &bytes);
+                if loaded {
+                    disk_write(url, &bytes);
+                }
+            }
+        }
         
-        thread::spawn(move || {
-            // Download image
-            if let Ok(response) = reqwest::blocking::get(&url) {
-                if let Ok(bytes) = response.bytes() {
-                    if let Ok(image) = image::load_from_memory(&bytes) {
-                        let size = [image.width() as _, image.height() as _];
-                        let image_buffer = image.to_rgba8();
-                        let pixels = image_buffer.as_flat_samples();
-                        let color_image = egui::ColorImage::from_rgba_unmultiplied(
-                            size,
-                            pixels.as_slice(),
-                        );
-                        
-                        // Store in cache
-                        let mut cache_guard = cache.lock().unwrap();
-                        let texture = ctx.load_texture(
-                            &url,
-                            color_image,
-                            egui::TextureOptions::LINEAR,
-                        );
-                        cache_guard.insert(url.clone(), texture);
-                        
-                        // Request repaint after texture is loaded
-                        ctx.request_repaint();
-                    }
+        {
+            let mut failures = self.failures.lock().unwrap();
+            if loaded {
+                failures.remove(url);
+            } else {
+                let entry = failures.entry(url.to_string()).or_insert((0, Instant::now()));
+                entry.0 += 1;
+                entry.1 = Instant::now();
+                if entry.0 > MAX_RETRIES {
+                    log::debug!("[Images] Giving up on {}", url);
                 }
             }
-            
-            // Remove from loading set
-            let mut loading_guard = loading.lock().unwrap();
-            loading_guard.remove(&url);
-        });
+        }
+        
+        self.loading.lock().unwrap().remove(url);
+    }
+    
+    /// Decodes image bytes into a texture for `url`. False if they aren't
+    /// a readable image.
+    fn decode(&self, ctx: &egui::Context, url: &str, bytes: &[u8]) -> bool {
+        let Ok(image) = image::load_from_memory(bytes) else {
+            return false;
+        };
+        let size = [image.width() as _, image.height() as _];
+        let image_buffer = image.to_rgba8();
+        let pixels = image_buffer.as_flat_samples();
+        let color_image = egui::ColorImage::from_rgba_unmultiplied(
+            size,
+            pixels.as_slice(),
+        );
+        
+        // Store in cache
+        let texture = ctx.load_texture(url, color_image, egui::TextureOptions::LINEAR);
+        self.cache.lock().unwrap().insert(url.to_string(), (texture, Instant::now()));
+        
+        // Request repaint after texture is loaded
+        ctx.request_repaint();
+        true
+    }
+    
+    /// Queues images that aren't on screen yet (e.g. the next page) behind
+    /// the visible ones. Replaces whatever was queued before, since only
+    /// the latest page matters.
+    pub fn prefetch(&self, ctx: &egui::Context, urls: impl IntoIterator<Item = String>) {
+        {
+            let mut queue = self.queue.lock().unwrap();
+            let cache = self.cache.lock().unwrap();
+            let loading = self.loading.lock().unwrap();
+            queue.prefetch.clear();
+            queue.prefetch.extend(urls.into_iter().filter(|url| {
+                !url.is_empty() && !cache.contains_key(url) && !loading.contains(url)
+            }));
+        }
+        self.spawn_workers(ctx);
     }
     
     /// Clears all cached images
@@ -114,8 +286,177 @@ impl ImageCache {
     }
 }
 
+/// Directory of the disk cache.
+fn disk_dir() -> PathBuf {
+    crate::models::config::cache_dir().join("images")
+}
+
+/// Disk cache file of `url`, named by its FNV-1a hash (stable across
+/// builds, unlike the std hasher).
+fn disk_path(url: &str) -> PathBuf {
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    disk_dir().join(format!("{:016x}", hash))
+}
+
+/// The cached bytes of `url`, marking the file as recently used.
+fn disk_read(url: &str) -> Option<Vec<u8>> {
+    if DISK_LIMIT.load(Ordering::Relaxed) == 0 {
+        return None;
+    }
+    let path = disk_path(url);
+    let bytes = fs::read(&path).ok()?;
+    // The modification time is what eviction goes by
+    let _ = fs::File::options().write(true).open(&path).and_then(|f| f.set_modified(SystemTime::now()));
+    Some(bytes)
+}
+
+/// Stores the bytes of `url`, trimming the cache every so often.
+fn disk_write(url: &str, bytes: &[u8]) {
+    let limit = DISK_LIMIT.load(Ordering::Relaxed);
+    if limit == 0 {
+        return;
+    }
+    let path = disk_path(url);
+    let tmp = path.with_extension("tmp");
+    let written = fs::create_dir_all(disk_dir())
+        .and_then(|_| fs::write(&tmp, bytes))
+        .and_then(|_| fs::rename(&tmp, &path));
+    if let Err(e) = written {
+        log::debug!("[Images] Couldn't cache {} on disk: {}", url, e);
+        return;
+    }
+    // Trim after every 5% of the limit written
+    if DISK_WRITTEN.fetch_add(bytes.len() as u64, Ordering::Relaxed) + bytes.len() as u64 > limit / 20 {
+        DISK_WRITTEN.store(0, Ordering::Relaxed);
+        trim_disk_cache();
+    }
+}
+
+/// Shrinks the disk cache to the configured size.
+fn trim_disk_cache() {
+    let limit = DISK_LIMIT.load(Ordering::Relaxed);
+    match trim_dir(&disk_dir(), limit) {
+        Ok(0) => {}
+        Ok(freed) => log::info!("[Images] Trimmed {} KB from the disk cache", freed / 1024),
+        Err(e) => log::debug!("[Images] Couldn't trim the disk cache: {}", e),
+    }
+}
+
+/// Deletes the least recently used files in `dir` until the rest fit in
+/// `limit` bytes. Returns the bytes freed.
+fn trim_dir(dir: &Path, limit: u64) -> std::io::Result<u64> {
+    let mut files: Vec<(SystemTime, u64, PathBuf)> = fs::read_dir(dir)?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let meta = entry.metadata().ok().filter(|m| m.is_file())?;
+            Some((meta.modified().unwrap_or(SystemTime::UNIX_EPOCH), meta.len(), entry.path()))
+        })
+        .collect();
+    let total: u64 = files.iter().map(|(_, len, _)| len).sum();
+    files.sort_by_key(|(modified, _, _)| *modified);
+    
+    let mut freed = 0;
+    for (_, len, path) in files {
+        if total - freed <= limit {
+            break;
+        }
+        if fs::remove_file(&path).is_ok() {
+            freed += len;
+        }
+    }
+    Ok(freed)
+}
+
+/// Deletes every image in the disk cache. Returns the bytes freed.
+pub fn clear_disk_cache() -> Result<u64, String> {
+    match trim_dir(&disk_dir(), 0) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        result => result.map_err(|e| format!("Couldn't clear the image cache: {}", e)),
+    }
+}
+
+/// Delay before retrying a URL that has failed `attempts` times.
+fn retry_delay(attempts: u32) -> Duration {
+    RETRY_BASE_DELAY * 2u32.pow(attempts.saturating_sub(1).min(8))
+}
+
+/// Up to two initials for a placeholder, e.g. "BO" for "|UK| BBC One".
+/// Words that don't start with a letter or digit (country tags and the
+/// like) are skipped.
+pub fn placeholder_initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter(|word| word.starts_with(char::is_alphanumeric))
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Loading placeholder: `base` with a soft highlight sweeping across it.
+/// Only drawn for images that are downloading, so the extra repaints stop
+/// once everything on screen has loaded.
+pub fn paint_shimmer(ui: &egui::Ui, rect: egui::Rect, rounding: f32, base: egui::Color32) {
+    ui.painter().rect_filled(rect, rounding, base);
+    
+    let phase = (ui.input(|i| i.time) % SHIMMER_PERIOD / SHIMMER_PERIOD) as f32;
+    let band_width = rect.width() * 0.4;
+    let band_x = rect.min.x - band_width + phase * (rect.width() + band_width);
+    let band = egui::Rect::from_min_size(
+        egui::pos2(band_x, rect.min.y),
+        egui::vec2(band_width, rect.height()),
+    );
+    ui.painter()
+        .with_clip_rect(rect.shrink(rounding * 0.3))
+        .rect_filled(band, 0.0, egui::Color32::from_white_alpha(10));
+    
+    ui.ctx().request_repaint_after(Duration::from_millis(33));
+}
+
 impl Default for ImageCache {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_images_jump_prefetch_queue() {
+        let images = ImageCache::new();
+        {
+            let mut queue = images.queue.lock().unwrap();
+            queue.workers = 1;
+            queue.prefetch.extend(["next-1".to_string(), "next-2".to_string()]);
+            queue.visible.push_back("shown".to_string());
+        }
+        images.loading.lock().unwrap().insert("next-2".to_string());
+
+        assert_eq!(images.next_job().as_deref(), Some("shown"));
+        assert_eq!(images.next_job().as_deref(), Some("next-1"));
+        // Already requested as a visible image
+        assert_eq!(images.next_job(), None);
+        assert_eq!(images.queue.lock().unwrap().workers, 0);
+    }
+
+    #[test]
+    fn test_trim_drops_least_recently_used() {
+        let dir = std::env::temp_dir().join(format!("iptv-image-trim-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let now = SystemTime::now();
+        for (name, age) in [("old", 30), ("newest", 0), ("middle", 10)] {
+            let path = dir.join(name);
+            fs::write(&path, [0u8; 100]).unwrap();
+            fs::File::options().write(true).open(&path).unwrap()
+                .set_modified(now - Duration::from_secs(age)).unwrap();
+        }
+
+        assert_eq!(trim_dir(&dir, 250).unwrap(), 100);
+        assert!(!dir.join("old").exists());
+        assert!(dir.join("middle").exists() && dir.join("newest").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
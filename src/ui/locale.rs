@@ -0,0 +1,166 @@
+//! Locale-aware formatting of numbers and dates shown in the UI.
+//!
+//! The locale comes from the system, or the override in App Settings, and
+//! only picks the conventions: date order and separators, decimal and
+//! thousands separators. The language of the texts is set in `i18n`.
+
+use std::sync::RwLock;
+
+/// Locales offered as overrides in App Settings, with a display name.
+pub const PRESETS: &[(&str, &str)] = &[
+    ("en-US", "English (US)"),
+    ("en-GB", "English (UK)"),
+    ("de-DE", "Deutsch"),
+    ("fr-FR", "Français"),
+    ("es-ES", "Español"),
+    ("it-IT", "Italiano"),
+    ("nl-NL", "Nederlands"),
+    ("pt-BR", "Português (Brasil)"),
+    ("sv-SE", "Svenska"),
+    ("pl-PL", "Polski"),
+    ("ja-JP", "日本語"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+/// How a locale writes dates and numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Conventions {
+    date_order: DateOrder,
+    date_separator: char,
+    decimal: char,
+    thousands: char,
+}
+
+impl Conventions {
+    /// US English, used until [`configure`] runs or when detection fails.
+    const DEFAULT: Self = Self {
+        date_order: DateOrder::MonthDayYear,
+        date_separator: '/',
+        decimal: '.',
+        thousands: ',',
+    };
+
+    /// Conventions of a BCP 47 or POSIX locale tag ("de-DE", "fr_FR.UTF-8").
+    fn for_tag(tag: &str) -> Self {
+        let tag = tag.split(['.', '@']).next().unwrap_or_default().replace('_', "-");
+        let mut parts = tag.split('-');
+        let language = parts.next().unwrap_or_default().to_lowercase();
+        let region = parts.find(|p| p.len() == 2).unwrap_or_default().to_uppercase();
+
+        let date_order = match (language.as_str(), region.as_str()) {
+            ("en", "US" | "PH" | "") => DateOrder::MonthDayYear,
+            ("ja" | "zh" | "ko" | "hu" | "lt" | "sv", _) => DateOrder::YearMonthDay,
+            ("c" | "posix", _) => DateOrder::MonthDayYear,
+            _ => DateOrder::DayMonthYear,
+        };
+        let date_separator = match language.as_str() {
+            "de" | "ru" | "pl" | "cs" | "sk" | "fi" | "nb" | "no" | "da" | "tr" | "uk" | "ro" | "hr" | "sl"
+            | "sr" | "bg" | "ko" | "hu" => '.',
+            "nl" | "sv" | "lt" => '-',
+            _ => '/',
+        };
+        let (decimal, thousands) = match language.as_str() {
+            "de" | "es" | "it" | "pt" | "nl" | "da" | "tr" | "ro" | "hr" | "sl" | "sr" | "el" | "id" | "vi" => {
+                (',', '.')
+            }
+            "fr" | "sv" | "fi" | "nb" | "no" | "pl" | "cs" | "sk" | "ru" | "uk" | "hu" | "bg" | "lt" => {
+                (',', '\u{a0}')
+            }
+            _ => ('.', ','),
+        };
+        Self { date_order, date_separator, decimal, thousands }
+    }
+}
+
+static CONVENTIONS: RwLock<Conventions> = RwLock::new(Conventions::DEFAULT);
+
+fn conventions() -> Conventions {
+    CONVENTIONS.read().map(|c| *c).unwrap_or(Conventions::DEFAULT)
+}
+
+/// The system locale tag, if it can be determined.
+pub fn system_locale() -> Option<String> {
+    sys_locale::get_locale()
+}
+
+/// Applies the locale override from the config, or the system locale.
+pub fn configure(config: &crate::models::Config) {
+    let tag = config.locale.clone().or_else(system_locale).unwrap_or_default();
+    log::info!("[Locale] Formatting for {}", if tag.is_empty() { "en-US" } else { &tag });
+    if let Ok(mut conventions) = CONVENTIONS.write() {
+        *conventions = if tag.is_empty() { Conventions::DEFAULT } else { Conventions::for_tag(&tag) };
+    }
+}
+
+/// A date the way the locale writes it, e.g. 31/12/2025 or 2025-12-31.
+pub fn format_date(date: chrono::NaiveDate) -> String {
+    format_date_with(&conventions(), date)
+}
+
+fn format_date_with(c: &Conventions, date: chrono::NaiveDate) -> String {
+    use chrono::Datelike;
+    let sep = c.date_separator;
+    let (y, m, d) = (date.year(), date.month(), date.day());
+    match c.date_order {
+        DateOrder::DayMonthYear => format!("{:02}{sep}{:02}{sep}{}", d, m, y),
+        DateOrder::MonthDayYear => format!("{:02}{sep}{:02}{sep}{}", m, d, y),
+        DateOrder::YearMonthDay => format!("{}{sep}{:02}{sep}{:02}", y, m, d),
+    }
+}
+
+/// `value` with `decimals` places and the locale's separators.
+pub fn format_number(value: f64, decimals: usize) -> String {
+    format_number_with(&conventions(), value, decimals)
+}
+
+fn format_number_with(c: &Conventions, value: f64, decimals: usize) -> String {
+    let plain = format!("{:.*}", decimals, value.abs());
+    let (int, frac) = plain.split_once('.').unwrap_or((&plain, ""));
+
+    let mut out = String::new();
+    if value < 0.0 && plain.chars().any(|ch| ch.is_ascii_digit() && ch != '0') {
+        out.push('-');
+    }
+    for (i, digit) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            out.push(c.thousands);
+        }
+        out.push(digit);
+    }
+    if !frac.is_empty() {
+        out.push(c.decimal);
+        out.push_str(frac);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_formatting() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        let us = Conventions::for_tag("en_US.UTF-8");
+        let de = Conventions::for_tag("de-DE");
+        let gb = Conventions::for_tag("en-GB");
+        let sv = Conventions::for_tag("sv_SE");
+
+        assert_eq!(format_date_with(&us, date), "03/09/2025");
+        assert_eq!(format_date_with(&gb, date), "09/03/2025");
+        assert_eq!(format_date_with(&de, date), "09.03.2025");
+        assert_eq!(format_date_with(&sv, date), "2025-03-09");
+
+        assert_eq!(format_number_with(&us, 1234567.891, 2), "1,234,567.89");
+        assert_eq!(format_number_with(&de, 1234.5, 1), "1.234,5");
+        assert_eq!(format_number_with(&sv, -8.25, 1), "-8,2");
+        assert_eq!(format_number_with(&de, 42.0, 0), "42");
+        assert_eq!(format_number_with(&us, -0.01, 0), "0");
+    }
+}
@@ -3,7 +3,12 @@
 //! This module defines the message types used for communication between
 //! the UI thread and background worker threads.
 
-use crate::models::{Category, Channel, Series};
+use std::sync::mpsc::{SendError, Sender};
+
+use eframe::egui;
+
+use crate::models::{Category, Channel, DefaultContent, Movie, Series};
+use crate::ui::i18n::t;
 
 /// Messages sent from background threads to the main UI thread.
 ///
@@ -17,11 +22,79 @@ pub enum AppMessage {
     /// An error occurred during an operation
     Error(String),
     
+    /// The provider turned the sign-in down (wrong or expired credentials)
+    LoginRejected(String),
+    
+    /// The connect thread moved on to a new stage
+    ConnectProgress(ConnectStage),
+    
+    /// The Xtream API failed (reason attached); the channels that follow
+    /// come from the M3U playlist instead
+    PlaylistFallback(String),
+    
+    /// The server is unreachable (reason attached); the channels that
+    /// follow come from the cache, however old
+    WentOffline(String),
+    
+    /// The server answered again while browsing offline
+    BackOnline,
+    
+    /// Update check finished: a newer release, if any, and whether the
+    /// user asked for the check
+    UpdateChecked(Result<Option<crate::api::updates::Release>, String>, bool),
+    
+    /// A connection self-test stage changed status
+    DiagnosticUpdate(DiagnosticStage, DiagnosticStatus),
+    
     /// Series data loaded successfully
     SeriesLoaded(Vec<Category>, Vec<Series>),
     
     /// Movies data loaded successfully
-    MoviesLoaded(Vec<Category>, Vec<serde_json::Value>),
+    MoviesLoaded(Vec<Category>, Vec<Movie>),
+    
+    /// One category of movies loaded (per-category catalog mode)
+    MovieCategoryLoaded(String, Vec<Movie>),
+    
+    /// One category of series loaded (per-category catalog mode)
+    SeriesCategoryLoaded(String, Vec<Series>),
+    
+    /// Fetching a library section (series or movies) failed
+    LibraryLoadFailed(ContentType, String),
+    
+    /// A movie or series category couldn't be fetched: section, category ID, error
+    CategoryLoadFailed(ContentType, String, String),
+    
+    /// A channel stream was probed: stream ID and whether it answered,
+    /// `None` when the provider refused the probe
+    ChannelProbed(String, Option<bool>),
+    
+    /// All requested channel probes have finished
+    ChannelProbeFinished,
+    
+    /// The preflight of a stream about to play finished: URL and outcome
+    StreamChecked(String, Result<(), String>),
+    
+    /// An external MPV reported its playback position (seconds); `socket`
+    /// tells which player it came from
+    PlaybackProgress {
+        socket: String,
+        position: f64,
+        duration: Option<f64>,
+    },
+    
+    /// Pause and volume read from an external MPV, which can also be
+    /// changed in its own window
+    PlayerRemoteState {
+        socket: String,
+        state: crate::ui::components::mini_player::RemoteState,
+    },
+    
+    /// A favorites and history sync finished: the merged state, or why it failed
+    SyncFinished(Result<crate::sync::Snapshot, String>),
+    
+    /// A library section's data is about to be delivered: when it was
+    /// fetched (unix seconds) and where it came from
+    LibraryUpdated(ContentType, u64, LibrarySource),
     
     /// Scraper started
     ScraperStarted,
@@ -31,17 +104,136 @@ pub enum AppMessage {
     
     /// Scraper failed with error
     ScraperFailed(String),
+    
+    /// Result of checking Python, the venv and the requirements
+    ScraperSetupChecked(crate::api::ScraperSetup),
+    
+    /// Scraper venv/requirements install finished
+    ScraperSetupFinished(Result<(), String>),
+    
+    /// A message from a library fetch started in the given library
+    /// generation; handled only while it is still the current one, so a
+    /// fetch for an account switched away from doesn't land in the next
+    Library(u64, Box<AppMessage>),
+}
+
+/// Sender for [`AppMessage`]s that wakes the UI, so results are handled
+/// straight away instead of on the next scheduled repaint.
+#[derive(Clone)]
+pub struct UiSender {
+    tx: Sender<AppMessage>,
+    ctx: egui::Context,
+    /// Library generation every message is wrapped in, if any
+    generation: Option<u64>,
+}
+
+impl UiSender {
+    pub fn new(tx: Sender<AppMessage>, ctx: egui::Context) -> Self {
+        Self { tx, ctx, generation: None }
+    }
+
+    /// A sender that wraps every message in [`AppMessage::Library`] with
+    /// `generation`.
+    pub fn for_library(&self, generation: u64) -> Self {
+        Self { generation: Some(generation), ..self.clone() }
+    }
+
+    pub fn send(&self, message: AppMessage) -> Result<(), SendError<AppMessage>> {
+        let message = match self.generation {
+            Some(generation) => AppMessage::Library(generation, Box::new(message)),
+            None => message,
+        };
+        let result = self.tx.send(message);
+        self.ctx.request_repaint();
+        result
+    }
+}
+
+/// Stages of the connect sequence, shown on the login screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectStage {
+    Authenticating,
+    LoadingCategories,
+    LoadingChannels,
+    LoadingPlaylist,
+    /// Signing in with an M3U playlist instead of Xtream
+    ReadingPlaylist,
+}
+
+impl ConnectStage {
+    /// Status text for the login screen
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectStage::Authenticating => "Authenticating…",
+            ConnectStage::LoadingCategories => "Loading categories…",
+            ConnectStage::LoadingChannels => "Loading channels…",
+            ConnectStage::LoadingPlaylist => "API unavailable, loading M3U playlist…",
+            ConnectStage::ReadingPlaylist => "Loading playlist…",
+        }
+    }
+}
+
+/// Stages of the login screen's "Test connection" self-test, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticStage {
+    Dns,
+    Tcp,
+    Http,
+    Auth,
+}
+
+impl DiagnosticStage {
+    pub const ALL: [DiagnosticStage; 4] = [
+        DiagnosticStage::Dns,
+        DiagnosticStage::Tcp,
+        DiagnosticStage::Http,
+        DiagnosticStage::Auth,
+    ];
+    
+    /// Checklist label
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiagnosticStage::Dns => "Resolve server address",
+            DiagnosticStage::Tcp => "Connect to server port",
+            DiagnosticStage::Http => "Reach player_api.php",
+            DiagnosticStage::Auth => "Check credentials",
+        }
+    }
+}
+
+/// Result of one self-test stage.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticStatus {
+    Pending,
+    Running,
+    Passed(String),
+    Failed(String),
+    /// Not run because an earlier stage failed
+    Skipped,
+}
+
+/// Where a library section's data came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibrarySource {
+    /// Fresh cache entry, no fetch needed
+    Cache,
+    /// Expired cache entry, shown while fresh data is fetched
+    StaleCache,
+    /// Fetched from the server
+    Server,
 }
 
 /// Content type currently being displayed in the main view.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum ContentType {
     #[default]
     LiveTV,
     Series,
     Movies,
+    RecentlyAdded,
     ContinueWatching,
     Favorites,
+    OnNow,
     Discover,
     FootballFixtures,
 }
@@ -50,26 +242,46 @@ impl ContentType {
     /// Returns the display title for this content type
     pub fn title(&self) -> &'static str {
         match self {
-            ContentType::LiveTV => "📺 Live Channels",
-            ContentType::Series => "📚 Series",
-            ContentType::Movies => "🎬 Movies",
-            ContentType::ContinueWatching => "▶️ Continue Watching",
-            ContentType::Favorites => "⭐ Favorite Channels",
-            ContentType::Discover => "🔥 Discover",
-            ContentType::FootballFixtures => "⚽ Football Fixtures",
+            ContentType::LiveTV => t("title.live"),
+            ContentType::Series => t("title.series"),
+            ContentType::Movies => t("title.movies"),
+            ContentType::RecentlyAdded => t("title.recent"),
+            ContentType::ContinueWatching => t("title.continue"),
+            ContentType::Favorites => t("title.favorites"),
+            ContentType::OnNow => t("title.on_now"),
+            ContentType::Discover => t("title.discover"),
+            ContentType::FootballFixtures => t("title.football"),
         }
     }
     
     /// Returns the empty state message
     pub fn empty_message(&self) -> &'static str {
         match self {
-            ContentType::LiveTV => "No channels found",
-            ContentType::Series => "No series found",
-            ContentType::Movies => "No movies found",
-            ContentType::ContinueWatching => "No recent viewing history",
-            ContentType::Favorites => "No favorites yet",
-            ContentType::Discover => "Configure TMDB API key in settings",
-            ContentType::FootballFixtures => "No upcoming fixtures found",
+            ContentType::LiveTV => t("empty.live"),
+            ContentType::Series => t("empty.series"),
+            ContentType::Movies => t("empty.movies"),
+            ContentType::RecentlyAdded => t("empty.recent"),
+            ContentType::ContinueWatching => t("empty.continue"),
+            ContentType::Favorites => t("empty.favorites"),
+            ContentType::OnNow => t("empty.on_now"),
+            ContentType::Discover => t("empty.discover"),
+            ContentType::FootballFixtures => t("empty.football"),
+        }
+    }
+}
+
+impl From<DefaultContent> for ContentType {
+    fn from(content: DefaultContent) -> Self {
+        match content {
+            DefaultContent::LiveTV => ContentType::LiveTV,
+            DefaultContent::Series => ContentType::Series,
+            DefaultContent::Movies => ContentType::Movies,
+            DefaultContent::RecentlyAdded => ContentType::RecentlyAdded,
+            DefaultContent::ContinueWatching => ContentType::ContinueWatching,
+            DefaultContent::Favorites => ContentType::Favorites,
+            DefaultContent::OnNow => ContentType::OnNow,
+            DefaultContent::Discover => ContentType::Discover,
+            DefaultContent::FootballFixtures => ContentType::FootballFixtures,
         }
     }
 }
@@ -7,6 +7,14 @@
 //! - `messages` - Application message types
 //! - `image_cache` - Async image loading and caching
 //! - `epg_cache` - EPG data caching and background loading
+//! - `episode_index` - Episode lists of watched series for Next Up
+//! - `gamepad` - Controller input for Steam Deck Game Mode
+//! - `sort` - Sort orders for the content grids
+//! - `highlight` - Search match highlighting in card titles
+//! - `fuzzy` - Fuzzy matching of search queries
+//! - `locale` - Locale-aware number and date formatting
+//! - `i18n` - Translations of the UI texts
+//! - `toast` - Transient notifications
 //! - `components` - Reusable UI components
 
 pub mod app;
@@ -14,6 +22,14 @@ pub mod theme;
 pub mod messages;
 pub mod image_cache;
 pub mod epg_cache;
+pub mod episode_index;
+pub mod gamepad;
+pub mod sort;
+pub mod highlight;
+pub mod fuzzy;
+pub mod locale;
+pub mod i18n;
+pub mod toast;
 pub mod components;
 
 pub use app::IPTVPlayerApp;
@@ -0,0 +1,101 @@
+//! Sort orders for the channel, series and movie grids.
+//!
+//! Providers are inconsistent about which fields they fill in, so sorting
+//! by date or rating keeps items without that field at the end (in name
+//! order) instead of failing or scattering them.
+
+use crate::models::{Channel, Movie, Series};
+use super::components::alpha_index;
+
+/// How content grids are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    NameAsc,
+    NameDesc,
+    RecentlyAdded,
+    Rating,
+}
+
+impl SortOrder {
+    pub const ALL: [SortOrder; 4] = [
+        SortOrder::NameAsc,
+        SortOrder::NameDesc,
+        SortOrder::RecentlyAdded,
+        SortOrder::Rating,
+    ];
+
+    /// Dropdown label
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortOrder::NameAsc => "Name A–Z",
+            SortOrder::NameDesc => "Name Z–A",
+            SortOrder::RecentlyAdded => "Recently added",
+            SortOrder::Rating => "Rating",
+        }
+    }
+
+    /// Whether the list ends up in alphabetical order (A–Z index usable).
+    pub fn is_alphabetical(&self) -> bool {
+        matches!(self, SortOrder::NameAsc | SortOrder::NameDesc)
+    }
+}
+
+/// Sort channels in place.
+pub fn sort_channels(channels: &mut [Channel], order: SortOrder) {
+    // Channels carry no rating, so that order falls back to name
+    apply(channels, order, |ch| &ch.name, |ch| parse_number(ch.added.as_deref()), |_| None);
+}
+
+/// Sort series in place. "Recently added" uses the last modification time.
+pub fn sort_series(series: &mut [Series], order: SortOrder) {
+    apply(
+        series,
+        order,
+        |s| &s.name,
+        |s| parse_number(s.last_modified.as_deref()),
+        |s| parse_number(s.rating.as_deref())
+            .or_else(|| s.rating_5based.map(|r| r as f64 * 2.0))
+            .filter(|r| *r > 0.0),
+    );
+}
+
+/// Sort movies in place.
+pub fn sort_movies(movies: &mut [Movie], order: SortOrder) {
+    apply(
+        movies,
+        order,
+        |m| &m.name,
+        |m| m.added.map(|a| a as f64),
+        |m| m.rating_out_of_10().map(f64::from),
+    );
+}
+
+fn apply<T>(
+    items: &mut [T],
+    order: SortOrder,
+    name: impl Fn(&T) -> &str,
+    added: impl Fn(&T) -> Option<f64>,
+    rating: impl Fn(&T) -> Option<f64>,
+) {
+    alpha_index::sort_by_name(items, name);
+    match order {
+        SortOrder::NameAsc => {}
+        SortOrder::NameDesc => items.reverse(),
+        SortOrder::RecentlyAdded => sort_descending(items, added),
+        SortOrder::Rating => sort_descending(items, rating),
+    }
+}
+
+/// Stable sort, highest key first, items without a key last.
+fn sort_descending<T>(items: &mut [T], key: impl Fn(&T) -> Option<f64>) {
+    items.sort_by_cached_key(|item| match key(item) {
+        // Two decimals are plenty for ratings and whole-second timestamps
+        Some(value) => (false, -((value * 100.0) as i64)),
+        None => (true, 0),
+    });
+}
+
+fn parse_number(text: Option<&str>) -> Option<f64> {
+    text.and_then(|t| t.trim().parse::<f64>().ok())
+}
@@ -386,6 +386,8 @@ impl Theme {
 /// UI dimensions and sizing constants
 #[allow(dead_code)]
 pub mod dimensions {
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
     /// Breakpoints for responsive design
     pub const MOBILE_BREAKPOINT: f32 = 600.0;
     pub const TABLET_BREAKPOINT: f32 = 900.0;
@@ -450,9 +452,28 @@ pub mod dimensions {
         is_deck_landscape || is_deck_portrait || is_exact_deck
     }
 
-    /// Check if in touch-friendly mode
+    /// Touch mode forced in settings, and the touch target size (f32 bits)
+    static FORCE_TOUCH: AtomicBool = AtomicBool::new(false);
+    static TOUCH_TARGET: AtomicU32 = AtomicU32::new(0);
+
+    /// Applies the touch mode override and target size from the config.
+    pub fn configure(config: &crate::models::Config) {
+        FORCE_TOUCH.store(config.touch_mode, Ordering::Relaxed);
+        TOUCH_TARGET.store(config.touch_target_size.max(0.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Minimum control height set in settings, if any.
+    pub fn custom_touch_target() -> Option<f32> {
+        let size = f32::from_bits(TOUCH_TARGET.load(Ordering::Relaxed));
+        (size >= MIN_TOUCH_TARGET).then_some(size)
+    }
+
+    /// Smallest custom touch target; below it the built-in sizes apply.
+    pub const MIN_TOUCH_TARGET: f32 = 32.0;
+
+    /// Check if in touch-friendly mode (detected, or forced in settings)
     pub fn is_touch_mode(screen_width: f32, screen_height: f32) -> bool {
-        is_steam_deck(screen_width, screen_height) || is_tablet(screen_width)
+        FORCE_TOUCH.load(Ordering::Relaxed) || is_steam_deck(screen_width, screen_height) || is_tablet(screen_width)
     }
 
     /// Check if in mobile mode
@@ -476,6 +497,51 @@ pub mod dimensions {
         }
     }
 
+    /// Narrowest cards a fixed column count may shrink them to
+    pub const MIN_POSTER_CARD_WIDTH: f32 = 100.0;
+    pub const MIN_CHANNEL_CARD_WIDTH: f32 = 240.0;
+
+    /// Width of a grid card, plus whether the screen calls for the compact
+    /// mobile styling.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct CardSize {
+        pub width: f32,
+        pub is_mobile: bool,
+    }
+
+    impl CardSize {
+        /// Movie/series poster card filling `column_width`, or the responsive
+        /// width when columns are automatic.
+        pub fn poster(screen_width: f32, column_width: Option<f32>) -> Self {
+            Self {
+                width: column_width.map_or_else(
+                    || card_width(screen_width),
+                    |w| (w - super::spacing::SM).max(MIN_POSTER_CARD_WIDTH),
+                ),
+                is_mobile: is_mobile(screen_width),
+            }
+        }
+
+        /// Channel card filling `column_width`, or the responsive width when
+        /// columns are automatic.
+        pub fn channel(screen_width: f32, column_width: Option<f32>) -> Self {
+            let is_mobile = is_mobile(screen_width);
+            let auto = if is_mobile { (screen_width - 40.0).max(300.0) } else { 360.0 };
+            Self {
+                width: column_width.map_or(auto, |w| (w - super::spacing::MD).max(MIN_CHANNEL_CARD_WIDTH)),
+                is_mobile,
+            }
+        }
+    }
+
+    /// Width of each of `columns` equal columns across `available_width`,
+    /// `gap` apart. None when columns are automatic (0).
+    pub fn column_width(available_width: f32, columns: u32, gap: f32) -> Option<f32> {
+        (columns > 0).then(|| {
+            ((available_width - gap * (columns - 1) as f32) / columns as f32).floor()
+        })
+    }
+
     /// Get card width for touch mode (Steam Deck, tablets)
     pub fn card_width_touch(screen_width: f32, screen_height: f32) -> f32 {
         if is_steam_deck(screen_width, screen_height) {
@@ -553,7 +619,7 @@ pub mod dimensions {
 
     /// Get button height for current mode
     pub fn button_height(screen_width: f32, screen_height: f32) -> f32 {
-        if is_steam_deck(screen_width, screen_height) || is_tablet(screen_width) {
+        if is_touch_mode(screen_width, screen_height) {
             STEAM_DECK_BUTTON_HEIGHT
         } else if is_mobile(screen_width) {
             48.0
@@ -564,8 +630,8 @@ pub mod dimensions {
 
     /// Get minimum touch target size
     pub fn touch_target(screen_width: f32, screen_height: f32) -> f32 {
-        if is_steam_deck(screen_width, screen_height) || is_tablet(screen_width) {
-            STEAM_DECK_TOUCH_TARGET
+        if is_touch_mode(screen_width, screen_height) {
+            custom_touch_target().unwrap_or(STEAM_DECK_TOUCH_TARGET)
         } else if is_mobile(screen_width) {
             48.0
         } else {
@@ -575,7 +641,7 @@ pub mod dimensions {
 
     /// Get category button height
     pub fn category_button_height(screen_width: f32, screen_height: f32) -> f32 {
-        if is_steam_deck(screen_width, screen_height) || is_tablet(screen_width) {
+        if is_touch_mode(screen_width, screen_height) {
             STEAM_DECK_CATEGORY_BUTTON_HEIGHT
         } else {
             CATEGORY_BUTTON_HEIGHT
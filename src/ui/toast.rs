@@ -0,0 +1,142 @@
+//! Toast notifications - short messages stacked in the bottom-left corner.
+//!
+//! Actions call [`Toasts::success`], [`Toasts::error`] etc. and carry on;
+//! the app draws the queue once per frame and each toast disappears on its
+//! own after a few seconds (or when clicked).
+
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+
+/// How long a toast stays up. Errors stay longer so they can be read.
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+const ERROR_TOAST_DURATION: Duration = Duration::from_secs(8);
+
+/// Fade-out at the end of a toast's life.
+const FADE_OUT: Duration = Duration::from_millis(300);
+
+/// Older toasts are dropped once this many are showing.
+const MAX_VISIBLE: usize = 4;
+
+/// Severity of a toast, picks its accent color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    fn color(self) -> egui::Color32 {
+        match self {
+            ToastLevel::Info => egui::Color32::from_rgb(100, 160, 255),
+            ToastLevel::Success => egui::Color32::from_rgb(52, 199, 89),
+            ToastLevel::Warning => egui::Color32::from_rgb(255, 180, 0),
+            ToastLevel::Error => egui::Color32::from_rgb(255, 99, 88),
+        }
+    }
+
+    fn duration(self) -> Duration {
+        match self {
+            ToastLevel::Error => ERROR_TOAST_DURATION,
+            _ => TOAST_DURATION,
+        }
+    }
+}
+
+struct Toast {
+    message: String,
+    level: ToastLevel,
+    shown_at: Instant,
+}
+
+/// Queue of toasts currently on screen.
+#[derive(Default)]
+pub struct Toasts {
+    toasts: Vec<Toast>,
+}
+
+impl Toasts {
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.push(ToastLevel::Info, message.into());
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) {
+        self.push(ToastLevel::Success, message.into());
+    }
+
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.push(ToastLevel::Warning, message.into());
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.push(ToastLevel::Error, message.into());
+    }
+
+    fn push(&mut self, level: ToastLevel, message: String) {
+        // Repeating the same message just restarts its timer
+        self.toasts.retain(|t| t.message != message);
+        self.toasts.push(Toast { message, level, shown_at: Instant::now() });
+        if self.toasts.len() > MAX_VISIBLE {
+            self.toasts.remove(0);
+        }
+    }
+
+    /// Draws the queued toasts, oldest on top, and drops expired ones.
+    pub fn show(&mut self, ctx: &egui::Context) {
+        self.toasts.retain(|t| t.shown_at.elapsed() < t.level.duration());
+        if self.toasts.is_empty() {
+            return;
+        }
+
+        let mut dismissed = None;
+        egui::Area::new(egui::Id::new("toasts"))
+            .anchor(egui::Align2::LEFT_BOTTOM, [20.0, -20.0])
+            .order(egui::Order::Foreground)
+            .interactable(true)
+            .show(ctx, |ui| {
+                ui.spacing_mut().item_spacing.y = 8.0;
+                for (index, toast) in self.toasts.iter().enumerate() {
+                    let remaining = toast.level.duration().saturating_sub(toast.shown_at.elapsed());
+                    let opacity = (remaining.as_secs_f32() / FADE_OUT.as_secs_f32()).min(1.0);
+                    let accent = toast.level.color();
+
+                    let frame = egui::Frame::none()
+                        .fill(egui::Color32::from_rgb(32, 32, 32).gamma_multiply(opacity))
+                        .stroke(egui::Stroke::new(1.0, accent.gamma_multiply(0.6 * opacity)))
+                        .rounding(egui::Rounding::same(8.0))
+                        .inner_margin(egui::Margin::symmetric(14.0, 10.0))
+                        .show(ui, |ui| {
+                            ui.set_max_width(360.0);
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new("●").color(accent.gamma_multiply(opacity)));
+                                ui.add(egui::Label::new(egui::RichText::new(&toast.message)
+                                    .color(egui::Color32::WHITE.gamma_multiply(opacity)))
+                                    .wrap());
+                            });
+                        });
+
+                    let response = ui.interact(frame.response.rect, ui.id().with(("toast", index)), egui::Sense::click());
+                    if response.on_hover_cursor(egui::CursorIcon::PointingHand).clicked() {
+                        dismissed = Some(index);
+                    }
+                }
+            });
+
+        if let Some(index) = dismissed {
+            self.toasts.remove(index);
+        }
+
+        // Wake up for the next fade or expiry even if nothing else happens
+        let next = self.toasts.iter()
+            .map(|t| t.level.duration().saturating_sub(t.shown_at.elapsed()))
+            .min()
+            .unwrap_or_default();
+        if next <= FADE_OUT {
+            ctx.request_repaint();
+        } else {
+            ctx.request_repaint_after(next - FADE_OUT);
+        }
+    }
+}
@@ -149,9 +149,7 @@ impl XmltvParser {
         use std::time::Duration;
 
         // Create client with timeout
-        let client = reqwest::blocking::Client::builder()
-            .timeout(Duration::from_secs(60))
-            .user_agent("IPTV-Player/1.0")
+        let client = crate::api::http::provider_client_builder()
             .build()
             .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
 
@@ -160,7 +158,7 @@ impl XmltvParser {
         for attempt in 0..3 {
             if attempt > 0 {
                 let delay = Duration::from_secs(2u64.pow(attempt - 1)); // 0s, 2s, 4s
-                eprintln!("[EPG] Retry attempt {} after {:?} delay", attempt + 1, delay);
+                log::warn!("[EPG] Retry attempt {} after {:?} delay", attempt + 1, delay);
                 std::thread::sleep(delay);
             }
 
@@ -169,7 +167,7 @@ impl XmltvParser {
                     // Check status code
                     if !response.status().is_success() {
                         last_error = format!("HTTP {}", response.status());
-                        eprintln!("[EPG] HTTP error: {}", last_error);
+                        log::warn!("[EPG] HTTP error: {}", last_error);
                         continue;
                     }
 
@@ -182,7 +180,7 @@ impl XmltvParser {
                             Ok(_) => decompressed,
                             Err(e) => {
                                 last_error = format!("Failed to decompress gzip: {}", e);
-                                eprintln!("[EPG] {}", last_error);
+                                log::warn!("[EPG] {}", last_error);
                                 continue;
                             }
                         }
@@ -191,7 +189,7 @@ impl XmltvParser {
                             Ok(text) => text,
                             Err(e) => {
                                 last_error = format!("Failed to read response: {}", e);
-                                eprintln!("[EPG] {}", last_error);
+                                log::warn!("[EPG] {}", last_error);
                                 continue;
                             }
                         }
@@ -202,7 +200,7 @@ impl XmltvParser {
                 }
                 Err(e) => {
                     last_error = format!("Network error: {}", e);
-                    eprintln!("[EPG] {}", last_error);
+                    log::warn!("[EPG] {}", last_error);
                 }
             }
         }